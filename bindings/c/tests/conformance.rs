@@ -25,7 +25,8 @@
 //!   assert "an error surfaced", not its exact shape.)
 //!
 //! The `flagd/` suites run unconditionally: `datalogic-c`'s Cargo.toml
-//! hard-enables the `flagd` feature on the core crate.
+//! hard-enables the `flagd` feature on the core crate. The `regex`
+//! feature is not enabled, so the `string/regex_*` suites are skipped.
 
 use datalogic_c::*;
 use serde_json::{Value, json};
@@ -173,6 +174,10 @@ fn conformance_suites_pass_through_c_abi() {
     let mut failures: Vec<String> = Vec::new();
 
     for suite_file in &index {
+        if suite_file.starts_with("string/regex_") {
+            println!("WARNING: skipping {suite_file} (requires the `regex` feature)");
+            continue;
+        }
         let path = format!("{SUITES_ROOT}/{suite_file}");
         if !std::path::Path::new(&path).exists() {
             // Mirror the core runner: a stale index entry is a warning,
@@ -24,6 +24,7 @@ import (
 	"os"
 	"path/filepath"
 	"reflect"
+	"strings"
 	"testing"
 )
 
@@ -72,6 +73,11 @@ func TestJSONLogicConformance(t *testing.T) {
 
 	totalPassed, totalFailed := 0, 0
 	for _, suiteFile := range index {
+		// The C library doesn't enable the core's `regex` feature.
+		if strings.HasPrefix(suiteFile, "string/regex_") {
+			t.Logf("WARNING: skipping %s (requires the `regex` feature)", suiteFile)
+			continue
+		}
 		path := filepath.Join(suitesRoot, suiteFile)
 		if _, err := os.Stat(path); err != nil {
 			// Mirror the core runner: a stale index entry is a warning,
@@ -122,6 +122,8 @@ func (b *EngineBuilder) Templating(on bool) *EngineBuilder {
 //   - numeric_coercion: object with bool keys empty_string_to_zero,
 //     null_to_zero, bool_to_number, reject_non_numeric
 //   - max_recursion_depth: integer >= 1
+//   - random_seed: integer >= 0, or null
+//   - deterministic_mode: bool
 //
 // Unknown keys, unknown enum strings, and type mismatches are rejected
 // with a *Error (Type "ConfigurationError") so typos fail loudly
@@ -43,6 +43,16 @@ def _collect_cases():
 
     params = []
     for suite_name in json.loads(index_path.read_text()):
+        if suite_name.startswith("string/regex_"):
+            # The extension doesn't enable the core's `regex` feature.
+            params.append(
+                pytest.param(
+                    None,
+                    id=suite_name,
+                    marks=pytest.mark.skip(reason="requires the `regex` feature"),
+                )
+            )
+            continue
         suite_path = SUITES_DIR / suite_name
         if not suite_path.exists():
             # The core runner warns and moves on; a skip is our visible
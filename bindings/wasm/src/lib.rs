@@ -65,7 +65,7 @@ fn input_err_to_json(stage: &str, message: impl std::fmt::Display) -> String {
 ///   `(in operator: ...)` suffix when the failing operator is known.
 /// - own properties: every field of the structured error JSON (`type`,
 ///   variant extras like `variable` / `thrown` / `index` / `length`,
-///   optional `operator` / `node_ids` / `stage`) attached via
+///   optional `operator` / `node_ids` / `data_path` / `stage`) attached via
 ///   `Reflect::set`.
 /// - `detailJson`: the raw JSON string that releases up to 5.0.0 used as
 ///   the rejection value, kept so existing consumers migrate with one
@@ -221,6 +221,9 @@ pub fn evaluate(logic: &str, data: &str, templating: bool) -> Result<String, JsV
 /// JSON string of the form `{ result, steps, expression_tree, error? }`. On
 /// runtime failure the `error` field carries the merged structured `Error`
 /// JSON (`type`, `message`, variant extras, optional `operator`/`path`).
+/// Each `expression_tree` node carries a `pointer`, the JSON pointer of its
+/// sub-expression within `logic`, so a step's `node_id` maps back to the
+/// submitted rule.
 #[wasm_bindgen(js_name = evaluateWithTrace)]
 pub fn evaluate_with_trace(logic: &str, data: &str, templating: bool) -> Result<String, JsValue> {
     let engine = make_engine(templating, None);
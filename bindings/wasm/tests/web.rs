@@ -5,7 +5,7 @@
 // what lets CI's `wasm-pack test --node` actually execute them. With the
 // browser configuration set, the node runner skips the whole suite.
 
-use datalogic_wasm::{CompiledRule, DataHandle, Engine, evaluate};
+use datalogic_wasm::{CompiledRule, DataHandle, Engine, evaluate, evaluate_with_trace};
 use js_sys::{Array, Function, Object, Reflect};
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_test::*;
@@ -153,6 +153,27 @@ fn test_input_error_has_stage_property() {
     assert_eq!(stage.as_string().as_deref(), Some("parse-data"));
 }
 
+#[wasm_bindgen_test]
+fn test_evaluate_with_trace_nodes_carry_logic_pointers() {
+    let logic = r#"{"and": [true, {"==": [{"var": "x"}, {"+": [1, 1]}]}]}"#;
+    let trace = evaluate_with_trace(logic, r#"{"x": 2}"#, false).unwrap();
+    let trace: serde_json::Value = serde_json::from_str(&trace).unwrap();
+    let logic: serde_json::Value = serde_json::from_str(logic).unwrap();
+
+    let root = &trace["expression_tree"];
+    assert_eq!(root["pointer"], "");
+    let eq = &root["children"][0];
+    assert_eq!(eq["pointer"], "/and/1");
+    let pointers: Vec<&str> = eq["children"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|c| c["pointer"].as_str().unwrap())
+        .collect();
+    assert_eq!(pointers, ["/and/1/==/0", "/and/1/==/1"]);
+    assert_eq!(logic.pointer(pointers[1]).unwrap()["+"][0], 1);
+}
+
 // =============== Custom operator tests ===============
 
 /// Build an options bag `{ templating, customOperators: { name: fn } }`
@@ -28,12 +28,10 @@ fn main() {
     #[cfg(feature = "error-handling")]
     {
         // `throw` wraps a string as `{type: <string>}`. To carry richer
-        // structured data, look it up from the input via `val`.
+        // structured data, write the object inline (two or more keys) or
+        // look it up from the input via `val`.
         let err = engine
-            .eval_str(
-                r#"{"throw": {"val": "err"}}"#,
-                r#"{"err": {"type": "NOT_FOUND", "user_id": 42}}"#,
-            )
+            .eval_str(r#"{"throw": {"type": "NOT_FOUND", "user_id": 42}}"#, "null")
             .unwrap_err();
         println!("\n[2] thrown payload");
         println!("    {:?}", err.thrown_value().unwrap());
@@ -12,11 +12,24 @@ pub(crate) enum ContextFrame<'a> {
         index: usize,
         key: &'a str,
     },
+    /// `index` is the position of `current` in the reduced input. A bare
+    /// `index` lookup that misses on `current` reads it, and error data
+    /// paths use it. `key` is set when the input is an object, and error
+    /// data paths use it instead.
     Reduce {
         current: &'a DataValue<'a>,
         accumulator: &'a DataValue<'a>,
+        index: usize,
+        key: Option<&'a str>,
     },
     Data(&'a DataValue<'a>),
+    /// A `try` catch arm: `data` is what the arm's lookups read, `error`
+    /// the caught error object reachable as `{"var": "error"}`.
+    #[cfg(feature = "error-handling")]
+    Caught {
+        data: &'a DataValue<'a>,
+        error: &'a DataValue<'a>,
+    },
 }
 
 impl<'a> ContextFrame<'a> {
@@ -25,13 +38,30 @@ impl<'a> ContextFrame<'a> {
         match self {
             Self::Indexed { data, .. } | Self::Keyed { data, .. } | Self::Data(data) => data,
             Self::Reduce { current, .. } => current,
+            #[cfg(feature = "error-handling")]
+            Self::Caught { data, .. } => data,
         }
     }
 
     #[inline]
     pub(crate) fn get_index(&self) -> Option<usize> {
         match self {
-            Self::Indexed { index, .. } | Self::Keyed { index, .. } => Some(*index),
+            Self::Indexed { index, .. }
+            | Self::Keyed { index, .. }
+            | Self::Reduce { index, .. } => Some(*index),
+            _ => None,
+        }
+    }
+
+    /// Position of the item this frame iterates over, for every iteration
+    /// frame including `reduce`. Error data paths use it for frames
+    /// without a [`Self::get_key`].
+    #[inline]
+    pub(crate) fn iteration_index(&self) -> Option<usize> {
+        match self {
+            Self::Indexed { index, .. }
+            | Self::Keyed { index, .. }
+            | Self::Reduce { index, .. } => Some(*index),
             _ => None,
         }
     }
@@ -40,6 +70,7 @@ impl<'a> ContextFrame<'a> {
     pub(crate) fn get_key(&self) -> Option<&'a str> {
         match self {
             Self::Keyed { key, .. } => Some(key),
+            Self::Reduce { key, .. } => *key,
             _ => None,
         }
     }
@@ -59,4 +90,13 @@ impl<'a> ContextFrame<'a> {
             _ => None,
         }
     }
+
+    #[cfg(feature = "error-handling")]
+    #[inline]
+    pub(crate) fn get_caught_error(&self) -> Option<&'a DataValue<'a>> {
+        match self {
+            Self::Caught { error, .. } => Some(error),
+            _ => None,
+        }
+    }
 }
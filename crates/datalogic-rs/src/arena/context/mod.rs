@@ -25,6 +25,7 @@ pub(crate) use frame::ContextFrame;
 pub(crate) use reference::ContextRef;
 
 use super::value::DataValue;
+use crate::error::ErrorPath;
 #[cfg(all(test, feature = "serde_json"))]
 use bumpalo::Bump;
 use smallvec::SmallVec;
@@ -57,6 +58,10 @@ pub(crate) struct ContextStack<'a> {
     parents: SmallVec<[ContextFrame<'a>; INLINE_FRAMES]>,
     /// Breadcrumb of `CompiledNode::id`s accumulated as errors unwind.
     error_path: Vec<u32>,
+    /// Iteration positions of the frames live at the failure site,
+    /// outermost first. Captured when the first breadcrumb step is pushed
+    /// — by the time the error reaches a boundary those frames are gone.
+    error_data_path: Vec<u32>,
     /// Per-evaluation CSE memo slots, indexed by `CseData::slot`. Fully
     /// lazy: starts empty and grows on the first [`Self::fill_cse_slot`],
     /// so evaluations of rules without CSE nodes never touch it (eager
@@ -66,6 +71,17 @@ pub(crate) struct ContextStack<'a> {
     /// length are simply misses. `Ok`-results only; errors are never
     /// cached.
     cse_slots: SmallVec<[Option<&'a DataValue<'a>>; 8]>,
+    /// Steps spent so far against `EvaluationConfig::step_limit`. Only
+    /// counted when a limit is configured; callers skip
+    /// [`Self::charge_steps`] entirely otherwise, so unlimited evaluations
+    /// pay nothing per node.
+    steps: u64,
+    /// SplitMix64 state for `random` calls that draw on the engine seed.
+    /// Seeded from `EvaluationConfig::random_seed` on the first such call
+    /// and advanced on every one, so a seeded evaluation yields the same
+    /// sequence every run without repeating a value per call.
+    #[cfg(feature = "ext-math")]
+    random_state: Option<u64>,
     /// Depth of enclosing `try` *protected* arms (every arm of a multi-arg
     /// `try` except the final catch arm). While > 0, any error raised is
     /// guaranteed to be consumed by the nearest enclosing `try`'s arm loop
@@ -103,7 +119,11 @@ impl<'a> ContextStack<'a> {
             top: None,
             parents: SmallVec::new(),
             error_path: Vec::new(),
+            error_data_path: Vec::new(),
             cse_slots: SmallVec::new(),
+            steps: 0,
+            #[cfg(feature = "ext-math")]
+            random_state: None,
             #[cfg(feature = "error-handling")]
             catch_depth: 0,
             #[cfg(feature = "error-handling")]
@@ -124,6 +144,59 @@ impl<'a> ContextStack<'a> {
         Self::new(arena.alloc(av))
     }
 
+    /// A stack over the same scope chain (root and frames) with fresh
+    /// per-evaluation state: no error path, CSE memos, catch scope, or
+    /// tracer. The starting point for a `parallel` `map` worker, which
+    /// resolves `var` / `val` lookups against the same scopes from another
+    /// thread. The step count carries over, so a worker trips the budget
+    /// no later than the sequential loop would at the same item.
+    ///
+    /// The fork may live for a shorter `'w`, so the worker can pair it with
+    /// its own, shorter-lived arena.
+    #[cfg(feature = "parallel")]
+    pub(crate) fn fork<'w>(&self) -> ContextStack<'w>
+    where
+        'a: 'w,
+    {
+        let mut forked = ContextStack::new(self.root);
+        forked.top = self.top;
+        forked.parents = self.parents.iter().copied().collect();
+        forked.steps = self.steps;
+        forked
+    }
+
+    /// Steps spent so far (see [`Self::charge_steps`]).
+    #[cfg(feature = "parallel")]
+    #[inline]
+    pub(crate) fn steps(&self) -> u64 {
+        self.steps
+    }
+
+    /// Spend `n` steps of the evaluation's budget, failing with
+    /// [`crate::ErrorKind::StepLimitExceeded`] once the running total
+    /// passes `limit`. Call only when a limit is configured.
+    #[inline(always)]
+    pub(crate) fn charge_steps(&mut self, n: u64, limit: u64) -> crate::Result<()> {
+        self.steps = self.steps.saturating_add(n);
+        if self.steps > limit {
+            return Err(crate::Error::step_limit_exceeded(limit));
+        }
+        Ok(())
+    }
+
+    /// Next state of the evaluation's seeded `random` stream, starting the
+    /// stream at `seed` on first use. The caller mixes the returned state
+    /// into output bits; stepping by SplitMix64's increment makes the
+    /// stream's first draw the same as a one-off draw from `seed`.
+    #[cfg(feature = "ext-math")]
+    #[inline]
+    pub(crate) fn next_random_state(&mut self, seed: u64) -> u64 {
+        let state = self.random_state.get_or_insert(seed);
+        let current = *state;
+        *state = current.wrapping_add(crate::operators::random::SPLITMIX64_GAMMA);
+        current
+    }
+
     /// Move a tracer into this stack. The trace driver pulls it back out
     /// via [`Self::detach_tracer`] after dispatch completes.
     #[cfg(feature = "trace")]
@@ -172,6 +245,17 @@ impl<'a> ContextStack<'a> {
         }
     }
 
+    /// Start a timer for the node about to run, when the attached tracer
+    /// records timings. `None` without a tracer or with timings off.
+    #[cfg(feature = "trace")]
+    #[inline]
+    pub(crate) fn trace_timer(&self) -> Option<std::time::Instant> {
+        self.tracer
+            .as_ref()
+            .filter(|t| t.timings())
+            .map(|_| std::time::Instant::now())
+    }
+
     /// Snapshot the current frame's data as an owned `Value`. Used by the
     /// arena dispatcher before recursing into a child, so the trace step
     /// can record the context that operator saw.
@@ -182,21 +266,29 @@ impl<'a> ContextStack<'a> {
 
     /// Record the result of a node into the attached tracer. No-op if no
     /// tracer is attached. Callers gate on [`has_tracer`] first to skip the
-    /// `Value::clone()` when not tracing.
+    /// `Value::clone()` when not tracing. `started` (from
+    /// [`Self::trace_timer`]) stamps the step with its elapsed time.
     #[cfg(all(feature = "trace", feature = "serde_json"))]
     pub(crate) fn record_node_result(
         &mut self,
         node_id: u32,
         ctx_data: serde_json::Value,
         result: &crate::Result<&'a crate::arena::DataValue<'a>>,
+        started: Option<std::time::Instant>,
     ) {
-        self.with_tracer(|collector| match result {
-            Ok(av) => {
-                let v = crate::arena::data_to_value(av);
-                collector.record_step(node_id, ctx_data, v);
+        self.with_tracer(|collector| {
+            if let Some(started) = started {
+                let nanos = started.elapsed().as_nanos();
+                collector.set_elapsed(u64::try_from(nanos).unwrap_or(u64::MAX));
             }
-            Err(e) => {
-                collector.record_error(node_id, ctx_data, e.to_string());
+            match result {
+                Ok(av) => {
+                    let v = crate::arena::data_to_value(av);
+                    collector.record_step(node_id, ctx_data, v);
+                }
+                Err(e) => {
+                    collector.record_error(node_id, ctx_data, e.to_string());
+                }
             }
         });
     }
@@ -263,9 +355,9 @@ impl<'a> ContextStack<'a> {
 
     /// Walk `level` frames up from the current context. Negative/positive
     /// magnitudes treated as absolute (matches `ContextStack::get_at_level`).
-    /// Index arithmetic is unchanged from the single-`Vec` layout: the
-    /// conceptual frame list is `parents ++ [top]`, so `parents.len()` is
-    /// the top frame's index.
+    /// The conceptual frame list is `parents ++ [top]`, so the top frame sits
+    /// at index `depth - 1` and every level above it is one of `parents`;
+    /// levels past the outermost frame land on the root.
     pub(crate) fn get_at_level(&self, level: isize) -> Option<ContextRef<'a, '_>> {
         let levels_up = level.unsigned_abs();
         if levels_up == 0 {
@@ -275,12 +367,9 @@ impl<'a> ContextStack<'a> {
         if levels_up >= frame_count {
             return Some(ContextRef::Root(self.root));
         }
-        let target_index = frame_count - levels_up;
-        if target_index == self.parents.len() {
-            self.top.as_ref().map(ContextRef::Frame)
-        } else {
-            self.parents.get(target_index).map(ContextRef::Frame)
-        }
+        self.parents
+            .get(frame_count - 1 - levels_up)
+            .map(ContextRef::Frame)
     }
 
     // ----- frame mutation ---------------------------------------------------
@@ -309,13 +398,36 @@ impl<'a> ContextStack<'a> {
     }
 
     #[inline]
-    fn push_reduce(&mut self, current: &'a DataValue<'a>, accumulator: &'a DataValue<'a>) {
+    fn push_reduce(
+        &mut self,
+        current: &'a DataValue<'a>,
+        accumulator: &'a DataValue<'a>,
+        index: usize,
+        key: Option<&'a str>,
+    ) {
         self.push_frame(ContextFrame::Reduce {
             current,
             accumulator,
+            index,
+            key,
         });
     }
 
+    /// Push a `try` catch-arm frame (see [`ContextFrame::Caught`]).
+    #[cfg(feature = "error-handling")]
+    #[inline]
+    pub(crate) fn push_caught(&mut self, data: &'a DataValue<'a>, error: &'a DataValue<'a>) {
+        self.push_frame(ContextFrame::Caught { data, error });
+    }
+
+    /// The error object of the enclosing catch arm, when the current frame
+    /// is one.
+    #[cfg(feature = "error-handling")]
+    #[inline]
+    pub(crate) fn caught_error(&self) -> Option<&'a DataValue<'a>> {
+        self.top.as_ref()?.get_caught_error()
+    }
+
     #[inline]
     fn replace_top_data(&mut self, data: &'a DataValue<'a>, index: usize) {
         if let Some(frame) = self.top.as_mut() {
@@ -331,11 +443,19 @@ impl<'a> ContextStack<'a> {
     }
 
     #[inline]
-    fn replace_reduce_data(&mut self, current: &'a DataValue<'a>, accumulator: &'a DataValue<'a>) {
+    fn replace_reduce_data(
+        &mut self,
+        current: &'a DataValue<'a>,
+        accumulator: &'a DataValue<'a>,
+        index: usize,
+        key: Option<&'a str>,
+    ) {
         if let Some(frame) = self.top.as_mut() {
             *frame = ContextFrame::Reduce {
                 current,
                 accumulator,
+                index,
+                key,
             };
         }
     }
@@ -356,9 +476,26 @@ impl<'a> ContextStack<'a> {
     #[cold]
     #[inline(never)]
     pub(crate) fn push_error_step(&mut self, id: u32) {
+        if self.error_path.is_empty() {
+            self.capture_error_data_path();
+        }
         self.error_path.push(id);
     }
 
+    /// Record the iteration position of every live frame: the key for
+    /// object iteration, the index otherwise. Runs at the innermost
+    /// failing node, while the frames are still pushed.
+    fn capture_error_data_path(&mut self) {
+        self.error_data_path.clear();
+        for frame in self.parents.iter().chain(self.top.iter()) {
+            if let Some(key) = frame.get_key() {
+                ErrorPath::push_key(&mut self.error_data_path, key);
+            } else if let Some(index) = frame.iteration_index() {
+                ErrorPath::push_index(&mut self.error_data_path, index);
+            }
+        }
+    }
+
     #[cfg(feature = "error-handling")]
     #[inline]
     pub(crate) fn error_path_len(&self) -> usize {
@@ -371,12 +508,29 @@ impl<'a> ContextStack<'a> {
         self.error_path.truncate(len);
     }
 
-    /// Move the breadcrumb out of the stack, leaving an empty `Vec` behind.
-    /// Used by the public `evaluate*` methods to attach the path to the
+    /// Append a breadcrumb collected on a [`Self::fork`]ed stack, as if
+    /// its error had unwound through this one. The fork's data path wins
+    /// when this stack has no error in flight: it was captured with the
+    /// worker's frames, which include the ones copied from here.
+    #[cfg(feature = "parallel")]
+    #[inline]
+    pub(crate) fn extend_error_path(&mut self, path: ErrorPath) {
+        if self.error_path.is_empty() {
+            self.error_data_path.clear();
+            self.error_data_path.extend_from_slice(path.data_words());
+        }
+        self.error_path.extend_from_slice(path.as_slice());
+    }
+
+    /// Move the breadcrumb and data path out of the stack, leaving it
+    /// empty. Used by the public `evaluate*` methods to attach both to the
     /// returned [`crate::Error`] on failure.
     #[inline]
-    pub(crate) fn take_error_path(&mut self) -> Vec<u32> {
-        std::mem::take(&mut self.error_path)
+    pub(crate) fn take_error_path(&mut self) -> ErrorPath {
+        let ids = std::mem::take(&mut self.error_path);
+        let path = ErrorPath::new(ids, &self.error_data_path);
+        self.error_data_path.clear();
+        path
     }
 
     // ----- deferred thrown-payload channel (see field docs) ------------------
@@ -483,11 +637,14 @@ impl<'g, 'a> IterGuard<'g, 'a> {
         &mut self,
         current: &'a DataValue<'a>,
         accumulator: &'a DataValue<'a>,
+        index: usize,
+        key: Option<&'a str>,
     ) {
         if self.pushed {
-            self.ctx.replace_reduce_data(current, accumulator);
+            self.ctx
+                .replace_reduce_data(current, accumulator, index, key);
         } else {
-            self.ctx.push_reduce(current, accumulator);
+            self.ctx.push_reduce(current, accumulator, index, key);
             self.pushed = true;
         }
     }
@@ -560,7 +717,7 @@ mod tests {
 
         let cur: &DataValue = arena.alloc(DataValue::Number(datavalue::NumberValue::from_i64(1)));
         let acc: &DataValue = arena.alloc(DataValue::Number(datavalue::NumberValue::from_i64(0)));
-        ctx.push_reduce(cur, acc);
+        ctx.push_reduce(cur, acc, 0, None);
         assert_eq!(ctx.depth(), 1);
 
         if let ContextRef::Frame(f) = ctx.current() {
@@ -584,9 +741,11 @@ mod tests {
         assert_eq!(ctx.depth(), 2);
 
         // Level 0 = current (b)
-        assert!(ctx.get_at_level(0).and_then(|r| r.frame_data()).is_some());
+        let level0 = ctx.get_at_level(0).and_then(|r| r.frame_data());
+        assert_eq!(level0.and_then(|d| d.as_i64()), Some(20));
         // Level 1 = parent (a)
-        assert!(ctx.get_at_level(1).and_then(|r| r.frame_data()).is_some());
+        let level1 = ctx.get_at_level(1).and_then(|r| r.frame_data());
+        assert_eq!(level1.and_then(|d| d.as_i64()), Some(10));
         // Level 2 = root
         assert!(ctx.get_at_level(2).and_then(|r| r.root_data()).is_some());
         // Level 5 (overflow) = root
@@ -639,11 +798,11 @@ mod tests {
         assert_eq!(ctx.depth(), depth);
         assert_eq!(ctx.current().get_index(), Some(depth - 1));
 
-        // Walking `levels_up` lands on frame index `depth - levels_up`
-        // (existing single-`Vec` arithmetic), and past the bottom is root.
+        // Walking `levels_up` lands on frame index `depth - 1 - levels_up`
+        // (the top frame is `depth - 1`), and past the bottom is root.
         for levels_up in 1..depth {
             let r = ctx.get_at_level(levels_up as isize).expect("in range");
-            assert_eq!(r.get_index(), Some(depth - levels_up));
+            assert_eq!(r.get_index(), Some(depth - 1 - levels_up));
         }
         assert!(
             ctx.get_at_level(depth as isize)
@@ -717,10 +876,13 @@ mod tests {
         let acc: &DataValue = arena.alloc(DataValue::Number(datavalue::NumberValue::from_i64(0)));
         {
             let mut g = IterGuard::new(&mut ctx);
-            g.step_reduce(cur, acc);
+            g.step_reduce(cur, acc, 0, None);
             assert_eq!(g.stack().depth(), 1);
-            g.step_reduce(acc, cur); // replace, not push
+            assert_eq!(g.stack().current().get_key(), None);
+            g.step_reduce(acc, cur, 1, Some("k2")); // replace, not push
             assert_eq!(g.stack().depth(), 1);
+            assert_eq!(g.stack().current().get_key(), Some("k2"));
+            assert_eq!(g.stack().current().get_index(), Some(1));
         }
         assert_eq!(ctx.depth(), 0);
     }
@@ -739,7 +901,8 @@ mod tests {
 
         ctx.truncate_error_path(1);
         let p = ctx.take_error_path();
-        assert_eq!(p, vec![1]);
+        assert_eq!(p.as_slice(), &[1]);
+        assert!(p.data_path().is_empty());
     }
 
     #[cfg(feature = "error-handling")]
@@ -21,8 +21,14 @@ pub(crate) use context::ContextStack;
 pub(crate) use context::IterGuard;
 pub(crate) use util::bvec;
 pub use value::DataValue;
+#[cfg(feature = "parallel")]
+pub(crate) use value::copy_to_arena;
+#[cfg(feature = "msgpack")]
+pub(crate) use value::msgpack_to_data;
+#[cfg(feature = "ext-control")]
+pub(crate) use value::truthy_js_arena;
 pub(crate) use value::{
-    coerce_to_number_cfg, data_to_str, truthy_arena, try_coerce_to_integer_cfg,
+    coerce_to_number_cfg, data_to_str, serialize_to_data, truthy_arena, try_coerce_to_integer_cfg,
 };
 #[cfg(feature = "serde_json")]
 pub(crate) use value::{data_to_value, value_to_data};
@@ -3,12 +3,16 @@
 //!
 //! `DataValue` is just `datavalue::DataValue` — the two crates share a
 //! single bump-allocated value type. Helpers (truthiness, coercion,
-//! traversal, lookup, conversion at the serde_json boundary) stay in
-//! datalogic-rs because they're tied to engine config / op semantics.
+//! traversal, lookup, conversion at the serde_json boundary, serialising
+//! `T: Serialize` inputs) stay in datalogic-rs because they're tied to
+//! engine config / op semantics.
 
 mod coercion;
 mod conversion;
 mod lookup;
+#[cfg(feature = "msgpack")]
+mod msgpack;
+mod serialize;
 mod strings;
 mod traversal;
 
@@ -20,13 +24,84 @@ pub(crate) use conversion::data_to_value;
 #[cfg(feature = "serde_json")]
 pub(crate) use conversion::value_to_data;
 pub(crate) use lookup::{object_lookup_field, object_lookup_field_hinted};
+#[cfg(feature = "msgpack")]
+pub(crate) use msgpack::msgpack_to_data;
+pub(crate) use serialize::serialize_to_data;
 pub(crate) use strings::{data_to_str, truthy_arena};
-pub(crate) use traversal::apply_path_element;
 pub(crate) use traversal::{
-    access_path_str_ref, path_exists_segments, path_exists_str, traverse_segments,
+    access_path_str_ref, path_exists_str, path_exists_wildcard_segments, traverse_segments,
+    traverse_wildcard_segments,
 };
+pub(crate) use traversal::{apply_path_element, parse_index_from_end};
 
 pub use datavalue::DataValue;
+use datavalue::NumberValue;
+
+/// Deep-copy `v` into `arena`, strings included, so the copy doesn't
+/// borrow from wherever `v` was allocated. Used to move `parallel` `map`
+/// results out of the per-worker arenas before they're dropped.
+#[cfg(feature = "parallel")]
+pub(crate) fn copy_to_arena<'a>(v: &DataValue<'_>, arena: &'a bumpalo::Bump) -> DataValue<'a> {
+    match v {
+        DataValue::Null => DataValue::Null,
+        DataValue::Bool(b) => DataValue::Bool(*b),
+        DataValue::Number(n) => DataValue::Number(*n),
+        DataValue::String(s) => DataValue::String(arena.alloc_str(s)),
+        DataValue::Array(items) => DataValue::Array(
+            arena.alloc_slice_fill_with(items.len(), |i| copy_to_arena(&items[i], arena)),
+        ),
+        DataValue::Object(pairs) => {
+            DataValue::Object(arena.alloc_slice_fill_with(pairs.len(), |i| {
+                let (k, v) = &pairs[i];
+                (&*arena.alloc_str(k), copy_to_arena(v, arena))
+            }))
+        }
+        #[cfg(feature = "datetime")]
+        DataValue::DateTime(d) => DataValue::DateTime(*d),
+        #[cfg(feature = "datetime")]
+        DataValue::Duration(d) => DataValue::Duration(*d),
+    }
+}
+
+/// Whether `v` holds a `NaN` / `±Infinity` float anywhere inside it.
+/// Backs [`crate::NonFiniteHandling::ThrowError`].
+pub(crate) fn contains_non_finite(v: &DataValue<'_>) -> bool {
+    match v {
+        DataValue::Number(NumberValue::Float(f)) => !f.is_finite(),
+        DataValue::Array(items) => items.iter().any(contains_non_finite),
+        DataValue::Object(pairs) => pairs.iter().any(|(_, v)| contains_non_finite(v)),
+        _ => false,
+    }
+}
+
+/// `v` with every `NaN` / `±Infinity` float replaced by `null`. Backs
+/// [`crate::NonFiniteHandling::ReturnNull`]; returns `v` itself (no
+/// allocation) when there is nothing to replace.
+pub(crate) fn null_non_finite<'a>(
+    v: &'a DataValue<'a>,
+    arena: &'a bumpalo::Bump,
+) -> &'a DataValue<'a> {
+    if !contains_non_finite(v) {
+        return v;
+    }
+    arena.alloc(nulled(v, arena))
+}
+
+fn nulled<'a>(v: &DataValue<'a>, arena: &'a bumpalo::Bump) -> DataValue<'a> {
+    match v {
+        DataValue::Number(NumberValue::Float(f)) if !f.is_finite() => DataValue::Null,
+        DataValue::Array(items) => {
+            DataValue::Array(arena.alloc_slice_fill_with(items.len(), |i| nulled(&items[i], arena)))
+        }
+        DataValue::Object(pairs) => {
+            DataValue::Object(arena.alloc_slice_fill_with(pairs.len(), |i| {
+                let (k, v) = &pairs[i];
+                (*k, nulled(v, arena))
+            }))
+        }
+        other => *other,
+    }
+}
 
 /// JavaScript/Python-style default truthiness for a [`DataValue`].
 /// `truthy_arena` (config-aware) delegates here for the common
@@ -0,0 +1,263 @@
+//! MessagePack decoder that builds a [`DataValue`] tree directly in the
+//! arena. Backs [`crate::Engine::eval_msgpack`].
+//!
+//! Hand-rolled rather than routed through `rmp-serde` + `DataValueSeed`:
+//! the serde path measured 25-30% slower than the JSON parser on the same
+//! records, which defeats the point of a binary input. Decoding straight
+//! from the markers lets composites allocate their exact length up front
+//! (MessagePack is length-prefixed) and lets strings borrow from the
+//! input bytes instead of being copied — the input outlives the
+//! evaluation, so the arena tree can point into it.
+//!
+//! Type mapping:
+//!
+//! - nil / bool / str / array / map → the matching `DataValue` variant.
+//! - Integers → integer numbers; `uint64` values above `i64::MAX` become
+//!   floats (same as the JSON parser). `float32` widens to `f64`.
+//! - bin → array of byte values, like a serialised `&[u8]`.
+//! - ext (including the timestamp extension) → `ParseError`.
+//! - Map keys must be strings; anything else is a `ParseError`.
+//!
+//! Nesting is capped at the JSON parser's depth (256) so hostile input
+//! can't overflow the stack.
+
+use bumpalo::Bump;
+use bumpalo::collections::Vec as BumpVec;
+
+use super::DataValue;
+use crate::{Error, Result};
+
+/// Same soft cap as datavalue's JSON parser.
+const MAX_DEPTH: u16 = 256;
+
+/// Decode one MessagePack value occupying the whole of `bytes`.
+pub(crate) fn msgpack_to_data<'a>(bytes: &'a [u8], arena: &'a Bump) -> Result<DataValue<'a>> {
+    let mut decoder = Decoder {
+        bytes,
+        pos: 0,
+        arena,
+    };
+    let value = decoder.value(0)?;
+    if decoder.pos != bytes.len() {
+        return Err(decoder.err("trailing bytes after value"));
+    }
+    Ok(value)
+}
+
+struct Decoder<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    arena: &'a Bump,
+}
+
+impl<'a> Decoder<'a> {
+    fn err(&self, msg: &str) -> Error {
+        Error::parse_error(format!("MessagePack data: {msg} at byte {}", self.pos))
+    }
+
+    fn take(&mut self, n: usize) -> Result<&'a [u8]> {
+        let end = self
+            .pos
+            .checked_add(n)
+            .filter(|&end| end <= self.bytes.len())
+            .ok_or_else(|| self.err("unexpected end of input"))?;
+        let out = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(out)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let mut out = [0u8; N];
+        out.copy_from_slice(self.take(N)?);
+        Ok(out)
+    }
+
+    fn u8(&mut self) -> Result<u8> {
+        Ok(self.array::<1>()?[0])
+    }
+
+    fn u16(&mut self) -> Result<usize> {
+        Ok(u16::from_be_bytes(self.array()?).into())
+    }
+
+    fn u32(&mut self) -> Result<usize> {
+        // Lengths beyond the address space can't be satisfied by the
+        // input anyway; `take` reports them as truncated.
+        Ok(usize::try_from(u32::from_be_bytes(self.array()?)).unwrap_or(usize::MAX))
+    }
+
+    /// Composite markers recurse from here; every other marker goes
+    /// through [`Self::scalar`]. Kept small so the per-level stack frame
+    /// stays small — the scalar arms' temporaries would otherwise be
+    /// reserved on every level of nesting (unoptimised builds overflowed
+    /// a 2 MB thread stack before reaching `MAX_DEPTH`).
+    fn value(&mut self, depth: u16) -> Result<DataValue<'a>> {
+        if depth > MAX_DEPTH {
+            return Err(self.err("nesting depth limit exceeded"));
+        }
+        let marker = self.u8()?;
+        match marker {
+            0x80..=0x8f => self.map((marker & 0x0f).into(), depth),
+            0x90..=0x9f => self.seq((marker & 0x0f).into(), depth),
+            0xdc => {
+                let len = self.u16()?;
+                self.seq(len, depth)
+            }
+            0xdd => {
+                let len = self.u32()?;
+                self.seq(len, depth)
+            }
+            0xde => {
+                let len = self.u16()?;
+                self.map(len, depth)
+            }
+            0xdf => {
+                let len = self.u32()?;
+                self.map(len, depth)
+            }
+            _ => self.scalar(marker),
+        }
+    }
+
+    #[inline(never)]
+    fn scalar(&mut self, marker: u8) -> Result<DataValue<'a>> {
+        match marker {
+            0x00..=0x7f => Ok(DataValue::from_i64(marker.into())),
+            0xa0..=0xbf => self.str((marker & 0x1f).into()),
+            0xc0 => Ok(DataValue::Null),
+            0xc2 => Ok(DataValue::Bool(false)),
+            0xc3 => Ok(DataValue::Bool(true)),
+            0xc4 => {
+                let len = self.u8()?.into();
+                self.bin(len)
+            }
+            0xc5 => {
+                let len = self.u16()?;
+                self.bin(len)
+            }
+            0xc6 => {
+                let len = self.u32()?;
+                self.bin(len)
+            }
+            0xca => Ok(DataValue::from_f64(
+                f32::from_be_bytes(self.array()?).into(),
+            )),
+            0xcb => Ok(DataValue::from_f64(f64::from_be_bytes(self.array()?))),
+            0xcc => Ok(DataValue::from_i64(self.u8()?.into())),
+            0xcd => Ok(DataValue::from_i64(
+                u16::from_be_bytes(self.array()?).into(),
+            )),
+            0xce => Ok(DataValue::from_i64(
+                u32::from_be_bytes(self.array()?).into(),
+            )),
+            0xcf => {
+                let v = u64::from_be_bytes(self.array()?);
+                Ok(match i64::try_from(v) {
+                    Ok(i) => DataValue::from_i64(i),
+                    Err(_) => DataValue::from_f64(v as f64),
+                })
+            }
+            0xd0 => Ok(DataValue::from_i64(i8::from_be_bytes(self.array()?).into())),
+            0xd1 => Ok(DataValue::from_i64(
+                i16::from_be_bytes(self.array()?).into(),
+            )),
+            0xd2 => Ok(DataValue::from_i64(
+                i32::from_be_bytes(self.array()?).into(),
+            )),
+            0xd3 => Ok(DataValue::from_i64(i64::from_be_bytes(self.array()?))),
+            0xd9 => {
+                let len = self.u8()?.into();
+                self.str(len)
+            }
+            0xda => {
+                let len = self.u16()?;
+                self.str(len)
+            }
+            0xdb => {
+                let len = self.u32()?;
+                self.str(len)
+            }
+            0xe0..=0xff => Ok(DataValue::from_i64((marker as i8).into())),
+            0xc7..=0xc9 | 0xd4..=0xd8 => {
+                self.pos -= 1;
+                Err(self.err("ext types are not supported"))
+            }
+            0xc1 => {
+                self.pos -= 1;
+                Err(self.err("reserved marker 0xc1"))
+            }
+            // Composite markers are handled by `value`.
+            0x80..=0x9f | 0xdc..=0xdf => unreachable!("composite marker {marker:#x}"),
+        }
+    }
+
+    fn str(&mut self, len: usize) -> Result<DataValue<'a>> {
+        Ok(DataValue::String(self.str_slice(len)?))
+    }
+
+    fn str_slice(&mut self, len: usize) -> Result<&'a str> {
+        let start = self.pos;
+        let raw = self.take(len)?;
+        std::str::from_utf8(raw).map_err(|_| {
+            self.pos = start;
+            self.err("string is not valid UTF-8")
+        })
+    }
+
+    fn bin(&mut self, len: usize) -> Result<DataValue<'a>> {
+        let raw = self.take(len)?;
+        let items = self
+            .arena
+            .alloc_slice_fill_iter(raw.iter().map(|&b| DataValue::from_i64(b.into())));
+        Ok(DataValue::Array(items))
+    }
+
+    /// Every element takes at least one byte, so a length larger than the
+    /// remaining input is truncated data — reject it before reserving.
+    fn check_len(&self, len: usize) -> Result<()> {
+        if len > self.bytes.len() - self.pos {
+            return Err(self.err("unexpected end of input"));
+        }
+        Ok(())
+    }
+
+    fn seq(&mut self, len: usize, depth: u16) -> Result<DataValue<'a>> {
+        if len == 0 {
+            return Ok(DataValue::Array(&[]));
+        }
+        self.check_len(len)?;
+        let mut items = BumpVec::with_capacity_in(len, self.arena);
+        for _ in 0..len {
+            items.push(self.value(depth + 1)?);
+        }
+        Ok(DataValue::Array(items.into_bump_slice()))
+    }
+
+    fn map(&mut self, len: usize, depth: u16) -> Result<DataValue<'a>> {
+        if len == 0 {
+            return Ok(DataValue::Object(&[]));
+        }
+        self.check_len(len)?;
+        let mut pairs = BumpVec::with_capacity_in(len, self.arena);
+        for _ in 0..len {
+            let key = self.key()?;
+            pairs.push((key, self.value(depth + 1)?));
+        }
+        Ok(DataValue::Object(pairs.into_bump_slice()))
+    }
+
+    fn key(&mut self) -> Result<&'a str> {
+        let marker = self.u8()?;
+        let len = match marker {
+            0xa0..=0xbf => (marker & 0x1f).into(),
+            0xd9 => self.u8()?.into(),
+            0xda => self.u16()?,
+            0xdb => self.u32()?,
+            _ => {
+                self.pos -= 1;
+                return Err(self.err("map key must be a string"));
+            }
+        };
+        self.str_slice(len)
+    }
+}
@@ -0,0 +1,569 @@
+//! `serde::Serializer` that writes any `T: Serialize` straight into the
+//! arena as a [`DataValue`] — no intermediate `serde_json::Value` or JSON
+//! text. Backs [`crate::Engine::eval_typed`].
+//!
+//! The mapping follows `serde_json`'s data model so a struct evaluates
+//! the same whether it arrives here or through `serde_json::to_value`:
+//!
+//! - `None` / `()` / unit structs → `null`; `Some(x)` / newtype structs
+//!   → `x`.
+//! - Integers → integer numbers; `u64` / `u128` / `i128` values outside
+//!   the `i64` range → floats. `f32` widens to `f64`.
+//! - `char` → one-character string; bytes → array of integers.
+//! - Sequences and tuples → arrays; maps and structs → objects. Map keys
+//!   must be strings, chars, bools, or integers (stringified).
+//! - Enum variants use the externally-tagged form: unit variants become
+//!   the variant name, every other variant `{"Variant": payload}`.
+
+use bumpalo::Bump;
+use bumpalo::collections::Vec as BumpVec;
+use serde::ser::{self, Serialize};
+
+use super::DataValue;
+use crate::{Error, Result};
+
+/// Serialise `value` into `arena`.
+pub(crate) fn serialize_to_data<'a, T: Serialize + ?Sized>(
+    value: &T,
+    arena: &'a Bump,
+) -> Result<DataValue<'a>> {
+    value.serialize(DataSerializer { arena })
+}
+
+#[derive(Clone, Copy)]
+struct DataSerializer<'a> {
+    arena: &'a Bump,
+}
+
+impl<'a> DataSerializer<'a> {
+    /// `{"variant": payload}` — serde's externally-tagged enum shape.
+    fn tagged(self, variant: &'static str, payload: DataValue<'a>) -> DataValue<'a> {
+        DataValue::Object(self.arena.alloc_slice_copy(&[(variant, payload)]))
+    }
+}
+
+impl<'a> ser::Serializer for DataSerializer<'a> {
+    type Ok = DataValue<'a>;
+    type Error = Error;
+
+    type SerializeSeq = SeqBuilder<'a>;
+    type SerializeTuple = SeqBuilder<'a>;
+    type SerializeTupleStruct = SeqBuilder<'a>;
+    type SerializeTupleVariant = SeqBuilder<'a>;
+    type SerializeMap = MapBuilder<'a>;
+    type SerializeStruct = MapBuilder<'a>;
+    type SerializeStructVariant = MapBuilder<'a>;
+
+    fn serialize_bool(self, v: bool) -> Result<DataValue<'a>> {
+        Ok(DataValue::Bool(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<DataValue<'a>> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<DataValue<'a>> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<DataValue<'a>> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<DataValue<'a>> {
+        Ok(DataValue::from_i64(v))
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<DataValue<'a>> {
+        Ok(match i64::try_from(v) {
+            Ok(i) => DataValue::from_i64(i),
+            Err(_) => DataValue::from_f64(v as f64),
+        })
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<DataValue<'a>> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<DataValue<'a>> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<DataValue<'a>> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<DataValue<'a>> {
+        self.serialize_i128(v.into())
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<DataValue<'a>> {
+        Ok(match i64::try_from(v) {
+            Ok(i) => DataValue::from_i64(i),
+            Err(_) => DataValue::from_f64(v as f64),
+        })
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<DataValue<'a>> {
+        self.serialize_f64(v.into())
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<DataValue<'a>> {
+        Ok(DataValue::from_f64(v))
+    }
+
+    fn serialize_char(self, v: char) -> Result<DataValue<'a>> {
+        let mut buf = [0u8; 4];
+        self.serialize_str(v.encode_utf8(&mut buf))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<DataValue<'a>> {
+        Ok(DataValue::from_str_in(v, self.arena))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<DataValue<'a>> {
+        let items = self
+            .arena
+            .alloc_slice_fill_iter(v.iter().map(|&b| DataValue::from_i64(b.into())));
+        Ok(DataValue::Array(items))
+    }
+
+    fn serialize_none(self) -> Result<DataValue<'a>> {
+        Ok(DataValue::Null)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<DataValue<'a>> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<DataValue<'a>> {
+        Ok(DataValue::Null)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<DataValue<'a>> {
+        Ok(DataValue::Null)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+    ) -> Result<DataValue<'a>> {
+        Ok(DataValue::String(variant))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<DataValue<'a>> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<DataValue<'a>> {
+        let payload = value.serialize(self)?;
+        Ok(self.tagged(variant, payload))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SeqBuilder<'a>> {
+        Ok(SeqBuilder::new(self, len.unwrap_or(0), None))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SeqBuilder<'a>> {
+        Ok(SeqBuilder::new(self, len, None))
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, len: usize) -> Result<SeqBuilder<'a>> {
+        Ok(SeqBuilder::new(self, len, None))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SeqBuilder<'a>> {
+        Ok(SeqBuilder::new(self, len, Some(variant)))
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<MapBuilder<'a>> {
+        Ok(MapBuilder::new(self, len.unwrap_or(0), None))
+    }
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<MapBuilder<'a>> {
+        Ok(MapBuilder::new(self, len, None))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<MapBuilder<'a>> {
+        Ok(MapBuilder::new(self, len, Some(variant)))
+    }
+}
+
+/// Collects array elements; wraps them in `{"variant": [...]}` when
+/// building a tuple variant.
+struct SeqBuilder<'a> {
+    ser: DataSerializer<'a>,
+    items: BumpVec<'a, DataValue<'a>>,
+    variant: Option<&'static str>,
+}
+
+impl<'a> SeqBuilder<'a> {
+    fn new(ser: DataSerializer<'a>, len: usize, variant: Option<&'static str>) -> Self {
+        Self {
+            ser,
+            items: BumpVec::with_capacity_in(len, ser.arena),
+            variant,
+        }
+    }
+
+    fn push<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.items.push(value.serialize(self.ser)?);
+        Ok(())
+    }
+
+    fn finish(self) -> Result<DataValue<'a>> {
+        let array = DataValue::Array(self.items.into_bump_slice());
+        Ok(match self.variant {
+            Some(variant) => self.ser.tagged(variant, array),
+            None => array,
+        })
+    }
+}
+
+impl<'a> ser::SerializeSeq for SeqBuilder<'a> {
+    type Ok = DataValue<'a>;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<DataValue<'a>> {
+        self.finish()
+    }
+}
+
+impl<'a> ser::SerializeTuple for SeqBuilder<'a> {
+    type Ok = DataValue<'a>;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<DataValue<'a>> {
+        self.finish()
+    }
+}
+
+impl<'a> ser::SerializeTupleStruct for SeqBuilder<'a> {
+    type Ok = DataValue<'a>;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<DataValue<'a>> {
+        self.finish()
+    }
+}
+
+impl<'a> ser::SerializeTupleVariant for SeqBuilder<'a> {
+    type Ok = DataValue<'a>;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<DataValue<'a>> {
+        self.finish()
+    }
+}
+
+/// Collects object entries; wraps them in `{"variant": {...}}` when
+/// building a struct variant.
+struct MapBuilder<'a> {
+    ser: DataSerializer<'a>,
+    entries: BumpVec<'a, (&'a str, DataValue<'a>)>,
+    /// Key from `serialize_key`, waiting for its `serialize_value`.
+    pending_key: Option<&'a str>,
+    variant: Option<&'static str>,
+}
+
+impl<'a> MapBuilder<'a> {
+    fn new(ser: DataSerializer<'a>, len: usize, variant: Option<&'static str>) -> Self {
+        Self {
+            ser,
+            entries: BumpVec::with_capacity_in(len, ser.arena),
+            pending_key: None,
+            variant,
+        }
+    }
+
+    fn insert<T: Serialize + ?Sized>(&mut self, key: &'a str, value: &T) -> Result<()> {
+        self.entries.push((key, value.serialize(self.ser)?));
+        Ok(())
+    }
+
+    fn finish(self) -> Result<DataValue<'a>> {
+        let object = DataValue::Object(self.entries.into_bump_slice());
+        Ok(match self.variant {
+            Some(variant) => self.ser.tagged(variant, object),
+            None => object,
+        })
+    }
+}
+
+impl<'a> ser::SerializeMap for MapBuilder<'a> {
+    type Ok = DataValue<'a>;
+    type Error = Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<()> {
+        self.pending_key = Some(key.serialize(KeySerializer {
+            arena: self.ser.arena,
+        })?);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        let key = self
+            .pending_key
+            .take()
+            .ok_or_else(|| Error::parse_error("map value serialized before its key"))?;
+        self.insert(key, value)
+    }
+
+    fn end(self) -> Result<DataValue<'a>> {
+        self.finish()
+    }
+}
+
+impl<'a> ser::SerializeStruct for MapBuilder<'a> {
+    type Ok = DataValue<'a>;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        self.insert(key, value)
+    }
+
+    fn end(self) -> Result<DataValue<'a>> {
+        self.finish()
+    }
+}
+
+impl<'a> ser::SerializeStructVariant for MapBuilder<'a> {
+    type Ok = DataValue<'a>;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        self.insert(key, value)
+    }
+
+    fn end(self) -> Result<DataValue<'a>> {
+        self.finish()
+    }
+}
+
+/// Serialises a map key to an arena string. Accepts the key shapes
+/// `serde_json` accepts; anything else is a `ParseError`.
+struct KeySerializer<'a> {
+    arena: &'a Bump,
+}
+
+impl<'a> KeySerializer<'a> {
+    fn display<T: std::fmt::Display>(self, v: T) -> Result<&'a str> {
+        Ok(self.arena.alloc_str(&v.to_string()))
+    }
+}
+
+fn key_must_be_string() -> Error {
+    Error::parse_error("map key must be a string")
+}
+
+impl<'a> ser::Serializer for KeySerializer<'a> {
+    type Ok = &'a str;
+    type Error = Error;
+
+    type SerializeSeq = ser::Impossible<&'a str, Error>;
+    type SerializeTuple = ser::Impossible<&'a str, Error>;
+    type SerializeTupleStruct = ser::Impossible<&'a str, Error>;
+    type SerializeTupleVariant = ser::Impossible<&'a str, Error>;
+    type SerializeMap = ser::Impossible<&'a str, Error>;
+    type SerializeStruct = ser::Impossible<&'a str, Error>;
+    type SerializeStructVariant = ser::Impossible<&'a str, Error>;
+
+    fn serialize_bool(self, v: bool) -> Result<&'a str> {
+        Ok(if v { "true" } else { "false" })
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<&'a str> {
+        self.display(v)
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<&'a str> {
+        self.display(v)
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<&'a str> {
+        self.display(v)
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<&'a str> {
+        self.display(v)
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<&'a str> {
+        self.display(v)
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<&'a str> {
+        self.display(v)
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<&'a str> {
+        self.display(v)
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<&'a str> {
+        self.display(v)
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<&'a str> {
+        self.display(v)
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<&'a str> {
+        self.display(v)
+    }
+
+    fn serialize_f32(self, _v: f32) -> Result<&'a str> {
+        Err(key_must_be_string())
+    }
+
+    fn serialize_f64(self, _v: f64) -> Result<&'a str> {
+        Err(key_must_be_string())
+    }
+
+    fn serialize_char(self, v: char) -> Result<&'a str> {
+        self.display(v)
+    }
+
+    fn serialize_str(self, v: &str) -> Result<&'a str> {
+        Ok(self.arena.alloc_str(v))
+    }
+
+    fn serialize_bytes(self, _v: &[u8]) -> Result<&'a str> {
+        Err(key_must_be_string())
+    }
+
+    fn serialize_none(self) -> Result<&'a str> {
+        Err(key_must_be_string())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, _value: &T) -> Result<&'a str> {
+        Err(key_must_be_string())
+    }
+
+    fn serialize_unit(self) -> Result<&'a str> {
+        Err(key_must_be_string())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<&'a str> {
+        Err(key_must_be_string())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+    ) -> Result<&'a str> {
+        Ok(variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<&'a str> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<&'a str> {
+        Err(key_must_be_string())
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq> {
+        Err(key_must_be_string())
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple> {
+        Err(key_must_be_string())
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct> {
+        Err(key_must_be_string())
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant> {
+        Err(key_must_be_string())
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap> {
+        Err(key_must_be_string())
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeStruct> {
+        Err(key_must_be_string())
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant> {
+        Err(key_must_be_string())
+    }
+}
@@ -7,8 +7,11 @@
 //! traversal step; the `_exists` variants are thin `.is_some()` wrappers
 //! over the value-returning core.
 
+use bumpalo::Bump;
+
 use super::DataValue;
 use super::lookup::object_lookup_field;
+use crate::arena::bvec;
 use crate::node::PathSegment;
 
 /// Take one traversal step by `PathSegment`. Tight loop body — must always
@@ -24,10 +27,35 @@ fn step_segment<'a>(cur: &'a DataValue<'a>, seg: &PathSegment) -> Option<&'a Dat
             object_lookup_field(pairs, key.as_ref())
         }
         (&DataValue::Array(items), PathSegment::FieldOrIndex(_, idx)) => items.get(*idx),
+        (&DataValue::Object(pairs), PathSegment::FieldOrIndexFromEnd(key, _)) => {
+            object_lookup_field(pairs, key.as_ref())
+        }
+        (&DataValue::Array(items), PathSegment::FieldOrIndexFromEnd(_, back)) => {
+            index_from_end(items, *back)
+        }
         _ => None,
     }
 }
 
+/// The element `back` places from the end of `items` (`1` is the last one),
+/// or `None` when `back` is past the start.
+#[inline]
+fn index_from_end<'a>(items: &'a [DataValue<'a>], back: usize) -> Option<&'a DataValue<'a>> {
+    items.len().checked_sub(back).and_then(|idx| items.get(idx))
+}
+
+/// Parse a negative index segment such as `"-1"` into its distance from the
+/// end of the array. `"-0"` and anything that isn't `-` followed by digits
+/// is `None`.
+#[inline]
+pub(crate) fn parse_index_from_end(seg: &str) -> Option<usize> {
+    let digits = seg.strip_prefix('-')?;
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse::<usize>().ok().filter(|back| *back > 0)
+}
+
 /// Take one traversal step by string segment (parses numeric segments as
 /// array indices on the fly). Tight loop body — `inline(always)` for the
 /// same reason as `step_segment`.
@@ -35,10 +63,10 @@ fn step_segment<'a>(cur: &'a DataValue<'a>, seg: &PathSegment) -> Option<&'a Dat
 fn step_str<'a>(cur: &'a DataValue<'a>, seg: &str) -> Option<&'a DataValue<'a>> {
     match *cur {
         DataValue::Object(pairs) => object_lookup_field(pairs, seg),
-        DataValue::Array(items) => {
-            let idx = seg.parse::<usize>().ok()?;
-            items.get(idx)
-        }
+        DataValue::Array(items) => match seg.parse::<usize>() {
+            Ok(idx) => items.get(idx),
+            Err(_) => index_from_end(items, parse_index_from_end(seg)?),
+        },
         _ => None,
     }
 }
@@ -67,6 +95,58 @@ pub(crate) fn traverse_segments<'a>(
     }
 }
 
+/// Walk path segments that may contain `*` wildcards. A `*` reached on an
+/// array resolves the rest of the path against each element and collects
+/// the results into a new array, with `null` for elements where it misses,
+/// so positions line up with the source. On an object a `*` is an ordinary
+/// key. Returns `None` if the path misses before its first wildcard.
+pub(crate) fn traverse_wildcard_segments<'a>(
+    av: &'a DataValue<'a>,
+    segments: &[PathSegment],
+    arena: &'a Bump,
+) -> Option<&'a DataValue<'a>> {
+    let Some(star) = segments.iter().position(PathSegment::is_wildcard) else {
+        return traverse_segments(av, segments);
+    };
+    let base = traverse_segments(av, &segments[..star])?;
+    let rest = &segments[star + 1..];
+    let DataValue::Array(items) = base else {
+        let next = step_segment(base, &segments[star])?;
+        return traverse_wildcard_segments(next, rest, arena);
+    };
+    let mut out = bvec::<DataValue<'a>>(arena, items.len());
+    for item in items.iter() {
+        let found = traverse_wildcard_segments(item, rest, arena);
+        out.push(found.copied().unwrap_or(DataValue::Null));
+    }
+    Some(arena.alloc(DataValue::Array(out.into_bump_slice())))
+}
+
+/// Exists-check for paths that may contain `*` wildcards, used by `missing`
+/// and `missing_some`. The same walk as [`traverse_wildcard_segments`], but
+/// where that one gives `null` for an element missing the rest of the path,
+/// here the whole path counts as missing. An empty array at a `*` has
+/// nothing missing.
+pub(crate) fn path_exists_wildcard_segments<'a>(
+    av: &'a DataValue<'a>,
+    segments: &[PathSegment],
+) -> bool {
+    let Some(star) = segments.iter().position(PathSegment::is_wildcard) else {
+        return path_exists_segments(av, segments);
+    };
+    let Some(base) = traverse_segments(av, &segments[..star]) else {
+        return false;
+    };
+    let rest = &segments[star + 1..];
+    match base {
+        DataValue::Array(items) => items
+            .iter()
+            .all(|item| path_exists_wildcard_segments(item, rest)),
+        _ => step_segment(base, &segments[star])
+            .is_some_and(|next| path_exists_wildcard_segments(next, rest)),
+    }
+}
+
 /// Allocation-free segments-exists check. Companion of [`traverse_segments`]
 /// for compile-time-parsed paths where the leaf value isn't consumed. A thin
 /// `.is_some()` wrapper; `traverse_segments` is `#[inline(always)]` so codegen
@@ -113,17 +193,13 @@ pub(crate) fn apply_path_element<'a>(
     if let Some(s) = elem.as_str() {
         return access_path_str_ref(cur, s);
     }
-    if let Some(i) = elem.as_i64() {
-        if i >= 0 {
-            let idx = i as usize;
-            return match *cur {
-                DataValue::Array(items) => items.get(idx),
-                // itoa renders the numeric key on the stack, skipping the
-                // heap `String` that `i.to_string()` paid per lookup.
-                DataValue::Object(_) => access_path_str_ref(cur, itoa::Buffer::new().format(i)),
-                _ => None,
-            };
-        }
+    let i = elem.as_i64()?;
+    match *cur {
+        DataValue::Array(items) if i >= 0 => items.get(i as usize),
+        DataValue::Array(items) => index_from_end(items, i.unsigned_abs() as usize),
+        // itoa renders the numeric key on the stack, skipping the
+        // heap `String` that `i.to_string()` paid per lookup.
+        DataValue::Object(_) => access_path_str_ref(cur, itoa::Buffer::new().format(i)),
+        _ => None,
     }
-    None
 }
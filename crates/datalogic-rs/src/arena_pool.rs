@@ -0,0 +1,102 @@
+//! Shared pool of evaluation arenas for [`crate::Engine::scoped`].
+//!
+//! A [`crate::Session`] amortises arena growth across the calls made
+//! through it, but a server handling each request on whichever thread is
+//! free has no long-lived session to hold on to — it would build a fresh
+//! `Bump` per request and pay for chunk growth every time. [`ArenaPool`]
+//! keeps those arenas alive between requests: `Engine::scoped` checks one
+//! out, lends it to a session for the duration of the closure, then resets
+//! it and puts it back. Chunks sized by earlier requests serve later ones
+//! without going back to the allocator.
+//!
+//! The pool is `Send + Sync` and engine-agnostic; share one behind an
+//! `Arc` (or a `static`) across worker threads. Arenas are only ever
+//! handed to one scope at a time, so the pool's lock is held for a
+//! `Vec::pop` / `Vec::push` and never during evaluation.
+
+use std::sync::{Mutex, MutexGuard};
+
+use bumpalo::Bump;
+
+/// A thread-safe pool of reusable evaluation arenas. See
+/// [`crate::Engine::scoped`].
+///
+/// The pool starts empty and grows to the peak number of concurrent
+/// scopes; idle arenas keep the chunks their largest scope needed. Use
+/// [`Self::clear`] to release that memory.
+///
+/// # Example
+///
+/// ```rust
+/// use datalogic_rs::{ArenaPool, Engine};
+///
+/// let engine = Engine::new();
+/// let pool = ArenaPool::new();
+/// let compiled = engine.compile(r#"{"*": [{"var": "x"}, 2]}"#).unwrap();
+///
+/// for x in 0..3 {
+///     let doubled = engine.scoped(&pool, |session| {
+///         session.eval(&compiled, format!(r#"{{"x": {x}}}"#).as_str())
+///     });
+///     assert_eq!(doubled.unwrap().as_i64(), Some(x * 2));
+/// }
+/// // Every scope ran on the same arena.
+/// assert_eq!(pool.idle(), 1);
+/// ```
+#[derive(Debug, Default)]
+pub struct ArenaPool {
+    idle: Mutex<Vec<Bump>>,
+    capacity: usize,
+}
+
+impl ArenaPool {
+    /// Create an empty pool whose arenas start at bumpalo's default size.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create an empty pool whose new arenas pre-allocate a first chunk
+    /// of at least `capacity` bytes — e.g. a workload's steady-state
+    /// high-water mark captured via [`crate::Session::allocated_bytes`].
+    pub fn with_arena_capacity(capacity: usize) -> Self {
+        Self {
+            idle: Mutex::default(),
+            capacity,
+        }
+    }
+
+    /// Number of arenas currently waiting in the pool.
+    pub fn idle(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Total bytes held by the idle arenas' chunks.
+    pub fn allocated_bytes(&self) -> usize {
+        self.lock().iter().map(Bump::allocated_bytes).sum()
+    }
+
+    /// Drop every idle arena, returning its memory to the allocator.
+    /// Arenas checked out by running scopes are unaffected and return to
+    /// the pool as usual.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    pub(crate) fn take(&self) -> Bump {
+        self.lock()
+            .pop()
+            .unwrap_or_else(|| Bump::with_capacity(self.capacity))
+    }
+
+    /// Reset `arena` and make it available to the next scope.
+    pub(crate) fn give_back(&self, mut arena: Bump) {
+        arena.reset();
+        self.lock().push(arena);
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Vec<Bump>> {
+        // Nothing panics while the lock is held, but a poisoned pool
+        // still holds valid arenas — recover rather than propagate.
+        self.idle.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
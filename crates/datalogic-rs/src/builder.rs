@@ -5,11 +5,11 @@
 //! 4.x constructors (`new`, `with_preserve_structure`, `with_config`,
 //! `with_config_and_structure`).
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
-use crate::CustomOperator;
 use crate::config::EvaluationConfig;
-use crate::engine::Engine;
+use crate::engine::{Engine, RegisteredOperator};
+use crate::{CustomOperator, LazyOperator};
 
 /// Builder for [`Engine`]. Construct via [`Engine::builder`].
 ///
@@ -42,12 +42,26 @@ use crate::engine::Engine;
 ///   [`Self::with_constant_folding`] when you need every operator to
 ///   survive in the compiled tree (e.g. for tooling that walks the
 ///   structure or applies its own rewrites).
+/// - **`max_depth`** — 256. The deepest rule nesting
+///   [`Engine::compile`] accepts. Change with [`Self::with_max_depth`].
+/// - **`removed_operators`** — empty. Every built-in compiled into this
+///   build is available; take one away with [`Self::remove_operator`].
+/// - **`operator_overrides`** — `false`. Built-in operators win over
+///   custom operators registered under the same name. Enable with
+///   [`Self::with_operator_overrides`].
+/// - **`parallel_threshold`** (`feature = "parallel"`) — unset. Every
+///   `map` runs sequentially. Set with [`Self::with_parallel_threshold`].
 #[must_use = "the builder is consumed by `.build()`"]
 pub struct EngineBuilder {
     config: EvaluationConfig,
     templating: bool,
     constant_folding: bool,
-    operators: HashMap<String, Box<dyn CustomOperator>>,
+    operator_overrides: bool,
+    max_depth: usize,
+    operators: HashMap<String, RegisteredOperator>,
+    removed_operators: HashSet<String>,
+    #[cfg(feature = "parallel")]
+    parallel_threshold: Option<usize>,
 }
 
 impl Default for EngineBuilder {
@@ -64,7 +78,12 @@ impl EngineBuilder {
             config: EvaluationConfig::default(),
             templating: false,
             constant_folding: true,
+            operator_overrides: false,
+            max_depth: crate::node::MAX_COMPILE_DEPTH,
             operators: HashMap::new(),
+            removed_operators: HashSet::new(),
+            #[cfg(feature = "parallel")]
+            parallel_threshold: None,
         }
     }
 
@@ -112,6 +131,120 @@ impl EngineBuilder {
         self
     }
 
+    /// Let custom operators replace built-ins. Default: `false` (a
+    /// built-in name always compiles to the built-in). When `true`, a
+    /// custom operator registered under a built-in's name — `"+"`,
+    /// `"substr"`, … — is dispatched in its place, and like any custom
+    /// operator receives its arguments already evaluated. Built-ins that
+    /// aren't overridden are unaffected.
+    ///
+    /// ```
+    /// # use datalogic_rs::{CustomOperator, DataValue, Engine, Result};
+    /// # use datalogic_rs::operator::EvalContext;
+    /// # use bumpalo::Bump;
+    /// struct Concat;
+    /// impl CustomOperator for Concat {
+    ///     fn evaluate<'a>(
+    ///         &self,
+    ///         args: &[&'a DataValue<'a>],
+    ///         _ctx: &mut EvalContext<'_, 'a>,
+    ///         arena: &'a Bump,
+    ///     ) -> Result<&'a DataValue<'a>> {
+    ///         let mut out = bumpalo::collections::String::new_in(arena);
+    ///         for arg in args {
+    ///             out.push_str(arg.as_str().unwrap_or_default());
+    ///         }
+    ///         Ok(arena.alloc(DataValue::String(out.into_bump_str())))
+    ///     }
+    /// }
+    ///
+    /// let engine = Engine::builder()
+    ///     .with_operator_overrides(true)
+    ///     .add_operator("+", Concat)
+    ///     .build();
+    /// assert_eq!(engine.eval_str(r#"{"+": ["a", "b"]}"#, "null").unwrap(), r#""ab""#);
+    /// ```
+    #[inline]
+    #[must_use = "builder methods return a new builder; chain into `.build()`"]
+    pub fn with_operator_overrides(mut self, on: bool) -> Self {
+        self.operator_overrides = on;
+        self
+    }
+
+    /// Set the deepest rule nesting [`Engine::compile`] accepts. Default:
+    /// 256, the same cap the JSON parser applies to rule strings. A rule
+    /// nested deeper fails to compile with
+    /// [`crate::ErrorKind::ConfigurationError`] instead of overflowing the
+    /// stack, which matters for rules built programmatically (an owned
+    /// value or `serde_json::Value`), since those never pass through the
+    /// parser.
+    ///
+    /// The parser's own cap is fixed: a rule *string* nested past 256
+    /// arrays and objects fails with [`crate::ErrorKind::ParseError`]
+    /// whatever this is set to. Raising the limit above 256 only admits
+    /// deeper programmatic rules; lowering it applies to both.
+    ///
+    /// Compilation and evaluation both recurse once per level, so raise
+    /// the cap only as far as the evaluating thread's stack allows; lower
+    /// it to reject deep user-authored rules early.
+    ///
+    /// The cap counts the root, so 1 admits a literal but nothing nested
+    /// under an operator. A cap of 0 is a misconfiguration: every compile
+    /// fails with [`crate::ErrorKind::ConfigurationError`] saying so.
+    #[inline]
+    #[must_use = "builder methods return a new builder; chain into `.build()`"]
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Evaluate `map` bodies in parallel on rayon's global thread pool
+    /// when the input array has more than `threshold` elements. Default:
+    /// unset, so every `map` runs on the calling thread.
+    ///
+    /// Each worker evaluates a contiguous chunk of the array into its own
+    /// arena; the results are copied back into the evaluation's arena in
+    /// input order, so the output is identical to the sequential one. If
+    /// several items fail, the error is the one the lowest-index item
+    /// raised — the same error a sequential run would stop at.
+    ///
+    /// The body runs concurrently and in no particular order, so it must
+    /// be pure. Built-in operators are, apart from `random` and `now`; a
+    /// custom operator with side effects (counters, I/O, a shared RNG)
+    /// will observe interleaved calls. The step budget
+    /// ([`EvaluationConfig::step_limit`]) is checked per worker and
+    /// charged in full once the workers finish.
+    ///
+    /// `map` stays sequential for traced evaluations, on a one-thread
+    /// rayon pool (a single core, or `RAYON_NUM_THREADS=1`), and for
+    /// bodies it already runs as a tight loop (a bare field read, or `+` /
+    /// `-` / `*` on fields and literals). Parallelism pays off only when
+    /// the per-item body is expensive; start with thresholds in the
+    /// thousands and measure. Workers run on rayon's threads, whose stacks
+    /// are smaller than a typical main thread's — size them with
+    /// `rayon::ThreadPoolBuilder::stack_size` if bodies nest deeply.
+    ///
+    /// ```
+    /// # #[cfg(feature = "parallel")] {
+    /// use datalogic_rs::Engine;
+    ///
+    /// let engine = Engine::builder().with_parallel_threshold(1_000).build();
+    /// let data = format!(r#"{{"xs": {:?}}}"#, (0..5_000).collect::<Vec<_>>());
+    /// let labels = engine
+    ///     .eval(r#"{"map": [{"var": "xs"}, {"cat": ["x", {"var": ""}]}]}"#, data.as_str())
+    ///     .unwrap();
+    /// assert_eq!(labels.as_array().unwrap()[4_999].as_str(), Some("x4999"));
+    /// # }
+    /// ```
+    #[cfg(feature = "parallel")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "parallel")))]
+    #[inline]
+    #[must_use = "builder methods return a new builder; chain into `.build()`"]
+    pub fn with_parallel_threshold(mut self, threshold: usize) -> Self {
+        self.parallel_threshold = Some(threshold);
+        self
+    }
+
     /// Register a [`CustomOperator`] under `name`. Multiple calls with the
     /// same name overwrite the prior registration.
     ///
@@ -128,30 +261,88 @@ impl EngineBuilder {
     /// ```
     ///
     /// Operator registration is builder-only; once [`Self::build`] hands
-    /// you an [`Engine`], its operator set is frozen.
+    /// you an [`Engine`], its operator set is frozen. The set belongs to
+    /// that engine alone — there is no process-wide registry — so engines
+    /// built side by side can register different operators under the
+    /// same name without interfering.
     ///
-    /// **Built-ins always win.** If `name` collides with a built-in
+    /// **Built-ins win by default.** If `name` collides with a built-in
     /// JSONLogic operator (`+`, `if`, `var`, `map`, …), the built-in is
     /// dispatched and the registered custom op is never reached. To
-    /// extend the operator set, choose a name that doesn't parse as a
-    /// built-in.
+    /// replace a built-in, enable [`Self::with_operator_overrides`];
+    /// otherwise choose a name that doesn't parse as a built-in.
     #[inline]
     #[must_use = "builder methods return a new builder; chain into `.build()`"]
     pub fn add_operator<T>(mut self, name: impl Into<String>, operator: T) -> Self
     where
         T: CustomOperator + 'static,
     {
-        self.operators.insert(name.into(), Box::new(operator));
+        let name = name.into();
+        self.removed_operators.remove(&name);
+        self.operators
+            .insert(name, RegisteredOperator::Eager(Box::new(operator)));
+        self
+    }
+
+    /// Register a [`LazyOperator`] under `name`. A lazy operator receives
+    /// its arguments unevaluated and evaluates only the ones it needs —
+    /// see the trait docs. Shares the name space, the overwrite rule and
+    /// the built-in precedence of [`Self::add_operator`]: registering
+    /// either kind under a name replaces the previous registration.
+    #[inline]
+    #[must_use = "builder methods return a new builder; chain into `.build()`"]
+    pub fn add_lazy_operator<T>(mut self, name: impl Into<String>, operator: T) -> Self
+    where
+        T: LazyOperator + 'static,
+    {
+        let name = name.into();
+        self.removed_operators.remove(&name);
+        self.operators
+            .insert(name, RegisteredOperator::Lazy(Box::new(operator)));
+        self
+    }
+
+    /// Remove the operator `name` from the engine: a rule that uses it
+    /// fails to compile with [`crate::ErrorKind::InvalidOperator`]. Works
+    /// for built-ins and custom operators alike, so a sandboxed engine can
+    /// drop operators it shouldn't expose. Removing a built-in removes its
+    /// aliases too: removing `switch` also takes away `match`, and
+    /// removing `var` takes away `val`.
+    ///
+    /// A later [`Self::add_operator`] under the same name registers it
+    /// again as a custom operator.
+    ///
+    /// ```
+    /// use datalogic_rs::Engine;
+    ///
+    /// let engine = Engine::builder().remove_operator("substr").build();
+    /// assert!(engine.compile(r#"{"substr": ["hello", 1]}"#).is_err());
+    /// ```
+    #[inline]
+    #[must_use = "builder methods return a new builder; chain into `.build()`"]
+    pub fn remove_operator(mut self, name: impl Into<String>) -> Self {
+        let name = name.into();
+        self.operators.remove(&name);
+        self.removed_operators.insert(name);
         self
     }
 
     /// Finalise the builder into an immutable [`Engine`] engine.
     pub fn build(self) -> Engine {
-        Engine::from_builder_parts(
+        #[cfg_attr(not(feature = "parallel"), allow(unused_mut))]
+        let mut engine = Engine::from_builder_parts(
             self.config,
             self.templating,
             self.constant_folding,
+            self.operator_overrides,
+            self.max_depth,
             self.operators,
-        )
+            self.removed_operators,
+        );
+        #[cfg(feature = "parallel")]
+        {
+            engine.parallel_threshold = self.parallel_threshold;
+        }
+        engine
     }
 }
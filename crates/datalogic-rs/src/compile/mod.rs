@@ -6,20 +6,24 @@
 //! - [`walker`] — the recursive `compile_node` dispatch.
 //! - [`operator`] — `var` / `val` / `exists` specialisations.
 //! - [`missing`] — `missing` / `missing_some` static path pre-parsing.
-//! - [`path_segments`] — shared dot-path parsing.
+//! - [`path_segments`] — shared dot-path parsing (also used at runtime by
+//!   `set` and `get`).
 //! - [`optimize`] — DCE, strength reduction, constant folding.
 
 mod optimize;
 
 mod missing;
 mod operator;
-mod path_segments;
+pub(crate) mod path_segments;
 mod walker;
 
+#[cfg(feature = "ext-control")]
+pub(crate) use walker::{LetOperand, let_operand};
+
 use datavalue::OwnedDataValue;
 
 use crate::node::{CompileCtx, Logic};
-use crate::{Engine, Result};
+use crate::{Engine, Error, Result};
 
 impl Logic {
     /// Compile an [`OwnedDataValue`] rule against `engine`. Honours the
@@ -45,8 +49,29 @@ impl Logic {
         Self::compile_inner(logic, engine, CompileCtx::no_fold())
     }
 
+    /// Walk `logic` like a compile, collecting every problem instead of
+    /// stopping at the first. Skips the optimizer (the tree is thrown
+    /// away). Used by [`Engine::validate`].
+    pub(crate) fn validate_with(logic: &OwnedDataValue, engine: &Engine) -> Vec<Error> {
+        let mut ctx = CompileCtx::no_fold()
+            .with_max_depth(engine.max_depth())
+            .collecting();
+        let result = walker::compile_node(
+            logic,
+            Some(engine),
+            engine.is_templating_enabled(),
+            &mut ctx,
+        );
+        let mut errors = ctx.take_diagnostics();
+        if let Err(err) = result {
+            errors.push(err);
+        }
+        errors
+    }
+
     #[inline]
-    fn compile_inner(logic: &OwnedDataValue, engine: &Engine, mut ctx: CompileCtx) -> Result<Self> {
+    fn compile_inner(logic: &OwnedDataValue, engine: &Engine, ctx: CompileCtx) -> Result<Self> {
+        let mut ctx = ctx.with_max_depth(engine.max_depth());
         let mut root = walker::compile_node(
             logic,
             Some(engine),
@@ -59,6 +84,8 @@ impl Logic {
         // like folding — traced/no-fold compiles produce zero `Cse` nodes —
         // and skipped under a `Custom` truthy evaluator, whose opaque
         // closure's call count would become observable through memoization.
+        // Hoisting shares the gate and the slot table, numbering its slots
+        // after CSE's; it runs second so the CSE pass sees pristine bodies.
         let cse_slot_count = if ctx.skip_fold()
             || matches!(
                 engine.config().truthy_evaluator,
@@ -66,7 +93,8 @@ impl Logic {
             ) {
             0
         } else {
-            optimize::cse::apply(&mut root)
+            let shared = optimize::cse::apply(&mut root);
+            optimize::hoist::apply(&mut root, engine, shared)
         };
         Ok(Self::new(root, cse_slot_count))
     }
@@ -8,7 +8,7 @@
 use crate::node::PathSegment;
 use crate::node::{CompileCtx, CompiledNode, MetadataHint, ReduceHint};
 
-use super::path_segments::{parse_path_segments, parse_var_path, str_to_segment};
+use super::path_segments::{int_to_segment, parse_path_segments, parse_var_path, str_to_segment};
 
 /// Build the empty-args `var` / `val` node: root scope, no segments, no hints.
 fn empty_var(ctx: &mut CompileCtx) -> CompiledNode {
@@ -47,6 +47,15 @@ pub(super) fn try_compile_var(args: &[CompiledNode], ctx: &mut CompileCtx) -> Op
         _ => return None,
     };
 
+    // A `*` segment needs the wildcard walk. The hint keeps the var off the
+    // plain-path fast paths, and the walk reads `current.` / `accumulator.`
+    // prefixes itself.
+    let (reduce_hint, metadata_hint) = if segments.iter().any(PathSegment::is_wildcard) {
+        (ReduceHint::None, MetadataHint::Wildcard)
+    } else {
+        (reduce_hint, MetadataHint::None)
+    };
+
     let default_value = if args.len() > 1 {
         Some(Box::new(args[1].clone()))
     } else {
@@ -58,7 +67,7 @@ pub(super) fn try_compile_var(args: &[CompiledNode], ctx: &mut CompileCtx) -> Op
         scope_level: 0,
         segments: segments.into_boxed_slice(),
         reduce_hint,
-        metadata_hint: MetadataHint::None,
+        metadata_hint,
         default_value,
     })
 }
@@ -178,10 +187,10 @@ fn val_arg_to_segment(arg: &CompiledNode) -> Option<PathSegment> {
         CompiledNode::Value {
             value: datavalue::OwnedDataValue::Number(n),
             ..
-        } => n
-            .as_i64()
-            .filter(|i| *i >= 0)
-            .map(|i| PathSegment::Index(i as usize)),
+        } => match n.as_i64()? {
+            i if i >= 0 => Some(PathSegment::Index(i as usize)),
+            i => int_to_segment(i),
+        },
         _ => None,
     }
 }
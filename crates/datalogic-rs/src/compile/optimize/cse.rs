@@ -17,7 +17,8 @@
 //! - **Purity (compile time):** only subtrees built entirely from pure
 //!   builtin operators are candidates. `CustomOperator` (opaque, possibly
 //!   re-entrant), `StructuredObject`, `Throw`/`Try` (error control flow),
-//!   `Now` (time), and `Fractional`/`SemVer` (kept dynamic by policy, see
+//!   `Now` (time), `Random` (entropy / engine seed), and
+//!   `Fractional`/`SemVer` (kept dynamic by policy, see
 //!   `opcode_is_static`) disqualify a subtree. `Var`/`Missing`/`Exists`
 //!   remain eligible — they read context, which the runtime gate pins.
 //! - **Context (runtime):** the memo is consulted only at
@@ -257,7 +258,11 @@ fn wrap(node: &mut CompiledNode, table: &ClassTable) {
             op_name: "",
         };
         let inner = std::mem::replace(node, placeholder);
-        *node = CompiledNode::Cse(Box::new(CseData { slot, inner }));
+        *node = CompiledNode::Cse(Box::new(CseData {
+            slot,
+            hoisted: false,
+            inner,
+        }));
         // Keep descending so nested classes (total ⊃ net ⊃ subtotal) each
         // get their own slot inside the wrapped occurrence.
         if let CompiledNode::Cse(data) = node {
@@ -287,25 +292,52 @@ fn wrap_children(node: &mut CompiledNode, table: &ClassTable) {
 /// wrapper there is pure dispatch overhead. Skipped by both phases (no
 /// wrapping, and no occurrence counting toward the ≥ 2 threshold):
 ///
-/// - iterator *bodies*: `args[1]` of filter/map/all/some/none/reduce and
-///   `args[2]` of sort (whose `args[1]` is the scalar direction flag) run
-///   under a per-item frame. `reduce`'s `args[2]` (initial accumulator)
-///   evaluates once outside the iteration frames and stays eligible;
+/// - iterator *bodies*: `args[1]` of filter/map/all/some/none/reduce/
+///   group_by/find/count/flat_map and `args[2]` of sort (whose `args[1]`
+///   is the scalar direction flag) run under a per-item frame. `reduce`'s
+///   `args[2]` (initial accumulator) evaluates once outside the iteration
+///   frames and stays eligible;
 /// - the *catch arm* (last arg) of a multi-arg `try`: it runs under the
 ///   caught-error context frame when the error was thrown, and only ever
-///   runs on the error path, so a memo wrapper there almost never pays.
+///   runs on the error path, so a memo wrapper there almost never pays;
+/// - the *body* (last arg) of `let`, which always runs under the
+///   bindings frame.
 ///
 /// The runtime `depth() == 0` gate remains authoritative — this predicate
 /// is an overhead optimization, not a correctness gate.
-fn child_never_cacheable(opcode: OpCode, index: usize, len: usize) -> bool {
+pub(super) fn child_never_cacheable(opcode: OpCode, index: usize, len: usize) -> bool {
     #[cfg(feature = "ext-array")]
     if matches!(opcode, OpCode::Sort) {
         return index == 2;
     }
+    // `reduce_while`'s reducer and condition both run per item.
+    #[cfg(feature = "ext-array")]
+    if matches!(opcode, OpCode::ReduceWhile) {
+        return index == 1 || index == 3;
+    }
+    #[cfg(feature = "ext-array")]
+    if matches!(
+        opcode,
+        OpCode::GroupBy
+            | OpCode::Find
+            | OpCode::Count
+            | OpCode::FlatMap
+            | OpCode::AllValues
+            | OpCode::SomeValues
+            | OpCode::NoneValues
+            | OpCode::MinBy
+            | OpCode::MaxBy
+    ) {
+        return index == 1;
+    }
     #[cfg(feature = "error-handling")]
     if matches!(opcode, OpCode::Try) {
         return len >= 2 && index == len - 1;
     }
+    #[cfg(feature = "ext-control")]
+    if matches!(opcode, OpCode::Let) {
+        return index == len - 1;
+    }
     let _ = len;
     matches!(
         opcode,
@@ -363,7 +395,7 @@ fn is_cse_pure(node: &CompiledNode) -> bool {
     }
 }
 
-fn opcode_is_cse_pure(opcode: OpCode) -> bool {
+pub(super) fn opcode_is_cse_pure(opcode: OpCode) -> bool {
     // With all the gated features off, every impure opcode compiles out
     // and `opcode` would be unused.
     let _ = opcode;
@@ -375,6 +407,10 @@ fn opcode_is_cse_pure(opcode: OpCode) -> bool {
     if matches!(opcode, OpCode::Now) {
         return false;
     }
+    #[cfg(feature = "ext-math")]
+    if matches!(opcode, OpCode::Random) {
+        return false;
+    }
     #[cfg(feature = "flagd")]
     if matches!(opcode, OpCode::Fractional | OpCode::SemVer) {
         return false;
@@ -409,7 +445,20 @@ fn contains_iterator_op(node: &CompiledNode) -> bool {
 
 fn is_iterator_opcode(opcode: OpCode) -> bool {
     #[cfg(feature = "ext-array")]
-    if matches!(opcode, OpCode::Sort) {
+    if matches!(
+        opcode,
+        OpCode::Sort
+            | OpCode::GroupBy
+            | OpCode::Find
+            | OpCode::Count
+            | OpCode::FlatMap
+            | OpCode::AllValues
+            | OpCode::SomeValues
+            | OpCode::NoneValues
+            | OpCode::MinBy
+            | OpCode::MaxBy
+            | OpCode::ReduceWhile
+    ) {
         return true;
     }
     matches!(
@@ -6,6 +6,12 @@
 //! - `{"and": [false, X]}` → `false` (absorbing element)
 //! - `{"or": [true, X]}` → `true` (absorbing element)
 //! - `{"or": [false, X]}` → `X` (strip identity elements)
+//!
+//! `and` / `or` rewrites preserve left-to-right evaluation: operands before
+//! an absorbing literal still run (`{"and": [X, false, Y]}` →
+//! `{"and": [X, false]}`, so a falsy `X` is still the result), and a
+//! trailing identity literal is kept because it is the value returned when
+//! everything before it passes (`{"and": [X, true]}` stays as is).
 
 use crate::Engine;
 use crate::node::CompiledNode;
@@ -165,28 +171,28 @@ fn eliminate_bool_chain(
     }
 
     let mut remaining: Vec<CompiledNode> = Vec::new();
+    let last = args.len() - 1;
 
-    for arg in args {
+    for (i, arg) in args.iter().enumerate() {
         match is_truthy_literal(arg, engine) {
             Some(b) if b == absorbing => {
-                // Absorbing element — the chain short-circuits to this value.
-                return Some(arg.clone());
+                // Absorbing element — everything after it is dead, but the
+                // operands before it still run and may short-circuit first.
+                remaining.push(arg.clone());
+                break;
             }
-            Some(_) => {
+            Some(_) if i < last => {
                 // Identity element — skip (the op returns the value, not bool).
                 continue;
             }
-            None => {
+            _ => {
+                // Non-literal, or the trailing identity literal, which is the
+                // chain's value when every earlier operand passes.
                 remaining.push(arg.clone());
             }
         }
     }
 
-    if remaining.is_empty() {
-        // Every element was an identity literal — return the last one.
-        return Some(args.last().unwrap().clone());
-    }
-
     if remaining.len() == 1 {
         return Some(remaining.into_iter().next().unwrap());
     }
@@ -285,6 +291,50 @@ mod tests {
         assert!(matches!(result, CompiledNode::Var { .. }));
     }
 
+    #[test]
+    fn test_and_keeps_operands_before_false() {
+        // `{"and": [x, false, y]}` must still evaluate `x` — a falsy `x`
+        // is the result — so only `y` is dropped.
+        let engine = Engine::new();
+        let node = builtin(
+            OpCode::And,
+            vec![
+                var_node("x"),
+                val(datavalue::OwnedDataValue::Bool(false)),
+                var_node("y"),
+            ],
+        );
+        let (result, changed) = eliminate(node, &engine);
+        assert!(changed);
+        match result {
+            CompiledNode::BuiltinOperator { opcode, args, .. } => {
+                assert_eq!(opcode, OpCode::And);
+                assert_eq!(args.len(), 2);
+                assert!(matches!(args[0], CompiledNode::Var { .. }));
+                assert!(matches!(args[1], CompiledNode::Value { .. }));
+            }
+            other => panic!("expected and, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_trailing_identity_is_kept() {
+        // `{"and": [x, true]}` returns `true` when `x` is truthy, not `x`.
+        let engine = Engine::new();
+        for opcode in [OpCode::And, OpCode::Or] {
+            let identity = opcode == OpCode::And;
+            let node = builtin(
+                opcode,
+                vec![
+                    var_node("x"),
+                    val(datavalue::OwnedDataValue::Bool(identity)),
+                ],
+            );
+            let (_result, changed) = eliminate(node, &engine);
+            assert!(!changed);
+        }
+    }
+
     #[test]
     fn test_ternary_true() {
         // `?:` normalizes to OpCode::If at FromStr; eliminate_if handles
@@ -0,0 +1,205 @@
+//! Loop-invariant hoisting out of iterator bodies.
+//!
+//! Constant folding collapses fully static subtrees, but some
+//! data-independent expressions never fold: `merge` / `min` / `max` keep
+//! their literal arguments for runtime disambiguation, a nested `map` over
+//! a literal array is an iterator, and a pure custom operator is opaque to
+//! the folder. Inside a `map` / `filter` / `reduce` body those expressions
+//! are rebuilt for every item. This pass finds the maximal such subtrees
+//! in iterator bodies and wraps each one in a [`CompiledNode::Cse`] with
+//! its own memo slot and `hoisted` set. `Engine::dispatch_cse` then
+//! computes it on the first iteration and serves every later one from the
+//! per-evaluation slot table on `ContextStack`.
+//!
+//! # Soundness
+//!
+//! The CSE pass needs a runtime `depth() == 0` gate because its subtrees
+//! may read context. A hoisted subtree must not read context at all, so
+//! its value is the same at any depth:
+//!
+//! - every operator in it is pure ([`opcode_is_cse_pure`]), and custom
+//!   operators are eager ones that declared
+//!   [`crate::CustomOperator::is_pure`];
+//! - no `val` / `missing` / `missing_some` / `exists` operator, and no
+//!   `let` (its body falls through to the enclosing frame for unbound
+//!   names);
+//! - every `Var` / `Exists` node resolves inside an iteration frame the
+//!   subtree itself pushes (a nested `map` over a literal array reading
+//!   its own item is fine; reading the outer item is not).
+//!
+//! Evaluation stays lazy — a body that never runs (empty input) never
+//! computes the hoisted value — and only `Ok` results are memoized, so
+//! errors surface exactly where and when they did before.
+
+use crate::Engine;
+use crate::node::{CompiledNode, CseData, SYNTHETIC_ID};
+use crate::opcode::OpCode;
+
+use super::cse::{child_never_cacheable, opcode_is_cse_pure};
+
+/// Run the pass over a finished compile tree, numbering memo slots from
+/// `first_slot` (the CSE pass's slot count). Returns the total slot count
+/// including the ones assigned here.
+pub(crate) fn apply(root: &mut CompiledNode, engine: &Engine, first_slot: u16) -> u16 {
+    let mut next_slot = first_slot;
+    hoist(root, engine, false, &mut next_slot);
+    next_slot
+}
+
+/// Wrap the maximal invariant subtrees below `node`. `in_body` is true
+/// once the walk has entered a position that runs under a pushed frame —
+/// outside one, every node evaluates at most once per occurrence and a
+/// wrapper would be pure overhead.
+fn hoist(node: &mut CompiledNode, engine: &Engine, in_body: bool, next_slot: &mut u16) {
+    if in_body && *next_slot < u16::MAX && is_candidate(node) && is_invariant(node, engine, 0) {
+        let placeholder = CompiledNode::InvalidArgs {
+            id: SYNTHETIC_ID,
+            op_name: "",
+        };
+        let inner = std::mem::replace(node, placeholder);
+        *node = CompiledNode::Cse(Box::new(CseData {
+            slot: *next_slot,
+            hoisted: true,
+            inner,
+        }));
+        *next_slot += 1;
+        return;
+    }
+    match node {
+        CompiledNode::BuiltinOperator { opcode, args, .. } => {
+            let len = args.len();
+            for (i, child) in args.iter_mut().enumerate() {
+                let body = in_body || child_never_cacheable(*opcode, i, len);
+                hoist(child, engine, body, next_slot);
+            }
+        }
+        _ => node.visit_children_mut(&mut |child| hoist(child, engine, in_body, next_slot)),
+    }
+}
+
+/// Only computed nodes are worth a slot: literals are already pre-built,
+/// and an existing memo wrapper is left alone.
+fn is_candidate(node: &CompiledNode) -> bool {
+    matches!(
+        node,
+        CompiledNode::BuiltinOperator { .. }
+            | CompiledNode::CustomOperator(_)
+            | CompiledNode::Array { .. }
+    )
+}
+
+/// True when `node` reads nothing outside the iteration frames it pushes
+/// itself. `frames` counts those frames between the candidate root and
+/// `node`: a context read `scope_level` frames up stays inside the
+/// subtree iff `scope_level < frames`.
+fn is_invariant(node: &CompiledNode, engine: &Engine, frames: u32) -> bool {
+    match node {
+        CompiledNode::Value { .. } => true,
+        CompiledNode::Array { nodes, .. } => nodes.iter().all(|n| is_invariant(n, engine, frames)),
+        CompiledNode::BuiltinOperator { opcode, args, .. } => {
+            if !opcode_is_cse_pure(*opcode) || reads_context(*opcode) {
+                return false;
+            }
+            let len = args.len();
+            args.iter().enumerate().all(|(i, arg)| {
+                let pushed = u32::from(child_never_cacheable(*opcode, i, len));
+                is_invariant(arg, engine, frames + pushed)
+            })
+        }
+        CompiledNode::CustomOperator(data) => {
+            engine.custom_operator_is_pure(&data.name)
+                && data.args.iter().all(|n| is_invariant(n, engine, frames))
+        }
+        CompiledNode::Cse(data) => is_invariant(&data.inner, engine, frames),
+        CompiledNode::Var {
+            scope_level,
+            default_value,
+            ..
+        } => {
+            *scope_level < frames
+                && default_value
+                    .as_deref()
+                    .is_none_or(|d| is_invariant(d, engine, frames))
+        }
+        #[cfg(feature = "ext-control")]
+        CompiledNode::Exists(data) => data.scope_level < frames,
+        // Conservative, as in CSE: templated objects, throws, `missing`
+        // (reads the current frame), and always-failing nodes stay put.
+        #[cfg(feature = "templating")]
+        CompiledNode::StructuredObject(_) => false,
+        #[cfg(feature = "error-handling")]
+        CompiledNode::Throw(_) => false,
+        CompiledNode::Missing(_) | CompiledNode::MissingSome(_) => false,
+        CompiledNode::InvalidArgs { .. } => false,
+    }
+}
+
+/// Operators that read the current context with a path computed at
+/// runtime, or push a frame that doesn't isolate their body.
+fn reads_context(opcode: OpCode) -> bool {
+    #[cfg(feature = "ext-control")]
+    if matches!(opcode, OpCode::Exists | OpCode::Let) {
+        return true;
+    }
+    #[cfg(feature = "ext-string")]
+    if opcode == OpCode::Template {
+        return true;
+    }
+    matches!(opcode, OpCode::Val | OpCode::Missing | OpCode::MissingSome)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Engine;
+
+    fn slot_count(rule: &str) -> u16 {
+        Engine::new().compile(rule).unwrap().cse_slot_count
+    }
+
+    #[test]
+    fn invariant_operator_in_map_body_gets_a_slot() {
+        let rule = r#"{"map": [{"var": "xs"}, {"in": [{"var": ""}, {"merge": [[1, 2], [3]]}]}]}"#;
+        assert_eq!(slot_count(rule), 1);
+    }
+
+    #[test]
+    fn item_reads_are_not_hoisted() {
+        let rule = r#"{"map": [{"var": "xs"}, {"merge": [[{"var": ""}], [3]]}]}"#;
+        assert_eq!(slot_count(rule), 0);
+    }
+
+    #[test]
+    fn outer_frame_reads_are_not_hoisted() {
+        // Invariant for the inner map, but the outer item changes; the
+        // memo is per evaluation, not per loop entry.
+        let rule = r#"{"map": [{"var": "xs"}, {"map": [{"var": "ys"}, {"max": [{"val": [[-1], "n"]}, 1]}]}]}"#;
+        assert_eq!(slot_count(rule), 0);
+    }
+
+    #[test]
+    fn closed_nested_iterators_are_hoisted_whole() {
+        // The inner map reads only its own item: one slot for the whole
+        // inner map, none for its body.
+        let rule = r#"{"filter": [{"var": "xs"}, {"in": [{"var": ""}, {"map": [[1, 2, 3], {"*": [{"var": ""}, 10]}]}]}]}"#;
+        assert_eq!(slot_count(rule), 1);
+    }
+
+    #[test]
+    fn root_level_expressions_are_not_wrapped() {
+        // Outside an iterator body each node runs once anyway.
+        assert_eq!(slot_count(r#"{"merge": [[1], [2]]}"#), 0);
+    }
+
+    #[test]
+    fn maximal_subtrees_take_one_slot_each() {
+        let rule = r#"{"reduce": [{"var": "xs"}, {"+": [{"var": "accumulator"}, {"max": [1, 5]}, {"min": [2, 3]}]}, 0]}"#;
+        assert_eq!(slot_count(rule), 2);
+    }
+
+    #[test]
+    fn no_fold_compile_hoists_nothing() {
+        let engine = Engine::builder().with_constant_folding(false).build();
+        let rule = r#"{"map": [{"var": "xs"}, {"in": [{"var": ""}, {"merge": [[1], [2]]}]}]}"#;
+        assert_eq!(engine.compile(rule).unwrap().cse_slot_count, 0);
+    }
+}
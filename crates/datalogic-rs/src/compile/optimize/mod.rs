@@ -15,6 +15,7 @@ pub(super) mod constant_fold;
 pub(super) mod cse;
 pub(super) mod dead_code;
 mod helpers;
+pub(super) mod hoist;
 pub(super) mod strength;
 
 #[cfg(test)]
@@ -1,23 +1,30 @@
 //! Shared path-parsing helpers used by `compile_missing`, the var/val
 //! compile paths, and other operators that pre-parse string paths at
-//! compile time.
+//! compile time. `set` and `get` parse their (runtime) paths with the same
+//! helpers so their segments mean exactly what they mean in `var`.
 
+#[cfg(any(feature = "ext-array", feature = "ext-control"))]
+use crate::arena::DataValue;
+use crate::arena::value::parse_index_from_end;
 use crate::node::{PathSegment, ReduceHint};
 
 /// Convert a single path component into a [`PathSegment`]: a component that
 /// parses as `usize` becomes `FieldOrIndex` (usable as an array index or an
-/// object key), everything else becomes `Field`.
+/// object key), a negative one such as `-1` becomes `FieldOrIndexFromEnd`,
+/// everything else becomes `Field`.
 #[inline]
-pub(super) fn str_to_segment(s: &str) -> PathSegment {
+pub(crate) fn str_to_segment(s: &str) -> PathSegment {
     if let Ok(idx) = s.parse::<usize>() {
         PathSegment::FieldOrIndex(s.into(), idx)
+    } else if let Some(back) = parse_index_from_end(s) {
+        PathSegment::FieldOrIndexFromEnd(s.into(), back)
     } else {
         PathSegment::Field(s.into())
     }
 }
 
 /// Parse a dot-separated path into pre-parsed segments.
-pub(super) fn parse_path_segments(path: &str) -> Vec<PathSegment> {
+pub(crate) fn parse_path_segments(path: &str) -> Vec<PathSegment> {
     if path.is_empty() {
         return Vec::new();
     }
@@ -27,6 +34,30 @@ pub(super) fn parse_path_segments(path: &str) -> Vec<PathSegment> {
     path.split('.').map(str_to_segment).collect()
 }
 
+/// Convert one element of an array-form path: a string is a single key
+/// (numeric strings may index arrays, as in a dotted path) and an integer is
+/// an index, counted from the end when negative. Anything else is `None`.
+#[cfg(any(feature = "ext-array", feature = "ext-control"))]
+pub(crate) fn segment_from_value(elem: &DataValue<'_>) -> Option<PathSegment> {
+    if let Some(s) = elem.as_str() {
+        return Some(str_to_segment(s));
+    }
+    elem.as_i64().and_then(int_to_segment)
+}
+
+/// Convert an integer path element into a segment: non-negative values index
+/// from the start, negative ones from the end. `None` only for `i64::MIN`.
+pub(crate) fn int_to_segment(i: i64) -> Option<PathSegment> {
+    let key: Box<str> = i.to_string().into();
+    match usize::try_from(i) {
+        Ok(idx) => Some(PathSegment::FieldOrIndex(key, idx)),
+        Err(_) => {
+            let back = usize::try_from(i.checked_neg()?).ok()?;
+            Some(PathSegment::FieldOrIndexFromEnd(key, back))
+        }
+    }
+}
+
 /// Parse a var path and determine the reduce hint. Recognises the special
 /// `current` / `accumulator` prefixes used by the reduce body fast paths.
 pub(super) fn parse_var_path(path: &str) -> (ReduceHint, Vec<PathSegment>) {
@@ -19,7 +19,8 @@ use super::optimize;
 ///
 /// Wraps the recursive descent in a depth guard: each nesting level bumps the
 /// compile-time depth counter and bails with a `ConfigurationError` once it
-/// passes `MAX_COMPILE_DEPTH`. This bounds a programmatically-built rule
+/// passes the engine's maximum depth (`MAX_COMPILE_DEPTH` unless set with
+/// `EngineBuilder::with_max_depth`). This bounds a programmatically-built rule
 /// (which reaches the compiler via `IntoLogic` without the JSON parser's own
 /// depth cap) so it can't overflow the stack here, in dispatch, or in the
 /// recursive `Drop` of the compiled tree.
@@ -81,13 +82,27 @@ fn compile_multi_key_object(
             },
         )));
     }
-    let _ = (pairs, engine, templating, ctx);
-    Err(crate::error::Error::invalid_operator("Unknown Operator"))
+    ctx.report(crate::error::Error::invalid_operator("Unknown Operator"))?;
+    // Collecting diagnostics: keep looking for problems in the values.
+    for (_, value) in pairs {
+        compile_node(value, engine, templating, ctx)?;
+    }
+    Ok(placeholder_node(ctx))
+}
+
+/// Stand-in for a subtree rejected while collecting diagnostics
+/// (`Engine::validate`). Never evaluated: a validating compile discards
+/// the tree.
+fn placeholder_node(ctx: &mut CompileCtx) -> CompiledNode {
+    CompiledNode::value_with_id(Some(ctx.next_id()), OwnedDataValue::Null)
 }
 
 /// Single-key object: an operator invocation. Routes to either the builtin
 /// path (when the key parses as an `OpCode`) or the custom-operator /
-/// templating-mode path.
+/// templating-mode path. A custom operator that overrides a built-in (see
+/// `EngineBuilder::with_operator_overrides`) takes the custom path first,
+/// as does one registered in place of a removed built-in, and a name
+/// removed with `EngineBuilder::remove_operator` is rejected before either.
 fn compile_operator_invocation(
     op_name: &str,
     args_value: &OwnedDataValue,
@@ -95,6 +110,17 @@ fn compile_operator_invocation(
     templating: bool,
     ctx: &mut CompileCtx,
 ) -> Result<CompiledNode> {
+    if let Some(eng) = engine {
+        if eng.is_operator_removed(op_name) {
+            ctx.report(crate::error::Error::invalid_operator(op_name.to_string()))?;
+            compile_args(args_value, engine, templating, ctx)?;
+            return Ok(placeholder_node(ctx));
+        }
+        if eng.custom_operator_overrides(op_name) || eng.is_builtin_removed(op_name) {
+            let args = compile_args(args_value, engine, templating, ctx)?;
+            return Ok(custom_operator_node(op_name, args, ctx));
+        }
+    }
     if let Ok(opcode) = op_name.parse::<OpCode>() {
         return compile_builtin(op_name, opcode, args_value, engine, templating, ctx);
     }
@@ -104,6 +130,11 @@ fn compile_operator_invocation(
         return compile_templating_unknown(op_name, args_value, engine, templating, ctx);
     }
 
+    // Unregistered names compile to a custom node that fails at
+    // evaluation; a validating compile reports them up front.
+    if ctx.is_collecting() && engine.is_some_and(|eng| !eng.has_custom_operator(op_name)) {
+        ctx.note(|| crate::error::Error::invalid_operator(op_name.to_string()));
+    }
     let args = compile_args(args_value, engine, templating, ctx)?;
     Ok(custom_operator_node(op_name, args, ctx))
 }
@@ -139,6 +170,26 @@ fn compile_builtin(
         return Ok(invalid_args_marker(opcode, ctx));
     }
 
+    #[cfg(feature = "ext-control")]
+    if opcode == OpCode::Let {
+        return compile_let(args_value, engine, templating, ctx);
+    }
+
+    if !iterator_arity_ok(opcode, args_value) {
+        return compile_bad_iterator_arity(opcode, args_value, engine, templating, ctx);
+    }
+
+    #[cfg(feature = "error-handling")]
+    if !templating {
+        if let Some(node) = try_compile_throw_object(opcode, args_value, ctx) {
+            return Ok(node);
+        }
+    }
+
+    if let Some(options) = cat_options(opcode, args_value, templating) {
+        return compile_cat_options(options, engine, templating, ctx);
+    }
+
     let args = compile_args(args_value, engine, templating, ctx)?;
 
     if let Some(node) = try_specialised(op_name, opcode, &args, ctx) {
@@ -157,6 +208,17 @@ fn compile_builtin(
         return Ok(node);
     }
 
+    Ok(builtin_node(opcode, args, engine, ctx))
+}
+
+/// Build the generic `BuiltinOperator` node for already-compiled `args`,
+/// then run the optimization + static-fold passes over it.
+fn builtin_node(
+    opcode: OpCode,
+    args: Box<[CompiledNode]>,
+    engine: Option<&Engine>,
+    ctx: &mut CompileCtx,
+) -> CompiledNode {
     let mut node = CompiledNode::BuiltinOperator {
         id: Some(ctx.next_id()),
         opcode,
@@ -176,13 +238,13 @@ fn compile_builtin(
             node = optimize::optimize(node, eng);
             if node_is_static(&node) {
                 if let Some(value) = optimize::constant_fold::fold_static_node(&node, eng) {
-                    return Ok(CompiledNode::compile_time_value(Some(ctx.next_id()), value));
+                    return CompiledNode::compile_time_value(Some(ctx.next_id()), value);
                 }
             }
         }
     }
 
-    Ok(node)
+    node
 }
 
 /// Try the operator-specific compile-time specialisations: `var`, `val`,
@@ -215,17 +277,174 @@ fn try_specialised(
     }
 }
 
+/// `map` / `filter` take exactly two arguments and `reduce` two or three.
+/// Checked at compile time so a missing predicate or reducer fails the
+/// compile instead of only erroring once evaluation reaches the node. A
+/// non-array argument counts as one argument, like `compile_args` treats it.
+fn iterator_arity_ok(opcode: OpCode, args_value: &OwnedDataValue) -> bool {
+    let len = match args_value {
+        OwnedDataValue::Array(items) => items.len(),
+        _ => 1,
+    };
+    match opcode {
+        OpCode::Map | OpCode::Filter => len == 2,
+        OpCode::Reduce => matches!(len, 2 | 3),
+        _ => true,
+    }
+}
+
+/// Report a `map` / `filter` / `reduce` arity mismatch and compile the args
+/// anyway so `Engine::validate` still sees problems nested inside them.
+/// `#[cold]` + `#[inline(never)]` keeps the error construction out of
+/// `compile_builtin`'s frame, which every nesting level of a rule pays for.
+#[cold]
+#[inline(never)]
+fn compile_bad_iterator_arity(
+    opcode: OpCode,
+    args_value: &OwnedDataValue,
+    engine: Option<&Engine>,
+    templating: bool,
+    ctx: &mut CompileCtx,
+) -> Result<CompiledNode> {
+    ctx.report(crate::error::Error::invalid_args().with_operator(opcode.as_str()))?;
+    compile_args(args_value, engine, templating, ctx)?;
+    Ok(placeholder_node(ctx))
+}
+
 /// Build the [`CompiledNode::InvalidArgs`] placeholder for `and` / `or` /
 /// `if` invoked with a non-array argument. Carries the op name forward so
 /// the dispatcher can produce an error that names the failing op rather
 /// than a generic "Invalid Arguments".
 fn invalid_args_marker(opcode: OpCode, ctx: &mut CompileCtx) -> CompiledNode {
+    ctx.note(|| crate::error::Error::invalid_args().with_operator(opcode.as_str()));
     CompiledNode::InvalidArgs {
         id: Some(ctx.next_id()),
         op_name: opcode.as_str(),
     }
 }
 
+/// `let`: `[{"x": <expr>, ...}, <body>]`. The bindings object isn't an
+/// operator invocation, so it can't go through `compile_args`; instead
+/// it's flattened into the argument list as `[name, expr, ..., body]`,
+/// which `evaluate_let` walks in pairs. Any other shape compiles to the
+/// invalid-args marker. Never folded: the body reads its bindings from a
+/// pushed context frame.
+#[cfg(feature = "ext-control")]
+fn compile_let(
+    args_value: &OwnedDataValue,
+    engine: Option<&Engine>,
+    templating: bool,
+    ctx: &mut CompileCtx,
+) -> Result<CompiledNode> {
+    let OwnedDataValue::Array(items) = args_value else {
+        return Ok(invalid_args_marker(OpCode::Let, ctx));
+    };
+    let [OwnedDataValue::Object(bindings), body] = items.as_slice() else {
+        return Ok(invalid_args_marker(OpCode::Let, ctx));
+    };
+    let mut args = Vec::with_capacity(bindings.len() * 2 + 1);
+    for (name, expr) in bindings {
+        args.push(CompiledNode::value_with_id(
+            Some(ctx.next_id()),
+            OwnedDataValue::String(name.clone()),
+        ));
+        args.push(compile_node(expr, engine, templating, ctx)?);
+    }
+    args.push(compile_node(body, engine, templating, ctx)?);
+    Ok(CompiledNode::BuiltinOperator {
+        id: Some(ctx.next_id()),
+        opcode: OpCode::Let,
+        args: args.into_boxed_slice(),
+        predicate_hint: None,
+        iter_arg_kind: crate::operators::array::IterArgKind::General,
+    })
+}
+
+/// Where argument `index` of a compiled `let` sits in its source
+/// `[{"x": <expr>, ...}, <body>]`, undoing [`compile_let`]'s flattening.
+/// `None` for the name slots, which have no source node of their own.
+#[cfg(feature = "ext-control")]
+pub(crate) fn let_operand(args: &[CompiledNode], index: usize) -> Option<LetOperand<'_>> {
+    if index + 1 == args.len() {
+        return Some(LetOperand::Body);
+    }
+    if index % 2 == 0 {
+        return None;
+    }
+    match &args[index - 1] {
+        CompiledNode::Value {
+            value: OwnedDataValue::String(name),
+            ..
+        } => Some(LetOperand::Binding(name)),
+        _ => None,
+    }
+}
+
+/// A `let` operand's place in the source, from [`let_operand`].
+#[cfg(feature = "ext-control")]
+pub(crate) enum LetOperand<'a> {
+    /// The expression bound to this name, at `/let/0/<name>`.
+    Binding(&'a str),
+    /// The body, at `/let/1`.
+    Body,
+}
+
+/// The `{"sep": ..., "items": [...]}` object form of `cat`, if
+/// `args_value` has that shape: an object with an `items` key and no keys
+/// besides `sep` and `items`. Any other object is an ordinary single
+/// argument (`{"cat": {"var": "x"}}`). Off in templating mode, where a
+/// multi-key object is an output template.
+fn cat_options(
+    opcode: OpCode,
+    args_value: &OwnedDataValue,
+    templating: bool,
+) -> Option<&[(String, OwnedDataValue)]> {
+    let OwnedDataValue::Object(pairs) = args_value else {
+        return None;
+    };
+    if opcode != OpCode::Concat || templating {
+        return None;
+    }
+    let has_items = pairs.iter().any(|(k, _)| k == "items");
+    let known = pairs.iter().all(|(k, _)| k == "sep" || k == "items");
+    (has_items && known).then_some(pairs.as_slice())
+}
+
+/// Compile the `cat` object form to [`OpCode::ConcatSep`] with args
+/// `[sep, a, b, c]`, so the separator is compiled and evaluated once. It
+/// serialises back to the object form. `sep` defaults to `""`. An `items`
+/// that isn't a literal array compiles to the invalid-args marker — a
+/// computed array is what `join` is for. `#[inline(never)]` keeps it out
+/// of `compile_builtin`'s frame.
+#[inline(never)]
+fn compile_cat_options(
+    options: &[(String, OwnedDataValue)],
+    engine: Option<&Engine>,
+    templating: bool,
+    ctx: &mut CompileCtx,
+) -> Result<CompiledNode> {
+    let field = |key: &str| options.iter().find(|(k, _)| k == key).map(|(_, v)| v);
+    let Some(OwnedDataValue::Array(items)) = field("items") else {
+        return Ok(invalid_args_marker(OpCode::Concat, ctx));
+    };
+    let mut args = Vec::with_capacity(items.len() + 1);
+    args.push(match field("sep") {
+        Some(sep) => compile_node(sep, engine, templating, ctx)?,
+        None => {
+            CompiledNode::value_with_id(Some(ctx.next_id()), OwnedDataValue::String(String::new()))
+        }
+    });
+    for item in items {
+        args.push(compile_node(item, engine, templating, ctx)?);
+    }
+    Ok(builtin_node(
+        OpCode::ConcatSep,
+        args.into_boxed_slice(),
+        engine,
+        ctx,
+    ))
+}
+
 /// `throw` with a literal string argument compiles to a pre-built error
 /// payload so runtime evaluation has nothing to coerce.
 #[cfg(feature = "error-handling")]
@@ -255,6 +474,35 @@ fn try_compile_throw_literal(
     )))
 }
 
+/// `throw` with a multi-key object argument (bare or as the only array
+/// element). Outside templating mode such an object can't be an operator
+/// invocation, so it's the error payload itself, thrown verbatim.
+/// Single-key objects still compile as operators: `{"throw": {"var": "e"}}`
+/// throws whatever `e` holds.
+#[cfg(feature = "error-handling")]
+fn try_compile_throw_object(
+    opcode: OpCode,
+    args_value: &OwnedDataValue,
+    ctx: &mut CompileCtx,
+) -> Option<CompiledNode> {
+    if opcode != OpCode::Throw {
+        return None;
+    }
+    let payload = match args_value {
+        OwnedDataValue::Array(items) if items.len() == 1 => &items[0],
+        other => other,
+    };
+    match payload {
+        OwnedDataValue::Object(pairs) if pairs.len() > 1 => Some(CompiledNode::Throw(Box::new(
+            crate::node::CompiledThrowData {
+                id: Some(ctx.next_id()),
+                error: payload.clone(),
+            },
+        ))),
+        _ => None,
+    }
+}
+
 /// Unknown-operator handling under `templating` mode. Custom
 /// operators registered on the engine compile to a `CustomOperator`;
 /// otherwise the key/value pair becomes a single-field structured-object
@@ -1,5 +1,5 @@
 //! Engine evaluation knobs: NaN/divbyzero handling, truthiness rules,
-//! numeric coercion, and the recursion-depth cap.
+//! numeric coercion, non-finite results, and the recursion-depth cap.
 //!
 //! The default configuration matches the JSONLogic reference behaviour
 //! (JavaScript-flavoured truthiness, NaN errors on bad arithmetic input,
@@ -66,6 +66,17 @@ pub struct EvaluationConfig {
     /// fractional operands take the configurable float path.
     pub division_by_zero: DivisionByZeroHandling,
 
+    /// Report a zero divisor in `/` and `%` as
+    /// [`ErrorKind::DivisionByZero`](crate::ErrorKind::DivisionByZero)
+    /// instead of the JSONLogic `{"type": "NaN"}` thrown for bad numeric
+    /// input. Default: `false`, which keeps the reference behaviour the
+    /// shared test suites expect. Set it to branch on the cause: a
+    /// `try` catch arm then sees `{"type": "DivisionByZero", ...}`.
+    /// Affects only the cases that error — the integer carve-out and
+    /// [`DivisionByZeroHandling::ThrowError`] — not the sentinel results
+    /// the other handling modes return.
+    pub distinct_division_by_zero: bool,
+
     /// Whether `==` / `!=` (loose equality) raise an error on values
     /// that can't be sensibly compared (e.g. an object compared to a
     /// number). Default: `true` (raise). Set to `false` for the
@@ -73,6 +84,16 @@ pub struct EvaluationConfig {
     /// returns `false` silently.
     pub loose_equality_errors: bool,
 
+    /// Whether a `var` / `val` lookup whose path doesn't exist fails
+    /// with [`ErrorKind::VariableNotFound`](crate::ErrorKind::VariableNotFound)
+    /// instead of resolving to `null`. Default: `false` (the JSONLogic
+    /// reference behaviour). A `var` that carries its own default
+    /// (`{"var": ["a.b", 0]}`) still falls back to it, and `missing` /
+    /// `exists` keep probing silently — use those to test for a key on
+    /// purpose. Turn this on to catch path typos that would otherwise
+    /// flow through the rule as `null`.
+    pub strict_variables: bool,
+
     /// How values are coerced to booleans by `if`, `and`, `or`, `!`,
     /// `!!`, and the predicate slot of array operators. Default:
     /// [`TruthyEvaluator::JavaScript`] (the reference JSONLogic rule:
@@ -114,6 +135,38 @@ pub struct EvaluationConfig {
     /// custom operators registered (built-ins can't recurse via
     /// boundary re-entry), so pure-built-in workloads pay nothing.
     pub max_recursion_depth: u32,
+
+    /// Seed for `random` calls that don't pass their own. Default: `None`
+    /// — an unseeded call draws fresh entropy each time (or errors under
+    /// [`Self::deterministic_mode`]). With a seed, unseeded calls draw
+    /// from a stream that restarts on each evaluation: successive calls
+    /// differ, and every evaluation repeats the same sequence.
+    pub random_seed: Option<u64>,
+
+    /// Require every `random` call to be seeded, either by its own seed
+    /// argument or by [`Self::random_seed`]. Default: `false`. When
+    /// `true`, an unseeded call fails with
+    /// [`ErrorKind::ConfigurationError`](crate::ErrorKind::ConfigurationError)
+    /// instead of silently producing a non-reproducible number.
+    pub deterministic_mode: bool,
+
+    /// Step budget for a single evaluation. Default: `None` (unbounded).
+    /// Every non-literal operator evaluation costs one step, and every
+    /// element an iterator operator (`map`, `filter`, `reduce`, ...) walks
+    /// costs one more, so a rule's cost tracks the work it actually does
+    /// rather than its size. Once the count passes the limit, evaluation
+    /// aborts with [`ErrorKind::StepLimitExceeded`](crate::ErrorKind::StepLimitExceeded);
+    /// `try` does not catch it. Use this to bound untrusted rules that
+    /// iterate over large data.
+    pub step_limit: Option<u64>,
+
+    /// What happens to a `NaN` or `±Infinity` float in an evaluation's
+    /// result, e.g. from `{"*": [1e308, 10]}`.
+    /// Default: [`NonFiniteHandling::Keep`] — the float is returned as-is,
+    /// and JSON output writes it as `null`. The check runs once on the
+    /// final result (nested arrays and objects included), so `try` does
+    /// not catch the error [`NonFiniteHandling::ThrowError`] raises.
+    pub non_finite_numbers: NonFiniteHandling,
 }
 
 /// Defines how to handle NaN (Not a Number) scenarios in arithmetic operations
@@ -145,6 +198,17 @@ pub enum DivisionByZeroHandling {
     ReturnInfinity,
 }
 
+/// Defines how to handle `NaN` / `±Infinity` in an evaluation's result
+#[derive(Clone, Debug, PartialEq)]
+pub enum NonFiniteHandling {
+    /// Return the float unchanged; JSON output writes it as `null` (default)
+    Keep,
+    /// Replace every non-finite float in the result with `null`
+    ReturnNull,
+    /// Fail with [`ErrorKind::NonFiniteNumber`](crate::ErrorKind::NonFiniteNumber)
+    ThrowError,
+}
+
 /// Defines how to evaluate truthiness of values
 #[derive(Clone)]
 pub enum TruthyEvaluator {
@@ -258,10 +322,16 @@ impl Default for EvaluationConfig {
         Self {
             arithmetic_nan_handling: NanHandling::ThrowError,
             division_by_zero: DivisionByZeroHandling::ReturnSaturated,
+            distinct_division_by_zero: false,
             loose_equality_errors: true,
+            strict_variables: false,
             truthy_evaluator: TruthyEvaluator::JavaScript,
             numeric_coercion: NumericCoercionConfig::default(),
             max_recursion_depth: 256,
+            random_seed: None,
+            deterministic_mode: false,
+            step_limit: None,
+            non_finite_numbers: NonFiniteHandling::Keep,
         }
     }
 }
@@ -324,6 +394,13 @@ impl EvaluationConfig {
         self
     }
 
+    /// Set [`Self::distinct_division_by_zero`].
+    #[must_use]
+    pub fn with_distinct_division_by_zero(mut self, value: bool) -> Self {
+        self.distinct_division_by_zero = value;
+        self
+    }
+
     /// Set [`Self::loose_equality_errors`].
     #[must_use]
     pub fn with_loose_equality_errors(mut self, value: bool) -> Self {
@@ -331,6 +408,13 @@ impl EvaluationConfig {
         self
     }
 
+    /// Set [`Self::strict_variables`].
+    #[must_use]
+    pub fn with_strict_variables(mut self, value: bool) -> Self {
+        self.strict_variables = value;
+        self
+    }
+
     /// Set [`Self::truthy_evaluator`].
     #[must_use]
     pub fn with_truthy_evaluator(mut self, value: TruthyEvaluator) -> Self {
@@ -352,6 +436,34 @@ impl EvaluationConfig {
         self
     }
 
+    /// Set [`Self::random_seed`].
+    #[must_use]
+    pub fn with_random_seed(mut self, value: Option<u64>) -> Self {
+        self.random_seed = value;
+        self
+    }
+
+    /// Set [`Self::deterministic_mode`].
+    #[must_use]
+    pub fn with_deterministic_mode(mut self, value: bool) -> Self {
+        self.deterministic_mode = value;
+        self
+    }
+
+    /// Set [`Self::step_limit`].
+    #[must_use]
+    pub fn with_step_limit(mut self, value: Option<u64>) -> Self {
+        self.step_limit = value;
+        self
+    }
+
+    /// Set [`Self::non_finite_numbers`].
+    #[must_use]
+    pub fn with_non_finite_numbers(mut self, value: NonFiniteHandling) -> Self {
+        self.non_finite_numbers = value;
+        self
+    }
+
     /// Create a configuration with safe arithmetic (ignores non-numeric values)
     pub fn safe_arithmetic() -> Self {
         Self {
@@ -404,10 +516,16 @@ impl EvaluationConfig {
     /// | `preset` | `"default"` \| `"safe_arithmetic"` \| `"strict"` |
     /// | `arithmetic_nan_handling` | `"throw_error"` \| `"ignore_value"` \| `"coerce_to_zero"` \| `"return_null"` |
     /// | `division_by_zero` | `"return_saturated"` \| `"throw_error"` \| `"return_null"` \| `"return_infinity"` |
+    /// | `distinct_division_by_zero` | bool |
     /// | `loose_equality_errors` | bool |
+    /// | `strict_variables` | bool |
     /// | `truthy_evaluator` | `"javascript"` \| `"python"` \| `"strict_boolean"` |
     /// | `numeric_coercion` | object with bool keys `empty_string_to_zero`, `null_to_zero`, `bool_to_number`, `reject_non_numeric` |
     /// | `max_recursion_depth` | integer ≥ 1 |
+    /// | `random_seed` | integer ≥ 0, or `null` |
+    /// | `deterministic_mode` | bool |
+    /// | `step_limit` | integer ≥ 1, or `null` |
+    /// | `non_finite_numbers` | `"keep"` \| `"return_null"` \| `"throw_error"` |
     ///
     /// # Example
     ///
@@ -498,9 +616,15 @@ impl EvaluationConfig {
                         }
                     };
                 }
+                "distinct_division_by_zero" => {
+                    config.distinct_division_by_zero = expect_bool(key, value)?;
+                }
                 "loose_equality_errors" => {
                     config.loose_equality_errors = expect_bool(key, value)?;
                 }
+                "strict_variables" => {
+                    config.strict_variables = expect_bool(key, value)?;
+                }
                 "truthy_evaluator" => {
                     config.truthy_evaluator = match expect_str(key, value)? {
                         "javascript" => TruthyEvaluator::JavaScript,
@@ -553,6 +677,43 @@ impl EvaluationConfig {
                         })?;
                     config.max_recursion_depth = depth as u32;
                 }
+                "random_seed" => {
+                    config.random_seed = match value {
+                        Value::Null => None,
+                        _ => Some(value.as_u64().ok_or_else(|| {
+                            cfg_err(
+                                "config key \"random_seed\" must be a non-negative integer or null"
+                                    .to_string(),
+                            )
+                        })?),
+                    };
+                }
+                "deterministic_mode" => {
+                    config.deterministic_mode = expect_bool(key, value)?;
+                }
+                "step_limit" => {
+                    config.step_limit = match value {
+                        Value::Null => None,
+                        _ => Some(value.as_u64().filter(|n| *n >= 1).ok_or_else(|| {
+                            cfg_err(
+                                "config key \"step_limit\" must be a positive integer or null"
+                                    .to_string(),
+                            )
+                        })?),
+                    };
+                }
+                "non_finite_numbers" => {
+                    config.non_finite_numbers = match expect_str(key, value)? {
+                        "keep" => NonFiniteHandling::Keep,
+                        "return_null" => NonFiniteHandling::ReturnNull,
+                        "throw_error" => NonFiniteHandling::ThrowError,
+                        other => {
+                            return Err(cfg_err(format!(
+                                "unknown non_finite_numbers {other:?} (expected \"keep\", \"return_null\", or \"throw_error\")"
+                            )));
+                        }
+                    };
+                }
                 other => {
                     return Err(cfg_err(format!("unknown config key {other:?}")));
                 }
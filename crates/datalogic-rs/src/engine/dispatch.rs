@@ -27,9 +27,11 @@ use crate::{CompiledNode, Error, Result};
 ///    the compiler cached on `iter_arg_kind`?** → use `iter`. The
 ///    dispatcher dereferences `*iter_arg_kind` and threads it in front of
 ///    `ctx`. This covers `filter`, `map`, `all`, `some`, `none`,
-///    `reduce`, `max`, `min`, `sort`. The `IterArgKind` value tells the
-///    op whether the source is an array, an arena-allocated array, an
-///    inline literal, etc., letting it skip work it has already done.
+///    `reduce`, `max`, `min`, `sort`, `group_by`, `find`, `count`,
+///    `flat_map`, `all_values`, `some_values`, `none_values`. The
+///    `IterArgKind` value tells the op whether the source is an array, an
+///    arena-allocated array, an inline literal, etc., letting it skip work
+///    it has already done.
 /// 3. **Does your operator just take `(args, ctx, engine, arena)`?** →
 ///    use `simple`. This is the dominant shape — most builtins land
 ///    here (`Add`, `Equals`, `Concat`, `If`, the comparison family, the
@@ -229,6 +231,10 @@ pub(super) fn dispatch_node_inner<'a>(
             Val => crate::operators::variable::evaluate_val,
             #[cfg(feature = "ext-control")]
             Exists => crate::operators::variable::evaluate_exists,
+            #[cfg(feature = "ext-control")]
+            Get => crate::operators::variable::evaluate_get,
+            #[cfg(feature = "ext-control")]
+            Has => crate::operators::variable::evaluate_has,
 
             // Array / collection
             Merge => crate::operators::array::evaluate_merge,
@@ -238,11 +244,45 @@ pub(super) fn dispatch_node_inner<'a>(
             Length => crate::operators::array::evaluate_length,
             #[cfg(feature = "ext-array")]
             Slice => crate::operators::array::evaluate_slice,
+            #[cfg(feature = "ext-array")]
+            Flatten => crate::operators::array::evaluate_flatten,
+            #[cfg(feature = "ext-array")]
+            Unique => crate::operators::array::evaluate_unique,
+            #[cfg(feature = "ext-array")]
+            IndexOf => crate::operators::array::evaluate_index_of,
+            #[cfg(feature = "ext-array")]
+            Zip => crate::operators::array::evaluate_zip,
+            #[cfg(feature = "ext-array")]
+            Chunk => crate::operators::array::evaluate_chunk,
+            #[cfg(feature = "ext-array")]
+            Window => crate::operators::array::evaluate_window,
+            #[cfg(feature = "ext-array")]
+            Reverse => crate::operators::array::evaluate_reverse,
+            #[cfg(feature = "ext-array")]
+            Contains => crate::operators::array::evaluate_contains,
+            #[cfg(feature = "ext-array")]
+            MergeObjects => crate::operators::array::evaluate_merge_objects,
+            #[cfg(feature = "ext-array")]
+            Set => crate::operators::array::evaluate_set,
 
             // Arithmetic (binary)
             Add => crate::operators::arithmetic::evaluate_add,
             Multiply => crate::operators::arithmetic::evaluate_multiply,
             Subtract => crate::operators::arithmetic::evaluate_subtract,
+            #[cfg(feature = "ext-math")]
+            Pow => crate::operators::arithmetic::evaluate_pow,
+            #[cfg(feature = "ext-math")]
+            Sqrt => crate::operators::arithmetic::evaluate_sqrt,
+            #[cfg(feature = "ext-math")]
+            Clamp => crate::operators::arithmetic::evaluate_clamp,
+            #[cfg(feature = "ext-math")]
+            Log => crate::operators::arithmetic::evaluate_log,
+            #[cfg(feature = "ext-math")]
+            Random => crate::operators::random::evaluate_random,
+            #[cfg(feature = "ext-math")]
+            BitNot => crate::operators::arithmetic::evaluate_bitnot,
+            #[cfg(feature = "ext-math")]
+            ToFixed => crate::operators::arithmetic::evaluate_to_fixed,
 
             // Comparison
             Equals => crate::operators::comparison::evaluate_equals,
@@ -253,6 +293,7 @@ pub(super) fn dispatch_node_inner<'a>(
             GreaterThanEqual => crate::operators::comparison::evaluate_greater_than_equal,
             LessThan => crate::operators::comparison::evaluate_less_than,
             LessThanEqual => crate::operators::comparison::evaluate_less_than_equal,
+            Between => crate::operators::comparison::evaluate_between,
 
             // Logical
             Not => crate::operators::logical::evaluate_not,
@@ -266,10 +307,15 @@ pub(super) fn dispatch_node_inner<'a>(
             #[cfg(feature = "ext-control")]
             Coalesce => crate::operators::control::evaluate_coalesce,
             #[cfg(feature = "ext-control")]
+            Default => crate::operators::control::evaluate_default,
+            #[cfg(feature = "ext-control")]
+            Let => crate::operators::control::evaluate_let,
+            #[cfg(feature = "ext-control")]
             Switch => crate::operators::control::evaluate_switch,
 
             // String
             Concat => crate::operators::string::evaluate_concat,
+            ConcatSep => crate::operators::string::evaluate_concat_sep,
             Substr => crate::operators::string::evaluate_substr,
             In => crate::operators::string::evaluate_in,
             #[cfg(feature = "ext-string")]
@@ -284,6 +330,32 @@ pub(super) fn dispatch_node_inner<'a>(
             Trim => crate::operators::string::evaluate_trim,
             #[cfg(feature = "ext-string")]
             Split => crate::operators::string::evaluate_split,
+            #[cfg(feature = "ext-string")]
+            Join => crate::operators::string::evaluate_join,
+            #[cfg(feature = "ext-string")]
+            Repeat => crate::operators::string::evaluate_repeat,
+            #[cfg(feature = "ext-string")]
+            JsonParse => crate::operators::json::evaluate_json_parse,
+            #[cfg(feature = "ext-string")]
+            JsonStringify => crate::operators::json::evaluate_json_stringify,
+            #[cfg(feature = "ext-string")]
+            NumberFormat => crate::operators::string::evaluate_number_format,
+            #[cfg(feature = "ext-string")]
+            Slugify => crate::operators::string::evaluate_slugify,
+            #[cfg(feature = "ext-string")]
+            Format => crate::operators::string::evaluate_format,
+            #[cfg(feature = "ext-string")]
+            Template => crate::operators::string::evaluate_template,
+            #[cfg(feature = "ext-string")]
+            StartsWithI => crate::operators::string::evaluate_starts_with_i,
+            #[cfg(feature = "ext-string")]
+            EndsWithI => crate::operators::string::evaluate_ends_with_i,
+            #[cfg(feature = "ext-string")]
+            InI => crate::operators::string::evaluate_in_i,
+            #[cfg(feature = "ext-string")]
+            TrimStart => crate::operators::string::evaluate_trim_start,
+            #[cfg(feature = "ext-string")]
+            TrimEnd => crate::operators::string::evaluate_trim_end,
 
             // DateTime
             #[cfg(feature = "datetime")]
@@ -298,10 +370,22 @@ pub(super) fn dispatch_node_inner<'a>(
             DateDiff => crate::operators::datetime::evaluate_date_diff,
             #[cfg(feature = "datetime")]
             Now => crate::operators::datetime::evaluate_now,
+            #[cfg(feature = "datetime")]
+            DateFormat => crate::operators::datetime::evaluate_date_format,
+            #[cfg(feature = "datetime")]
+            DateParse => crate::operators::datetime::evaluate_date_parse,
+            #[cfg(feature = "datetime")]
+            ToTimezone => crate::operators::datetime::evaluate_to_timezone,
+            #[cfg(feature = "datetime")]
+            Duration => crate::operators::datetime::evaluate_duration,
 
             // Type
             #[cfg(feature = "ext-control")]
             Type => crate::operators::inspect::evaluate_type,
+            #[cfg(feature = "ext-control")]
+            ParseInt => crate::operators::convert::evaluate_parse_int,
+            #[cfg(feature = "ext-control")]
+            ParseFloat => crate::operators::convert::evaluate_parse_float,
 
             // Throw / Try
             #[cfg(feature = "error-handling")]
@@ -314,6 +398,12 @@ pub(super) fn dispatch_node_inner<'a>(
             Fractional => crate::operators::flagd::evaluate_fractional,
             #[cfg(feature = "flagd")]
             SemVer => crate::operators::flagd::evaluate_sem_ver,
+
+            // regex
+            #[cfg(feature = "regex")]
+            RegexMatch => crate::operators::regex::evaluate_regex_match,
+            #[cfg(feature = "regex")]
+            RegexReplace => crate::operators::regex::evaluate_regex_replace,
         ],
 
         // `BuiltinOperator { opcode, args, iter_arg_kind, .. } => fn(args,
@@ -330,11 +420,35 @@ pub(super) fn dispatch_node_inner<'a>(
             Min => crate::operators::arithmetic::evaluate_min,
             #[cfg(feature = "ext-array")]
             Sort => crate::operators::array::evaluate_sort,
+            #[cfg(feature = "ext-array")]
+            GroupBy => crate::operators::array::evaluate_group_by,
+            #[cfg(feature = "ext-array")]
+            Find => crate::operators::array::evaluate_find,
+            #[cfg(feature = "ext-array")]
+            Count => crate::operators::array::evaluate_count,
+            #[cfg(feature = "ext-array")]
+            FlatMap => crate::operators::array::evaluate_flat_map,
+            #[cfg(feature = "ext-array")]
+            AllValues => crate::operators::array::evaluate_all_values,
+            #[cfg(feature = "ext-array")]
+            SomeValues => crate::operators::array::evaluate_some_values,
+            #[cfg(feature = "ext-array")]
+            NoneValues => crate::operators::array::evaluate_none_values,
+            #[cfg(feature = "ext-array")]
+            MinBy => crate::operators::array::evaluate_min_by,
+            #[cfg(feature = "ext-array")]
+            MaxBy => crate::operators::array::evaluate_max_by,
+            #[cfg(feature = "ext-array")]
+            ReduceWhile => crate::operators::array::evaluate_reduce_while,
         ],
 
         // `BuiltinOperator { opcode, args, .. } => fn(args, ctx, engine,
         // arena, kind)` shape — operators sharing one impl behind a
-        // discriminator (Divide/Modulo, Abs/Ceil/Floor).
+        // discriminator (Divide/Modulo, Abs/Ceil/Floor/Sign, Exp/Sin/Cos/Tan,
+        // Gcd/Lcm, BitAnd/BitOr/BitXor, LShift/RShift, Sum/Avg, Take/Drop, Pick/Omit, PadStart/PadEnd, Capitalize/TitleCase,
+        // CharAt/CodePointAt, Base64Encode/Base64Decode,
+        // ToNumber/ToInt/ToString/ToBool, IsEmpty/IsNull,
+        // Year/Month/Day/Hour/Minute/Weekday, DateAdd/DateSub, StartOf/EndOf).
         with_kind: [
             Divide => (crate::operators::arithmetic::div_or_mod, crate::operators::arithmetic::DivOp::Divide),
             Modulo => (crate::operators::arithmetic::div_or_mod, crate::operators::arithmetic::DivOp::Modulo),
@@ -344,6 +458,90 @@ pub(super) fn dispatch_node_inner<'a>(
             Ceil => (crate::operators::arithmetic::unary_math, crate::operators::arithmetic::UnaryMathOp::Ceil),
             #[cfg(feature = "ext-math")]
             Floor => (crate::operators::arithmetic::unary_math, crate::operators::arithmetic::UnaryMathOp::Floor),
+            #[cfg(feature = "ext-math")]
+            Sign => (crate::operators::arithmetic::unary_math, crate::operators::arithmetic::UnaryMathOp::Sign),
+            #[cfg(feature = "ext-math")]
+            Exp => (crate::operators::arithmetic::math_fn, crate::operators::arithmetic::MathFn::Exp),
+            #[cfg(feature = "ext-math")]
+            Sin => (crate::operators::arithmetic::math_fn, crate::operators::arithmetic::MathFn::Sin),
+            #[cfg(feature = "ext-math")]
+            Cos => (crate::operators::arithmetic::math_fn, crate::operators::arithmetic::MathFn::Cos),
+            #[cfg(feature = "ext-math")]
+            Tan => (crate::operators::arithmetic::math_fn, crate::operators::arithmetic::MathFn::Tan),
+            #[cfg(feature = "ext-math")]
+            Gcd => (crate::operators::arithmetic::gcd_lcm, crate::operators::arithmetic::DivisorOp::Gcd),
+            #[cfg(feature = "ext-math")]
+            Lcm => (crate::operators::arithmetic::gcd_lcm, crate::operators::arithmetic::DivisorOp::Lcm),
+            #[cfg(feature = "ext-math")]
+            BitAnd => (crate::operators::arithmetic::bitwise_fold, crate::operators::arithmetic::BitwiseOp::And),
+            #[cfg(feature = "ext-math")]
+            BitOr => (crate::operators::arithmetic::bitwise_fold, crate::operators::arithmetic::BitwiseOp::Or),
+            #[cfg(feature = "ext-math")]
+            BitXor => (crate::operators::arithmetic::bitwise_fold, crate::operators::arithmetic::BitwiseOp::Xor),
+            #[cfg(feature = "ext-math")]
+            LShift => (crate::operators::arithmetic::bit_shift, crate::operators::arithmetic::ShiftOp::Left),
+            #[cfg(feature = "ext-math")]
+            RShift => (crate::operators::arithmetic::bit_shift, crate::operators::arithmetic::ShiftOp::Right),
+            #[cfg(feature = "ext-math")]
+            Sum => (crate::operators::arithmetic::aggregate, crate::operators::arithmetic::AggregateOp::Sum),
+            #[cfg(feature = "ext-math")]
+            Avg => (crate::operators::arithmetic::aggregate, crate::operators::arithmetic::AggregateOp::Avg),
+            #[cfg(feature = "ext-array")]
+            Take => (crate::operators::array::take_drop, crate::operators::array::TakeDropOp::Take),
+            #[cfg(feature = "ext-array")]
+            Drop => (crate::operators::array::take_drop, crate::operators::array::TakeDropOp::Drop),
+            #[cfg(feature = "ext-array")]
+            Pick => (crate::operators::array::pick_omit, crate::operators::array::PickOmitOp::Pick),
+            #[cfg(feature = "ext-array")]
+            Omit => (crate::operators::array::pick_omit, crate::operators::array::PickOmitOp::Omit),
+            #[cfg(feature = "ext-string")]
+            PadStart => (crate::operators::string::evaluate_pad, crate::operators::string::PadSide::Start),
+            #[cfg(feature = "ext-string")]
+            PadEnd => (crate::operators::string::evaluate_pad, crate::operators::string::PadSide::End),
+            #[cfg(feature = "ext-string")]
+            Capitalize => (crate::operators::string::evaluate_case, crate::operators::string::CaseStyle::Capitalize),
+            #[cfg(feature = "ext-string")]
+            TitleCase => (crate::operators::string::evaluate_case, crate::operators::string::CaseStyle::Title),
+            #[cfg(feature = "ext-string")]
+            CharAt => (crate::operators::string::evaluate_char_at, crate::operators::string::CharLookup::Char),
+            #[cfg(feature = "ext-string")]
+            CodePointAt => (crate::operators::string::evaluate_char_at, crate::operators::string::CharLookup::CodePoint),
+            #[cfg(feature = "ext-string")]
+            Base64Encode => (crate::operators::base64::evaluate_base64, crate::operators::base64::Base64Op::Encode),
+            #[cfg(feature = "ext-string")]
+            Base64Decode => (crate::operators::base64::evaluate_base64, crate::operators::base64::Base64Op::Decode),
+            #[cfg(feature = "ext-control")]
+            ToNumber => (crate::operators::convert::evaluate_conversion, crate::operators::convert::Conversion::Number),
+            #[cfg(feature = "ext-control")]
+            ToInt => (crate::operators::convert::evaluate_conversion, crate::operators::convert::Conversion::Int),
+            #[cfg(feature = "ext-control")]
+            ToString => (crate::operators::convert::evaluate_conversion, crate::operators::convert::Conversion::String),
+            #[cfg(feature = "ext-control")]
+            ToBool => (crate::operators::convert::evaluate_conversion, crate::operators::convert::Conversion::Bool),
+            #[cfg(feature = "ext-control")]
+            IsEmpty => (crate::operators::convert::evaluate_type_predicate, crate::operators::convert::TypePredicate::Empty),
+            #[cfg(feature = "ext-control")]
+            IsNull => (crate::operators::convert::evaluate_type_predicate, crate::operators::convert::TypePredicate::Null),
+            #[cfg(feature = "datetime")]
+            Year => (crate::operators::datetime::evaluate_date_part, crate::operators::datetime::DatePart::Year),
+            #[cfg(feature = "datetime")]
+            Month => (crate::operators::datetime::evaluate_date_part, crate::operators::datetime::DatePart::Month),
+            #[cfg(feature = "datetime")]
+            Day => (crate::operators::datetime::evaluate_date_part, crate::operators::datetime::DatePart::Day),
+            #[cfg(feature = "datetime")]
+            Hour => (crate::operators::datetime::evaluate_date_part, crate::operators::datetime::DatePart::Hour),
+            #[cfg(feature = "datetime")]
+            Minute => (crate::operators::datetime::evaluate_date_part, crate::operators::datetime::DatePart::Minute),
+            #[cfg(feature = "datetime")]
+            Weekday => (crate::operators::datetime::evaluate_date_part, crate::operators::datetime::DatePart::Weekday),
+            #[cfg(feature = "datetime")]
+            DateAdd => (crate::operators::datetime::evaluate_date_shift, crate::operators::datetime::DateShift::Add),
+            #[cfg(feature = "datetime")]
+            DateSub => (crate::operators::datetime::evaluate_date_shift, crate::operators::datetime::DateShift::Sub),
+            #[cfg(feature = "datetime")]
+            StartOf => (crate::operators::datetime::evaluate_date_boundary, crate::operators::datetime::DateBoundary::Start),
+            #[cfg(feature = "datetime")]
+            EndOf => (crate::operators::datetime::evaluate_date_boundary, crate::operators::datetime::DateBoundary::End),
         ],
     }
 }
@@ -360,7 +558,11 @@ pub(super) fn dispatch_node_inner<'a>(
 /// function of (root data, engine config) only — up-level `val`s clamp to
 /// root — so the memoized borrow is context-safe; under an iterator/catch
 /// frame or an attached tracer the memo is bypassed and the wrapper
-/// degrades to a plain delegating dispatch. Only `Ok` results are cached:
+/// degrades to a plain delegating dispatch. A hoisted wrapper (see
+/// `crate::compile::optimize::hoist`) skips the depth gate: its subtree
+/// reads nothing outside its own frames, so its value is the same at any
+/// depth and the first iteration's result serves the rest. Only `Ok`
+/// results are cached:
 /// errors re-evaluate deterministically (e.g. after a `try` caught the
 /// first occurrence's failure).
 ///
@@ -376,7 +578,7 @@ fn dispatch_cse<'a>(
     ctx: &mut ContextStack<'a>,
     arena: &'a bumpalo::Bump,
 ) -> Result<&'a crate::arena::DataValue<'a>> {
-    if ctx.depth() == 0 && !ctx.is_tracing() {
+    if (data.hoisted || ctx.depth() == 0) && !ctx.is_tracing() {
         if let Some(hit) = ctx.cse_slot(data.slot) {
             return Ok(hit);
         }
@@ -441,10 +643,14 @@ fn evaluate_custom_operator<'a>(
     arena: &'a bumpalo::Bump,
 ) -> crate::Result<&'a crate::arena::DataValue<'a>> {
     use crate::arena::DataValue;
-    let op = engine
-        .custom_operators
-        .get(&data.name)
-        .ok_or_else(|| Error::invalid_operator(data.name.clone()))?;
+    let op = match engine.custom_operators.get(&data.name) {
+        Some(super::RegisteredOperator::Eager(op)) => op,
+        Some(super::RegisteredOperator::Lazy(op)) => {
+            let mut args = crate::operator::LazyArgs::new(&data.args, ctx, engine, arena);
+            return op.evaluate(&mut args, arena);
+        }
+        None => return Err(Error::invalid_operator(data.name.clone())),
+    };
     let mut args: bumpalo::collections::Vec<'a, &'a DataValue<'a>> =
         bumpalo::collections::Vec::with_capacity_in(data.args.len(), arena);
     for arg in data.args.iter() {
@@ -1,7 +1,8 @@
 use std::cell::Cell;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use crate::config::EvaluationConfig;
+use crate::opcode::OpCode;
 
 use crate::{CompiledNode, Logic, Result};
 
@@ -152,8 +153,8 @@ impl Drop for DepthGuard {
 /// `EvaluationConfig` rustdoc for arena-management and behaviour-tuning
 /// options respectively.
 pub struct Engine {
-    /// Custom `CustomOperator` implementations registered with the engine.
-    pub(super) custom_operators: HashMap<String, Box<dyn crate::CustomOperator>>,
+    /// Custom operators registered with the engine.
+    pub(super) custom_operators: HashMap<String, RegisteredOperator>,
     /// Whether templating mode is enabled — multi-key objects compile
     /// to output-shaping templates and unknown operator keys pass through.
     #[cfg(feature = "templating")]
@@ -164,12 +165,38 @@ pub struct Engine {
     /// always disables folding regardless of this flag (handled in
     /// `TracedSession`).
     constant_folding: bool,
+    /// Whether a custom operator registered under a built-in name replaces
+    /// the built-in. Defaults to `false`; toggled via
+    /// [`crate::EngineBuilder::with_operator_overrides`].
+    operator_overrides: bool,
+    /// Maximum rule nesting accepted by [`Self::compile`]. Defaults to
+    /// `MAX_COMPILE_DEPTH`; set via [`crate::EngineBuilder::with_max_depth`].
+    max_depth: usize,
+    /// Operator names removed with [`crate::EngineBuilder::remove_operator`].
+    /// A rule that uses one fails to compile.
+    removed_operators: HashSet<String>,
+    /// Built-ins behind [`Self::removed_operators`], so every alias of a
+    /// removed built-in (`match` for `switch`, `var` for `val`) goes too.
+    removed_opcodes: Vec<OpCode>,
     /// Configuration for evaluation behavior
     config: EvaluationConfig,
+    /// Array length above which `map` fans its body out across rayon's
+    /// thread pool. `None` (the default) keeps every `map` sequential;
+    /// set via [`crate::EngineBuilder::with_parallel_threshold`].
+    #[cfg(feature = "parallel")]
+    pub(super) parallel_threshold: Option<usize>,
 }
 
 mod dispatch;
 
+/// A custom operator as registered on the builder: eager operators get
+/// their arguments evaluated by the dispatcher, lazy ones evaluate them on
+/// demand through [`crate::operator::LazyArgs`].
+pub(crate) enum RegisteredOperator {
+    Eager(Box<dyn crate::CustomOperator>),
+    Lazy(Box<dyn crate::LazyOperator>),
+}
+
 /// Convert an `OwnedDataValue` literal to an arena-resident `DataValue`
 /// reference. Reached from the `dispatch_node` literal path for any
 /// `CompiledNode::Value` whose `lit` was not precomputed — in practice
@@ -246,6 +273,24 @@ fn borrow_to_arena<'a>(
     }
 }
 
+/// Type name used in `eval_as_*` mismatch errors. Matches the names the
+/// `type` operator reports.
+fn result_type_name(value: &crate::arena::DataValue<'_>) -> &'static str {
+    use crate::arena::DataValue;
+    match value {
+        DataValue::Null => "null",
+        DataValue::Bool(_) => "boolean",
+        DataValue::Number(_) => "number",
+        DataValue::String(_) => "string",
+        DataValue::Array(_) => "array",
+        DataValue::Object(_) => "object",
+        #[cfg(feature = "datetime")]
+        DataValue::DateTime(_) => "datetime",
+        #[cfg(feature = "datetime")]
+        DataValue::Duration(_) => "duration",
+    }
+}
+
 impl Default for Engine {
     fn default() -> Self {
         Self::new()
@@ -263,6 +308,8 @@ impl std::fmt::Debug for Engine {
         #[cfg(feature = "templating")]
         s.field("templating", &self.templating);
         s.field("config", &self.config);
+        #[cfg(feature = "parallel")]
+        s.field("parallel_threshold", &self.parallel_threshold);
         s.finish_non_exhaustive()
     }
 }
@@ -305,6 +352,55 @@ impl Engine {
         crate::Session::new(self)
     }
 
+    /// Run `f` with a [`crate::Session`] whose arena is borrowed from
+    /// `pool`.
+    ///
+    /// The arena is checked out when the scope opens and reset and
+    /// returned to the pool when `f` returns, so chunks grown by one scope
+    /// are reused by the next instead of being freed and re-allocated —
+    /// the [`Self::session`] amortisation, for callers (typically request
+    /// handlers on a thread pool) that can't keep a session alive between
+    /// units of work. Within the scope the session behaves as usual: its
+    /// arena accumulates across calls, and [`crate::Session::reset`]
+    /// starts a new logical batch. Nothing borrowed from the arena can
+    /// outlive `f`; return owned results.
+    ///
+    /// If `f` panics, the arena is dropped rather than returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use datalogic_rs::{ArenaPool, Engine};
+    ///
+    /// let engine = Engine::new();
+    /// let pool = ArenaPool::new();
+    /// let eligible = engine.compile(r#"{">=": [{"var": "age"}, 18]}"#).unwrap();
+    /// let greeting = engine.compile(r#"{"cat": ["hi ", {"var": "name"}]}"#).unwrap();
+    ///
+    /// // One request: several rules against one payload, one arena.
+    /// let (ok, msg) = engine
+    ///     .scoped(&pool, |session| -> datalogic_rs::Result<_> {
+    ///         let payload = r#"{"age": 30, "name": "Ada"}"#;
+    ///         Ok((
+    ///             session.eval(&eligible, payload)?,
+    ///             session.eval_str(&greeting, payload)?,
+    ///         ))
+    ///     })
+    ///     .unwrap();
+    /// assert_eq!(ok.as_bool(), Some(true));
+    /// assert_eq!(msg, r#""hi Ada""#);
+    /// ```
+    pub fn scoped<T>(
+        &self,
+        pool: &crate::ArenaPool,
+        f: impl FnOnce(&mut crate::Session<'_>) -> T,
+    ) -> T {
+        let mut session = crate::Session::with_arena(self, pool.take());
+        let out = f(&mut session);
+        pool.give_back(session.into_arena());
+        out
+    }
+
     /// Internal seam used by the builder. `pub(crate)` is enough — no
     /// `#[doc(hidden)]` needed since it's not externally reachable.
     #[inline]
@@ -312,14 +408,27 @@ impl Engine {
         config: EvaluationConfig,
         _templating: bool,
         constant_folding: bool,
-        operators: HashMap<String, Box<dyn crate::CustomOperator>>,
+        operator_overrides: bool,
+        max_depth: usize,
+        operators: HashMap<String, RegisteredOperator>,
+        removed_operators: HashSet<String>,
     ) -> Self {
+        let removed_opcodes = removed_operators
+            .iter()
+            .filter_map(|name| name.parse::<OpCode>().ok())
+            .collect();
         Self {
             custom_operators: operators,
             #[cfg(feature = "templating")]
             templating: _templating,
             constant_folding,
+            operator_overrides,
+            max_depth,
+            removed_operators,
+            removed_opcodes,
             config,
+            #[cfg(feature = "parallel")]
+            parallel_threshold: None,
         }
     }
 
@@ -338,7 +447,23 @@ impl Engine {
     /// let engine = Engine::new();
     /// ```
     pub fn new() -> Self {
-        Self::from_builder_parts(EvaluationConfig::default(), false, true, HashMap::new())
+        Self::from_builder_parts(
+            EvaluationConfig::default(),
+            false,
+            true,
+            false,
+            crate::node::MAX_COMPILE_DEPTH,
+            HashMap::new(),
+            HashSet::new(),
+        )
+    }
+
+    /// Array length above which `map` evaluates its body in parallel, as
+    /// set by [`crate::EngineBuilder::with_parallel_threshold`].
+    #[cfg(feature = "parallel")]
+    #[inline]
+    pub(crate) fn parallel_threshold(&self) -> Option<usize> {
+        self.parallel_threshold
     }
 
     /// Gets a reference to the current evaluation configuration.
@@ -354,6 +479,13 @@ impl Engine {
         self.constant_folding
     }
 
+    /// Internal: the rule nesting cap applied by [`Self::compile`]. Reads
+    /// the field set by [`crate::EngineBuilder::with_max_depth`].
+    #[inline]
+    pub(crate) fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+
     /// Internal: whether templating mode is on. Always returns `false`
     /// when the crate is built without `feature = "templating"` (the
     /// underlying field doesn't exist off-feature). Folded here so the
@@ -370,6 +502,52 @@ impl Engine {
         }
     }
 
+    /// Internal: whether the custom operator `name` replaces a built-in of
+    /// the same name. True only when overrides are enabled
+    /// ([`crate::EngineBuilder::with_operator_overrides`]) and `name` is
+    /// registered; the compiler checks this before parsing `name` as a
+    /// built-in.
+    #[inline]
+    pub(crate) fn custom_operator_overrides(&self, name: &str) -> bool {
+        self.operator_overrides && self.custom_operators.contains_key(name)
+    }
+
+    /// Internal: whether `name` is an eager custom operator that declared
+    /// itself pure ([`crate::CustomOperator::is_pure`]). The hoisting pass
+    /// only moves custom-operator calls that pass this check.
+    #[inline]
+    pub(crate) fn custom_operator_is_pure(&self, name: &str) -> bool {
+        matches!(
+            self.custom_operators.get(name),
+            Some(RegisteredOperator::Eager(op)) if op.is_pure()
+        )
+    }
+
+    /// Internal: whether `name` was removed with
+    /// [`crate::EngineBuilder::remove_operator`]. The compiler rejects a
+    /// removed name before resolving it as a built-in or custom operator.
+    /// Removing a built-in removes all of its aliases, unless a custom
+    /// operator was registered under the alias afterwards.
+    #[inline]
+    pub(crate) fn is_operator_removed(&self, name: &str) -> bool {
+        if self.removed_operators.is_empty() {
+            return false;
+        }
+        self.removed_operators.contains(name)
+            || (self.is_builtin_removed(name) && !self.custom_operators.contains_key(name))
+    }
+
+    /// Internal: whether `name` resolves to a built-in that was removed,
+    /// under this name or any of its aliases. A custom operator registered
+    /// under such a name takes the built-in's place.
+    #[inline]
+    pub(crate) fn is_builtin_removed(&self, name: &str) -> bool {
+        !self.removed_opcodes.is_empty()
+            && name
+                .parse::<OpCode>()
+                .is_ok_and(|op| self.removed_opcodes.contains(&op))
+    }
+
     /// Checks if a custom operator with the given name is registered.
     ///
     /// Operator registration is builder-only; this is a read-only check
@@ -386,6 +564,30 @@ impl Engine {
         self.custom_operators.keys().map(String::as_str)
     }
 
+    /// Iterator over every operator name a rule can use on this engine:
+    /// the built-ins compiled into this build (aliases such as `var` and
+    /// `distinct` included), then the custom operators. Names removed with
+    /// [`crate::EngineBuilder::remove_operator`], and their aliases, are
+    /// left out, and each
+    /// name appears once even when a custom operator shares a built-in's
+    /// name. Custom names follow in unspecified order.
+    ///
+    /// ```
+    /// use datalogic_rs::Engine;
+    ///
+    /// let engine = Engine::builder().remove_operator("substr").build();
+    /// let names: Vec<&str> = engine.operator_names().collect();
+    /// assert!(names.contains(&"cat"));
+    /// assert!(!names.contains(&"substr"));
+    /// ```
+    pub fn operator_names(&self) -> impl Iterator<Item = &str> {
+        let builtins = OpCode::names().filter(|name| !self.is_builtin_removed(name));
+        let custom = self
+            .custom_operator_names()
+            .filter(|name| name.parse::<OpCode>().is_err() || self.is_builtin_removed(name));
+        builtins.chain(custom)
+    }
+
     // ============================================================
     // V5 PUBLIC API
     //   - One-shot:   `eval` / `eval_str` / `eval_into`   (engine-owned arena per call)
@@ -414,6 +616,49 @@ impl Engine {
         Logic::compile_with(&owned, self)
     }
 
+    /// Check a rule without evaluating it, returning every problem found
+    /// rather than just the first.
+    ///
+    /// [`Self::compile`] stops at the first error, and some mistakes only
+    /// fail once evaluation reaches them. `validate` walks the whole rule
+    /// and reports, in document order:
+    ///
+    /// - unknown operators (neither built in nor registered on this
+    ///   engine), and operators taken away with
+    ///   [`crate::EngineBuilder::remove_operator`], as
+    ///   [`crate::ErrorKind::InvalidOperator`];
+    /// - `and` / `or` / `if` / `let` called with the wrong argument shape,
+    ///   and `map` / `filter` / `reduce` with the wrong number of
+    ///   arguments, as [`crate::ErrorKind::InvalidArguments`] naming the
+    ///   operator;
+    /// - multi-key objects outside templating mode;
+    /// - rule text that fails to parse, or nesting past the depth limit
+    ///   (these end the walk).
+    ///
+    /// An empty vector means the rule compiles and no operator in it is
+    /// known to be invalid. Errors that depend on data (type mismatches,
+    /// a `map` over a number, ...) can still occur when evaluating.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use datalogic_rs::Engine;
+    ///
+    /// let engine = Engine::new();
+    /// let errors = engine.validate(r#"{"and": [{"frobnicate": [1]}, {"if": "oops"}]}"#);
+    /// assert_eq!(errors.len(), 2);
+    /// assert_eq!(errors[0].to_string(), "Invalid operator: frobnicate");
+    /// assert_eq!(errors[1].operator(), Some("if"));
+    ///
+    /// assert!(engine.validate(r#"{"+": [1, {"var": "x"}]}"#).is_empty());
+    /// ```
+    pub fn validate<R: crate::IntoLogic>(&self, rule: R) -> Vec<crate::Error> {
+        match rule.into_owned_logic() {
+            Ok(owned) => Logic::validate_with(&owned, self),
+            Err(err) => vec![err],
+        }
+    }
+
     /// Compile and wrap in an [`Arc`](std::sync::Arc) in one call. Convenience for the
     /// dominant cross-thread-sharing pattern; equivalent to
     /// `Arc::new(engine.compile(rule)?)`.
@@ -506,12 +751,184 @@ impl Engine {
         let _depth_guard = self.enter_dispatch_boundary()?;
         let data_ref = data.into_arena_value(arena)?;
         let mut ctx = crate::arena::ContextStack::new(data_ref);
-        match self.dispatch_node(&compiled.root, &mut ctx, arena) {
+        match self
+            .dispatch_node(&compiled.root, &mut ctx, arena)
+            .and_then(|av| self.check_non_finite(av, arena))
+        {
             Ok(av) => Ok(av),
             Err(e) => Err(e.decorated(ctx.take_error_path(), compiled, true)),
         }
     }
 
+    /// Internal: apply [`EvaluationConfig::non_finite_numbers`] to a
+    /// finished result. The default `Keep` returns `av` untouched without
+    /// walking it.
+    #[inline]
+    pub(crate) fn check_non_finite<'a>(
+        &self,
+        av: &'a crate::arena::DataValue<'a>,
+        arena: &'a bumpalo::Bump,
+    ) -> Result<&'a crate::arena::DataValue<'a>> {
+        match self.config.non_finite_numbers {
+            crate::NonFiniteHandling::Keep => Ok(av),
+            crate::NonFiniteHandling::ReturnNull => {
+                Ok(crate::arena::value::null_non_finite(av, arena))
+            }
+            crate::NonFiniteHandling::ThrowError => {
+                if crate::arena::value::contains_non_finite(av) {
+                    Err(crate::Error::non_finite_number())
+                } else {
+                    Ok(av)
+                }
+            }
+        }
+    }
+
+    /// Evaluate compiled logic against any `D: Serialize`, returning an
+    /// owned result.
+    ///
+    /// `data` is serialised straight into a fresh per-call arena — there
+    /// is no intermediate `serde_json::Value` or JSON text, and the
+    /// `serde_json` feature isn't needed. The mapping follows
+    /// `serde_json`'s data model (structs and maps become objects, `None`
+    /// becomes `null`, enums are externally tagged), so a struct evaluates
+    /// the same as its `serde_json::to_value` form would. A `Serialize`
+    /// impl that fails, or a map keyed by something other than strings,
+    /// chars, bools, or integers, surfaces as a `ParseError`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use datalogic_rs::Engine;
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Serialize)]
+    /// struct Applicant {
+    ///     age: u32,
+    ///     country: &'static str,
+    /// }
+    ///
+    /// let engine = Engine::new();
+    /// let compiled = engine
+    ///     .compile(r#"{"and": [{">=": [{"var": "age"}, 18]}, {"==": [{"var": "country"}, "NZ"]}]}"#)
+    ///     .unwrap();
+    /// let applicant = Applicant { age: 30, country: "NZ" };
+    /// let result = engine.eval_typed(&compiled, &applicant).unwrap();
+    /// assert_eq!(result.as_bool(), Some(true));
+    /// ```
+    pub fn eval_typed<D>(&self, compiled: &Logic, data: &D) -> Result<datavalue::OwnedDataValue>
+    where
+        D: serde::Serialize + ?Sized,
+    {
+        use crate::FromDataValue;
+
+        // Same starting capacity as the one-shot path.
+        let arena = bumpalo::Bump::with_capacity(4096);
+        let data = crate::arena::serialize_to_data(data, &arena)?;
+        let result = self.evaluate(compiled, data, &arena)?;
+        datavalue::OwnedDataValue::from_arena(result)
+    }
+
+    /// Evaluate one compiled rule against every record in `data`, returning
+    /// one owned result per record in input order.
+    ///
+    /// A single arena is allocated up front and reset between records, so
+    /// the per-call setup [`Self::eval`] pays (fresh bump, input clone) is
+    /// amortised across the batch. Each record is evaluated independently:
+    /// an error in one slot doesn't stop the rest, and the step budget and
+    /// depth limits apply per record. Results are identical to calling
+    /// [`Self::evaluate`] on each record with a fresh arena.
+    ///
+    /// `T` is any input whose reference is an [`crate::EvalInput`] —
+    /// `OwnedDataValue`, `String`, [`crate::ParsedData`], or
+    /// `serde_json::Value` (gated on `serde_json`).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use datalogic_rs::Engine;
+    ///
+    /// let engine = Engine::new();
+    /// let compiled = engine.compile(r#"{"*": [{"var": "x"}, 2]}"#).unwrap();
+    /// let records: Vec<String> = (1..=3).map(|x| format!(r#"{{"x": {x}}}"#)).collect();
+    ///
+    /// let results = engine.eval_batch(&compiled, &records);
+    /// let doubled: Vec<i64> = results
+    ///     .iter()
+    ///     .map(|r| r.as_ref().unwrap().as_i64().unwrap())
+    ///     .collect();
+    /// assert_eq!(doubled, [2, 4, 6]);
+    /// ```
+    pub fn eval_batch<T>(
+        &self,
+        compiled: &Logic,
+        data: &[T],
+    ) -> Vec<Result<datavalue::OwnedDataValue>>
+    where
+        for<'a> &'a T: crate::EvalInput<'a>,
+    {
+        use crate::FromDataValue;
+
+        // Same starting capacity as the one-shot path; the bump keeps
+        // whatever chunks the largest record needed across resets.
+        let mut arena = bumpalo::Bump::with_capacity(4096);
+        let mut results = Vec::with_capacity(data.len());
+        for record in data {
+            let result = self
+                .evaluate(compiled, record, &arena)
+                .and_then(datavalue::OwnedDataValue::from_arena);
+            results.push(result);
+            arena.reset();
+        }
+        results
+    }
+
+    /// Lazily evaluate one compiled rule against each record of `data`,
+    /// yielding one owned result per record in input order.
+    ///
+    /// The returned [`crate::EvalIter`] owns a single arena and resets it
+    /// before every record, so results can be filtered, taken, or
+    /// streamed onward without materialising a result `Vec` — records are
+    /// pulled from `data` only as results are requested. As with
+    /// [`Self::eval_batch`], an error in one record doesn't stop the rest
+    /// and the depth and step limits apply per record.
+    ///
+    /// Items go through [`crate::OwnedInput`], the same adapter the
+    /// one-shot [`Self::eval`] uses: `OwnedDataValue` (moved),
+    /// `&OwnedDataValue` (cloned), `&str` / `&String` (JSON-parsed), or
+    /// `&serde_json::Value` (gated on `serde_json`).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use datalogic_rs::Engine;
+    ///
+    /// let engine = Engine::new();
+    /// let compiled = engine.compile(r#"{">": [{"var": "x"}, 2]}"#).unwrap();
+    /// let records: Vec<String> = (1..=100).map(|x| format!(r#"{{"x": {x}}}"#)).collect();
+    ///
+    /// // Only the first four records are evaluated: `take` stops pulling
+    /// // from the source once it has two hits.
+    /// let hits = engine
+    ///     .eval_iter(&compiled, &records)
+    ///     .filter(|r| r.as_ref().is_ok_and(|v| v.as_bool() == Some(true)))
+    ///     .take(2)
+    ///     .count();
+    /// assert_eq!(hits, 2);
+    /// ```
+    #[inline]
+    pub fn eval_iter<'a, I>(
+        &'a self,
+        compiled: &'a Logic,
+        data: I,
+    ) -> crate::EvalIter<'a, I::IntoIter>
+    where
+        I: IntoIterator,
+        I::Item: crate::OwnedInput,
+    {
+        crate::EvalIter::new(self, compiled, data.into_iter())
+    }
+
     /// Apply the engine's configured truthiness rules
     /// ([`crate::TruthyEvaluator`]) to an evaluated value.
     ///
@@ -619,6 +1036,170 @@ impl Engine {
         serde_json::from_value(value).map_err(crate::Error::from)
     }
 
+    /// One-shot evaluation that expects a boolean result.
+    ///
+    /// The result must be a JSON `true` / `false`; anything else fails
+    /// with a `TypeError` naming the type that came back. No truthiness
+    /// coercion is applied — wrap the rule in `{"!!": ...}`, or call
+    /// [`Self::truthy`] on an [`Self::evaluate`] result, to collapse
+    /// arbitrary values to a boolean.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use datalogic_rs::Engine;
+    ///
+    /// let engine = Engine::new();
+    /// let rule = r#"{">=": [{"var": "age"}, 18]}"#;
+    /// assert!(engine.eval_as_bool(rule, r#"{"age": 21}"#).unwrap());
+    ///
+    /// let err = engine.eval_as_bool(r#"{"var": "age"}"#, r#"{"age": 21}"#).unwrap_err();
+    /// assert_eq!(err.tag(), "TypeError");
+    /// ```
+    pub fn eval_as_bool<R, D>(&self, rule: R, data: D) -> Result<bool>
+    where
+        R: crate::IntoLogic,
+        D: crate::OwnedInput,
+    {
+        self.eval_expecting(rule, data, "boolean", |v| v.as_bool())
+    }
+
+    /// One-shot evaluation that expects a numeric result, widened to
+    /// `f64`.
+    ///
+    /// Integer and float results are both accepted; any other type —
+    /// including a numeric string such as `"42"` — fails with a
+    /// `TypeError`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use datalogic_rs::Engine;
+    ///
+    /// let engine = Engine::new();
+    /// let total = engine
+    ///     .eval_as_f64(r#"{"*": [{"var": "qty"}, 2.5]}"#, r#"{"qty": 4}"#)
+    ///     .unwrap();
+    /// assert_eq!(total, 10.0);
+    /// ```
+    pub fn eval_as_f64<R, D>(&self, rule: R, data: D) -> Result<f64>
+    where
+        R: crate::IntoLogic,
+        D: crate::OwnedInput,
+    {
+        self.eval_expecting(rule, data, "number", |v| v.as_f64())
+    }
+
+    /// One-shot evaluation that expects a string result.
+    ///
+    /// Returns the string itself, unquoted — unlike [`Self::eval_str`],
+    /// which serialises any result to JSON text. A non-string result
+    /// fails with a `TypeError`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use datalogic_rs::Engine;
+    ///
+    /// let engine = Engine::new();
+    /// let greeting = engine
+    ///     .eval_as_string(r#"{"cat": ["hi ", {"var": "name"}]}"#, r#"{"name": "Ada"}"#)
+    ///     .unwrap();
+    /// assert_eq!(greeting, "hi Ada");
+    /// ```
+    pub fn eval_as_string<R, D>(&self, rule: R, data: D) -> Result<String>
+    where
+        R: crate::IntoLogic,
+        D: crate::OwnedInput,
+    {
+        self.eval_expecting(rule, data, "string", |v| v.as_str().map(str::to_owned))
+    }
+
+    /// One-shot evaluation of a YAML rule against YAML data, returning
+    /// [`datavalue::OwnedDataValue`].
+    ///
+    /// Both documents go through `serde_yaml`: the rule into an owned
+    /// value that is then compiled as usual, the data straight into the
+    /// per-call arena. A document that isn't valid YAML fails with a
+    /// `ParseError` whose message names the side (`YAML logic` or
+    /// `YAML data`); a rule that parses but doesn't compile, or fails
+    /// at evaluation, returns the same error its JSON form would.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "yaml")] {
+    /// use datalogic_rs::Engine;
+    ///
+    /// let engine = Engine::new();
+    /// let rule = "
+    /// if:
+    ///   - '>=': [{var: age}, 18]
+    ///   - adult
+    ///   - minor
+    /// ";
+    /// let result = engine.eval_yaml(rule, "age: 21").unwrap();
+    /// assert_eq!(result.as_str(), Some("adult"));
+    /// # }
+    /// ```
+    #[cfg(feature = "yaml")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "yaml")))]
+    pub fn eval_yaml(&self, logic: &str, data: &str) -> Result<datavalue::OwnedDataValue> {
+        use crate::FromDataValue;
+        use serde::de::DeserializeSeed;
+
+        let yaml_error = |side: &str, err: serde_yaml::Error| {
+            crate::Error::parse_error(format!("YAML {side}: {err}"))
+        };
+
+        let rule: datavalue::OwnedDataValue =
+            serde_yaml::from_str(logic).map_err(|e| yaml_error("logic", e))?;
+        let compiled = self.compile(rule)?;
+        let arena = bumpalo::Bump::with_capacity(4096);
+        let data = datavalue::DataValueSeed::new(&arena)
+            .deserialize(serde_yaml::Deserializer::from_str(data))
+            .map_err(|e| yaml_error("data", e))?;
+        let result = self.evaluate(&compiled, data, &arena)?;
+        datavalue::OwnedDataValue::from_arena(result)
+    }
+
+    /// Evaluate compiled logic against MessagePack-encoded data, returning
+    /// [`datavalue::OwnedDataValue`].
+    ///
+    /// `data` is decoded straight into a fresh per-call arena — no JSON
+    /// text and no owned intermediate tree; strings borrow from `data`
+    /// rather than being copied. Maps must have string keys, so encode
+    /// structs as maps (e.g. `rmp_serde::to_vec_named`); the compact
+    /// `to_vec` form writes structs as arrays, which rules would then have
+    /// to index by position. Ext types aren't supported. Input that isn't
+    /// a single valid MessagePack value fails with a `ParseError` whose
+    /// message starts with `MessagePack data`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "msgpack")] {
+    /// use datalogic_rs::Engine;
+    ///
+    /// let engine = Engine::new();
+    /// let compiled = engine.compile(r#"{"+": [{"var": "a"}, {"var": "b.0"}]}"#).unwrap();
+    /// // {"a": 1, "b": [2]}
+    /// let data = [0x82, 0xa1, b'a', 0x01, 0xa1, b'b', 0x91, 0x02];
+    /// let result = engine.eval_msgpack(&compiled, &data).unwrap();
+    /// assert_eq!(result.as_i64(), Some(3));
+    /// # }
+    /// ```
+    #[cfg(feature = "msgpack")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "msgpack")))]
+    pub fn eval_msgpack(&self, compiled: &Logic, data: &[u8]) -> Result<datavalue::OwnedDataValue> {
+        use crate::FromDataValue;
+
+        let arena = bumpalo::Bump::with_capacity(4096);
+        let data = crate::arena::msgpack_to_data(data, &arena)?;
+        let result = self.evaluate(compiled, data, &arena)?;
+        datavalue::OwnedDataValue::from_arena(result)
+    }
+
     /// Internal generic shared by `eval` / `eval_str` / `eval_into`.
     /// Compiles, allocates a fresh per-call arena, evaluates, and
     /// projects the result through [`crate::FromDataValue`].
@@ -636,6 +1217,32 @@ impl Engine {
         O::from_arena(result)
     }
 
+    /// Internal generic shared by the `eval_as_*` family. Same pipeline
+    /// as [`Self::eval_with`], but projects the arena result with `pick`
+    /// and reports a `TypeError` naming `expected` when it returns `None`.
+    fn eval_expecting<T, R, D>(
+        &self,
+        rule: R,
+        data: D,
+        expected: &str,
+        pick: impl FnOnce(&crate::arena::DataValue<'_>) -> Option<T>,
+    ) -> Result<T>
+    where
+        R: crate::IntoLogic,
+        D: crate::OwnedInput,
+    {
+        let compiled = self.compile(rule)?;
+        let arena = bumpalo::Bump::with_capacity(4096);
+        let owned_data = data.into_owned_input()?;
+        let result = self.evaluate(&compiled, &owned_data, &arena)?;
+        pick(result).ok_or_else(|| {
+            crate::Error::type_error(format!(
+                "expected a {expected} result, got {}",
+                result_type_name(result)
+            ))
+        })
+    }
+
     /// Bump the per-thread dispatch-boundary depth counter, bailing with
     /// `ConfigurationError` if the configured cap is reached. Returns a
     /// guard that decrements the counter on drop (covers `?` early returns
@@ -715,8 +1322,19 @@ impl Engine {
         #[cfg(feature = "trace")]
         let ctx_snapshot: Option<serde_json::Value> =
             ctx.has_tracer().then(|| ctx.current_data_as_value());
+        // Started after the snapshot so a node's time excludes its own
+        // context conversion.
+        #[cfg(feature = "trace")]
+        let trace_started = ctx.trace_timer();
 
-        let result = dispatch::dispatch_node_inner(self, node, ctx, arena);
+        // Step limiting is opt-in: without a limit, nothing is counted.
+        let result = match self.config.step_limit {
+            None => dispatch::dispatch_node_inner(self, node, ctx, arena),
+            Some(limit) => match ctx.charge_steps(1, limit) {
+                Ok(()) => dispatch::dispatch_node_inner(self, node, ctx, arena),
+                Err(e) => Err(e),
+            },
+        };
 
         // Accumulate the failing node's id on every Err. We always pay
         // the (single) Vec::push since errors are rare and structured-error
@@ -727,7 +1345,7 @@ impl Engine {
 
         #[cfg(feature = "trace")]
         if let Some(ctx_data) = ctx_snapshot {
-            ctx.record_node_result(node.id(), ctx_data, &result);
+            ctx.record_node_result(node.id(), ctx_data, &result, trace_started);
         }
 
         result
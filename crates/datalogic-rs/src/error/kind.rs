@@ -44,4 +44,18 @@ pub enum ErrorKind {
     },
     /// Invalid operator configuration
     ConfigurationError(Cow<'static, str>),
+    /// Evaluation ran past the configured step budget
+    /// ([`crate::EvaluationConfig::step_limit`]).
+    StepLimitExceeded {
+        /// The budget that was exceeded.
+        limit: u64,
+    },
+    /// `/` or `%` with a zero divisor. Only raised when
+    /// [`crate::EvaluationConfig::distinct_division_by_zero`] is set; by
+    /// default a zero divisor throws the JSONLogic `{"type": "NaN"}`.
+    DivisionByZero,
+    /// The result held a `NaN` or `±Infinity` float. Only raised when
+    /// [`crate::EvaluationConfig::non_finite_numbers`] is
+    /// [`crate::NonFiniteHandling::ThrowError`].
+    NonFiniteNumber,
 }
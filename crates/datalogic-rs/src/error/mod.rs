@@ -2,7 +2,8 @@
 //! operation. Submodules are structured by concern:
 //!
 //! - [`kind`] — `ErrorKind` enum + `CustomErrorSource` trait alias.
-//! - [`path`] — `ErrorPath`, the internal breadcrumb storage.
+//! - [`path`] — `ErrorPath`, the internal breadcrumb storage, and the
+//!   public `DataPathStep`.
 //! - [`serde`] — `Display`, `std::error::Error`, `Serialize`, and `From`
 //!   impls for foreign error types.
 //!
@@ -14,6 +15,7 @@ mod path;
 mod serde;
 
 pub use kind::{CustomErrorSource, ErrorKind};
+pub use path::DataPathStep;
 pub(crate) use path::ErrorPath;
 
 use datavalue::OwnedDataValue;
@@ -50,13 +52,16 @@ impl std::error::Error for MessageError {}
 /// produced the error, and `node_ids` is a breadcrumb of compiled-node ids
 /// from the failure site toward the root (leaf-to-root). Use
 /// [`Error::resolve_path`] to translate the ids into structured
-/// [`crate::PathStep`]s callers can act on.
+/// [`crate::PathStep`]s callers can act on. [`Error::data_path`] gives the
+/// matching location in the input: the item index or object key of every
+/// enclosing iterator.
 ///
 /// # Wire format
 ///
 /// `Error` serialises as:
-/// `{"type": <kind tag>, "message": <Display>, ...kind-extras, "operator"?, "node_ids"?}`.
-/// `operator` is omitted when `None`; `node_ids` is omitted when empty. JS
+/// `{"type": <kind tag>, "message": <Display>, ...kind-extras, "operator"?, "node_ids"?, "data_path"?}`.
+/// `operator` is omitted when `None`; `node_ids` and `data_path` are
+/// omitted when empty. JS
 /// consumers can `JSON.parse(err)` and switch on `err.type`.
 ///
 /// # Source chains
@@ -118,6 +123,22 @@ impl Error {
         self.node_ids.as_slice()
     }
 
+    /// Where in the input data evaluation failed: the item each enclosing
+    /// iterator (`map`, `filter`, `reduce`, `all`, `some`, `none`, ...) was
+    /// evaluating, outermost first. Array iteration records the item's
+    /// [`DataPathStep::Index`]; object iteration records the entry's
+    /// [`DataPathStep::Key`].
+    ///
+    /// `{"map": [{"var": "rows"}, {"map": [{"var": "cells"}, ...]}]}`
+    /// failing on `rows[2].cells[0]` reports `[Index(2), Index(0)]`. Empty
+    /// when the failure was outside any iterator, and for errors that
+    /// didn't cross a public `evaluate*` boundary. Pair with
+    /// [`Self::resolve_path`] to see which operators those iterations
+    /// belong to.
+    pub fn data_path(&self) -> Vec<DataPathStep> {
+        self.node_ids.data_path()
+    }
+
     /// Get a stable string tag for the error kind. Stable across releases.
     pub fn tag(&self) -> &'static str {
         match self.kind {
@@ -133,6 +154,9 @@ impl Error {
             ErrorKind::FormatError(_) => "FormatError",
             ErrorKind::IndexOutOfBounds { .. } => "IndexOutOfBounds",
             ErrorKind::ConfigurationError(_) => "ConfigurationError",
+            ErrorKind::StepLimitExceeded { .. } => "StepLimitExceeded",
+            ErrorKind::DivisionByZero => "DivisionByZero",
+            ErrorKind::NonFiniteNumber => "NonFiniteNumber",
         }
     }
 
@@ -289,6 +313,23 @@ impl Error {
     pub fn configuration_error(msg: impl Into<Cow<'static, str>>) -> Self {
         ErrorKind::ConfigurationError(msg.into()).into()
     }
+    /// Shorthand for `ErrorKind::StepLimitExceeded { limit }.into()`.
+    #[inline]
+    pub fn step_limit_exceeded(limit: u64) -> Self {
+        ErrorKind::StepLimitExceeded { limit }.into()
+    }
+
+    /// Shorthand for `ErrorKind::DivisionByZero.into()`.
+    #[inline]
+    pub fn division_by_zero() -> Self {
+        ErrorKind::DivisionByZero.into()
+    }
+
+    /// Shorthand for `ErrorKind::NonFiniteNumber.into()`.
+    #[inline]
+    pub fn non_finite_number() -> Self {
+        ErrorKind::NonFiniteNumber.into()
+    }
 
     /// Canonical "Invalid Arguments" error. Used wherever an operator
     /// rejects malformed args before evaluating.
@@ -298,8 +339,8 @@ impl Error {
     }
 
     /// Decorate an error from a public `evaluate*` boundary with the
-    /// breadcrumb path (raw ids only — see below) and the outermost
-    /// operator name. Marked `#[cold]` + `#[inline(never)]` so the
+    /// breadcrumb path (raw ids only — see below), the data path, and the
+    /// outermost operator name. Marked `#[cold]` + `#[inline(never)]` so the
     /// dispatch caller's `Err` arm shrinks to a single call instruction,
     /// keeping the hot `Ok` arm's I-cache footprint tight.
     ///
@@ -324,11 +365,11 @@ impl Error {
     #[inline(never)]
     pub(crate) fn decorated(
         mut self,
-        node_ids: Vec<u32>,
+        node_ids: ErrorPath,
         compiled: &crate::Logic,
         prefer_existing_op: bool,
     ) -> Self {
-        self.node_ids = node_ids.into();
+        self.node_ids = node_ids;
         if !prefer_existing_op || self.operator.is_none() {
             if let Some(name) = compiled.root_op_name.clone() {
                 self.operator = Some(name);
@@ -459,6 +500,55 @@ mod tests {
         assert_eq!(p.as_slice(), &[10, 20, 30]);
     }
 
+    #[test]
+    fn error_path_packs_data_path_after_node_ids() {
+        let p = ErrorPath::new(vec![7, 8], &[3, 0]);
+        assert_eq!(p.as_slice(), &[7, 8]);
+        assert_eq!(
+            p.data_path(),
+            [DataPathStep::Index(3), DataPathStep::Index(0)]
+        );
+
+        // Either half may be empty on its own.
+        let ids_only = ErrorPath::new(vec![7], &[]);
+        assert_eq!(ids_only.as_slice(), &[7]);
+        assert!(ids_only.data_path().is_empty());
+        let data_only = ErrorPath::new(Vec::new(), &[4]);
+        assert!(data_only.as_slice().is_empty());
+        assert_eq!(data_only.data_path(), [DataPathStep::Index(4)]);
+    }
+
+    #[test]
+    fn error_path_encodes_object_keys() {
+        let mut words = Vec::new();
+        ErrorPath::push_index(&mut words, 2);
+        ErrorPath::push_key(&mut words, "prĭce");
+        ErrorPath::push_key(&mut words, "");
+        ErrorPath::push_index(&mut words, 0);
+        let p = ErrorPath::new(vec![5], &words);
+        assert_eq!(p.as_slice(), &[5]);
+        assert_eq!(
+            p.data_path(),
+            [
+                DataPathStep::Index(2),
+                DataPathStep::Key("prĭce".to_string()),
+                DataPathStep::Key(String::new()),
+                DataPathStep::Index(0),
+            ]
+        );
+    }
+
+    #[test]
+    fn error_path_debug_shows_both_halves() {
+        let mut words = Vec::new();
+        ErrorPath::push_key(&mut words, "a");
+        let p = ErrorPath::new(vec![7, 8], &words);
+        assert_eq!(
+            format!("{p:?}"),
+            r#"ErrorPath { node_ids: [7, 8], data_path: [Key("a")] }"#
+        );
+    }
+
     #[test]
     fn with_node_ids_round_trips() {
         // Engine boundary calls `with_node_ids` once per escaping error;
@@ -1,26 +1,133 @@
+use std::fmt;
+
 use serde::ser::{Serialize, Serializer};
 
+/// One step of [`crate::Error::data_path`]: which item an enclosing
+/// iterator was evaluating when the error was raised.
+///
+/// Serialises as the bare index or key, so a path reads like
+/// `[1, "prices", 0]` on the wire.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DataPathStep {
+    /// Position of the item in an array.
+    Index(u32),
+    /// Key of the entry in an object.
+    Key(String),
+}
+
+impl Serialize for DataPathStep {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Index(index) => serializer.serialize_u32(*index),
+            Self::Key(key) => serializer.serialize_str(key),
+        }
+    }
+}
+
+/// Marks a data-path word as the start of an object key: the low bits
+/// hold the key's length in chars, and that many `char`s follow, one per
+/// word. Array indices stay below it.
+const KEY_FLAG: u32 = 1 << 31;
+
 /// Internal storage for the breadcrumb inside `Error`'s boxed metadata.
-/// Hidden from the public surface so the layout (currently a plain
-/// `Vec<u32>`) can evolve (smallvec, inline buffer, deferred-grow) without
-/// an API change. Construct via `ErrorPath::default()` (empty) or
-/// `From<Vec<u32>>`.
-#[derive(Debug, Clone, Default)]
+/// Hidden from the public surface so the layout can evolve (smallvec,
+/// inline buffer, deferred-grow) without an API change. Construct via
+/// `ErrorPath::default()` (empty), `ErrorPath::new`, or `From<Vec<u32>>`.
+///
+/// The node-id breadcrumb and the data path share one allocation so
+/// `Error` stays at its 80-byte ceiling: `inner` is laid out as
+/// `[node ids (leaf-to-root)..., data path (outermost first)..., data_len]`,
+/// with the trailing length word present whenever `inner` is non-empty.
+/// The data path is stored in words built by [`Self::push_index`] and
+/// [`Self::push_key`] and counted in words, not steps.
+#[derive(Clone, Default)]
 pub(crate) struct ErrorPath {
     inner: Vec<u32>,
 }
 
 impl ErrorPath {
+    /// Pack a node-id breadcrumb and a data path. Reuses `node_ids`'
+    /// allocation; the only growth is the appended data path + length word.
+    #[inline]
+    pub(crate) fn new(mut node_ids: Vec<u32>, data_path: &[u32]) -> Self {
+        if node_ids.is_empty() && data_path.is_empty() {
+            return Self { inner: node_ids };
+        }
+        node_ids.extend_from_slice(data_path);
+        node_ids.push(data_path.len() as u32);
+        Self { inner: node_ids }
+    }
+
+    #[inline]
+    fn data_len(&self) -> usize {
+        self.inner.last().map_or(0, |&n| n as usize)
+    }
+
+    /// The node-id breadcrumb, leaf-to-root.
     #[inline]
     pub(crate) fn as_slice(&self) -> &[u32] {
-        &self.inner
+        let end = self.inner.len().saturating_sub(self.data_len() + 1);
+        &self.inner[..end]
+    }
+
+    /// The encoded data path words, outermost iteration first.
+    #[inline]
+    pub(crate) fn data_words(&self) -> &[u32] {
+        let Some(end) = self.inner.len().checked_sub(1) else {
+            return &[];
+        };
+        &self.inner[end - self.data_len()..end]
+    }
+
+    /// The data path, outermost iteration first.
+    pub(crate) fn data_path(&self) -> Vec<DataPathStep> {
+        let mut words = self.data_words().iter();
+        let mut steps = Vec::new();
+        while let Some(&word) = words.next() {
+            if word & KEY_FLAG == 0 {
+                steps.push(DataPathStep::Index(word));
+                continue;
+            }
+            let len = (word & !KEY_FLAG) as usize;
+            let key = words
+                .by_ref()
+                .take(len)
+                .filter_map(|&c| char::from_u32(c))
+                .collect();
+            steps.push(DataPathStep::Key(key));
+        }
+        steps
+    }
+
+    /// Append an array position to encoded data path `words`. Positions
+    /// past `KEY_FLAG` saturate just below it.
+    #[inline]
+    pub(crate) fn push_index(words: &mut Vec<u32>, index: usize) {
+        words.push(index.min(KEY_FLAG as usize - 1) as u32);
+    }
+
+    /// Append an object key to encoded data path `words`.
+    pub(crate) fn push_key(words: &mut Vec<u32>, key: &str) {
+        let len_at = words.len();
+        words.push(KEY_FLAG);
+        words.extend(key.chars().map(u32::from));
+        words[len_at] |= (words.len() - len_at - 1) as u32;
+    }
+}
+
+impl fmt::Debug for ErrorPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ErrorPath")
+            .field("node_ids", &self.as_slice())
+            .field("data_path", &self.data_path())
+            .finish()
     }
 }
 
 impl From<Vec<u32>> for ErrorPath {
     #[inline]
     fn from(inner: Vec<u32>) -> Self {
-        Self { inner }
+        Self::new(inner, &[])
     }
 }
 
@@ -35,6 +142,6 @@ impl Eq for ErrorPath {}
 
 impl Serialize for ErrorPath {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        self.inner.serialize(serializer)
+        self.as_slice().serialize(serializer)
     }
 }
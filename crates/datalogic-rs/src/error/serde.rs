@@ -50,6 +50,15 @@ fn write_kind_message(f: &mut fmt::Formatter<'_>, kind: &ErrorKind) -> fmt::Resu
             index, length
         ),
         ErrorKind::ConfigurationError(msg) => write!(f, "Configuration error: {}", msg),
+        ErrorKind::StepLimitExceeded { limit } => {
+            write!(
+                f,
+                "Step limit exceeded: evaluation took more than {} steps",
+                limit
+            )
+        }
+        ErrorKind::DivisionByZero => f.write_str("Division by zero"),
+        ErrorKind::NonFiniteNumber => f.write_str("Result contains a non-finite number"),
     }
 }
 
@@ -98,6 +107,15 @@ impl From<datavalue::ParseError> for Error {
     }
 }
 
+/// Lets `Error` be the error type of the arena serializer behind
+/// [`crate::Engine::eval_typed`]; a failing `Serialize` impl surfaces as a
+/// `ParseError`, like a malformed JSON input.
+impl serde::ser::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error::parse_error(msg.to_string())
+    }
+}
+
 impl From<ErrorKind> for Error {
     #[inline]
     fn from(kind: ErrorKind) -> Self {
@@ -108,7 +126,7 @@ impl From<ErrorKind> for Error {
 impl Serialize for Error {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         // Shape:
-        // { "type": <tag>, "message": <Display>, ...kind-extras, "operator"?, "node_ids"? }
+        // { "type": <tag>, "message": <Display>, ...kind-extras, "operator"?, "node_ids"?, "data_path"? }
         let mut map = serializer.serialize_map(None)?;
         map.serialize_entry("type", self.tag())?;
         // The Display impl appends "(in operator: ...)" when set; for the
@@ -125,6 +143,7 @@ impl Serialize for Error {
                 map.serialize_entry("index", index)?;
                 map.serialize_entry("length", length)?;
             }
+            ErrorKind::StepLimitExceeded { limit } => map.serialize_entry("limit", limit)?,
             _ => {}
         }
         if let Some(op) = self.operator() {
@@ -134,10 +153,23 @@ impl Serialize for Error {
         if !ids.is_empty() {
             map.serialize_entry("node_ids", ids)?;
         }
+        let data_path = self.data_path();
+        if !data_path.is_empty() {
+            map.serialize_entry("data_path", &data_path)?;
+        }
         map.end()
     }
 }
 
+impl Error {
+    /// The error message without the `(in operator: ...)` suffix — the same
+    /// text as the serialized `message` field.
+    #[cfg(feature = "error-handling")]
+    pub(crate) fn kind_message(&self) -> impl fmt::Display + '_ {
+        KindDisplay(&self.kind)
+    }
+}
+
 /// Render an [`ErrorKind`] without the operator suffix. Used by
 /// [`Error::serialize`] to populate the `message` field.
 struct KindDisplay<'a>(&'a ErrorKind);
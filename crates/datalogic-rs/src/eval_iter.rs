@@ -0,0 +1,112 @@
+//! Lazy batch evaluation — one compiled rule over a stream of records.
+//!
+//! [`EvalIter`] is returned by [`crate::Engine::eval_iter`]. It owns a
+//! [`bumpalo::Bump`] and resets it at the start of every
+//! [`Iterator::next`] call: each yielded result is deep-cloned out of the
+//! arena into an [`OwnedDataValue`] before `next` returns, so nothing the
+//! caller holds ever borrows from the arena and the reset is always sound.
+//! Peak memory is bounded by the largest single record, not the batch.
+//!
+//! Records are pulled from the source iterator only as results are
+//! requested — `.take(n)` stops reading input after `n` items, and no
+//! result `Vec` is ever materialised. For a slice already in memory,
+//! [`crate::Engine::eval_batch`] avoids the per-record input conversion
+//! this path pays.
+
+use std::iter::FusedIterator;
+
+use bumpalo::Bump;
+use datavalue::OwnedDataValue;
+
+use crate::{Engine, FromDataValue, Logic, OwnedInput, Result};
+
+/// Iterator of per-record evaluation results. Construct via
+/// [`Engine::eval_iter`].
+///
+/// Yields one `Result<OwnedDataValue>` per item of the source iterator,
+/// in order. An error for one record doesn't end iteration; the next
+/// call to `next` evaluates the next record on a freshly reset arena.
+pub struct EvalIter<'a, I> {
+    engine: &'a Engine,
+    compiled: &'a Logic,
+    data: I,
+    arena: Bump,
+}
+
+impl<I> std::fmt::Debug for EvalIter<'_, I> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Same shape as `Session`'s Debug: the source iterator is usually
+        // an opaque adapter chain, so only the arena high-water mark is
+        // worth printing.
+        f.debug_struct("EvalIter")
+            .field("engine", &self.engine)
+            .field("arena_allocated_bytes", &self.arena.allocated_bytes())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, I> EvalIter<'a, I> {
+    #[inline]
+    pub(crate) fn new(engine: &'a Engine, compiled: &'a Logic, data: I) -> Self {
+        Self {
+            engine,
+            compiled,
+            data,
+            // Same starting capacity as the one-shot path; the bump keeps
+            // whatever chunks the largest record needed across resets.
+            arena: Bump::with_capacity(4096),
+        }
+    }
+
+    /// Total bytes currently occupied by the iterator's arena chunks.
+    ///
+    /// Stable across the per-item resets (chunks aren't freed), so after
+    /// draining the iterator this is the batch's high-water mark.
+    #[inline]
+    pub fn allocated_bytes(&self) -> usize {
+        self.arena.allocated_bytes()
+    }
+}
+
+impl<I> Iterator for EvalIter<'_, I>
+where
+    I: Iterator,
+    I::Item: OwnedInput,
+{
+    type Item = Result<OwnedDataValue>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let record = self.data.next()?;
+        // The previous item's result was cloned out before it was
+        // returned, so no live reference points into the arena here.
+        self.arena.reset();
+        Some(self.eval_record(record))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.data.size_hint()
+    }
+}
+
+impl<I> EvalIter<'_, I> {
+    fn eval_record<D: OwnedInput>(&self, record: D) -> Result<OwnedDataValue> {
+        let owned = record.into_owned_input()?;
+        let result = self.engine.evaluate(self.compiled, &owned, &self.arena)?;
+        OwnedDataValue::from_arena(result)
+    }
+}
+
+impl<I> ExactSizeIterator for EvalIter<'_, I>
+where
+    I: ExactSizeIterator,
+    I::Item: OwnedInput,
+{
+}
+
+impl<I> FusedIterator for EvalIter<'_, I>
+where
+    I: FusedIterator,
+    I::Item: OwnedInput,
+{
+}
@@ -21,7 +21,7 @@
 //!
 //! - **Compilation-based optimization**: Parse once, evaluate many times
 //! - **Thread-safe by design**: Share compiled logic across threads with `Arc`
-//! - **59 built-in operators**: Complete JSONLogic compatibility plus extensions
+//! - **154 built-in operators**: Complete JSONLogic compatibility plus extensions
 //! - **Arena-allocated evaluation**: Results live in a `bumpalo::Bump` arena and can borrow directly into caller input for zero-copy paths
 //! - **Extensible**: Add custom operators via the [`CustomOperator`] trait
 //! - **Structured templates**: Preserve object structure for dynamic outputs
@@ -62,6 +62,11 @@
 //! }
 //! ```
 //!
+//! When units of work (e.g. requests) run on whichever thread is free,
+//! share an [`ArenaPool`] instead and wrap each one in
+//! [`Engine::scoped`]: it lends a pooled arena to a session for the
+//! duration of a closure, then resets it and returns it to the pool.
+//!
 //! ## Power-user (compile once, evaluate many, zero-copy results)
 //!
 //! When the result borrow can stay scoped to a caller-managed
@@ -97,12 +102,14 @@
 
 mod arena;
 mod arena_ext;
+mod arena_pool;
 mod builder;
 mod compile;
 mod config;
 mod engine;
 mod error;
 mod eval_input;
+mod eval_iter;
 mod logic_input;
 mod node;
 mod node_serialize;
@@ -111,6 +118,7 @@ pub mod operator;
 mod operators;
 mod parsed_data;
 mod path;
+mod references;
 mod result_output;
 #[cfg(feature = "serde_json")]
 mod serde_bridge;
@@ -121,6 +129,7 @@ mod trace;
 
 pub use arena::DataValue;
 pub use arena_ext::ArenaExt;
+pub use arena_pool::ArenaPool;
 pub use builder::EngineBuilder;
 /// The [`bumpalo`] arena allocator, re-exported.
 ///
@@ -131,7 +140,8 @@ pub use builder::EngineBuilder;
 /// instead of an independent `bumpalo` dep to avoid major-version skew.
 pub use bumpalo;
 pub use config::{
-    DivisionByZeroHandling, EvaluationConfig, NanHandling, NumericCoercionConfig, TruthyEvaluator,
+    DivisionByZeroHandling, EvaluationConfig, NanHandling, NonFiniteHandling,
+    NumericCoercionConfig, TruthyEvaluator,
 };
 /// The `datavalue` crate, re-exported. `datalogic-rs` builds on `datavalue`'s
 /// owned and borrowed value types — accessing them through this module makes
@@ -184,12 +194,14 @@ pub use config::{
 /// what [`Engine::eval_str`] uses internally.
 pub use datavalue;
 pub use engine::Engine;
-pub use error::{CustomErrorSource, Error, ErrorKind};
+pub use error::{CustomErrorSource, DataPathStep, Error, ErrorKind};
 pub use eval_input::{EvalInput, OwnedInput};
+pub use eval_iter::EvalIter;
 pub use logic_input::IntoLogic;
 pub use node::Logic;
 pub use parsed_data::ParsedData;
 pub use path::PathStep;
+pub use references::ReferencedPaths;
 pub use result_output::FromDataValue;
 pub use session::Session;
 #[cfg(feature = "serde_json")]
@@ -287,6 +299,18 @@ pub trait CustomOperator: Send + Sync {
         ctx: &mut operator::EvalContext<'_, 'a>,
         arena: &'a bumpalo::Bump,
     ) -> Result<&'a DataValue<'a>>;
+
+    /// Whether the result depends only on `args`: no reads through `ctx`,
+    /// no side effects, and the same output for the same input every
+    /// time. Default: `false`.
+    ///
+    /// Returning `true` lets the compiler hoist a call whose arguments
+    /// are themselves loop-invariant out of `map` / `filter` / `reduce`
+    /// bodies, so it runs once per evaluation instead of once per item.
+    /// Calls whose arguments depend on the item still run per item.
+    fn is_pure(&self) -> bool {
+        false
+    }
 }
 
 // `Box<dyn CustomOperator>` itself implements `CustomOperator` by
@@ -306,4 +330,84 @@ impl CustomOperator for Box<dyn CustomOperator> {
     ) -> Result<&'a DataValue<'a>> {
         (**self).evaluate(args, ctx, arena)
     }
+
+    #[inline]
+    fn is_pure(&self) -> bool {
+        (**self).is_pure()
+    }
+}
+
+/// Custom operator hook that receives its arguments **unevaluated**.
+///
+/// Where a [`CustomOperator`] gets every argument already evaluated, a
+/// `LazyOperator` gets an [`operator::LazyArgs`] handle and evaluates
+/// only the arguments it needs, in the order it chooses — the shape
+/// `and`, `if` and `??` have. Use it for short-circuiting operators or
+/// ones that must skip an argument's side effects (a throwing branch, a
+/// custom logging operator). Register with
+/// [`EngineBuilder::add_lazy_operator`].
+///
+/// ## Example
+///
+/// ```rust
+/// use datalogic_rs::{DataValue, Engine, LazyOperator, Result, operator::LazyArgs};
+/// use bumpalo::Bump;
+///
+/// /// First non-null argument, evaluating no further than that.
+/// struct FirstPresent;
+/// impl LazyOperator for FirstPresent {
+///     fn evaluate<'a>(
+///         &self,
+///         args: &mut LazyArgs<'_, 'a>,
+///         _arena: &'a Bump,
+///     ) -> Result<&'a DataValue<'a>> {
+///         let mut last = args.evaluate(0)?;
+///         for i in 1..args.len() {
+///             if !last.is_null() {
+///                 break;
+///             }
+///             last = args.evaluate(i)?;
+///         }
+///         Ok(last)
+///     }
+/// }
+///
+/// let engine = Engine::builder().add_lazy_operator("first", FirstPresent).build();
+/// // The unknown operator in the second argument is never evaluated.
+/// let result = engine.eval_str(r#"{"first": [1, {"nope": []}]}"#, "null").unwrap();
+/// assert_eq!(result, "1");
+/// ```
+///
+/// ## Stability
+///
+/// Same commitment as [`CustomOperator`]: within 5.x only default-method
+/// additions.
+pub trait LazyOperator: Send + Sync {
+    /// Evaluate this operator, evaluating arguments on demand.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` — the operator's arguments, unevaluated. Call
+    ///   [`operator::LazyArgs::evaluate`] for each one you need; each call
+    ///   runs that argument's expression in the operator's context.
+    ///   [`operator::LazyArgs::context`] gives the same context view a
+    ///   [`CustomOperator`] receives.
+    /// * `arena` — the [`bumpalo::Bump`] allocator, as for
+    ///   [`CustomOperator::evaluate`].
+    fn evaluate<'a>(
+        &self,
+        args: &mut operator::LazyArgs<'_, 'a>,
+        arena: &'a bumpalo::Bump,
+    ) -> Result<&'a DataValue<'a>>;
+}
+
+impl LazyOperator for Box<dyn LazyOperator> {
+    #[inline]
+    fn evaluate<'a>(
+        &self,
+        args: &mut operator::LazyArgs<'_, 'a>,
+        arena: &'a bumpalo::Bump,
+    ) -> Result<&'a DataValue<'a>> {
+        (**self).evaluate(args, arena)
+    }
 }
@@ -29,11 +29,17 @@ pub(crate) const SYNTHETIC_ID: NodeId = None;
 ///
 /// `skip_fold` is set by the trace path so the constant-fold + optimizer
 /// passes are bypassed and every operator survives in the compiled tree.
+///
+/// `diagnostics` is `Some` only for `Engine::validate`: problems that would
+/// normally stop compilation (or be deferred to evaluation as a
+/// placeholder node) are recorded there instead, and the walk carries on.
 #[derive(Debug)]
 pub(crate) struct CompileCtx {
     next_id: NonZeroU32,
     skip_fold: bool,
     depth: usize,
+    max_depth: usize,
+    diagnostics: Option<Vec<crate::Error>>,
 }
 
 const ID_ONE: NonZeroU32 = match NonZeroU32::new(1) {
@@ -41,8 +47,9 @@ const ID_ONE: NonZeroU32 = match NonZeroU32::new(1) {
     None => unreachable!(),
 };
 
-/// Maximum rule-tree nesting accepted at compile time. Mirrors the JSON
-/// parser's own depth cap so a programmatically-built `OwnedDataValue` rule
+/// Default maximum rule-tree nesting accepted at compile time (override per
+/// engine with `EngineBuilder::with_max_depth`). Mirrors the JSON parser's
+/// own depth cap so a programmatically-built `OwnedDataValue` rule
 /// (which reaches the compiler via `IntoLogic` without going through the
 /// string parser) can't drive unbounded recursion in `compile_node`,
 /// dispatch, or the recursive `Drop` of the resulting `CompiledNode` tree.
@@ -54,6 +61,8 @@ impl CompileCtx {
             next_id: ID_ONE,
             skip_fold: false,
             depth: 0,
+            max_depth: MAX_COMPILE_DEPTH,
+            diagnostics: None,
         }
     }
 
@@ -67,20 +76,85 @@ impl CompileCtx {
             next_id: ID_ONE,
             skip_fold: true,
             depth: 0,
+            max_depth: MAX_COMPILE_DEPTH,
+            diagnostics: None,
+        }
+    }
+
+    /// Replace the nesting cap checked by [`Self::enter`] (default
+    /// [`MAX_COMPILE_DEPTH`]). Used by `Logic::compile_*` to apply the
+    /// engine's `EngineBuilder::with_max_depth` setting.
+    #[inline]
+    pub(crate) fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Collect problems into a diagnostics list instead of failing on the
+    /// first one. See [`Self::report`] and [`Self::note`].
+    #[inline]
+    pub(crate) fn collecting(mut self) -> Self {
+        self.diagnostics = Some(Vec::new());
+        self
+    }
+
+    /// A problem that normally fails compilation. Returns it as `Err`,
+    /// unless diagnostics are being collected, in which case it is
+    /// recorded and the caller continues with a placeholder node.
+    #[inline]
+    pub(crate) fn report(&mut self, err: crate::Error) -> crate::Result<()> {
+        match &mut self.diagnostics {
+            Some(diagnostics) => {
+                diagnostics.push(err);
+                Ok(())
+            }
+            None => Err(err),
+        }
+    }
+
+    /// A problem that normally only surfaces at evaluation time (an
+    /// invalid-args placeholder, an unregistered operator). Recorded when
+    /// collecting diagnostics; otherwise ignored without building the error.
+    #[inline]
+    pub(crate) fn note(&mut self, err: impl FnOnce() -> crate::Error) {
+        if let Some(diagnostics) = &mut self.diagnostics {
+            diagnostics.push(err());
         }
     }
 
+    /// Whether diagnostics are being collected.
+    #[inline]
+    pub(crate) fn is_collecting(&self) -> bool {
+        self.diagnostics.is_some()
+    }
+
+    /// Take the collected diagnostics, leaving the list empty.
+    pub(crate) fn take_diagnostics(&mut self) -> Vec<crate::Error> {
+        self.diagnostics
+            .as_mut()
+            .map(std::mem::take)
+            .unwrap_or_default()
+    }
+
     /// Enter one level of rule nesting during compilation. Errors once
-    /// nesting passes [`MAX_COMPILE_DEPTH`], bounding recursion for
+    /// nesting passes the configured cap, bounding recursion for
     /// programmatically-built rules that skip the JSON parser's own cap.
     /// Every successful `enter()` must be paired with a [`Self::leave`] so
     /// sibling subtrees are accounted from the correct depth.
     #[inline]
     pub(crate) fn enter(&mut self) -> crate::Result<()> {
         self.depth += 1;
-        if self.depth > MAX_COMPILE_DEPTH {
+        if self.depth > self.max_depth {
+            // A cap of 0 would reject even a bare literal; report the
+            // setting itself rather than a nesting overflow.
+            if self.max_depth == 0 {
+                return Err(crate::Error::configuration_error(
+                    "max_depth must be at least 1",
+                ));
+            }
             return Err(crate::Error::configuration_error(format!(
-                "rule nesting exceeds the maximum compile depth of {MAX_COMPILE_DEPTH}"
+                "rule nesting exceeds the maximum compile depth of {}",
+                self.max_depth
             )));
         }
         Ok(())
@@ -54,11 +54,11 @@ pub struct Logic {
     /// `CustomOperator` (one alloc per compile, amortised over many
     /// evaluations), `None` for `Value` literals.
     pub(crate) root_op_name: Option<std::borrow::Cow<'static, str>>,
-    /// Number of CSE memo slots assigned by the compile-time CSE pass
-    /// (`crate::compile::optimize::cse`); the upper bound of the lazily
-    /// grown per-evaluation slot table on `ContextStack`. `0` for rules
-    /// with no shared pure aggregates (and for every no-fold / traced
-    /// compile). Plain `Copy` data — `Logic` stays `Send + Sync` with no
+    /// Number of memo slots assigned by the compile-time CSE and hoisting
+    /// passes (`crate::compile::optimize::{cse, hoist}`); the upper bound
+    /// of the lazily grown per-evaluation slot table on `ContextStack`.
+    /// `0` for rules with no shared pure aggregates and no loop-invariant
+    /// iterator-body expressions (and for every no-fold / traced compile). Plain `Copy` data — `Logic` stays `Send + Sync` with no
     /// interior mutability.
     pub(crate) cse_slot_count: u16,
 }
@@ -141,17 +141,19 @@ impl Logic {
         matches!(self.root, CompiledNode::Value { .. })
     }
 
-    /// Number of shared-subexpression memo slots the compiler assigned to
-    /// this rule.
+    /// Number of memo slots the compiler assigned to this rule.
     ///
     /// The compile-time CSE pass detects structurally identical pure
     /// subtrees (typically repeated aggregates — JSONLogic has no `let`
     /// bindings, so rule authors paste them) and arranges for each
-    /// equivalence class to be computed once per evaluation. `0` means no
-    /// shared subexpressions were found — or the pass didn't run (engines
-    /// built with [`crate::EngineBuilder::with_constant_folding`]`(false)`
-    /// or configured with a [`crate::TruthyEvaluator::Custom`] evaluator
-    /// compile without CSE). Useful for rule-analysis tooling and for
+    /// equivalence class to be computed once per evaluation. A second
+    /// pass gives a slot to each data-independent expression inside a
+    /// `map` / `filter` / `reduce` body (say, a `merge` of literals), so
+    /// it is computed on the first iteration only. `0` means neither pass
+    /// found anything — or they didn't run (engines built with
+    /// [`crate::EngineBuilder::with_constant_folding`]`(false)` or
+    /// configured with a [`crate::TruthyEvaluator::Custom`] evaluator
+    /// compile without them). Useful for rule-analysis tooling and for
     /// verifying that a hot rule benefits from sharing.
     ///
     /// # Example
@@ -202,6 +204,35 @@ impl Logic {
     pub fn to_json(&self) -> String {
         crate::node_serialize::node_to_json_string(&self.root)
     }
+
+    /// Reconstruct this compiled tree as a JSONLogic value — the
+    /// structured counterpart of [`Self::to_json`].
+    ///
+    /// The shape is the same as `to_json`'s: constant-folded
+    /// sub-expressions appear as literals and rewritten forms appear as
+    /// their rewrite (`{"!": {"!": x}}` becomes `{"!!": x}`). Feeding the
+    /// result back to [`crate::Engine::compile`] yields a `Logic` whose
+    /// `to_value` is equal to this one's.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use datalogic_rs::Engine;
+    ///
+    /// let engine = Engine::new();
+    /// let compiled = engine.compile(r#"{"if": [{"var": "ok"}, "yes", {"+": [1, 2]}]}"#).unwrap();
+    /// let value = compiled.to_value();
+    /// assert_eq!(value.to_json_string(), r#"{"if":[{"var":"ok"},"yes",3]}"#);
+    ///
+    /// let recompiled = engine.compile(&value).unwrap();
+    /// assert_eq!(recompiled.to_value(), value);
+    /// ```
+    pub fn to_value(&self) -> datavalue::OwnedDataValue {
+        // `to_json` escapes every key and path it emits, so its output
+        // always re-parses.
+        datavalue::OwnedDataValue::from_json(&self.to_json())
+            .expect("Logic::to_json emits valid JSON")
+    }
 }
 
 impl std::fmt::Display for Logic {
@@ -260,11 +291,21 @@ fn opcode_is_static(opcode: &OpCode, args: &[CompiledNode]) -> bool {
         Val | Missing | MissingSome => false,
         #[cfg(feature = "ext-control")]
         Exists => false,
+        // `template` without a data argument renders the current frame.
+        #[cfg(feature = "ext-string")]
+        Template if args.len() < 2 => false,
 
         // Iteration operators: These push new contexts for each iteration and use
         // callbacks that may reference the iteration variable. Even with static
         // arrays, the callback logic depends on the per-element context.
         Map | Filter | Reduce | All | Some | None => false,
+        #[cfg(feature = "ext-array")]
+        GroupBy | Find | Count | FlatMap | AllValues | SomeValues | NoneValues | MinBy | MaxBy
+        | ReduceWhile => false,
+        // `let` pushes a frame holding its bindings; the body reads them
+        // through `var`, so it is no more foldable than an iterator body.
+        #[cfg(feature = "ext-control")]
+        Let => false,
 
         // Error handling: These have control flow effects (early exit, error propagation)
         // that should be preserved for runtime execution.
@@ -275,6 +316,12 @@ fn opcode_is_static(opcode: &OpCode, args: &[CompiledNode]) -> bool {
         #[cfg(feature = "datetime")]
         Now => false,
 
+        // Config- or entropy-dependent: an unseeded `random` reads the
+        // engine seed or draws fresh entropy, and deterministic mode turns
+        // it into an error. Keep dynamic even when seeded.
+        #[cfg(feature = "ext-math")]
+        Random => false,
+
         // Context-dependent in implicit form: when the bucketing
         // expression is omitted, `fractional` reads `$flagd.flagKey` and
         // `targetingKey` from the root data, so it cannot be folded even
@@ -24,7 +24,7 @@ mod prelit;
 
 pub use logic::Logic;
 
-pub(crate) use compile_ctx::{CompileCtx, NodeId, SYNTHETIC_ID};
+pub(crate) use compile_ctx::{CompileCtx, MAX_COMPILE_DEPTH, NodeId, SYNTHETIC_ID};
 pub(crate) use logic::node_is_static;
 #[cfg(feature = "ext-control")]
 pub(crate) use payload::CompiledExistsData;
@@ -164,10 +164,12 @@ pub(crate) enum CompiledNode {
     InvalidArgs { id: NodeId, op_name: &'static str },
 
     /// A common-subexpression memo wrapper produced by the compile-time
-    /// CSE pass (`crate::compile::optimize::cse`). Transparent everywhere
-    /// except the dispatch hub: `dispatch_cse` consults/fills the
-    /// per-evaluation memo slot when `ctx.depth() == 0` and no tracer is
-    /// attached; every other consumer (id, serialization, static
+    /// CSE pass (`crate::compile::optimize::cse`), or by the hoisting pass
+    /// (`crate::compile::optimize::hoist`) around a loop-invariant
+    /// iterator-body expression. Transparent everywhere except the
+    /// dispatch hub: `dispatch_cse` consults/fills the per-evaluation memo
+    /// slot when `ctx.depth() == 0` (at any depth for hoisted wrappers)
+    /// and no tracer is attached; every other consumer (id, serialization, static
     /// classification, trace tree, path walk) delegates to `inner`, so a
     /// wrapped tree is observably identical to an unwrapped one.
     /// Boxed to keep the enum within the 48-byte layout budget. Declared
@@ -20,6 +20,19 @@ pub(crate) enum PathSegment {
     /// Try as object key first, then as array index (for segments that could be either).
     /// Pre-parses the index at compile time to avoid runtime parsing.
     FieldOrIndex(Box<str>, usize),
+    /// Try as object key first, then as an array index counted from the end
+    /// (`"-1"` is the last element). The `usize` is the distance from the
+    /// end and is always at least 1.
+    FieldOrIndexFromEnd(Box<str>, usize),
+}
+
+impl PathSegment {
+    /// Whether this is a `*` segment, which a wildcard `var` path maps over
+    /// the array it reaches.
+    #[inline]
+    pub(crate) fn is_wildcard(&self) -> bool {
+        matches!(self, PathSegment::Field(key) if key.as_ref() == "*")
+    }
 }
 
 /// Hint for reduce context resolution, detected at compile time.
@@ -37,7 +50,9 @@ pub(crate) enum ReduceHint {
     AccumulatorPath,
 }
 
-/// Hint for metadata access (index/key), detected at compile time.
+/// Hint for lookups that aren't a plain path walk (frame index/key metadata,
+/// wildcard paths), detected at compile time. Fast paths only take vars
+/// with `MetadataHint::None`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub(crate) enum MetadataHint {
     /// Normal data access
@@ -46,6 +61,9 @@ pub(crate) enum MetadataHint {
     Index,
     /// Access frame key metadata
     Key,
+    /// Path has a `*` segment — collect the rest of the path from each
+    /// element of the array found there
+    Wildcard,
 }
 
 /// Data for a custom operator (boxed inside CompiledNode to reduce enum size).
@@ -57,14 +75,19 @@ pub(crate) struct CustomOperatorData {
 }
 
 /// Data for a CSE memo wrapper (boxed inside CompiledNode to reduce enum
-/// size). Produced only by the compile-time CSE pass
-/// (`crate::compile::optimize::cse`); `slot` indexes the per-evaluation
+/// size). Produced by the compile-time CSE pass
+/// (`crate::compile::optimize::cse`) and the loop-invariant hoisting pass
+/// (`crate::compile::optimize::hoist`); `slot` indexes the per-evaluation
 /// memo table on `ContextStack`, shared by every occurrence of one
 /// equivalence class. Carries no id of its own — id, name, serialization,
 /// and trace queries all delegate to `inner`.
 #[derive(Debug, Clone)]
 pub(crate) struct CseData {
     pub slot: u16,
+    /// Set by the hoisting pass: `inner` reads no context outside its own
+    /// iteration frames, so the memo is valid at any depth, not just at
+    /// the root.
+    pub hoisted: bool,
     pub inner: CompiledNode,
 }
 
@@ -44,7 +44,20 @@ pub(super) fn precompute_lit(value: &OwnedDataValue) -> Option<PreLit> {
 #[inline]
 fn iterates_args0(opcode: OpCode) -> bool {
     #[cfg(feature = "ext-array")]
-    if matches!(opcode, OpCode::Sort) {
+    if matches!(
+        opcode,
+        OpCode::Sort
+            | OpCode::GroupBy
+            | OpCode::Find
+            | OpCode::Count
+            | OpCode::FlatMap
+            | OpCode::AllValues
+            | OpCode::SomeValues
+            | OpCode::NoneValues
+            | OpCode::MinBy
+            | OpCode::MaxBy
+            | OpCode::ReduceWhile
+    ) {
         return true;
     }
     matches!(
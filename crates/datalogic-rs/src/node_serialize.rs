@@ -37,11 +37,13 @@ pub(crate) fn node_to_json_string(node: &CompiledNode) -> String {
         CompiledNode::Exists(data) => compiled_exists_to_json_string(&data.segments),
         #[cfg(feature = "error-handling")]
         CompiledNode::Throw(data) => {
+            // `{"type": s}` came from the string shorthand; any other
+            // payload is a multi-key object literal and re-parses as one.
             if let datavalue::OwnedDataValue::Object(pairs) = &data.error {
-                if let Some((_, datavalue::OwnedDataValue::String(s))) =
-                    pairs.iter().find(|(k, _)| k == "type")
-                {
-                    return format!("{{\"throw\": \"{}\"}}", s);
+                if let [(key, datavalue::OwnedDataValue::String(s))] = pairs.as_slice() {
+                    if key == "type" {
+                        return format!("{{\"throw\": {}}}", quote(s));
+                    }
                 }
             }
             format!("{{\"throw\": {}}}", data.error.to_json_string())
@@ -51,7 +53,7 @@ pub(crate) fn node_to_json_string(node: &CompiledNode) -> String {
                 .args
                 .iter()
                 .map(|a| match a {
-                    crate::node::CompiledMissingArg::Now((path, _)) => format!("\"{}\"", path),
+                    crate::node::CompiledMissingArg::Now((path, _)) => quote(path),
                     crate::node::CompiledMissingArg::Later(n) => node_to_json_string(n),
                 })
                 .collect();
@@ -64,8 +66,7 @@ pub(crate) fn node_to_json_string(node: &CompiledNode) -> String {
             };
             let paths_str = match &data.paths {
                 crate::node::CompiledMissingPaths::Now(paths) => {
-                    let items: Vec<String> =
-                        paths.iter().map(|(p, _)| format!("\"{}\"", p)).collect();
+                    let items: Vec<String> = paths.iter().map(|(p, _)| quote(p)).collect();
                     format!("[{}]", items.join(", "))
                 }
                 crate::node::CompiledMissingPaths::Later(n) => node_to_json_string(n),
@@ -76,6 +77,13 @@ pub(crate) fn node_to_json_string(node: &CompiledNode) -> String {
     }
 }
 
+/// Render `s` as a JSON string literal. Keys, paths, and operator names
+/// come straight from rule text, so quotes and control characters must be
+/// escaped for the output to re-parse.
+fn quote(s: &str) -> String {
+    datavalue::OwnedDataValue::String(s.to_owned()).to_json_string()
+}
+
 /// Render an operator's argument list: a single arg inlines, multiple args
 /// become a JSON array. Shared by the builtin and custom operator renderers.
 fn args_to_json_string(args: &[CompiledNode]) -> String {
@@ -88,18 +96,63 @@ fn args_to_json_string(args: &[CompiledNode]) -> String {
 }
 
 pub(crate) fn builtin_to_json_string(opcode: &OpCode, args: &[CompiledNode]) -> String {
+    #[cfg(feature = "ext-control")]
+    if *opcode == OpCode::Let {
+        return let_to_json_string(args);
+    }
+    if *opcode == OpCode::ConcatSep {
+        return concat_sep_to_json_string(args);
+    }
     format!("{{\"{}\": {}}}", opcode.as_str(), args_to_json_string(args))
 }
 
+/// `let` compiles its bindings object into flat `name, expr` argument
+/// pairs; fold them back into `[{"name": expr, ...}, body]`.
+#[cfg(feature = "ext-control")]
+fn let_to_json_string(args: &[CompiledNode]) -> String {
+    let Some((body, bindings)) = args.split_last() else {
+        return "{\"let\": []}".to_string();
+    };
+    let fields: Vec<String> = bindings
+        .chunks_exact(2)
+        .map(|pair| {
+            format!(
+                "{}: {}",
+                node_to_json_string(&pair[0]),
+                node_to_json_string(&pair[1])
+            )
+        })
+        .collect();
+    format!(
+        "{{\"let\": [{{{}}}, {}]}}",
+        fields.join(", "),
+        node_to_json_string(body)
+    )
+}
+
+/// `cat`'s separator form compiles to `[sep, item, ...]`; render it as
+/// the `{"sep": sep, "items": [item, ...]}` object it came from.
+fn concat_sep_to_json_string(args: &[CompiledNode]) -> String {
+    let Some((sep, items)) = args.split_first() else {
+        return "{\"cat\": []}".to_string();
+    };
+    let items: Vec<String> = items.iter().map(node_to_json_string).collect();
+    format!(
+        "{{\"cat\": {{\"sep\": {}, \"items\": [{}]}}}}",
+        node_to_json_string(sep),
+        items.join(", ")
+    )
+}
+
 pub(crate) fn custom_to_json_string(name: &str, args: &[CompiledNode]) -> String {
-    format!("{{\"{}\": {}}}", name, args_to_json_string(args))
+    format!("{{{}: {}}}", quote(name), args_to_json_string(args))
 }
 
 #[cfg(feature = "templating")]
 pub(crate) fn structured_to_json_string(fields: &[(String, CompiledNode)]) -> String {
     let items: Vec<String> = fields
         .iter()
-        .map(|(key, node)| format!("\"{}\": {}", key, node_to_json_string(node)))
+        .map(|(key, node)| format!("{}: {}", quote(key), node_to_json_string(node)))
         .collect();
     format!("{{{}}}", items.join(", "))
 }
@@ -113,22 +166,28 @@ pub(crate) fn compiled_var_to_json_string(
         let path: String = segments
             .iter()
             .map(|seg| match seg {
-                PathSegment::Field(s) | PathSegment::FieldOrIndex(s, _) => s.to_string(),
+                PathSegment::Field(s)
+                | PathSegment::FieldOrIndex(s, _)
+                | PathSegment::FieldOrIndexFromEnd(s, _) => s.to_string(),
                 PathSegment::Index(i) => i.to_string(),
             })
             .collect::<Vec<_>>()
             .join(".");
         match default_value {
-            Some(def) => format!("{{\"var\": [\"{}\", {}]}}", path, node_to_json_string(def)),
-            None => format!("{{\"var\": \"{}\"}}", path),
+            Some(def) => format!(
+                "{{\"var\": [{}, {}]}}",
+                quote(&path),
+                node_to_json_string(def)
+            ),
+            None => format!("{{\"var\": {}}}", quote(&path)),
         }
     } else {
         let mut parts = vec![format!("[{}]", scope_level)];
         for seg in segments {
             match seg {
-                PathSegment::Field(s) | PathSegment::FieldOrIndex(s, _) => {
-                    parts.push(format!("\"{}\"", s))
-                }
+                PathSegment::Field(s)
+                | PathSegment::FieldOrIndex(s, _)
+                | PathSegment::FieldOrIndexFromEnd(s, _) => parts.push(quote(s)),
                 PathSegment::Index(i) => parts.push(i.to_string()),
             }
         }
@@ -140,8 +199,10 @@ pub(crate) fn compiled_var_to_json_string(
 pub(crate) fn compiled_exists_to_json_string(segments: &[PathSegment]) -> String {
     if segments.len() == 1 {
         match &segments[0] {
-            PathSegment::Field(s) | PathSegment::FieldOrIndex(s, _) => {
-                format!("{{\"exists\": \"{}\"}}", s)
+            PathSegment::Field(s)
+            | PathSegment::FieldOrIndex(s, _)
+            | PathSegment::FieldOrIndexFromEnd(s, _) => {
+                format!("{{\"exists\": {}}}", quote(s))
             }
             PathSegment::Index(i) => format!("{{\"exists\": {}}}", i),
         }
@@ -149,7 +210,9 @@ pub(crate) fn compiled_exists_to_json_string(segments: &[PathSegment]) -> String
         let parts: Vec<String> = segments
             .iter()
             .map(|seg| match seg {
-                PathSegment::Field(s) | PathSegment::FieldOrIndex(s, _) => format!("\"{}\"", s),
+                PathSegment::Field(s)
+                | PathSegment::FieldOrIndex(s, _)
+                | PathSegment::FieldOrIndexFromEnd(s, _) => quote(s),
                 PathSegment::Index(i) => i.to_string(),
             })
             .collect();
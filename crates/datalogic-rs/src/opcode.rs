@@ -21,7 +21,7 @@
 //! - **Core** (always available):
 //!   - Variable Access: `val` (canonical; `var` is accepted as input and
 //!     normalized to `val` at compile time)
-//!   - Comparison: `==`, `===`, `!=`, `!==`, `>`, `>=`, `<`, `<=`
+//!   - Comparison: `==`, `===`, `!=`, `!==`, `>`, `>=`, `<`, `<=`, `between`
 //!   - Logical: `!`, `!!`, `and`, `or`
 //!   - Control Flow: `if` (canonical; `?:` is accepted as input and normalized
 //!     to `if` at compile time)
@@ -29,15 +29,33 @@
 //!   - String: `cat`, `substr`, `in`
 //!   - Array: `merge`, `filter`, `map`, `reduce`, `all`, `some`, `none`
 //!   - Missing: `missing`, `missing_some`
-//! - **datetime**: `datetime`, `timestamp`, `parse_date`, `format_date`, `date_diff`, `now`
-//! - **ext-string**: `length`, `starts_with`, `ends_with`, `upper`, `lower`, `trim`, `split`
-//! - **ext-array**: `sort`, `slice`
-//! - **ext-control**: `exists`, `??`, `switch`/`match`, `type`
+//! - **datetime**: `datetime`, `timestamp`, `parse_date`, `format_date`,
+//!   `date_diff`, `now`, `date_format`, `date_parse`, `year`, `month`,
+//!   `day`, `hour`, `minute`, `weekday`, `date_add`, `date_sub`,
+//!   `to_timezone`, `start_of`, `end_of`, `duration`
+//! - **ext-string**: `length`, `starts_with`, `ends_with`, `upper`, `lower`,
+//!   `trim`, `split`, `join`, `pad_start`, `pad_end`, `repeat`, `capitalize`,
+//!   `title_case`, `char_at`, `code_point_at`, `base64_encode`,
+//!   `base64_decode`, `json_parse`, `json_stringify`, `number_format`,
+//!   `slugify`, `format`, `template`, `starts_with_i`, `ends_with_i`,
+//!   `in_i`, `trim_start`, `trim_end`
+//! - **ext-array**: `sort`, `slice`, `flatten`, `unique` (alias `distinct`),
+//!   `group_by`, `find`, `index_of`, `zip`, `chunk`, `count`, `flat_map`,
+//!   `reverse`, `take`, `drop`, `contains` (alias `includes`),
+//!   `merge_objects`, `pick`, `omit`, `set`, `all_values`, `some_values`,
+//!   `none_values`, `min_by`, `max_by`, `window`, `reduce_while`
+//! - **ext-control**: `exists`, `get`, `has`, `??`, `default`, `let`,
+//!   `switch`/`match`, `type`, `to_number`, `to_int`, `to_string`,
+//!   `to_bool`, `is_empty`, `is_null`, `parse_int`, `parse_float`
 //! - **error-handling**: `try`, `throw`
-//! - **ext-math**: `abs`, `ceil`, `floor`
+//! - **ext-math**: `abs`, `ceil`, `floor`, `sum`, `avg`, `pow`, `sqrt`,
+//!   `sign`, `clamp`, `log`, `exp`, `sin`, `cos`, `tan`, `gcd`, `lcm`,
+//!   `random`, `bitand`, `bitor`, `bitxor`, `bitnot`, `lshift`, `rshift`,
+//!   `to_fixed`
 //! - **flagd** ([spec](https://flagd.dev/reference/custom-operations/)):
 //!   `fractional` (murmurhash3 percentage bucketing), `sem_ver`
 //!   (semantic-version comparison with flagd-spec normalizations)
+//! - **regex**: `regex_match`, `regex_replace`
 //!
 //! # Adding New Operators
 //!
@@ -64,6 +82,7 @@ pub(crate) enum OpCode {
     GreaterThanEqual = 7,
     LessThan = 8,
     LessThanEqual = 9,
+    Between = 132,
 
     // === Core: Logical Operators ===
     Not = 10,
@@ -87,6 +106,10 @@ pub(crate) enum OpCode {
     Concat = 23,
     Substr = 24,
     In = 25,
+    /// `cat`'s `{"sep": ..., "items": [...]}` form, compiled as
+    /// `[sep, item, ...]`. Internal: it has no entry in [`OPCODE_NAMES`],
+    /// so no rule can name it, and it displays as `cat`.
+    ConcatSep = 157,
 
     // === Core: Array Operations ===
     Merge = 26,
@@ -114,6 +137,34 @@ pub(crate) enum OpCode {
     DateDiff = 48,
     #[cfg(feature = "datetime")]
     Now = 58,
+    #[cfg(feature = "datetime")]
+    DateFormat = 118,
+    #[cfg(feature = "datetime")]
+    DateParse = 119,
+    #[cfg(feature = "datetime")]
+    Year = 120,
+    #[cfg(feature = "datetime")]
+    Month = 121,
+    #[cfg(feature = "datetime")]
+    Day = 122,
+    #[cfg(feature = "datetime")]
+    Hour = 123,
+    #[cfg(feature = "datetime")]
+    Minute = 124,
+    #[cfg(feature = "datetime")]
+    Weekday = 125,
+    #[cfg(feature = "datetime")]
+    DateAdd = 126,
+    #[cfg(feature = "datetime")]
+    DateSub = 127,
+    #[cfg(feature = "datetime")]
+    ToTimezone = 128,
+    #[cfg(feature = "datetime")]
+    StartOf = 129,
+    #[cfg(feature = "datetime")]
+    EndOf = 130,
+    #[cfg(feature = "datetime")]
+    Duration = 131,
 
     // === ext-string ===
     #[cfg(feature = "ext-string")]
@@ -130,12 +181,102 @@ pub(crate) enum OpCode {
     Trim = 42,
     #[cfg(feature = "ext-string")]
     Split = 43,
+    #[cfg(feature = "ext-string")]
+    Join = 73,
+    #[cfg(feature = "ext-string")]
+    PadStart = 78,
+    #[cfg(feature = "ext-string")]
+    PadEnd = 79,
+    #[cfg(feature = "ext-string")]
+    Repeat = 80,
+    #[cfg(feature = "ext-string")]
+    Capitalize = 83,
+    #[cfg(feature = "ext-string")]
+    TitleCase = 84,
+    #[cfg(feature = "ext-string")]
+    CharAt = 85,
+    #[cfg(feature = "ext-string")]
+    CodePointAt = 86,
+    #[cfg(feature = "ext-string")]
+    Base64Encode = 87,
+    #[cfg(feature = "ext-string")]
+    Base64Decode = 88,
+    #[cfg(feature = "ext-string")]
+    JsonParse = 89,
+    #[cfg(feature = "ext-string")]
+    JsonStringify = 90,
+    #[cfg(feature = "ext-string")]
+    NumberFormat = 91,
+    #[cfg(feature = "ext-string")]
+    Slugify = 92,
+    #[cfg(feature = "ext-string")]
+    Format = 141,
+    #[cfg(feature = "ext-string")]
+    Template = 142,
+    #[cfg(feature = "ext-string")]
+    StartsWithI = 143,
+    #[cfg(feature = "ext-string")]
+    EndsWithI = 144,
+    #[cfg(feature = "ext-string")]
+    InI = 145,
+    #[cfg(feature = "ext-string")]
+    TrimStart = 146,
+    #[cfg(feature = "ext-string")]
+    TrimEnd = 147,
 
     // === ext-array ===
     #[cfg(feature = "ext-array")]
     Sort = 54,
     #[cfg(feature = "ext-array")]
     Slice = 55,
+    #[cfg(feature = "ext-array")]
+    Flatten = 62,
+    #[cfg(feature = "ext-array")]
+    Unique = 63,
+    #[cfg(feature = "ext-array")]
+    GroupBy = 64,
+    #[cfg(feature = "ext-array")]
+    Find = 65,
+    #[cfg(feature = "ext-array")]
+    IndexOf = 66,
+    #[cfg(feature = "ext-array")]
+    Zip = 67,
+    #[cfg(feature = "ext-array")]
+    Chunk = 68,
+    #[cfg(feature = "ext-array")]
+    Count = 71,
+    #[cfg(feature = "ext-array")]
+    FlatMap = 72,
+    #[cfg(feature = "ext-array")]
+    Reverse = 74,
+    #[cfg(feature = "ext-array")]
+    Take = 75,
+    #[cfg(feature = "ext-array")]
+    Drop = 76,
+    #[cfg(feature = "ext-array")]
+    Contains = 77,
+    #[cfg(feature = "ext-array")]
+    MergeObjects = 113,
+    #[cfg(feature = "ext-array")]
+    Pick = 114,
+    #[cfg(feature = "ext-array")]
+    Omit = 115,
+    #[cfg(feature = "ext-array")]
+    Set = 116,
+    #[cfg(feature = "ext-array")]
+    AllValues = 133,
+    #[cfg(feature = "ext-array")]
+    SomeValues = 134,
+    #[cfg(feature = "ext-array")]
+    NoneValues = 135,
+    #[cfg(feature = "ext-array")]
+    MinBy = 136,
+    #[cfg(feature = "ext-array")]
+    MaxBy = 137,
+    #[cfg(feature = "ext-array")]
+    Window = 138,
+    #[cfg(feature = "ext-array")]
+    ReduceWhile = 139,
 
     // === ext-control ===
     #[cfg(feature = "ext-control")]
@@ -146,6 +287,30 @@ pub(crate) enum OpCode {
     Switch = 59,
     #[cfg(feature = "ext-control")]
     Type = 37,
+    #[cfg(feature = "ext-control")]
+    ToNumber = 105,
+    #[cfg(feature = "ext-control")]
+    ToInt = 106,
+    #[cfg(feature = "ext-control")]
+    ToString = 107,
+    #[cfg(feature = "ext-control")]
+    ToBool = 108,
+    #[cfg(feature = "ext-control")]
+    IsEmpty = 109,
+    #[cfg(feature = "ext-control")]
+    IsNull = 110,
+    #[cfg(feature = "ext-control")]
+    ParseInt = 154,
+    #[cfg(feature = "ext-control")]
+    ParseFloat = 155,
+    #[cfg(feature = "ext-control")]
+    Default = 111,
+    #[cfg(feature = "ext-control")]
+    Let = 112,
+    #[cfg(feature = "ext-control")]
+    Get = 117,
+    #[cfg(feature = "ext-control")]
+    Has = 140,
 
     // === error-handling ===
     #[cfg(feature = "error-handling")]
@@ -160,12 +325,60 @@ pub(crate) enum OpCode {
     Ceil = 50,
     #[cfg(feature = "ext-math")]
     Floor = 51,
+    #[cfg(feature = "ext-math")]
+    Sum = 69,
+    #[cfg(feature = "ext-math")]
+    Avg = 70,
+    #[cfg(feature = "ext-math")]
+    Pow = 93,
+    #[cfg(feature = "ext-math")]
+    Sqrt = 94,
+    #[cfg(feature = "ext-math")]
+    Sign = 95,
+    #[cfg(feature = "ext-math")]
+    Clamp = 96,
+    #[cfg(feature = "ext-math")]
+    Log = 97,
+    #[cfg(feature = "ext-math")]
+    Exp = 98,
+    #[cfg(feature = "ext-math")]
+    Sin = 99,
+    #[cfg(feature = "ext-math")]
+    Cos = 100,
+    #[cfg(feature = "ext-math")]
+    Tan = 101,
+    #[cfg(feature = "ext-math")]
+    Gcd = 102,
+    #[cfg(feature = "ext-math")]
+    Lcm = 103,
+    #[cfg(feature = "ext-math")]
+    Random = 104,
+    #[cfg(feature = "ext-math")]
+    BitAnd = 148,
+    #[cfg(feature = "ext-math")]
+    BitOr = 149,
+    #[cfg(feature = "ext-math")]
+    BitXor = 150,
+    #[cfg(feature = "ext-math")]
+    BitNot = 151,
+    #[cfg(feature = "ext-math")]
+    LShift = 152,
+    #[cfg(feature = "ext-math")]
+    RShift = 153,
+    #[cfg(feature = "ext-math")]
+    ToFixed = 156,
 
     // === flagd ===
     #[cfg(feature = "flagd")]
     Fractional = 60,
     #[cfg(feature = "flagd")]
     SemVer = 61,
+
+    // === regex ===
+    #[cfg(feature = "regex")]
+    RegexMatch = 81,
+    #[cfg(feature = "regex")]
+    RegexReplace = 82,
 }
 
 /// Single source of truth for `(operator string, OpCode)` mappings.
@@ -190,6 +403,7 @@ const OPCODE_NAMES: &[(&str, OpCode)] = &[
     (">=", OpCode::GreaterThanEqual),
     ("<", OpCode::LessThan),
     ("<=", OpCode::LessThanEqual),
+    ("between", OpCode::Between),
     // Core: logical
     ("!", OpCode::Not),
     ("!!", OpCode::BoolCast),
@@ -236,6 +450,34 @@ const OPCODE_NAMES: &[(&str, OpCode)] = &[
     ("date_diff", OpCode::DateDiff),
     #[cfg(feature = "datetime")]
     ("now", OpCode::Now),
+    #[cfg(feature = "datetime")]
+    ("date_format", OpCode::DateFormat),
+    #[cfg(feature = "datetime")]
+    ("date_parse", OpCode::DateParse),
+    #[cfg(feature = "datetime")]
+    ("year", OpCode::Year),
+    #[cfg(feature = "datetime")]
+    ("month", OpCode::Month),
+    #[cfg(feature = "datetime")]
+    ("day", OpCode::Day),
+    #[cfg(feature = "datetime")]
+    ("hour", OpCode::Hour),
+    #[cfg(feature = "datetime")]
+    ("minute", OpCode::Minute),
+    #[cfg(feature = "datetime")]
+    ("weekday", OpCode::Weekday),
+    #[cfg(feature = "datetime")]
+    ("date_add", OpCode::DateAdd),
+    #[cfg(feature = "datetime")]
+    ("date_sub", OpCode::DateSub),
+    #[cfg(feature = "datetime")]
+    ("to_timezone", OpCode::ToTimezone),
+    #[cfg(feature = "datetime")]
+    ("start_of", OpCode::StartOf),
+    #[cfg(feature = "datetime")]
+    ("end_of", OpCode::EndOf),
+    #[cfg(feature = "datetime")]
+    ("duration", OpCode::Duration),
     // ext-string
     #[cfg(feature = "ext-string")]
     ("length", OpCode::Length),
@@ -251,22 +493,140 @@ const OPCODE_NAMES: &[(&str, OpCode)] = &[
     ("trim", OpCode::Trim),
     #[cfg(feature = "ext-string")]
     ("split", OpCode::Split),
+    #[cfg(feature = "ext-string")]
+    ("join", OpCode::Join),
+    #[cfg(feature = "ext-string")]
+    ("pad_start", OpCode::PadStart),
+    #[cfg(feature = "ext-string")]
+    ("pad_end", OpCode::PadEnd),
+    #[cfg(feature = "ext-string")]
+    ("repeat", OpCode::Repeat),
+    #[cfg(feature = "ext-string")]
+    ("capitalize", OpCode::Capitalize),
+    #[cfg(feature = "ext-string")]
+    ("title_case", OpCode::TitleCase),
+    #[cfg(feature = "ext-string")]
+    ("char_at", OpCode::CharAt),
+    #[cfg(feature = "ext-string")]
+    ("code_point_at", OpCode::CodePointAt),
+    #[cfg(feature = "ext-string")]
+    ("base64_encode", OpCode::Base64Encode),
+    #[cfg(feature = "ext-string")]
+    ("base64_decode", OpCode::Base64Decode),
+    #[cfg(feature = "ext-string")]
+    ("json_parse", OpCode::JsonParse),
+    #[cfg(feature = "ext-string")]
+    ("json_stringify", OpCode::JsonStringify),
+    #[cfg(feature = "ext-string")]
+    ("number_format", OpCode::NumberFormat),
+    #[cfg(feature = "ext-string")]
+    ("slugify", OpCode::Slugify),
+    #[cfg(feature = "ext-string")]
+    ("format", OpCode::Format),
+    #[cfg(feature = "ext-string")]
+    ("template", OpCode::Template),
+    #[cfg(feature = "ext-string")]
+    ("starts_with_i", OpCode::StartsWithI),
+    #[cfg(feature = "ext-string")]
+    ("ends_with_i", OpCode::EndsWithI),
+    #[cfg(feature = "ext-string")]
+    ("in_i", OpCode::InI),
+    #[cfg(feature = "ext-string")]
+    ("trim_start", OpCode::TrimStart),
+    #[cfg(feature = "ext-string")]
+    ("trim_end", OpCode::TrimEnd),
     // ext-array
     #[cfg(feature = "ext-array")]
     ("sort", OpCode::Sort),
     #[cfg(feature = "ext-array")]
     ("slice", OpCode::Slice),
+    #[cfg(feature = "ext-array")]
+    ("flatten", OpCode::Flatten),
+    #[cfg(feature = "ext-array")]
+    ("unique", OpCode::Unique),
+    #[cfg(feature = "ext-array")]
+    ("distinct", OpCode::Unique),
+    #[cfg(feature = "ext-array")]
+    ("group_by", OpCode::GroupBy),
+    #[cfg(feature = "ext-array")]
+    ("find", OpCode::Find),
+    #[cfg(feature = "ext-array")]
+    ("index_of", OpCode::IndexOf),
+    #[cfg(feature = "ext-array")]
+    ("zip", OpCode::Zip),
+    #[cfg(feature = "ext-array")]
+    ("chunk", OpCode::Chunk),
+    #[cfg(feature = "ext-array")]
+    ("count", OpCode::Count),
+    #[cfg(feature = "ext-array")]
+    ("flat_map", OpCode::FlatMap),
+    #[cfg(feature = "ext-array")]
+    ("reverse", OpCode::Reverse),
+    #[cfg(feature = "ext-array")]
+    ("take", OpCode::Take),
+    #[cfg(feature = "ext-array")]
+    ("drop", OpCode::Drop),
+    #[cfg(feature = "ext-array")]
+    ("contains", OpCode::Contains),
+    #[cfg(feature = "ext-array")]
+    ("includes", OpCode::Contains),
+    #[cfg(feature = "ext-array")]
+    ("merge_objects", OpCode::MergeObjects),
+    #[cfg(feature = "ext-array")]
+    ("pick", OpCode::Pick),
+    #[cfg(feature = "ext-array")]
+    ("omit", OpCode::Omit),
+    #[cfg(feature = "ext-array")]
+    ("set", OpCode::Set),
+    #[cfg(feature = "ext-array")]
+    ("all_values", OpCode::AllValues),
+    #[cfg(feature = "ext-array")]
+    ("some_values", OpCode::SomeValues),
+    #[cfg(feature = "ext-array")]
+    ("none_values", OpCode::NoneValues),
+    #[cfg(feature = "ext-array")]
+    ("min_by", OpCode::MinBy),
+    #[cfg(feature = "ext-array")]
+    ("max_by", OpCode::MaxBy),
+    #[cfg(feature = "ext-array")]
+    ("window", OpCode::Window),
+    #[cfg(feature = "ext-array")]
+    ("reduce_while", OpCode::ReduceWhile),
     // ext-control
     #[cfg(feature = "ext-control")]
     ("exists", OpCode::Exists),
     #[cfg(feature = "ext-control")]
     ("??", OpCode::Coalesce),
     #[cfg(feature = "ext-control")]
+    ("default", OpCode::Default),
+    #[cfg(feature = "ext-control")]
+    ("let", OpCode::Let),
+    #[cfg(feature = "ext-control")]
+    ("get", OpCode::Get),
+    #[cfg(feature = "ext-control")]
     ("switch", OpCode::Switch),
     #[cfg(feature = "ext-control")]
     ("match", OpCode::Switch),
     #[cfg(feature = "ext-control")]
     ("type", OpCode::Type),
+    #[cfg(feature = "ext-control")]
+    ("to_number", OpCode::ToNumber),
+    #[cfg(feature = "ext-control")]
+    ("to_int", OpCode::ToInt),
+    #[cfg(feature = "ext-control")]
+    ("to_string", OpCode::ToString),
+    #[cfg(feature = "ext-control")]
+    ("to_bool", OpCode::ToBool),
+    #[cfg(feature = "ext-control")]
+    ("is_empty", OpCode::IsEmpty),
+    #[cfg(feature = "ext-control")]
+    ("is_null", OpCode::IsNull),
+    #[cfg(feature = "ext-control")]
+    ("parse_int", OpCode::ParseInt),
+    #[cfg(feature = "ext-control")]
+    ("parse_float", OpCode::ParseFloat),
+    #[cfg(feature = "ext-control")]
+    ("has", OpCode::Has),
     // error-handling
     #[cfg(feature = "error-handling")]
     ("try", OpCode::Try),
@@ -279,11 +639,58 @@ const OPCODE_NAMES: &[(&str, OpCode)] = &[
     ("ceil", OpCode::Ceil),
     #[cfg(feature = "ext-math")]
     ("floor", OpCode::Floor),
+    #[cfg(feature = "ext-math")]
+    ("sum", OpCode::Sum),
+    #[cfg(feature = "ext-math")]
+    ("avg", OpCode::Avg),
+    #[cfg(feature = "ext-math")]
+    ("pow", OpCode::Pow),
+    #[cfg(feature = "ext-math")]
+    ("sqrt", OpCode::Sqrt),
+    #[cfg(feature = "ext-math")]
+    ("sign", OpCode::Sign),
+    #[cfg(feature = "ext-math")]
+    ("clamp", OpCode::Clamp),
+    #[cfg(feature = "ext-math")]
+    ("log", OpCode::Log),
+    #[cfg(feature = "ext-math")]
+    ("exp", OpCode::Exp),
+    #[cfg(feature = "ext-math")]
+    ("sin", OpCode::Sin),
+    #[cfg(feature = "ext-math")]
+    ("cos", OpCode::Cos),
+    #[cfg(feature = "ext-math")]
+    ("tan", OpCode::Tan),
+    #[cfg(feature = "ext-math")]
+    ("gcd", OpCode::Gcd),
+    #[cfg(feature = "ext-math")]
+    ("lcm", OpCode::Lcm),
+    #[cfg(feature = "ext-math")]
+    ("random", OpCode::Random),
+    #[cfg(feature = "ext-math")]
+    ("bitand", OpCode::BitAnd),
+    #[cfg(feature = "ext-math")]
+    ("bitor", OpCode::BitOr),
+    #[cfg(feature = "ext-math")]
+    ("bitxor", OpCode::BitXor),
+    #[cfg(feature = "ext-math")]
+    ("bitnot", OpCode::BitNot),
+    #[cfg(feature = "ext-math")]
+    ("lshift", OpCode::LShift),
+    #[cfg(feature = "ext-math")]
+    ("rshift", OpCode::RShift),
+    #[cfg(feature = "ext-math")]
+    ("to_fixed", OpCode::ToFixed),
     // flagd
     #[cfg(feature = "flagd")]
     ("fractional", OpCode::Fractional),
     #[cfg(feature = "flagd")]
     ("sem_ver", OpCode::SemVer),
+    // regex
+    #[cfg(feature = "regex")]
+    ("regex_match", OpCode::RegexMatch),
+    #[cfg(feature = "regex")]
+    ("regex_replace", OpCode::RegexReplace),
 ];
 
 impl FromStr for OpCode {
@@ -302,6 +709,13 @@ impl FromStr for OpCode {
 }
 
 impl OpCode {
+    /// Every operator name [`OpCode::from_str`] accepts, canonical names
+    /// and aliases alike, in [`OPCODE_NAMES`] order. Only the operators of
+    /// the enabled features are included.
+    pub(crate) fn names<'s>() -> impl Iterator<Item = &'s str> {
+        OPCODE_NAMES.iter().map(|(name, _)| *name)
+    }
+
     /// Convert OpCode back to its canonical string form (for debugging /
     /// display / serialization).
     ///
@@ -325,6 +739,7 @@ impl OpCode {
             OpCode::GreaterThanEqual => ">=",
             OpCode::LessThan => "<",
             OpCode::LessThanEqual => "<=",
+            OpCode::Between => "between",
             // Core: logical
             OpCode::Not => "!",
             OpCode::BoolCast => "!!",
@@ -341,7 +756,7 @@ impl OpCode {
             OpCode::Max => "max",
             OpCode::Min => "min",
             // Core: string
-            OpCode::Concat => "cat",
+            OpCode::Concat | OpCode::ConcatSep => "cat",
             OpCode::Substr => "substr",
             OpCode::In => "in",
             // Core: array
@@ -368,6 +783,34 @@ impl OpCode {
             OpCode::DateDiff => "date_diff",
             #[cfg(feature = "datetime")]
             OpCode::Now => "now",
+            #[cfg(feature = "datetime")]
+            OpCode::DateFormat => "date_format",
+            #[cfg(feature = "datetime")]
+            OpCode::DateParse => "date_parse",
+            #[cfg(feature = "datetime")]
+            OpCode::Year => "year",
+            #[cfg(feature = "datetime")]
+            OpCode::Month => "month",
+            #[cfg(feature = "datetime")]
+            OpCode::Day => "day",
+            #[cfg(feature = "datetime")]
+            OpCode::Hour => "hour",
+            #[cfg(feature = "datetime")]
+            OpCode::Minute => "minute",
+            #[cfg(feature = "datetime")]
+            OpCode::Weekday => "weekday",
+            #[cfg(feature = "datetime")]
+            OpCode::DateAdd => "date_add",
+            #[cfg(feature = "datetime")]
+            OpCode::DateSub => "date_sub",
+            #[cfg(feature = "datetime")]
+            OpCode::ToTimezone => "to_timezone",
+            #[cfg(feature = "datetime")]
+            OpCode::StartOf => "start_of",
+            #[cfg(feature = "datetime")]
+            OpCode::EndOf => "end_of",
+            #[cfg(feature = "datetime")]
+            OpCode::Duration => "duration",
             // ext-string
             #[cfg(feature = "ext-string")]
             OpCode::Length => "length",
@@ -383,20 +826,135 @@ impl OpCode {
             OpCode::Trim => "trim",
             #[cfg(feature = "ext-string")]
             OpCode::Split => "split",
-            // ext-array
+            #[cfg(feature = "ext-string")]
+            OpCode::Join => "join",
+            #[cfg(feature = "ext-string")]
+            OpCode::PadStart => "pad_start",
+            #[cfg(feature = "ext-string")]
+            OpCode::PadEnd => "pad_end",
+            #[cfg(feature = "ext-string")]
+            OpCode::Repeat => "repeat",
+            #[cfg(feature = "ext-string")]
+            OpCode::Capitalize => "capitalize",
+            #[cfg(feature = "ext-string")]
+            OpCode::TitleCase => "title_case",
+            #[cfg(feature = "ext-string")]
+            OpCode::CharAt => "char_at",
+            #[cfg(feature = "ext-string")]
+            OpCode::CodePointAt => "code_point_at",
+            #[cfg(feature = "ext-string")]
+            OpCode::Base64Encode => "base64_encode",
+            #[cfg(feature = "ext-string")]
+            OpCode::Base64Decode => "base64_decode",
+            #[cfg(feature = "ext-string")]
+            OpCode::JsonParse => "json_parse",
+            #[cfg(feature = "ext-string")]
+            OpCode::JsonStringify => "json_stringify",
+            #[cfg(feature = "ext-string")]
+            OpCode::NumberFormat => "number_format",
+            #[cfg(feature = "ext-string")]
+            OpCode::Slugify => "slugify",
+            #[cfg(feature = "ext-string")]
+            OpCode::Format => "format",
+            #[cfg(feature = "ext-string")]
+            OpCode::Template => "template",
+            #[cfg(feature = "ext-string")]
+            OpCode::StartsWithI => "starts_with_i",
+            #[cfg(feature = "ext-string")]
+            OpCode::EndsWithI => "ends_with_i",
+            #[cfg(feature = "ext-string")]
+            OpCode::InI => "in_i",
+            #[cfg(feature = "ext-string")]
+            OpCode::TrimStart => "trim_start",
+            #[cfg(feature = "ext-string")]
+            OpCode::TrimEnd => "trim_end",
+            // ext-array. `unique` and `contains` are canonical; `distinct` and
+            // `includes` are aliases.
             #[cfg(feature = "ext-array")]
             OpCode::Sort => "sort",
             #[cfg(feature = "ext-array")]
             OpCode::Slice => "slice",
+            #[cfg(feature = "ext-array")]
+            OpCode::Flatten => "flatten",
+            #[cfg(feature = "ext-array")]
+            OpCode::Unique => "unique",
+            #[cfg(feature = "ext-array")]
+            OpCode::GroupBy => "group_by",
+            #[cfg(feature = "ext-array")]
+            OpCode::Find => "find",
+            #[cfg(feature = "ext-array")]
+            OpCode::IndexOf => "index_of",
+            #[cfg(feature = "ext-array")]
+            OpCode::Zip => "zip",
+            #[cfg(feature = "ext-array")]
+            OpCode::Chunk => "chunk",
+            #[cfg(feature = "ext-array")]
+            OpCode::Count => "count",
+            #[cfg(feature = "ext-array")]
+            OpCode::FlatMap => "flat_map",
+            #[cfg(feature = "ext-array")]
+            OpCode::Reverse => "reverse",
+            #[cfg(feature = "ext-array")]
+            OpCode::Take => "take",
+            #[cfg(feature = "ext-array")]
+            OpCode::Drop => "drop",
+            #[cfg(feature = "ext-array")]
+            OpCode::Contains => "contains",
+            #[cfg(feature = "ext-array")]
+            OpCode::MergeObjects => "merge_objects",
+            #[cfg(feature = "ext-array")]
+            OpCode::Pick => "pick",
+            #[cfg(feature = "ext-array")]
+            OpCode::Omit => "omit",
+            #[cfg(feature = "ext-array")]
+            OpCode::Set => "set",
+            #[cfg(feature = "ext-array")]
+            OpCode::AllValues => "all_values",
+            #[cfg(feature = "ext-array")]
+            OpCode::SomeValues => "some_values",
+            #[cfg(feature = "ext-array")]
+            OpCode::NoneValues => "none_values",
+            #[cfg(feature = "ext-array")]
+            OpCode::MinBy => "min_by",
+            #[cfg(feature = "ext-array")]
+            OpCode::MaxBy => "max_by",
+            #[cfg(feature = "ext-array")]
+            OpCode::Window => "window",
+            #[cfg(feature = "ext-array")]
+            OpCode::ReduceWhile => "reduce_while",
             // ext-control. `switch` is canonical; `match` is an alias.
             #[cfg(feature = "ext-control")]
             OpCode::Exists => "exists",
             #[cfg(feature = "ext-control")]
             OpCode::Coalesce => "??",
             #[cfg(feature = "ext-control")]
+            OpCode::Default => "default",
+            #[cfg(feature = "ext-control")]
+            OpCode::Let => "let",
+            #[cfg(feature = "ext-control")]
+            OpCode::Get => "get",
+            #[cfg(feature = "ext-control")]
             OpCode::Switch => "switch",
             #[cfg(feature = "ext-control")]
             OpCode::Type => "type",
+            #[cfg(feature = "ext-control")]
+            OpCode::ToNumber => "to_number",
+            #[cfg(feature = "ext-control")]
+            OpCode::ToInt => "to_int",
+            #[cfg(feature = "ext-control")]
+            OpCode::ToString => "to_string",
+            #[cfg(feature = "ext-control")]
+            OpCode::ToBool => "to_bool",
+            #[cfg(feature = "ext-control")]
+            OpCode::IsEmpty => "is_empty",
+            #[cfg(feature = "ext-control")]
+            OpCode::IsNull => "is_null",
+            #[cfg(feature = "ext-control")]
+            OpCode::ParseInt => "parse_int",
+            #[cfg(feature = "ext-control")]
+            OpCode::ParseFloat => "parse_float",
+            #[cfg(feature = "ext-control")]
+            OpCode::Has => "has",
             // error-handling
             #[cfg(feature = "error-handling")]
             OpCode::Try => "try",
@@ -409,11 +967,58 @@ impl OpCode {
             OpCode::Ceil => "ceil",
             #[cfg(feature = "ext-math")]
             OpCode::Floor => "floor",
+            #[cfg(feature = "ext-math")]
+            OpCode::Sum => "sum",
+            #[cfg(feature = "ext-math")]
+            OpCode::Avg => "avg",
+            #[cfg(feature = "ext-math")]
+            OpCode::Pow => "pow",
+            #[cfg(feature = "ext-math")]
+            OpCode::Sqrt => "sqrt",
+            #[cfg(feature = "ext-math")]
+            OpCode::Sign => "sign",
+            #[cfg(feature = "ext-math")]
+            OpCode::Clamp => "clamp",
+            #[cfg(feature = "ext-math")]
+            OpCode::Log => "log",
+            #[cfg(feature = "ext-math")]
+            OpCode::Exp => "exp",
+            #[cfg(feature = "ext-math")]
+            OpCode::Sin => "sin",
+            #[cfg(feature = "ext-math")]
+            OpCode::Cos => "cos",
+            #[cfg(feature = "ext-math")]
+            OpCode::Tan => "tan",
+            #[cfg(feature = "ext-math")]
+            OpCode::Gcd => "gcd",
+            #[cfg(feature = "ext-math")]
+            OpCode::Lcm => "lcm",
+            #[cfg(feature = "ext-math")]
+            OpCode::Random => "random",
+            #[cfg(feature = "ext-math")]
+            OpCode::BitAnd => "bitand",
+            #[cfg(feature = "ext-math")]
+            OpCode::BitOr => "bitor",
+            #[cfg(feature = "ext-math")]
+            OpCode::BitXor => "bitxor",
+            #[cfg(feature = "ext-math")]
+            OpCode::BitNot => "bitnot",
+            #[cfg(feature = "ext-math")]
+            OpCode::LShift => "lshift",
+            #[cfg(feature = "ext-math")]
+            OpCode::RShift => "rshift",
+            #[cfg(feature = "ext-math")]
+            OpCode::ToFixed => "to_fixed",
             // flagd
             #[cfg(feature = "flagd")]
             OpCode::Fractional => "fractional",
             #[cfg(feature = "flagd")]
             OpCode::SemVer => "sem_ver",
+            // regex
+            #[cfg(feature = "regex")]
+            OpCode::RegexMatch => "regex_match",
+            #[cfg(feature = "regex")]
+            OpCode::RegexReplace => "regex_replace",
         }
     }
 }
@@ -2,6 +2,8 @@
 //!
 //! Custom operators implement [`crate::CustomOperator`] and receive an
 //! [`EvalContext`] handle alongside the pre-evaluated arguments and arena.
+//! Lazy operators implement [`crate::LazyOperator`] and receive a
+//! [`LazyArgs`] handle instead, which evaluates arguments on demand.
 //! The handle is opaque: it exposes the read-only context observations a
 //! custom operator may legitimately need ([`EvalContext::root_input`],
 //! [`EvalContext::depth`]) and hides the internal evaluation stack so its
@@ -51,3 +53,66 @@ impl<'ctx, 'a> EvalContext<'ctx, 'a> {
         Self { inner }
     }
 }
+
+/// Unevaluated arguments of a [`crate::LazyOperator`], passed to
+/// [`crate::LazyOperator::evaluate`].
+///
+/// Like [`EvalContext`], the handle is opaque: the compiled argument
+/// expressions stay internal, and the operator can only ask how many there
+/// are and evaluate one. Each [`Self::evaluate`] call runs the argument's
+/// expression again — call it once per argument and keep the result if
+/// you need it twice.
+pub struct LazyArgs<'ctx, 'a> {
+    nodes: &'a [crate::CompiledNode],
+    ctx: &'ctx mut crate::arena::ContextStack<'a>,
+    engine: &'ctx crate::Engine,
+    arena: &'a bumpalo::Bump,
+}
+
+impl<'ctx, 'a> LazyArgs<'ctx, 'a> {
+    /// Number of arguments the operator was called with.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// True when the operator was called with no arguments.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Evaluate argument `index` in the operator's context. An index past
+    /// the last argument evaluates to `null`, the way built-ins treat a
+    /// missing argument. Errors from the argument propagate unchanged.
+    #[inline]
+    pub fn evaluate(&mut self, index: usize) -> crate::Result<&'a crate::DataValue<'a>> {
+        match self.nodes.get(index) {
+            Some(node) => self.engine.dispatch_node(node, self.ctx, self.arena),
+            None => Ok(crate::arena::singletons::singleton_null()),
+        }
+    }
+
+    /// The same context view a [`crate::CustomOperator`] receives.
+    #[inline]
+    pub fn context(&mut self) -> EvalContext<'_, 'a> {
+        EvalContext::new(self.ctx)
+    }
+
+    /// Engine-internal constructor. Used by the dispatcher when invoking a
+    /// lazy operator's `evaluate` method.
+    #[inline]
+    pub(crate) fn new(
+        nodes: &'a [crate::CompiledNode],
+        ctx: &'ctx mut crate::arena::ContextStack<'a>,
+        engine: &'ctx crate::Engine,
+        arena: &'a bumpalo::Bump,
+    ) -> Self {
+        Self {
+            nodes,
+            ctx,
+            engine,
+            arena,
+        }
+    }
+}
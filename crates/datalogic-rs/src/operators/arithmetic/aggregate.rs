@@ -0,0 +1,101 @@
+//! `sum`, `avg` — numeric aggregates over a single array.
+
+use crate::arena::{ContextStack, DataValue, coerce_to_number_cfg, try_coerce_to_integer_cfg};
+use crate::config::NanHandling;
+use crate::{CompiledNode, Engine, Result};
+use bumpalo::Bump;
+use datavalue::NumberValue;
+
+use super::helpers::{FoldState, FoldStepOutcome, alloc_number};
+
+/// `sum` / `avg` discriminant for the shared aggregate entry point.
+#[derive(Clone, Copy)]
+pub(crate) enum AggregateOp {
+    Sum,
+    Avg,
+}
+
+/// Arena-mode `sum` / `avg` over one array argument.
+///
+/// Elements are coerced with the same strategy as the 1-arg array form of
+/// `+` (integer track first, float fallback, overflow promotes to `f64`), and
+/// non-numeric elements go through the engine's `NanHandling`. `sum` stays an
+/// integer while every element is one. `avg` is an integer only when the
+/// integer total divides evenly; otherwise it is a float.
+///
+/// Null or empty input sums to `0` and averages to `null`. Elements skipped
+/// under `IgnoreValue` do not count towards the `avg` divisor; under
+/// `CoerceToZero` they count as `0`. Any non-array input is an error.
+#[inline]
+pub(crate) fn aggregate<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+    op: AggregateOp,
+) -> Result<&'a DataValue<'a>> {
+    if args.len() != 1 {
+        return Err(crate::Error::invalid_args());
+    }
+
+    let items = match engine.dispatch_node(&args[0], ctx, arena)? {
+        DataValue::Array(items) => *items,
+        DataValue::Null => &[],
+        _ => return Err(crate::Error::invalid_args()),
+    };
+
+    let mut state = FoldState::new(0, 0.0);
+    let mut count: usize = 0;
+    for item in items {
+        let int_opt = try_coerce_to_integer_cfg(item, engine);
+        let float_opt = if int_opt.is_some() {
+            None
+        } else {
+            coerce_to_number_cfg(item, engine)
+        };
+        let numeric = int_opt.is_some() || float_opt.is_some();
+        if let FoldStepOutcome::ReturnNull = state.step(
+            int_opt,
+            float_opt,
+            i64::checked_add,
+            |a, b| a + b,
+            ctx,
+            engine,
+        )? {
+            return Ok(crate::arena::singletons::singleton_null());
+        }
+        if numeric
+            || matches!(
+                engine.config().arithmetic_nan_handling,
+                NanHandling::CoerceToZero
+            )
+        {
+            count += 1;
+        }
+    }
+
+    match op {
+        AggregateOp::Sum => Ok(state.finalize(arena)),
+        AggregateOp::Avg => {
+            if count == 0 {
+                return Ok(crate::arena::singletons::singleton_null());
+            }
+            let n = count as i64;
+            if state.all_int && state.int_acc % n == 0 {
+                return Ok(alloc_number(
+                    arena,
+                    NumberValue::from_i64(state.int_acc / n),
+                ));
+            }
+            let total = if state.all_int {
+                state.int_acc as f64
+            } else {
+                state.float_acc
+            };
+            Ok(alloc_number(
+                arena,
+                NumberValue::from_f64(total / count as f64),
+            ))
+        }
+    }
+}
@@ -0,0 +1,112 @@
+//! `bitand` / `bitor` / `bitxor` / `bitnot` / `lshift` / `rshift` —
+//! two's-complement bit operations on `i64`.
+
+use crate::arena::{ContextStack, DataValue, try_coerce_to_integer_cfg};
+use crate::{CompiledNode, Engine, Result};
+use bumpalo::Bump;
+use datavalue::NumberValue;
+
+use super::helpers::alloc_number;
+
+/// `bitand` vs `bitor` vs `bitxor` discriminant for the shared variadic
+/// entry point.
+#[derive(Clone, Copy)]
+pub(crate) enum BitwiseOp {
+    And,
+    Or,
+    Xor,
+}
+
+impl BitwiseOp {
+    #[inline]
+    fn combine(self, acc: i64, n: i64) -> i64 {
+        match self {
+            BitwiseOp::And => acc & n,
+            BitwiseOp::Or => acc | n,
+            BitwiseOp::Xor => acc ^ n,
+        }
+    }
+}
+
+/// `lshift` vs `rshift` discriminant.
+#[derive(Clone, Copy)]
+pub(crate) enum ShiftOp {
+    Left,
+    Right,
+}
+
+/// Native arena-mode `bitand` / `bitor` / `bitxor`. Variadic over integer
+/// operands, which go through the engine's integer coercion; a fractional
+/// or non-numeric operand is `Invalid Arguments`. A single operand is
+/// returned unchanged.
+#[inline]
+pub(crate) fn bitwise_fold<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+    op: BitwiseOp,
+) -> Result<&'a DataValue<'a>> {
+    let Some((first, rest)) = args.split_first() else {
+        return Err(crate::Error::invalid_args());
+    };
+    let mut acc = integer_arg(first, ctx, engine, arena)?;
+    for arg in rest {
+        acc = op.combine(acc, integer_arg(arg, ctx, engine, arena)?);
+    }
+    Ok(alloc_number(arena, NumberValue::from_i64(acc)))
+}
+
+/// Native arena-mode `bitnot`: the bitwise complement of one integer, so
+/// `{"bitnot": 0}` is `-1`.
+#[inline]
+pub(crate) fn evaluate_bitnot<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    let [arg] = args else {
+        return Err(crate::Error::invalid_args());
+    };
+    let n = integer_arg(arg, ctx, engine, arena)?;
+    Ok(alloc_number(arena, NumberValue::from_i64(!n)))
+}
+
+/// Native arena-mode `lshift` / `rshift`: `[value, bits]` with `bits` in
+/// `0..=63`; any other count is `Invalid Arguments`. `lshift` drops the
+/// bits shifted past the top (so it can change the sign), and `rshift` is
+/// arithmetic, copying the sign bit in from the left.
+#[inline]
+pub(crate) fn bit_shift<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+    op: ShiftOp,
+) -> Result<&'a DataValue<'a>> {
+    let [value, bits] = args else {
+        return Err(crate::Error::invalid_args());
+    };
+    let n = integer_arg(value, ctx, engine, arena)?;
+    let bits = u32::try_from(integer_arg(bits, ctx, engine, arena)?)
+        .ok()
+        .filter(|b| *b < i64::BITS)
+        .ok_or_else(crate::Error::invalid_args)?;
+    let r = match op {
+        ShiftOp::Left => n << bits,
+        ShiftOp::Right => n >> bits,
+    };
+    Ok(alloc_number(arena, NumberValue::from_i64(r)))
+}
+
+#[inline]
+fn integer_arg<'a>(
+    arg: &'a CompiledNode,
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<i64> {
+    let av = engine.dispatch_node(arg, ctx, arena)?;
+    try_coerce_to_integer_cfg(av, engine).ok_or_else(crate::Error::invalid_args)
+}
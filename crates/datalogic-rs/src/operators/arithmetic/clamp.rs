@@ -0,0 +1,52 @@
+//! `clamp` — bound a number to an inclusive range.
+
+use crate::arena::{ContextStack, DataValue, coerce_to_number_cfg};
+use crate::{CompiledNode, Engine, Result};
+use bumpalo::Bump;
+use datavalue::NumberValue;
+
+use super::helpers::alloc_number;
+
+/// Native arena-mode `clamp`. `{"clamp": [value, min, max]}` returns `min`
+/// when `value < min`, `max` when `value > max`, and `value` otherwise.
+///
+/// All three operands are coerced like arithmetic operands. The selected
+/// operand is returned as-is when it is already a number, so an integer
+/// stays an integer and a float stays a float; a coerced string comes back
+/// as the number it parsed to. A non-numeric operand is a `NaN` error and
+/// `min > max` is `Invalid Arguments`.
+#[inline]
+pub(crate) fn evaluate_clamp<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    if args.len() != 3 {
+        return Err(crate::Error::invalid_args());
+    }
+    let mut operands = [(&DataValue::Null, 0.0); 3];
+    for (slot, arg) in operands.iter_mut().zip(args) {
+        let av = engine.dispatch_node(arg, ctx, arena)?;
+        let f = coerce_to_number_cfg(av, engine)
+            .filter(|f| !f.is_nan())
+            .ok_or_else(|| crate::Error::nan_at(ctx))?;
+        *slot = (av, f);
+    }
+    let [value, min, max] = operands;
+    if min.1 > max.1 {
+        return Err(crate::Error::invalid_args());
+    }
+
+    let (av, f) = if value.1 < min.1 {
+        min
+    } else if value.1 > max.1 {
+        max
+    } else {
+        value
+    };
+    Ok(match av {
+        DataValue::Number(_) => av,
+        _ => alloc_number(arena, NumberValue::from_f64(f)),
+    })
+}
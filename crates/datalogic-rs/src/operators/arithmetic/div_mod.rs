@@ -78,7 +78,11 @@ fn div_mod_two_arg<'a>(
     // is not defined).
     #[cfg(feature = "datetime")]
     if !op.is_modulo() {
-        if let Some(r) = crate::operators::datetime::arith::datetime_divide(a_av, b_av, arena) {
+        if let Some(r) =
+            crate::operators::datetime::arith::datetime_divide(a_av, b_av, arena, || {
+                zero_divisor_error(ctx, engine)
+            })
+        {
             return r;
         }
     }
@@ -91,7 +95,7 @@ fn div_mod_two_arg<'a>(
         // Integer/integer with divisor=0 errors regardless of the
         // `division_by_zero` config (config only governs the float path).
         if a_av.as_i64().is_some() && b_av.as_i64().is_some() {
-            return Err(crate::Error::nan_at(ctx));
+            return Err(zero_divisor_error(ctx, engine));
         }
         return divbyzero(ctx, arena, na.as_f64(), engine);
     }
@@ -109,7 +113,7 @@ fn divbyzero<'a>(
     engine: &Engine,
 ) -> Result<&'a DataValue<'a>> {
     match engine.config().division_by_zero {
-        DivisionByZeroHandling::ThrowError => Err(crate::Error::nan_at(ctx)),
+        DivisionByZeroHandling::ThrowError => Err(zero_divisor_error(ctx, engine)),
         DivisionByZeroHandling::ReturnNull => Ok(crate::arena::singletons::singleton_null()),
         DivisionByZeroHandling::ReturnInfinity => {
             let v = if dividend >= 0.0 {
@@ -171,7 +175,7 @@ fn one_arg_div_mod<'a>(
     // 1/x with integer-preserving fast path.
     if let Some(i) = av.as_i64() {
         if i == 0 {
-            return Err(crate::Error::nan_at(ctx));
+            return Err(zero_divisor_error(ctx, engine));
         }
         if i == -1 {
             return Ok(alloc_number(arena, NumberValue::from_i64(-1)));
@@ -183,7 +187,7 @@ fn one_arg_div_mod<'a>(
     }
     let f = coerce_to_number_cfg(av, engine).ok_or_else(|| crate::Error::nan_at(ctx))?;
     if f == 0.0 {
-        return Err(crate::Error::nan_at(ctx));
+        return Err(zero_divisor_error(ctx, engine));
     }
     Ok(alloc_number(arena, NumberValue::from_f64(1.0 / f)))
 }
@@ -242,7 +246,20 @@ fn fold_divbyzero<'a>(
         }
     };
     if dividend_is_int && divisor.as_i64().is_some() {
-        return Err(crate::Error::nan_at(ctx));
+        return Err(zero_divisor_error(ctx, engine));
     }
     divbyzero(ctx, arena, dividend, engine)
 }
+
+/// The error for a zero divisor that isn't mapped to a sentinel value:
+/// the JSONLogic `{"type": "NaN"}` unless the engine opted into
+/// [`crate::ErrorKind::DivisionByZero`] via
+/// [`crate::EvaluationConfig::distinct_division_by_zero`].
+#[inline]
+fn zero_divisor_error(ctx: &mut ContextStack<'_>, engine: &Engine) -> crate::Error {
+    if engine.config().distinct_division_by_zero {
+        crate::Error::division_by_zero()
+    } else {
+        crate::Error::nan_at(ctx)
+    }
+}
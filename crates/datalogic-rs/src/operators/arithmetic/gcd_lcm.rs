@@ -0,0 +1,76 @@
+//! `gcd` / `lcm` — greatest common divisor and least common multiple.
+
+use crate::arena::{ContextStack, DataValue, try_coerce_to_integer_cfg};
+use crate::{CompiledNode, Engine, Result};
+use bumpalo::Bump;
+use datavalue::NumberValue;
+
+use super::helpers::alloc_number;
+
+/// `gcd` vs `lcm` discriminant for the shared variadic entry point.
+#[derive(Clone, Copy)]
+pub(crate) enum DivisorOp {
+    Gcd,
+    Lcm,
+}
+
+impl DivisorOp {
+    /// Fold one more magnitude into the accumulator. `None` on overflow.
+    #[inline]
+    fn combine(self, acc: u64, n: u64) -> Option<u64> {
+        match self {
+            DivisorOp::Gcd => Some(gcd(acc, n)),
+            DivisorOp::Lcm if acc == 0 || n == 0 => Some(0),
+            DivisorOp::Lcm => (acc / gcd(acc, n)).checked_mul(n),
+        }
+    }
+}
+
+#[inline]
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Native arena-mode `gcd` / `lcm`. Variadic over integer operands, which
+/// go through the engine's integer coercion; a fractional or non-numeric
+/// operand is `Invalid Arguments`. Signs are ignored, so the result is
+/// never negative, and `lcm` with any zero operand is `0`.
+///
+/// The fold runs on `u64` magnitudes so `i64::MIN` is representable along
+/// the way; a result that does not fit back into `i64` is an
+/// `Integer overflow` error.
+#[inline]
+pub(crate) fn gcd_lcm<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+    op: DivisorOp,
+) -> Result<&'a DataValue<'a>> {
+    if args.is_empty() {
+        return Err(crate::Error::invalid_args());
+    }
+    let mut acc: Option<u64> = None;
+    for arg in args {
+        let av = engine.dispatch_node(arg, ctx, arena)?;
+        let n = try_coerce_to_integer_cfg(av, engine)
+            .ok_or_else(crate::Error::invalid_args)?
+            .unsigned_abs();
+        acc = Some(match acc {
+            None => n,
+            Some(acc) => op.combine(acc, n).ok_or_else(overflow)?,
+        });
+    }
+    let r = acc
+        .and_then(|r| i64::try_from(r).ok())
+        .ok_or_else(overflow)?;
+    Ok(alloc_number(arena, NumberValue::from_i64(r)))
+}
+
+#[inline]
+fn overflow() -> crate::Error {
+    crate::Error::invalid_arguments("Integer overflow")
+}
@@ -50,6 +50,23 @@ pub(super) fn alloc_number<'a>(arena: &'a Bump, n: NumberValue) -> &'a DataValue
     arena.alloc(DataValue::Number(n))
 }
 
+/// Allocate the f64 result of a math function (`pow`, `sqrt`, `exp`,
+/// `log`, ...). Infinities (overflow) and NaN have no JSON representation
+/// and are `NaN` errors, so these operators never hand a non-finite value
+/// to the caller.
+#[cfg(feature = "ext-math")]
+#[inline]
+pub(super) fn alloc_finite<'a>(
+    ctx: &mut ContextStack<'a>,
+    arena: &'a Bump,
+    r: f64,
+) -> Result<&'a DataValue<'a>> {
+    if !r.is_finite() {
+        return Err(crate::Error::nan_at(ctx));
+    }
+    Ok(alloc_number(arena, NumberValue::from_f64(r)))
+}
+
 /// Try the checked-integer op; on overflow promote both operands to `f64` and
 /// apply the float fallback. Collapses the recurring
 /// `match a.checked_op(b) { Some(r) => from_i64(r), None => from_f64(...) }`
@@ -4,7 +4,17 @@
 //! - [`basic`] — `+`, `-`, `*` with overflow promotion to `f64`.
 //! - [`div_mod`] — `/` and `%` with config-aware divbyzero handling.
 //! - [`min_max`] — `min` and `max` (array reduction + variadic).
-//! - [`unary_math`] — `abs` / `ceil` / `floor` (gated on `ext-math`).
+//! - [`unary_math`] — `abs` / `ceil` / `floor` / `sign` (gated on `ext-math`).
+//! - [`aggregate`] — `sum` / `avg` over an array (gated on `ext-math`).
+//! - [`power`] — `pow` / `sqrt` (gated on `ext-math`).
+//! - [`clamp`] — `clamp` (gated on `ext-math`).
+//! - [`gcd_lcm`] — `gcd` / `lcm` over integers (gated on `ext-math`).
+//! - [`bitwise`] — `bitand` / `bitor` / `bitxor` / `bitnot` / `lshift` /
+//!   `rshift` on `i64` (gated on `ext-math`).
+//! - [`rounding`] — `to_fixed` (gated on `ext-math`) and the decimal
+//!   rounding it shares with `number_format`.
+//! - [`transcendental`] — `log` / `exp` / `sin` / `cos` / `tan` (gated on
+//!   `ext-math`).
 //! - [`helpers`] — shared NaN handling, coercion-pair, integer/float fold.
 //!
 //! Datetime/duration arithmetic moved to `crate::operators::datetime::arith`
@@ -36,6 +46,20 @@ mod div_mod;
 mod helpers;
 mod min_max;
 
+#[cfg(feature = "ext-math")]
+mod aggregate;
+#[cfg(feature = "ext-math")]
+mod bitwise;
+#[cfg(feature = "ext-math")]
+mod clamp;
+#[cfg(feature = "ext-math")]
+mod gcd_lcm;
+#[cfg(feature = "ext-math")]
+mod power;
+#[cfg(any(feature = "ext-math", feature = "ext-string"))]
+mod rounding;
+#[cfg(feature = "ext-math")]
+mod transcendental;
 #[cfg(feature = "ext-math")]
 mod unary_math;
 
@@ -43,5 +67,21 @@ pub(crate) use basic::{evaluate_add, evaluate_multiply, evaluate_subtract};
 pub(crate) use div_mod::{DivOp, div_or_mod};
 pub(crate) use min_max::{evaluate_max, evaluate_min};
 
+#[cfg(feature = "ext-math")]
+pub(crate) use aggregate::{AggregateOp, aggregate};
+#[cfg(feature = "ext-math")]
+pub(crate) use bitwise::{BitwiseOp, ShiftOp, bit_shift, bitwise_fold, evaluate_bitnot};
+#[cfg(feature = "ext-math")]
+pub(crate) use clamp::evaluate_clamp;
+#[cfg(feature = "ext-math")]
+pub(crate) use gcd_lcm::{DivisorOp, gcd_lcm};
+#[cfg(feature = "ext-math")]
+pub(crate) use power::{evaluate_pow, evaluate_sqrt};
+#[cfg(feature = "ext-math")]
+pub(crate) use rounding::evaluate_to_fixed;
+#[cfg(feature = "ext-string")]
+pub(crate) use rounding::{decimal_places, round_half_away};
+#[cfg(feature = "ext-math")]
+pub(crate) use transcendental::{MathFn, evaluate_log, math_fn};
 #[cfg(feature = "ext-math")]
 pub(crate) use unary_math::{UnaryMathOp, unary_math};
@@ -0,0 +1,61 @@
+//! `pow` / `sqrt` — exponentiation and square root.
+
+use crate::arena::{ContextStack, DataValue, coerce_to_number_cfg, try_coerce_to_integer_cfg};
+use crate::{CompiledNode, Engine, Result};
+use bumpalo::Bump;
+use datavalue::NumberValue;
+
+use super::helpers::{alloc_finite, alloc_number};
+
+/// Native arena-mode `pow`. `{"pow": [base, exp]}` raises `base` to `exp`.
+///
+/// An integer base with a non-negative integer exponent stays on the
+/// checked `i64` track, so results are exact up to `i64::MAX`; on overflow
+/// (or for any fractional / negative exponent) it falls back to `powf`.
+/// Non-numeric operands, a NaN result (a negative base with a fractional
+/// exponent) and a result past f64's range are `NaN` errors.
+#[inline]
+pub(crate) fn evaluate_pow<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    if args.len() != 2 {
+        return Err(crate::Error::invalid_args());
+    }
+    let base_av = engine.dispatch_node(&args[0], ctx, arena)?;
+    let exp_av = engine.dispatch_node(&args[1], ctx, arena)?;
+
+    let int_pow = try_coerce_to_integer_cfg(base_av, engine).and_then(|base| {
+        let exp = u32::try_from(try_coerce_to_integer_cfg(exp_av, engine)?).ok()?;
+        base.checked_pow(exp)
+    });
+    if let Some(r) = int_pow {
+        return Ok(alloc_number(arena, NumberValue::from_i64(r)));
+    }
+
+    let base = coerce_to_number_cfg(base_av, engine).ok_or_else(|| crate::Error::nan_at(ctx))?;
+    let exp = coerce_to_number_cfg(exp_av, engine).ok_or_else(|| crate::Error::nan_at(ctx))?;
+    alloc_finite(ctx, arena, base.powf(exp))
+}
+
+/// Native arena-mode `sqrt`. `{"sqrt": value}` returns the square root;
+/// perfect squares come back as integers. A negative, non-numeric or
+/// infinite value is a `NaN` error.
+#[inline]
+pub(crate) fn evaluate_sqrt<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    if args.len() != 1 {
+        return Err(crate::Error::invalid_args());
+    }
+    let av = engine.dispatch_node(&args[0], ctx, arena)?;
+    let n = coerce_to_number_cfg(av, engine)
+        .filter(|n| *n >= 0.0)
+        .ok_or_else(|| crate::Error::nan_at(ctx))?;
+    alloc_finite(ctx, arena, n.sqrt())
+}
@@ -0,0 +1,141 @@
+//! Decimal rounding shared by `to_fixed` (a number) and `number_format`
+//! (a string), so both round a value to the same digits.
+
+#[cfg(feature = "ext-math")]
+use crate::CompiledNode;
+use crate::arena::{ContextStack, DataValue};
+use crate::{Engine, Result};
+use bumpalo::Bump;
+use bumpalo::collections::Vec;
+use datavalue::NumberValue;
+
+/// Upper bound on the decimal places `to_fixed` and `number_format`
+/// accept. Twenty places already exceed the ~17 significant digits an f64
+/// can carry.
+const MAX_DECIMALS: i64 = 20;
+
+/// A value rounded to a fixed number of decimal places: `digits` holds the
+/// integer digits followed by exactly `decimals` fraction digits, as ASCII.
+pub(crate) struct RoundedDecimal<'a> {
+    pub(crate) negative: bool,
+    pub(crate) digits: Vec<'a, u8>,
+    pub(crate) decimals: usize,
+}
+
+impl RoundedDecimal<'_> {
+    /// Split point between the integer and fraction digits.
+    #[inline]
+    pub(crate) fn int_len(&self) -> usize {
+        self.digits.len() - self.decimals
+    }
+}
+
+/// The `decimals` operand of `to_fixed` / `number_format`: an integer in
+/// `0..=20`, anything else is `Invalid Arguments`.
+#[inline]
+pub(crate) fn decimal_places(av: &DataValue<'_>) -> Result<usize> {
+    match av.as_i64() {
+        Some(d) if (0..=MAX_DECIMALS).contains(&d) => Ok(d as usize),
+        _ => Err(crate::Error::invalid_args()),
+    }
+}
+
+/// Round `value` to `decimals` places, half away from zero. Coerces like
+/// arithmetic does; a value that isn't a finite number is a `NaN` error.
+///
+/// Rounding works on the value's shortest decimal form (the digits `cat`
+/// would print), so `1.005` rounds to `1.01` even though the nearest f64
+/// sits just below it. Integers are rounded from their exact digits. A
+/// result that rounds to zero is not negative.
+pub(crate) fn round_half_away<'a>(
+    value: &DataValue<'_>,
+    decimals: usize,
+    ctx: &mut ContextStack<'_>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<RoundedDecimal<'a>> {
+    // Magnitude as plain decimal digits. `f64`'s `Display` never switches to
+    // exponent notation, so this is always `int[.frac]`.
+    let (negative, plain) = match value {
+        DataValue::Number(NumberValue::Integer(i)) => {
+            (*i < 0, bumpalo::format!(in arena, "{}", i.unsigned_abs()))
+        }
+        _ => {
+            let n = crate::arena::coerce_to_number_cfg(value, engine)
+                .filter(|n| n.is_finite())
+                .ok_or_else(|| crate::Error::nan_at(ctx))?;
+            (n < 0.0, bumpalo::format!(in arena, "{}", n.abs()))
+        }
+    };
+    let (int_part, frac_part) = plain.split_once('.').unwrap_or((plain.as_str(), ""));
+
+    // Integer digits followed by exactly `decimals` fraction digits, then
+    // round on the first dropped digit and carry leftwards.
+    let mut digits = Vec::with_capacity_in(int_part.len() + decimals + 1, arena);
+    digits.extend_from_slice(int_part.as_bytes());
+    let frac = frac_part.as_bytes();
+    digits.extend((0..decimals).map(|i| frac.get(i).copied().unwrap_or(b'0')));
+    if frac.get(decimals).is_some_and(|&d| d >= b'5') {
+        let mut carry = true;
+        for d in digits.iter_mut().rev() {
+            if *d == b'9' {
+                *d = b'0';
+            } else {
+                *d += 1;
+                carry = false;
+                break;
+            }
+        }
+        if carry {
+            digits.insert(0, b'1');
+        }
+    }
+
+    Ok(RoundedDecimal {
+        negative: negative && digits.iter().any(|&d| d != b'0'),
+        digits,
+        decimals,
+    })
+}
+
+/// Native arena-mode `to_fixed`: `[value, decimals]` rounded the way
+/// `number_format` rounds, but returned as a number. Integers pass through
+/// unchanged, and a whole result (always the case for `decimals = 0`) is
+/// an integer.
+#[cfg(feature = "ext-math")]
+#[inline]
+pub(crate) fn evaluate_to_fixed<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    let [value, decimals] = args else {
+        return Err(crate::Error::invalid_args());
+    };
+    let value = engine.dispatch_node(value, ctx, arena)?;
+    let decimals = decimal_places(engine.dispatch_node(decimals, ctx, arena)?)?;
+    if let DataValue::Number(NumberValue::Integer(_)) = value {
+        return Ok(value);
+    }
+
+    let rounded = round_half_away(value, decimals, ctx, engine, arena)?;
+    let int_len = rounded.int_len();
+    let mut text = bumpalo::collections::String::with_capacity_in(rounded.digits.len() + 2, arena);
+    if rounded.negative {
+        text.push('-');
+    }
+    for (i, &d) in rounded.digits.iter().enumerate() {
+        if i == int_len {
+            text.push('.');
+        }
+        text.push(d as char);
+    }
+    // Digit strings always parse; an integer that overflows i64 falls back
+    // to f64 like arithmetic results do.
+    let n = match text.parse::<i64>() {
+        Ok(i) => NumberValue::from_i64(i),
+        Err(_) => NumberValue::from_f64(text.parse().map_err(|_| crate::Error::nan_at(ctx))?),
+    };
+    Ok(super::helpers::alloc_number(arena, n))
+}
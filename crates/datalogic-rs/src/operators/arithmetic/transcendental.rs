@@ -0,0 +1,95 @@
+//! `log`, `exp`, `sin`, `cos`, `tan` — transcendental math functions.
+//!
+//! All operands go through the engine's numeric coercion and every result
+//! is computed in f64. As with the other arithmetic operators, a whole
+//! result (`exp(0)`) is stored as an integer. A result that overflows to
+//! infinity is a `NaN` error, matching `pow`.
+
+use crate::arena::{ContextStack, DataValue, coerce_to_number_cfg};
+use crate::{CompiledNode, Engine, Result};
+use bumpalo::Bump;
+
+use super::helpers::alloc_finite;
+
+/// `exp` / `sin` / `cos` / `tan` discriminant for the shared one-operand
+/// entry point. `log` takes an optional base and has its own entry.
+#[derive(Clone, Copy)]
+pub(crate) enum MathFn {
+    Exp,
+    Sin,
+    Cos,
+    Tan,
+}
+
+impl MathFn {
+    #[inline]
+    fn apply(self, x: f64) -> f64 {
+        match self {
+            MathFn::Exp => x.exp(),
+            MathFn::Sin => x.sin(),
+            MathFn::Cos => x.cos(),
+            MathFn::Tan => x.tan(),
+        }
+    }
+}
+
+/// Coerce an operand, mapping a non-numeric value to a `NaN` error.
+#[inline]
+fn operand<'a>(
+    arg: &'a CompiledNode,
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<f64> {
+    let av = engine.dispatch_node(arg, ctx, arena)?;
+    coerce_to_number_cfg(av, engine)
+        .filter(|f| !f.is_nan())
+        .ok_or_else(|| crate::Error::nan_at(ctx))
+}
+
+/// Native arena-mode `exp` / `sin` / `cos` / `tan`. Takes exactly one
+/// operand; angles are in radians.
+#[inline]
+pub(crate) fn math_fn<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+    op: MathFn,
+) -> Result<&'a DataValue<'a>> {
+    if args.len() != 1 {
+        return Err(crate::Error::invalid_args());
+    }
+    let x = operand(&args[0], ctx, engine, arena)?;
+    alloc_finite(ctx, arena, op.apply(x))
+}
+
+/// Native arena-mode `log`. `{"log": value}` is the natural logarithm;
+/// `{"log": [value, base]}` uses the given base. A non-positive value, or a
+/// base that is non-positive or `1`, is a `NaN` error.
+#[inline]
+pub(crate) fn evaluate_log<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    if args.is_empty() || args.len() > 2 {
+        return Err(crate::Error::invalid_args());
+    }
+    let x = operand(&args[0], ctx, engine, arena)?;
+    if x <= 0.0 {
+        return Err(crate::Error::nan_at(ctx));
+    }
+    let r = match args.get(1) {
+        None => x.ln(),
+        Some(base) => {
+            let b = operand(base, ctx, engine, arena)?;
+            if b <= 0.0 || b == 1.0 {
+                return Err(crate::Error::nan_at(ctx));
+            }
+            x.ln() / b.ln()
+        }
+    };
+    alloc_finite(ctx, arena, r)
+}
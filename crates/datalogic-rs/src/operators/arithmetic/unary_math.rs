@@ -1,4 +1,4 @@
-//! `abs`, `ceil`, `floor` — unary numeric ops.
+//! `abs`, `ceil`, `floor`, `sign` — unary numeric ops.
 
 use crate::arena::{ContextStack, DataValue, bvec};
 use crate::{CompiledNode, Engine, Result};
@@ -18,13 +18,14 @@ fn value_strict_f64(av: &DataValue<'_>) -> Option<f64> {
     }
 }
 
-/// `abs` / `ceil` / `floor` discriminant for the unified unary-math entry
-/// point.
+/// `abs` / `ceil` / `floor` / `sign` discriminant for the unified
+/// unary-math entry point.
 #[derive(Clone, Copy)]
 pub(crate) enum UnaryMathOp {
     Abs,
     Ceil,
     Floor,
+    Sign,
 }
 
 impl UnaryMathOp {
@@ -34,19 +35,37 @@ impl UnaryMathOp {
             UnaryMathOp::Abs => x.abs(),
             UnaryMathOp::Ceil => x.ceil(),
             UnaryMathOp::Floor => x.floor(),
+            // `f64::signum` maps ±0.0 to ±1.0.
+            UnaryMathOp::Sign if x == 0.0 => 0.0,
+            UnaryMathOp::Sign => x.signum(),
         }
     }
 
-    /// True when the result should be quantized to i64 (ceil / floor) rather
-    /// than kept as f64 (abs).
+    /// Exact result for an integer operand, or `None` when it does not fit
+    /// in i64 (`abs(i64::MIN)`) and the f64 path must take over.
+    #[inline]
+    fn apply_int(self, i: i64) -> Option<i64> {
+        match self {
+            UnaryMathOp::Abs => i.checked_abs(),
+            UnaryMathOp::Ceil | UnaryMathOp::Floor => Some(i),
+            UnaryMathOp::Sign => Some(i.signum()),
+        }
+    }
+
+    /// True when the result should be quantized to i64 (ceil / floor /
+    /// sign) rather than kept as f64 (abs).
     #[inline]
     fn returns_int(self) -> bool {
-        matches!(self, UnaryMathOp::Ceil | UnaryMathOp::Floor)
+        matches!(
+            self,
+            UnaryMathOp::Ceil | UnaryMathOp::Floor | UnaryMathOp::Sign
+        )
     }
 }
 
-/// Generic native unary math op shared by abs / ceil / floor.
+/// Generic native unary math op shared by abs / ceil / floor / sign.
 /// - `args.is_empty()` → InvalidArguments
+/// - integer operands stay on the exact i64 path where the result fits
 /// - 1 arg, numeric → apply op, return arena Number
 /// - 1 arg, non-numeric → InvalidArguments
 /// - >1 args → variadic, return arena Array of results (any non-numeric → error)
@@ -62,28 +81,32 @@ pub(crate) fn unary_math<'a>(
         return Err(crate::Error::invalid_args());
     }
 
-    let to_number = |x: f64| -> NumberValue {
-        if op.returns_int() {
+    let compute = |av: &DataValue<'_>| -> Result<NumberValue> {
+        if let DataValue::Number(NumberValue::Integer(i)) = av {
+            if let Some(r) = op.apply_int(*i) {
+                return Ok(NumberValue::from_i64(r));
+            }
+        }
+        let x = op.apply(value_strict_f64(av).ok_or_else(crate::Error::invalid_args)?);
+        Ok(if op.returns_int() {
             NumberValue::from_i64(x as i64)
         } else {
             NumberValue::from_f64(x)
-        }
+        })
     };
 
     if args.len() == 1 {
         let av = engine.dispatch_node(&args[0], ctx, arena)?;
-        let n = value_strict_f64(av).ok_or_else(crate::Error::invalid_args)?;
-        return Ok(alloc_number(arena, to_number(op.apply(n))));
+        return Ok(alloc_number(arena, compute(av)?));
     }
 
     let mut items = bvec::<DataValue<'a>>(arena, args.len());
     for arg in args {
         let av = engine.dispatch_node(arg, ctx, arena)?;
-        let n = value_strict_f64(av).ok_or_else(crate::Error::invalid_args)?;
         // Push the Number straight into the result Vec; the previous code
         // arena-allocated each element via `alloc_number` then copied it in,
         // discarding the throwaway allocation.
-        items.push(DataValue::Number(to_number(op.apply(n))));
+        items.push(DataValue::Number(compute(av)?));
     }
     Ok(arena.alloc(DataValue::Array(items.into_bump_slice())))
 }
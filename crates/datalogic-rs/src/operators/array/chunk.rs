@@ -0,0 +1,45 @@
+//! `chunk` — split an array into fixed-size sub-arrays.
+
+use crate::arena::{ContextStack, DataValue, bvec};
+use crate::{CompiledNode, Engine, Result};
+use bumpalo::Bump;
+
+use super::slice::extract_opt_i64_arena;
+
+/// Arena-mode `chunk`. `{"chunk": [array, size]}` returns consecutive
+/// sub-arrays of `size` items; the last one holds the remainder and may be
+/// shorter. Each chunk is a view over the input items, so nothing is cloned.
+///
+/// `size` must be a positive integer. Null or empty input yields `[]`; any
+/// other non-array input is an error.
+#[inline]
+pub(crate) fn evaluate_chunk<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    if args.len() != 2 {
+        return Err(crate::Error::invalid_args());
+    }
+
+    let input = engine.dispatch_node(&args[0], ctx, arena)?;
+    let size = match extract_opt_i64_arena(&args[1], ctx, engine, arena)? {
+        Some(n) if n > 0 => n as usize,
+        _ => return Err(crate::Error::invalid_args()),
+    };
+    let items = match input {
+        DataValue::Array(items) => *items,
+        DataValue::Null => return Ok(crate::arena::singletons::singleton_empty_array()),
+        _ => return Err(crate::Error::invalid_args()),
+    };
+    if items.is_empty() {
+        return Ok(crate::arena::singletons::singleton_empty_array());
+    }
+
+    let mut chunks = bvec::<DataValue<'a>>(arena, items.len().div_ceil(size));
+    for chunk in items.chunks(size) {
+        chunks.push(DataValue::Array(chunk));
+    }
+    Ok(arena.alloc(DataValue::Array(chunks.into_bump_slice())))
+}
@@ -0,0 +1,31 @@
+//! `contains` — haystack-first membership test (`in` with swapped args).
+
+use crate::arena::{ContextStack, DataValue};
+use crate::operators::string::haystack_contains;
+use crate::{CompiledNode, Engine, Result};
+use bumpalo::Bump;
+
+/// Arena-mode `contains` (alias `includes`). `{"contains": [haystack,
+/// needle]}` answers exactly what `{"in": [needle, haystack]}` does for
+/// string and array haystacks — the matching code is shared — and
+/// additionally accepts an object haystack, matching when a string needle
+/// is one of its keys. Any other haystack, including null, is `false`.
+#[inline]
+pub(crate) fn evaluate_contains<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    if args.len() != 2 {
+        return Err(crate::Error::invalid_args());
+    }
+    let haystack = engine.dispatch_node(&args[0], ctx, arena)?;
+    let needle = engine.dispatch_node(&args[1], ctx, arena)?;
+
+    let found = match (haystack, needle) {
+        (DataValue::Object(pairs), DataValue::String(key)) => pairs.iter().any(|(k, _)| k == key),
+        _ => haystack_contains(haystack, needle, engine),
+    };
+    Ok(crate::arena::singletons::singleton_bool(found))
+}
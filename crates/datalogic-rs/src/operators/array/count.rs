@@ -0,0 +1,78 @@
+//! `count` — number of array items whose predicate is truthy.
+
+use crate::arena::{ContextStack, DataValue};
+use crate::{CompiledNode, Engine, Result};
+use bumpalo::Bump;
+use std::ops::ControlFlow;
+
+use super::helpers::{
+    FastPredicate, IterArgKind, ResolvedInput, fast_paths_allowed, for_each_iter_array,
+    resolve_iter_input,
+};
+
+/// `count`. `{"count": [array, predicate]}` evaluates `predicate` against
+/// every item with the same per-item frame as `filter` and returns how many
+/// results are truthy. Unlike the quantifiers it never stops early. Without
+/// a predicate it returns the array length.
+///
+/// Null or empty input yields `0`; any other non-array input is an error.
+#[inline]
+pub(crate) fn evaluate_count<'a>(
+    args: &'a [CompiledNode],
+    iter_arg_kind: IterArgKind,
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    if args.is_empty() || args.len() > 2 {
+        return Err(crate::Error::invalid_args());
+    }
+
+    let src = match resolve_iter_input(&args[0], iter_arg_kind, ctx, engine, arena)? {
+        ResolvedInput::Iterable(s) => s,
+        ResolvedInput::Empty => return Ok(count_result(0, arena)),
+        ResolvedInput::Bridge(_) => return Err(crate::Error::invalid_args()),
+    };
+    let Some(predicate) = args.get(1) else {
+        return Ok(count_result(src.len(), arena));
+    };
+
+    // Fast predicate path — skipped under a tracer; an indeterminate item
+    // abandons the partial count and drops to the general loop.
+    if fast_paths_allowed(ctx, engine) {
+        if let Some(fast_pred) = FastPredicate::from_node(predicate) {
+            let mut n = Some(0usize);
+            for item in src.0 {
+                match fast_pred.evaluate_opt(item, engine) {
+                    Some(true) => n = n.map(|c| c + 1),
+                    Some(false) => {}
+                    None => {
+                        n = None;
+                        break;
+                    }
+                }
+            }
+            if let Some(n) = n {
+                return Ok(count_result(n, arena));
+            }
+        }
+    }
+
+    let mut n = 0usize;
+    for_each_iter_array(src.0, predicate, ctx, engine, arena, |_, _, av| {
+        if crate::arena::truthy_arena(av, engine) {
+            n += 1;
+        }
+        Ok(ControlFlow::Continue(()))
+    })?;
+    Ok(count_result(n, arena))
+}
+
+#[inline]
+fn count_result<'a>(n: usize, arena: &'a Bump) -> &'a DataValue<'a> {
+    let n = n as i64;
+    if let Some(av) = crate::arena::singletons::singleton_small_int(n) {
+        return av;
+    }
+    arena.alloc(DataValue::Number(datavalue::NumberValue::from_i64(n)))
+}
@@ -8,7 +8,8 @@ use std::ops::ControlFlow;
 
 use super::helpers::{
     FastPredicate, FieldCursor, IterArgKind, IterSrc, ResolvedInput, evaluate_invariant_no_push,
-    for_each_iter_array, for_each_iter_object, resolve_iter_input, try_extract_filter_field_cmp,
+    fast_paths_allowed, for_each_iter_array, for_each_iter_object, resolve_iter_input,
+    try_extract_filter_field_cmp,
 };
 
 /// `filter`. Fast path: input collection resolves at root scope (the dominant
@@ -42,7 +43,7 @@ pub(crate) fn evaluate_filter<'a>(
 
     // Fast paths bypass `run_iter_body` and skip tracer markers. Defer to the
     // general path when a tracer is attached.
-    if !ctx.is_tracing() {
+    if fast_paths_allowed(ctx, engine) {
         if let Some(result) = filter_strict_eq_field_fast_path(&src, predicate, ctx, engine, arena)?
         {
             return Ok(result);
@@ -0,0 +1,74 @@
+//! `find` — first array item whose predicate is truthy.
+
+use crate::arena::{ContextStack, DataValue};
+use crate::{CompiledNode, Engine, Result};
+use bumpalo::Bump;
+use std::ops::ControlFlow;
+
+use super::helpers::{
+    FastPredicate, IterArgKind, ResolvedInput, fast_paths_allowed, for_each_iter_array,
+    resolve_iter_input,
+};
+
+/// `find`. Scans items in order with the same per-item frame as `filter`
+/// and returns the first one whose predicate result is truthy, stopping
+/// there — later items are never evaluated. Returns `null` when nothing
+/// matches or the input is null/empty; any other non-array input is an
+/// error.
+///
+/// The match is re-borrowed from the input, never cloned.
+#[inline]
+pub(crate) fn evaluate_find<'a>(
+    args: &'a [CompiledNode],
+    iter_arg_kind: IterArgKind,
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    if args.len() != 2 {
+        return Err(crate::Error::invalid_args());
+    }
+
+    let src = match resolve_iter_input(&args[0], iter_arg_kind, ctx, engine, arena)? {
+        ResolvedInput::Iterable(s) => s,
+        ResolvedInput::Empty => return Ok(crate::arena::singletons::singleton_null()),
+        ResolvedInput::Bridge(_) => return Err(crate::Error::invalid_args()),
+    };
+
+    let predicate = &args[1];
+
+    // Fast predicate path — same contract as the quantifiers: skipped under
+    // a tracer, and an indeterminate item drops to the general loop, which
+    // is exact because fast evaluation is pure.
+    if fast_paths_allowed(ctx, engine) {
+        if let Some(fast_pred) = FastPredicate::from_node(predicate) {
+            let mut verdict = Some(None);
+            for item in src.0 {
+                match fast_pred.evaluate_opt(item, engine) {
+                    Some(true) => {
+                        verdict = Some(Some(item));
+                        break;
+                    }
+                    Some(false) => {}
+                    None => {
+                        verdict = None;
+                        break;
+                    }
+                }
+            }
+            if let Some(found) = verdict {
+                return Ok(found.unwrap_or(crate::arena::singletons::singleton_null()));
+            }
+        }
+    }
+
+    let mut found: Option<&'a DataValue<'a>> = None;
+    for_each_iter_array(src.0, predicate, ctx, engine, arena, |_, item, av| {
+        if crate::arena::truthy_arena(av, engine) {
+            found = Some(item);
+            return Ok(ControlFlow::Break(()));
+        }
+        Ok(ControlFlow::Continue(()))
+    })?;
+    Ok(found.unwrap_or(crate::arena::singletons::singleton_null()))
+}
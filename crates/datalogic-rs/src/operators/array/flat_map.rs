@@ -0,0 +1,79 @@
+//! `flat_map` — `map` followed by a one-level flatten of the results.
+
+use crate::arena::{ContextStack, DataValue, bvec};
+use crate::{CompiledNode, Engine, Result};
+use bumpalo::Bump;
+use std::ops::ControlFlow;
+
+use super::helpers::{
+    IterArgKind, ResolvedInput, for_each_iter_array, for_each_iter_object, resolve_iter_input,
+};
+
+/// `flat_map`. Evaluates the body per item with exactly the frames `map`
+/// pushes (array items, object `(key, value)` pairs, or a single primitive
+/// input) and concatenates the results: array results are spliced in one
+/// level deep, `null` results are dropped, and any other value is appended
+/// as a single element.
+///
+/// Spliced items are re-borrowed from the body results, never cloned. Null
+/// or empty input yields `[]`.
+#[inline]
+pub(crate) fn evaluate_flat_map<'a>(
+    args: &'a [CompiledNode],
+    iter_arg_kind: IterArgKind,
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    if args.len() != 2 {
+        return Err(crate::Error::invalid_args());
+    }
+
+    let body = &args[1];
+    let results = match resolve_iter_input(&args[0], iter_arg_kind, ctx, engine, arena)? {
+        ResolvedInput::Iterable(src) => {
+            let mut results = bvec::<DataValue<'a>>(arena, src.len());
+            for_each_iter_array(src.0, body, ctx, engine, arena, |_, _item, av| {
+                push_flat(&mut results, av);
+                Ok(ControlFlow::Continue(()))
+            })?;
+            results
+        }
+        ResolvedInput::Empty => return Ok(crate::arena::singletons::singleton_empty_array()),
+        ResolvedInput::Bridge(DataValue::Object(pairs)) => {
+            let mut results = bvec::<DataValue<'a>>(arena, pairs.len());
+            for_each_iter_object(pairs, body, ctx, engine, arena, |_, _item, _key, av| {
+                push_flat(&mut results, av);
+                Ok(ControlFlow::Continue(()))
+            })?;
+            results
+        }
+        ResolvedInput::Bridge(input) => {
+            // Single-element collection, as in `map`. Pop before propagating
+            // errors so a surrounding `try` never sees a leaked frame.
+            ctx.push_with_index(input, 0);
+            let result = engine.run_iter_body(body, ctx, arena, 0, 1);
+            ctx.pop();
+            let mut results = bvec::<DataValue<'a>>(arena, 1);
+            push_flat(&mut results, result?);
+            results
+        }
+    };
+
+    if results.is_empty() {
+        return Ok(crate::arena::singletons::singleton_empty_array());
+    }
+    Ok(arena.alloc(DataValue::Array(results.into_bump_slice())))
+}
+
+#[inline]
+fn push_flat<'a>(
+    results: &mut bumpalo::collections::Vec<'a, DataValue<'a>>,
+    av: &'a DataValue<'a>,
+) {
+    match av {
+        DataValue::Array(items) => results.extend_from_slice(items),
+        DataValue::Null => {}
+        _ => results.push(*av),
+    }
+}
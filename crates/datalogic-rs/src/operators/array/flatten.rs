@@ -0,0 +1,85 @@
+//! `flatten` — collapse nested arrays up to a configurable depth.
+
+use crate::arena::{ContextStack, DataValue, bvec};
+use crate::{CompiledNode, Engine, Result};
+use bumpalo::Bump;
+
+/// Arena-mode `flatten`. `{"flatten": [array, depth?]}` splices sub-array
+/// items into their parent, recursing up to `depth` levels (default 1;
+/// `true` flattens fully). Non-array items stay in place — unlike `merge`,
+/// nulls are kept.
+///
+/// Depth 0 and arrays without any nested array return the input slice
+/// unchanged, so the common "already flat" case never touches the arena.
+/// A null input yields `[]`; any other non-array input is an error.
+#[inline]
+pub(crate) fn evaluate_flatten<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    if args.is_empty() || args.len() > 2 {
+        return Err(crate::Error::invalid_args());
+    }
+
+    let input = engine.dispatch_node(&args[0], ctx, arena)?;
+    let items = match input {
+        DataValue::Array(items) => *items,
+        DataValue::Null => return Ok(crate::arena::singletons::singleton_empty_array()),
+        _ => return Err(crate::Error::invalid_args()),
+    };
+
+    let depth = if args.len() > 1 {
+        flatten_depth(engine.dispatch_node(&args[1], ctx, arena)?)?
+    } else {
+        1
+    };
+
+    if depth == 0 || !items.iter().any(|v| matches!(v, DataValue::Array(_))) {
+        return Ok(input);
+    }
+
+    let mut out = bvec::<DataValue<'a>>(arena, items.len());
+    flatten_into(items, depth, &mut out);
+    if out.is_empty() {
+        return Ok(crate::arena::singletons::singleton_empty_array());
+    }
+    Ok(arena.alloc(DataValue::Array(out.into_bump_slice())))
+}
+
+/// Resolve the depth argument: `true` is fully recursive, `false` is depth 0
+/// and `null` is the default of 1. Numbers truncate toward zero and clamp
+/// negatives to 0, mirroring JavaScript's `Array.prototype.flat`; anything
+/// else is an error.
+#[inline]
+fn flatten_depth(av: &DataValue<'_>) -> Result<usize> {
+    match av {
+        DataValue::Null => Ok(1),
+        DataValue::Bool(true) => Ok(usize::MAX),
+        DataValue::Bool(false) => Ok(0),
+        DataValue::Number(n) => {
+            let f = n.as_f64();
+            if f.is_nan() {
+                return Err(crate::Error::invalid_args());
+            }
+            // `as` saturates, so huge depths land on `usize::MAX`.
+            Ok(if f <= 0.0 { 0 } else { f as usize })
+        }
+        _ => Err(crate::Error::invalid_args()),
+    }
+}
+
+/// Push `items` into `out`, splicing sub-arrays while `depth` remains.
+fn flatten_into<'a>(
+    items: &'a [DataValue<'a>],
+    depth: usize,
+    out: &mut bumpalo::collections::Vec<'a, DataValue<'a>>,
+) {
+    for item in items {
+        match item {
+            DataValue::Array(inner) if depth > 0 => flatten_into(inner, depth - 1, out),
+            other => out.push(*other),
+        }
+    }
+}
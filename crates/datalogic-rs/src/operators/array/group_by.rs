@@ -0,0 +1,64 @@
+//! `group_by` — bucket array items into an object keyed by an extractor.
+
+use std::collections::HashMap;
+use std::ops::ControlFlow;
+
+use crate::arena::{ContextStack, DataValue, bvec, data_to_str};
+use crate::{CompiledNode, Engine, Result};
+use bumpalo::Bump;
+
+use super::helpers::{IterArgKind, ResolvedInput, for_each_iter_array, resolve_iter_input};
+
+/// `group_by`. `{"group_by": [array, extractor]}` evaluates `extractor` with
+/// each item as the current context, stringifies the result (the same
+/// coercion `cat` applies) and collects items under that key. Keys appear in
+/// first-seen order; each bucket keeps its items in input order.
+///
+/// Items are re-borrowed from the input, never cloned. The per-key slot map
+/// is a scratch `HashMap` so long inputs with many distinct keys stay
+/// linear. Null or empty input yields `{}`; any other non-array input is an
+/// error.
+#[inline]
+pub(crate) fn evaluate_group_by<'a>(
+    args: &'a [CompiledNode],
+    iter_arg_kind: IterArgKind,
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    if args.len() != 2 {
+        return Err(crate::Error::invalid_args());
+    }
+
+    let src = match resolve_iter_input(&args[0], iter_arg_kind, ctx, engine, arena)? {
+        ResolvedInput::Iterable(s) => s,
+        ResolvedInput::Empty => return Ok(crate::arena::singletons::singleton_empty_object()),
+        ResolvedInput::Bridge(_) => return Err(crate::Error::invalid_args()),
+    };
+    if src.is_empty() {
+        return Ok(crate::arena::singletons::singleton_empty_object());
+    }
+
+    let mut groups = bvec::<(&'a str, bumpalo::collections::Vec<'a, DataValue<'a>>)>(arena, 4);
+    let mut slots: HashMap<&'a str, usize> = HashMap::new();
+    for_each_iter_array(src.0, &args[1], ctx, engine, arena, |_, item, key_av| {
+        let key = data_to_str(key_av, arena);
+        match slots.get(key) {
+            Some(&slot) => groups[slot].1.push(*item),
+            None => {
+                slots.insert(key, groups.len());
+                let mut bucket = bvec::<DataValue<'a>>(arena, 1);
+                bucket.push(*item);
+                groups.push((key, bucket));
+            }
+        }
+        Ok(ControlFlow::Continue(()))
+    })?;
+
+    let pairs = arena.alloc_slice_fill_iter(
+        groups
+            .into_iter()
+            .map(|(key, bucket)| (key, DataValue::Array(bucket.into_bump_slice()))),
+    );
+    Ok(arena.alloc(DataValue::Object(pairs)))
+}
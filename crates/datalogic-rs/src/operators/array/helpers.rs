@@ -638,13 +638,32 @@ pub(crate) fn resolve_iter_input<'a>(
                 None
             };
             if let Some(av) = av {
-                return Ok(value_as_iter(av));
+                let input = value_as_iter(av);
+                charge_iteration(&input, ctx, engine)?;
+                return Ok(input);
             }
         }
     }
 
     let av = engine.dispatch_node(arg, ctx, arena)?;
-    Ok(value_as_iter(av))
+    let input = value_as_iter(av);
+    charge_iteration(&input, ctx, engine)?;
+    Ok(input)
+}
+
+/// Charge one step per element about to be walked, so fused fast paths —
+/// which never dispatch a node per item — still count against
+/// [`crate::EvaluationConfig::step_limit`].
+#[inline(always)]
+fn charge_iteration(
+    input: &ResolvedInput<'_>,
+    ctx: &mut ContextStack<'_>,
+    engine: &Engine,
+) -> Result<()> {
+    match (input, engine.config().step_limit) {
+        (ResolvedInput::Iterable(src), Some(limit)) => ctx.charge_steps(src.len() as u64, limit),
+        _ => Ok(()),
+    }
 }
 
 /// Convert a resolved arena value into an `IterSrc` view, or signal Empty/Bridge.
@@ -657,6 +676,15 @@ fn value_as_iter<'a>(av: &'a DataValue<'a>) -> ResolvedInput<'a> {
     }
 }
 
+/// Whether the per-item fast paths may run. They bypass `run_iter_body`,
+/// so a tracer wouldn't see iteration markers, and they read missing
+/// fields as `null`, which `strict_variables` turns into an error only
+/// the general dispatch path raises.
+#[inline]
+pub(super) fn fast_paths_allowed(ctx: &ContextStack<'_>, engine: &Engine) -> bool {
+    !ctx.is_tracing() && !engine.config().strict_variables
+}
+
 /// True iff this arena value is `null`.
 #[inline]
 pub(super) fn item_is_null(av: &DataValue<'_>) -> bool {
@@ -751,6 +779,7 @@ impl<'n> FieldCursor<'n> {
         let single_key = match segments {
             [PathSegment::Field(k)] => Some(k.as_ref()),
             [PathSegment::FieldOrIndex(k, _)] => Some(k.as_ref()),
+            [PathSegment::FieldOrIndexFromEnd(k, _)] => Some(k.as_ref()),
             _ => None,
         };
         Self {
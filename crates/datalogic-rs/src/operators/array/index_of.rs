@@ -0,0 +1,69 @@
+//! `index_of` — position of an item in an array or a substring in a string.
+
+use crate::arena::{ContextStack, DataValue};
+use crate::operators::comparison::compare_equals;
+use crate::operators::string::char_to_byte_offset;
+use crate::{CompiledNode, Engine, Result};
+use bumpalo::Bump;
+
+use super::slice::{extract_opt_i64_arena, normalize_index};
+
+/// Arena-mode `index_of`. `{"index_of": [haystack, needle, from?]}` returns
+/// the zero-based position of the first match at or after `from`, or `-1`.
+///
+/// Array haystacks compare items with strict (`===`) equality, the same
+/// check `in` uses. String haystacks search for a string needle and report
+/// the offset in chars, consistent with `length` and `substr`; a non-string
+/// needle never matches. A negative `from` counts back from the end and is
+/// clamped to the start, as in `slice`. Null haystack yields `-1`; any other
+/// non-array, non-string haystack is an error.
+#[inline]
+pub(crate) fn evaluate_index_of<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    if args.len() < 2 || args.len() > 3 {
+        return Err(crate::Error::invalid_args());
+    }
+
+    let haystack = engine.dispatch_node(&args[0], ctx, arena)?;
+    let needle = engine.dispatch_node(&args[1], ctx, arena)?;
+    let from = match args.get(2) {
+        Some(node) => extract_opt_i64_arena(node, ctx, engine, arena)?.unwrap_or(0),
+        None => 0,
+    };
+
+    let found = match haystack {
+        DataValue::Array(items) => {
+            let start = normalize_index(from, items.len() as i64) as usize;
+            let mut found = None;
+            for (i, item) in items.iter().enumerate().skip(start) {
+                if compare_equals(item, needle, true, engine)? {
+                    found = Some(i);
+                    break;
+                }
+            }
+            found
+        }
+        DataValue::String(s) => match needle {
+            DataValue::String(n) => {
+                let start = normalize_index(from, s.chars().count() as i64) as usize;
+                let byte_start = char_to_byte_offset(s, start);
+                s[byte_start..]
+                    .find(*n)
+                    .map(|b| start + s[byte_start..byte_start + b].chars().count())
+            }
+            _ => None,
+        },
+        DataValue::Null => None,
+        _ => return Err(crate::Error::invalid_args()),
+    };
+
+    let n = found.map_or(-1, |i| i as i64);
+    if let Some(av) = crate::arena::singletons::singleton_small_int(n) {
+        return Ok(av);
+    }
+    Ok(arena.alloc(DataValue::Number(datavalue::NumberValue::from_i64(n))))
+}
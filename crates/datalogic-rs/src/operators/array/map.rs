@@ -9,8 +9,8 @@ use datavalue::{NumberValue, OwnedDataValue};
 use std::ops::ControlFlow;
 
 use super::helpers::{
-    FieldCursor, FusedMapBody, IterArgKind, IterSrc, ResolvedInput, for_each_iter_array,
-    for_each_iter_object, resolve_iter_input,
+    FieldCursor, FusedMapBody, IterArgKind, IterSrc, ResolvedInput, fast_paths_allowed,
+    for_each_iter_array, for_each_iter_object, resolve_iter_input,
 };
 
 /// `map`. Borrows input from root scope when possible. Body fast path for
@@ -47,7 +47,7 @@ pub(crate) fn evaluate_map<'a>(
     // per-iteration markers. Only enter them when no tracer is attached.
     // Shape detection is shared with the reduce(map(...)) fusion — see
     // `FusedMapBody::detect`.
-    if !ctx.is_tracing() {
+    if fast_paths_allowed(ctx, engine) {
         if let Some(shape) = FusedMapBody::detect(body) {
             if let Some(result) = map_fused(&src, &shape, arena) {
                 return Ok(result);
@@ -55,6 +55,17 @@ pub(crate) fn evaluate_map<'a>(
         }
     }
 
+    // A one-thread pool can't overlap anything; the copy-back would be
+    // pure overhead.
+    #[cfg(feature = "parallel")]
+    if engine.parallel_threshold().is_some_and(|n| len > n)
+        && !ctx.is_tracing()
+        && rayon::current_num_threads() > 1
+        && !draws_seeded_random(body, engine)
+    {
+        return map_parallel(src.0, body, ctx, engine, arena);
+    }
+
     map_general(&src, body, ctx, engine, arena)
 }
 
@@ -281,6 +292,119 @@ fn map_general<'a>(
     Ok(arena.alloc(DataValue::Array(results.into_bump_slice())))
 }
 
+/// Whether `node` calls `random` while the engine has a seed. Those calls
+/// share one per-evaluation stream, so the items must run in order for the
+/// sequence to be reproducible.
+#[cfg(feature = "parallel")]
+fn draws_seeded_random(node: &CompiledNode, engine: &Engine) -> bool {
+    #[cfg(feature = "ext-math")]
+    {
+        fn calls_random(node: &CompiledNode) -> bool {
+            if let CompiledNode::BuiltinOperator {
+                opcode: OpCode::Random,
+                ..
+            } = node
+            {
+                return true;
+            }
+            let mut found = false;
+            node.visit_indexed_children(&mut |_, child| found = found || calls_random(child));
+            found
+        }
+        engine.config().random_seed.is_some() && calls_random(node)
+    }
+    #[cfg(not(feature = "ext-math"))]
+    {
+        let _ = (node, engine);
+        false
+    }
+}
+
+/// Parallel general path (`feature = "parallel"`, arrays past
+/// `Engine::parallel_threshold`). The array is split into one contiguous
+/// chunk per rayon thread; each chunk runs on its own `Bump` with a
+/// [`ContextStack::fork`] of the caller's scopes, so nothing `!Sync` is
+/// shared. Results are copied back into `arena` chunk by chunk, which
+/// keeps input order, and the worker arenas are dropped on return.
+///
+/// A failing item records its index; chunks that start past the lowest
+/// failure stop early, and the error reported is the lowest-index one —
+/// what the sequential loop would have stopped at. Its breadcrumb and the
+/// steps every worker spent are folded back into `ctx`.
+#[cfg(feature = "parallel")]
+fn map_parallel<'a>(
+    items: &'a [DataValue<'a>],
+    body: &'a CompiledNode,
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    use crate::arena::IterGuard;
+    use rayon::prelude::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let total = items.len() as u32;
+    let chunk_len = items.len().div_ceil(rayon::current_num_threads());
+    let mut worker_arenas: Vec<Bump> = items.chunks(chunk_len).map(|_| Bump::new()).collect();
+    let first_failure = AtomicUsize::new(usize::MAX);
+    let scope: &ContextStack<'a> = ctx;
+    let base_steps = scope.steps();
+
+    let chunks: Vec<_> = worker_arenas
+        .par_iter_mut()
+        .zip(items.par_chunks(chunk_len))
+        .enumerate()
+        .map(|(c, (worker_arena, chunk))| {
+            let worker_arena: &Bump = worker_arena;
+            let mut worker_ctx = scope.fork();
+            let mut results = Vec::with_capacity(chunk.len());
+            let mut failure = None;
+            let mut guard = IterGuard::new(&mut worker_ctx);
+            for (offset, item) in chunk.iter().enumerate() {
+                let index = c * chunk_len + offset;
+                if first_failure.load(Ordering::Relaxed) < index {
+                    break;
+                }
+                guard.step_indexed(item, index);
+                match engine.run_iter_body(body, guard.stack(), worker_arena, index as u32, total) {
+                    Ok(av) => results.push(*av),
+                    Err(e) => {
+                        first_failure.fetch_min(index, Ordering::Relaxed);
+                        failure = Some(e);
+                        break;
+                    }
+                }
+            }
+            drop(guard);
+            let spent = worker_ctx.steps() - base_steps;
+            match failure {
+                None => (Ok(results), spent),
+                Some(e) => (Err((e, worker_ctx.take_error_path())), spent),
+            }
+        })
+        .collect();
+
+    let mut out = bvec::<DataValue<'a>>(arena, items.len());
+    let mut steps = 0u64;
+    let mut failure = None;
+    for (outcome, spent) in chunks {
+        steps = steps.saturating_add(spent);
+        match outcome {
+            _ if failure.is_some() => {}
+            Ok(values) => out.extend(values.iter().map(|v| crate::arena::copy_to_arena(v, arena))),
+            Err(err) => failure = Some(err),
+        }
+    }
+    if let Some((err, path)) = failure {
+        ctx.extend_error_path(path);
+        return Err(err);
+    }
+    if let Some(limit) = engine.config().step_limit {
+        ctx.charge_steps(steps, limit)?;
+    }
+    Ok(arena.alloc(DataValue::Array(out.into_bump_slice())))
+}
+
 /// Map Bridge case — Object inputs iterate (key, value) pairs; inline arena
 /// Array inputs (e.g. literal `[1,2,3]` arg) iterate items; other shapes are
 /// treated as a single-element collection.
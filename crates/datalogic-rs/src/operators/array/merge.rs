@@ -1,4 +1,5 @@
-//! `merge` — flatten args into a single array, skipping nulls.
+//! `merge` — flatten args into a single array, skipping nulls; and
+//! `merge_objects` — deep-merge object args, later keys winning.
 
 use crate::arena::{ContextStack, DataValue, bvec};
 use crate::{CompiledNode, Engine, Result};
@@ -53,3 +54,61 @@ pub(crate) fn evaluate_merge<'a>(
         _ => Ok(crate::arena::singletons::singleton_empty_array()),
     }
 }
+
+/// Arena-mode `merge_objects`. Deep-merges its object args left to right:
+/// later keys win, a key holding an object on both sides is merged
+/// recursively, and any other value (arrays included) replaces the earlier
+/// one outright. Null args are skipped; any other non-object arg is an
+/// error.
+///
+/// Keys keep their first-seen position. A lone object (after skipping
+/// nulls) is returned as-is without copying; no objects at all yields `{}`.
+#[cfg(feature = "ext-array")]
+#[inline]
+pub(crate) fn evaluate_merge_objects<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    let mut merged: Option<&'a DataValue<'a>> = None;
+    for arg in args {
+        let av = engine.dispatch_node(arg, ctx, arena)?;
+        merged = match (merged, av) {
+            (_, DataValue::Null) => merged,
+            (None, DataValue::Object(_)) => Some(av),
+            (Some(DataValue::Object(base)), DataValue::Object(over)) => {
+                Some(arena.alloc(DataValue::Object(deep_merge_pairs(base, over, arena))))
+            }
+            _ => return Err(crate::Error::invalid_args()),
+        };
+    }
+    Ok(merged.unwrap_or_else(crate::arena::singletons::singleton_empty_object))
+}
+
+/// Merge `over` onto `base` into a fresh arena slice. Values are
+/// re-borrowed, never cloned; only objects merged on both sides are
+/// rebuilt.
+#[cfg(feature = "ext-array")]
+fn deep_merge_pairs<'a>(
+    base: &'a [(&'a str, DataValue<'a>)],
+    over: &'a [(&'a str, DataValue<'a>)],
+    arena: &'a Bump,
+) -> &'a [(&'a str, DataValue<'a>)] {
+    let mut out = bvec::<(&'a str, DataValue<'a>)>(arena, base.len() + over.len());
+    out.extend_from_slice(base);
+    for &(key, value) in over {
+        match out.iter_mut().find(|(k, _)| *k == key) {
+            Some(slot) => {
+                slot.1 = match (slot.1, value) {
+                    (DataValue::Object(a), DataValue::Object(b)) => {
+                        DataValue::Object(deep_merge_pairs(a, b, arena))
+                    }
+                    _ => value,
+                };
+            }
+            None => out.push((key, value)),
+        }
+    }
+    out.into_bump_slice()
+}
@@ -0,0 +1,74 @@
+//! `min_by` / `max_by` — the array item with the smallest or largest key.
+
+use std::cmp::Ordering;
+use std::ops::ControlFlow;
+
+use crate::arena::{ContextStack, DataValue};
+use crate::{CompiledNode, Engine, Result};
+use bumpalo::Bump;
+
+use super::helpers::{IterArgKind, ResolvedInput, for_each_iter_array, resolve_iter_input};
+use super::sort::compare_values;
+
+/// `min_by`. `{"min_by": [array, extractor]}` returns the item whose
+/// extracted key is smallest.
+#[inline]
+pub(crate) fn evaluate_min_by<'a>(
+    args: &'a [CompiledNode],
+    iter_arg_kind: IterArgKind,
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    extreme_by(args, iter_arg_kind, ctx, engine, arena, Ordering::Less)
+}
+
+/// `max_by`. `{"max_by": [array, extractor]}` returns the item whose
+/// extracted key is largest.
+#[inline]
+pub(crate) fn evaluate_max_by<'a>(
+    args: &'a [CompiledNode],
+    iter_arg_kind: IterArgKind,
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    extreme_by(args, iter_arg_kind, ctx, engine, arena, Ordering::Greater)
+}
+
+/// Shared `min_by` / `max_by` scan. Evaluates the extractor once per item
+/// with the same per-item frame as `sort`'s extractor and keeps the item
+/// whose key compares as `wanted` against the best so far, using `sort`'s
+/// value order. Only a strictly better key replaces the current pick, so
+/// ties return the first such item.
+///
+/// The item is re-borrowed from the input, never cloned. Null or empty
+/// input yields `null`; any other non-array input is an error.
+fn extreme_by<'a>(
+    args: &'a [CompiledNode],
+    iter_arg_kind: IterArgKind,
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+    wanted: Ordering,
+) -> Result<&'a DataValue<'a>> {
+    if args.len() != 2 {
+        return Err(crate::Error::invalid_args());
+    }
+
+    let src = match resolve_iter_input(&args[0], iter_arg_kind, ctx, engine, arena)? {
+        ResolvedInput::Iterable(s) => s,
+        ResolvedInput::Empty => return Ok(crate::arena::singletons::singleton_null()),
+        ResolvedInput::Bridge(_) => return Err(crate::Error::invalid_args()),
+    };
+
+    let mut best: Option<(&'a DataValue<'a>, &'a DataValue<'a>)> = None;
+    for_each_iter_array(src.0, &args[1], ctx, engine, arena, |_, item, key| {
+        match best {
+            Some((_, best_key)) if compare_values(key, best_key) != wanted => {}
+            _ => best = Some((item, key)),
+        }
+        Ok(ControlFlow::Continue(()))
+    })?;
+    Ok(best.map_or(crate::arena::singletons::singleton_null(), |(item, _)| item))
+}
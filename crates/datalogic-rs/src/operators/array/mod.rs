@@ -1,16 +1,58 @@
 //! Array operators: filter / map / reduce / merge / quantifiers / sort /
-//! slice / length.
+//! slice / flatten / unique / group_by / find / index_of / zip /
+//! chunk / count / flat_map / reverse / take / drop / contains /
+//! all_values / some_values / none_values / min_by / max_by / window /
+//! reduce_while /
+//! merge_objects / pick / omit / set / length.
 //!
 //! # File map
 //!
 //! - [`filter`] — `filter` (predicate-based array selection).
 //! - [`map`] — `map` (per-element transformation).
-//! - [`merge`] — `merge` (variadic array concatenation, flattening one level).
-//! - [`quantifiers`] — `all`, `some`, `none` (predicate scan with short-circuit).
-//! - [`reduce`] — `reduce` (fold over an array with `accumulator` / `current` slots).
+//! - [`merge`] — `merge` (variadic array concatenation, flattening one level)
+//!   and `merge_objects` (recursive object merge, gated on `feature =
+//!   "ext-array"`).
+//! - [`quantifiers`] — `all`, `some`, `none` (predicate scan with short-circuit),
+//!   and their object-value forms `all_values`, `some_values`, `none_values`
+//!   (gated on `feature = "ext-array"`).
+//! - [`reduce`] — `reduce` (fold over an array with `accumulator` / `current` slots),
+//!   and `reduce_while` (the same fold stopping at a falsy condition, gated on
+//!   `feature = "ext-array"`).
 //! - [`length`] — `length` of strings/arrays (gated on `feature = "ext-string"`).
 //! - [`slice`] — Python-style `slice` (gated on `feature = "ext-array"`).
 //! - [`sort`] — `sort` (key-based ordering, gated on `feature = "ext-array"`).
+//! - [`min_max_by`] — `min_by` / `max_by` (the item with the smallest or
+//!   largest extracted key, gated on `feature = "ext-array"`).
+//! - [`find`] — `find` (first item matching a predicate, short-circuiting;
+//!   gated on `feature = "ext-array"`).
+//! - [`flatten`] — `flatten` (depth-limited nested-array collapse, gated on
+//!   `feature = "ext-array"`).
+//! - [`group_by`] — `group_by` (bucket items into an object keyed by an
+//!   extractor, gated on `feature = "ext-array"`).
+//! - [`unique`] — `unique` / `distinct` (order-preserving de-duplication,
+//!   gated on `feature = "ext-array"`).
+//! - [`index_of`] — `index_of` (first position of an item or substring, gated
+//!   on `feature = "ext-array"`).
+//! - [`zip`] — `zip` (combine parallel arrays into tuples, gated on `feature =
+//!   "ext-array"`).
+//! - [`chunk`] — `chunk` (split into fixed-size batches, gated on `feature =
+//!   "ext-array"`).
+//! - [`window`] — `window` (overlapping runs of a fixed size, step 1, gated on
+//!   `feature = "ext-array"`).
+//! - [`count`] — `count` (number of items matching a predicate, gated on
+//!   `feature = "ext-array"`).
+//! - [`flat_map`] — `flat_map` (map then flatten one level, gated on `feature =
+//!   "ext-array"`).
+//! - [`reverse`] — `reverse` (array items or string chars back to front, gated
+//!   on `feature = "ext-array"`).
+//! - [`take_drop`] — `take` / `drop` (head and tail slices by count, gated on
+//!   `feature = "ext-array"`).
+//! - [`pick_omit`] — `pick` / `omit` (keep or drop an object's listed keys,
+//!   gated on `feature = "ext-array"`).
+//! - [`set`] — `set` (copy with a value written at a path, gated on `feature
+//!   = "ext-array"`).
+//! - [`contains`] — `contains` / `includes` (haystack-first `in`, also matching
+//!   object keys; gated on `feature = "ext-array"`).
 //! - [`helpers`] — shared infrastructure: `IterSrc`, `ResolvedInput`,
 //!   `resolve_iter_input` (used by every iterator op), `FastPredicate`
 //!   (filter / quantifier fast paths), and a few small comparison helpers.
@@ -23,26 +65,96 @@ mod merge;
 mod quantifiers;
 mod reduce;
 
+#[cfg(feature = "ext-array")]
+mod chunk;
+#[cfg(feature = "ext-array")]
+mod contains;
+#[cfg(feature = "ext-array")]
+mod count;
+#[cfg(feature = "ext-array")]
+mod find;
+#[cfg(feature = "ext-array")]
+mod flat_map;
+#[cfg(feature = "ext-array")]
+mod flatten;
+#[cfg(feature = "ext-array")]
+mod group_by;
+#[cfg(feature = "ext-array")]
+mod index_of;
 #[cfg(feature = "ext-string")]
 mod length;
 #[cfg(feature = "ext-array")]
+mod min_max_by;
+#[cfg(feature = "ext-array")]
+mod pick_omit;
+#[cfg(feature = "ext-array")]
+mod reverse;
+#[cfg(feature = "ext-array")]
+mod set;
+#[cfg(feature = "ext-array")]
 mod slice;
 #[cfg(feature = "ext-array")]
 mod sort;
+#[cfg(feature = "ext-array")]
+mod take_drop;
+#[cfg(feature = "ext-array")]
+mod unique;
+#[cfg(feature = "ext-array")]
+mod window;
+#[cfg(feature = "ext-array")]
+mod zip;
 
 // Operator entry points (consumed by the dispatcher).
 pub(crate) use filter::evaluate_filter;
 pub(crate) use map::evaluate_map;
 pub(crate) use merge::evaluate_merge;
+#[cfg(feature = "ext-array")]
+pub(crate) use merge::evaluate_merge_objects;
 pub(crate) use quantifiers::{evaluate_all, evaluate_none, evaluate_some};
+#[cfg(feature = "ext-array")]
+pub(crate) use quantifiers::{evaluate_all_values, evaluate_none_values, evaluate_some_values};
 pub(crate) use reduce::evaluate_reduce;
+#[cfg(feature = "ext-array")]
+pub(crate) use reduce::evaluate_reduce_while;
 
+#[cfg(feature = "ext-array")]
+pub(crate) use chunk::evaluate_chunk;
+#[cfg(feature = "ext-array")]
+pub(crate) use contains::evaluate_contains;
+#[cfg(feature = "ext-array")]
+pub(crate) use count::evaluate_count;
+#[cfg(feature = "ext-array")]
+pub(crate) use find::evaluate_find;
+#[cfg(feature = "ext-array")]
+pub(crate) use flat_map::evaluate_flat_map;
+#[cfg(feature = "ext-array")]
+pub(crate) use flatten::evaluate_flatten;
+#[cfg(feature = "ext-array")]
+pub(crate) use group_by::evaluate_group_by;
+#[cfg(feature = "ext-array")]
+pub(crate) use index_of::evaluate_index_of;
 #[cfg(feature = "ext-string")]
 pub(crate) use length::evaluate_length;
 #[cfg(feature = "ext-array")]
+pub(crate) use min_max_by::{evaluate_max_by, evaluate_min_by};
+#[cfg(feature = "ext-array")]
+pub(crate) use pick_omit::{PickOmitOp, pick_omit};
+#[cfg(feature = "ext-array")]
+pub(crate) use reverse::evaluate_reverse;
+#[cfg(feature = "ext-array")]
+pub(crate) use set::evaluate_set;
+#[cfg(feature = "ext-array")]
 pub(crate) use slice::evaluate_slice;
 #[cfg(feature = "ext-array")]
 pub(crate) use sort::evaluate_sort;
+#[cfg(feature = "ext-array")]
+pub(crate) use take_drop::{TakeDropOp, take_drop};
+#[cfg(feature = "ext-array")]
+pub(crate) use unique::evaluate_unique;
+#[cfg(feature = "ext-array")]
+pub(crate) use window::evaluate_window;
+#[cfg(feature = "ext-array")]
+pub(crate) use zip::evaluate_zip;
 
 // Iterator-input infrastructure consumed by `arithmetic` (and other crate
 // callers) to compose with array results.
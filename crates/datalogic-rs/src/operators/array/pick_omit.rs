@@ -0,0 +1,68 @@
+//! `pick` / `omit` — keep or drop an object's top-level keys.
+
+use crate::arena::{ContextStack, DataValue, bvec};
+use crate::{CompiledNode, Engine, Result};
+use bumpalo::Bump;
+
+/// `pick` / `omit` discriminant for the shared entry point.
+#[derive(Clone, Copy)]
+pub(crate) enum PickOmitOp {
+    Pick,
+    Omit,
+}
+
+/// Arena-mode `pick` / `omit`. `{"pick": [obj, keys]}` keeps only the
+/// entries of `obj` whose key is listed and `{"omit": [obj, keys]}` keeps
+/// every other entry, so the two always partition `obj`. `keys` is an array
+/// of strings or a single string; listed keys that `obj` lacks are ignored
+/// rather than filled with null.
+///
+/// Entries keep their order in `obj` and their values are re-borrowed, not
+/// cloned; when nothing is dropped `obj` itself is returned. Null `obj`
+/// yields `{}`; any other non-object `obj`, or a non-string key, is an
+/// error.
+#[inline]
+pub(crate) fn pick_omit<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+    op: PickOmitOp,
+) -> Result<&'a DataValue<'a>> {
+    if args.len() != 2 {
+        return Err(crate::Error::invalid_args());
+    }
+
+    let obj = engine.dispatch_node(&args[0], ctx, arena)?;
+    let keys = engine.dispatch_node(&args[1], ctx, arena)?;
+    let keys: &[DataValue<'a>] = match keys {
+        DataValue::Array(items) => items,
+        DataValue::String(_) => std::slice::from_ref(keys),
+        _ => return Err(crate::Error::invalid_args()),
+    };
+    if !keys.iter().all(|k| matches!(k, DataValue::String(_))) {
+        return Err(crate::Error::invalid_args());
+    }
+    let pairs = match obj {
+        DataValue::Object(pairs) => *pairs,
+        DataValue::Null => return Ok(crate::arena::singletons::singleton_empty_object()),
+        _ => return Err(crate::Error::invalid_args()),
+    };
+
+    let keep = |key: &str| {
+        let listed = keys
+            .iter()
+            .any(|k| matches!(k, DataValue::String(s) if *s == key));
+        listed == matches!(op, PickOmitOp::Pick)
+    };
+    let kept = pairs.iter().filter(|(k, _)| keep(k)).count();
+    if kept == pairs.len() {
+        return Ok(obj);
+    }
+    if kept == 0 {
+        return Ok(crate::arena::singletons::singleton_empty_object());
+    }
+    let mut out = bvec::<(&'a str, DataValue<'a>)>(arena, kept);
+    out.extend(pairs.iter().filter(|(k, _)| keep(k)).copied());
+    Ok(arena.alloc(DataValue::Object(out.into_bump_slice())))
+}
@@ -7,8 +7,8 @@ use bumpalo::Bump;
 use std::ops::ControlFlow;
 
 use super::helpers::{
-    FastPredicate, IterArgKind, ResolvedInput, for_each_iter_array, for_each_iter_object,
-    resolve_iter_input,
+    FastPredicate, IterArgKind, ResolvedInput, fast_paths_allowed, for_each_iter_array,
+    for_each_iter_object, resolve_iter_input,
 };
 
 /// Shape of a quantifier (`all` / `some` / `none`) — the three flags
@@ -78,7 +78,7 @@ fn evaluate_quantifier<'a>(
     // when a tracer is attached so iteration markers still get recorded.
     // An indeterminate item (see `FastPredicate::evaluate_opt`) drops to
     // the general loop below, which is exact: fast evaluation is pure.
-    if !ctx.is_tracing() {
+    if fast_paths_allowed(ctx, engine) {
         if let Some(fast_pred) = FastPredicate::from_node(predicate) {
             let len = src.len();
             let mut verdict = Some(false);
@@ -226,3 +226,104 @@ pub(crate) fn evaluate_none<'a>(
         },
     )
 }
+
+/// Shared body of `all_values` / `some_values` / `none_values`: the
+/// quantifier `shape` applied to an object's values. Each value runs under
+/// the same keyed frame as object iteration in `all` / `some` / `none`, so
+/// the predicate reads the value as `{"var": ""}` and its key as
+/// `{"val": [[1], "key"]}`. A null or missing input counts as an empty
+/// object; arrays and other non-objects are an error.
+#[cfg(feature = "ext-array")]
+#[inline]
+fn evaluate_value_quantifier<'a>(
+    args: &'a [CompiledNode],
+    iter_arg_kind: IterArgKind,
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+    shape: QuantifierShape,
+) -> Result<&'a DataValue<'a>> {
+    if args.len() != 2 {
+        return Err(crate::Error::invalid_args());
+    }
+    match resolve_iter_input(&args[0], iter_arg_kind, ctx, engine, arena)? {
+        ResolvedInput::Empty => Ok(singleton_bool(shape.empty_result)),
+        ResolvedInput::Bridge(av @ DataValue::Object(_)) => {
+            quantifier_arena_bridge(av, &args[1], shape, ctx, engine, arena)
+        }
+        _ => Err(crate::Error::invalid_args()),
+    }
+}
+
+/// `all_values` — true iff every value of the object satisfies the
+/// predicate. Same shape as `all`, so an empty object is `false`.
+#[cfg(feature = "ext-array")]
+#[inline]
+pub(crate) fn evaluate_all_values<'a>(
+    args: &'a [CompiledNode],
+    iter_arg_kind: IterArgKind,
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    evaluate_value_quantifier(
+        args,
+        iter_arg_kind,
+        ctx,
+        engine,
+        arena,
+        QuantifierShape {
+            short_circuit_on: false,
+            invert_final: false,
+            empty_result: false,
+        },
+    )
+}
+
+/// `some_values` — true iff any value of the object satisfies the predicate.
+#[cfg(feature = "ext-array")]
+#[inline]
+pub(crate) fn evaluate_some_values<'a>(
+    args: &'a [CompiledNode],
+    iter_arg_kind: IterArgKind,
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    evaluate_value_quantifier(
+        args,
+        iter_arg_kind,
+        ctx,
+        engine,
+        arena,
+        QuantifierShape {
+            short_circuit_on: true,
+            invert_final: false,
+            empty_result: false,
+        },
+    )
+}
+
+/// `none_values` — true iff no value of the object satisfies the predicate.
+#[cfg(feature = "ext-array")]
+#[inline]
+pub(crate) fn evaluate_none_values<'a>(
+    args: &'a [CompiledNode],
+    iter_arg_kind: IterArgKind,
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    evaluate_value_quantifier(
+        args,
+        iter_arg_kind,
+        ctx,
+        engine,
+        arena,
+        QuantifierShape {
+            short_circuit_on: true,
+            invert_final: true,
+            empty_result: true,
+        },
+    )
+}
@@ -1,13 +1,16 @@
-//! `reduce` — fold an array into a single value via an accumulator.
+//! `reduce` — fold an array into a single value via an accumulator — and
+//! `reduce_while`, which stops the fold once a condition turns falsy.
 
 use crate::arena::{ContextStack, DataValue, IterGuard};
 use crate::node::{PathSegment, ReduceHint};
 use crate::opcode::OpCode;
 use crate::{CompiledNode, Engine, Result};
 use bumpalo::Bump;
+use datavalue::NumberValue;
 
 use super::helpers::{
-    FieldCursor, FusedMapBody, IterArgKind, IterSrc, ResolvedInput, resolve_iter_input,
+    FieldCursor, FusedMapBody, IterArgKind, IterSrc, ResolvedInput, fast_paths_allowed,
+    resolve_iter_input,
 };
 
 /// `reduce` — folds an array into a single value via an accumulator. Input
@@ -40,7 +43,7 @@ pub(crate) fn evaluate_reduce<'a>(
     // input (the established fast-path precedent — fires only on
     // non-numeric data). The inline candidate pre-check keeps non-pipeline
     // reduces at two discriminant compares.
-    if !ctx.is_tracing() && is_map_candidate(&args[0]) {
+    if fast_paths_allowed(ctx, engine) && is_map_candidate(&args[0]) {
         match try_fused_reduce_map(args, initial, ctx, engine, arena)? {
             FusedOutcome::Done(value) => return Ok(value),
             FusedOutcome::Bail => {}
@@ -51,7 +54,7 @@ pub(crate) fn evaluate_reduce<'a>(
         ResolvedInput::Iterable(s) => s,
         ResolvedInput::Empty => return Ok(initial),
         ResolvedInput::Bridge(av) => {
-            return reduce_arena_bridge(av, body, initial, ctx, engine, arena);
+            return reduce_arena_bridge(av, body, initial, None, ctx, engine, arena);
         }
     };
 
@@ -63,22 +66,60 @@ pub(crate) fn evaluate_reduce<'a>(
     // operand order for + / - / *. Skipped when a tracer is attached so
     // per-iteration trace markers still get recorded via `run_iter_body` in
     // the general path.
-    if !ctx.is_tracing() {
+    if fast_paths_allowed(ctx, engine) {
         if let Some(result) = try_reduce_fast_path(&src, initial, body, arena) {
             return Ok(result);
         }
     }
 
-    reduce_general(&src, body, initial, ctx, engine, arena)
+    reduce_general(&src, body, initial, None, ctx, engine, arena)
+}
+
+/// `reduce_while`. `{"reduce_while": [array, reducer, initial, condition]}`
+/// folds like `reduce`, but evaluates `condition` before each item under
+/// the same frame the reducer sees (`current`, `accumulator`, and the
+/// metadata), and stops at the first falsy result, returning the
+/// accumulator as it stands. Items after that are never visited.
+///
+/// Skips `reduce`'s fused and arithmetic fast paths, which have no stopping
+/// point; objects iterate their entries as in `reduce`.
+#[cfg(feature = "ext-array")]
+#[inline]
+pub(crate) fn evaluate_reduce_while<'a>(
+    args: &'a [CompiledNode],
+    iter_arg_kind: IterArgKind,
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    if args.len() != 4 {
+        return Err(crate::Error::invalid_args());
+    }
+
+    let body = &args[1];
+    let initial = engine.dispatch_node(&args[2], ctx, arena)?;
+    let condition = Some(&args[3]);
+
+    match resolve_iter_input(&args[0], iter_arg_kind, ctx, engine, arena)? {
+        ResolvedInput::Iterable(src) => {
+            reduce_general(&src, body, initial, condition, ctx, engine, arena)
+        }
+        ResolvedInput::Empty => Ok(initial),
+        ResolvedInput::Bridge(av) => {
+            reduce_arena_bridge(av, body, initial, condition, ctx, engine, arena)
+        }
+    }
 }
 
 /// General reduce path — push reduce frames via `IterGuard` and dispatch the
-/// body per item.
+/// body per item. With a `condition` (`reduce_while`), it is evaluated under
+/// each item's frame first and a falsy result ends the fold.
 #[inline]
 fn reduce_general<'a>(
     src: &IterSrc<'a>,
     body: &'a CompiledNode,
     initial: &'a DataValue<'a>,
+    condition: Option<&'a CompiledNode>,
     ctx: &mut ContextStack<'a>,
     engine: &Engine,
     arena: &'a Bump,
@@ -89,13 +130,34 @@ fn reduce_general<'a>(
     let mut guard = IterGuard::new(ctx);
     for i in 0..len {
         let item = src.get(i);
-        guard.step_reduce(item, acc_av);
+        guard.step_reduce(item, acc_av, i, None);
+        if !keep_folding(condition, guard.stack(), engine, arena)? {
+            break;
+        }
         acc_av = engine.run_iter_body(body, guard.stack(), arena, i as u32, total)?;
     }
     drop(guard);
     Ok(acc_av)
 }
 
+/// `reduce_while`'s per-item check: `true` when there is no condition (plain
+/// `reduce`) or it evaluates truthy under the current reduce frame.
+#[inline(always)]
+fn keep_folding<'a>(
+    condition: Option<&'a CompiledNode>,
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<bool> {
+    match condition {
+        None => Ok(true),
+        Some(node) => {
+            let av = engine.dispatch_node(node, ctx, arena)?;
+            Ok(crate::arena::truthy_arena(av, engine))
+        }
+    }
+}
+
 /// Reduce Bridge case — Object inputs iterate (key, value) pairs. The Bridge
 /// variant is only produced for non-null, non-array values (`value_as_iter`
 /// routes Null to Empty and Array to Iterable), so every other shape returns
@@ -105,6 +167,7 @@ fn reduce_arena_bridge<'a>(
     input: &'a DataValue<'a>,
     body: &'a CompiledNode,
     initial: &'a DataValue<'a>,
+    condition: Option<&'a CompiledNode>,
     ctx: &mut ContextStack<'a>,
     engine: &Engine,
     arena: &'a Bump,
@@ -114,8 +177,11 @@ fn reduce_arena_bridge<'a>(
             let total = pairs.len() as u32;
             let mut acc_av: &'a DataValue<'a> = initial;
             let mut guard = IterGuard::new(ctx);
-            for (i, (_k, v)) in pairs.iter().enumerate() {
-                guard.step_reduce(v, acc_av);
+            for (i, (k, v)) in pairs.iter().enumerate() {
+                guard.step_reduce(v, acc_av, i, Some(k));
+                if !keep_folding(condition, guard.stack(), engine, arena)? {
+                    break;
+                }
                 acc_av = engine.run_iter_body(body, guard.stack(), arena, i as u32, total)?;
             }
             drop(guard);
@@ -213,8 +279,8 @@ fn try_fused_reduce_map<'a>(
 enum FusedRestart {
     /// Fold overflowed (or the initial value is fractional) while every
     /// mapped value so far was integral: keep exact integer map results and
-    /// fold them in f64 — exactly what the unfused pipeline does when it
-    /// f64-folds a materialized `Integer` array.
+    /// fold them step by step with [`fold_number`] — exactly what the unfused
+    /// pipeline does when it folds a materialized `Integer` array.
     IntMapF64Fold,
     /// A mapped value wasn't integral: the unfused map would have produced
     /// the whole intermediate array in f64, so recompute everything in f64.
@@ -223,9 +289,11 @@ enum FusedRestart {
 
 /// The fused loops. Three modes mirror the unfused pipeline's
 /// representation choices so outputs stay bit-identical:
-/// integer map + integer fold; integer map + f64 fold (after fold overflow
-/// or a fractional initial); whole-array f64 map + f64 fold (once any
-/// mapped value is non-integral). Anything non-numeric bails.
+/// integer map + integer fold; integer map + mixed fold (after fold overflow
+/// or a fractional initial); whole-array f64 map + mixed fold (once any
+/// mapped value is non-integral). The mixed fold renormalizes after every
+/// step, so an accumulator that returns to an integer continues in exact
+/// integer math. Anything non-numeric bails.
 fn run_fused_fold<'a>(
     src: &IterSrc<'a>,
     initial: &'a DataValue<'a>,
@@ -272,20 +340,19 @@ fn run_fused_fold<'a>(
             acc = next;
         }
         return FusedOutcome::Done(
-            crate::arena::singletons::singleton_small_int(acc).unwrap_or_else(|| {
-                &*arena.alloc(DataValue::Number(datavalue::NumberValue::from_i64(acc)))
-            }),
+            crate::arena::singletons::singleton_small_int(acc)
+                .unwrap_or_else(|| &*arena.alloc(DataValue::Number(NumberValue::from_i64(acc)))),
         );
     };
 
-    let Some(init_f) = initial.as_f64() else {
+    let Some(&init_n) = initial.as_number() else {
         return FusedOutcome::Bail;
     };
 
     // Integer-map / f64-fold mode.
     if matches!(restart, FusedRestart::IntMapF64Fold) {
         if let Some(lit_i) = lit_i {
-            let mut acc_f = init_f;
+            let mut acc = init_n;
             let mut all_int = true;
             for i in 0..len {
                 let item = src.get(i);
@@ -293,12 +360,10 @@ fn run_fused_fold<'a>(
                     all_int = false;
                     break;
                 };
-                acc_f = fold_f64(op, acc_is_lhs, acc_f, mapped as f64);
+                acc = fold_number(op, acc_is_lhs, acc, NumberValue::Integer(mapped));
             }
             if all_int {
-                return FusedOutcome::Done(
-                    arena.alloc(DataValue::Number(datavalue::NumberValue::from_f64(acc_f))),
-                );
+                return FusedOutcome::Done(arena.alloc(DataValue::Number(acc)));
             }
             // A non-integral value past the overflow point: the unfused map
             // would have produced the whole array in f64 — fall through.
@@ -306,15 +371,15 @@ fn run_fused_fold<'a>(
     }
 
     // Full f64 mode.
-    let mut acc_f = init_f;
+    let mut acc = init_n;
     for i in 0..len {
         let item = src.get(i);
         let Some(mapped) = mapped_f64(map_body, &mut cursors, item, lit_f) else {
             return FusedOutcome::Bail;
         };
-        acc_f = fold_f64(op, acc_is_lhs, acc_f, mapped);
+        acc = fold_number(op, acc_is_lhs, acc, NumberValue::from_f64(mapped));
     }
-    FusedOutcome::Done(arena.alloc(DataValue::Number(datavalue::NumberValue::from_f64(acc_f))))
+    FusedOutcome::Done(arena.alloc(DataValue::Number(acc)))
 }
 
 /// Field cursors for the map body's var operands; persist across mode
@@ -439,6 +504,21 @@ fn fold_f64(op: OpCode, acc_is_lhs: bool, acc: f64, cur: f64) -> f64 {
     }
 }
 
+/// One fold step with the general arithmetic path's representation rules:
+/// integer math when both sides are integral (promoting to f64 only on
+/// overflow), f64 otherwise, and the result renormalized through
+/// `NumberValue::from_f64`. An f64 intermediate that lands on an integer
+/// therefore continues in exact integer math, as it does unfused.
+#[inline(always)]
+fn fold_number(op: OpCode, acc_is_lhs: bool, acc: NumberValue, cur: NumberValue) -> NumberValue {
+    if let (Some(a), Some(c)) = (acc.as_i64(), cur.as_i64()) {
+        if let Some(r) = fold_i64(op, acc_is_lhs, a, c) {
+            return NumberValue::from_i64(r);
+        }
+    }
+    NumberValue::from_f64(fold_f64(op, acc_is_lhs, acc.as_f64(), cur.as_f64()))
+}
+
 /// Detected `{+|-|*: [var, var]}` fold body over `current`/`accumulator`,
 /// operand order preserved.
 struct FoldShape<'a> {
@@ -560,25 +640,19 @@ fn try_reduce_fast_path<'a>(
         if all_int {
             return Some(
                 crate::arena::singletons::singleton_small_int(acc).unwrap_or_else(|| {
-                    &*arena.alloc(DataValue::Number(datavalue::NumberValue::from_i64(acc)))
+                    &*arena.alloc(DataValue::Number(NumberValue::from_i64(acc)))
                 }),
             );
         }
     }
 
-    // f64 fallback.
-    let mut acc_f = initial.as_f64()?;
+    // Mixed fallback — each step follows the general path's int/f64 choice.
+    let mut acc = *initial.as_number()?;
     for i in 0..len {
         let item = src.get(i);
         let current_val = current_field.resolve(item)?;
-        let cur_f = current_val.as_f64()?;
-        acc_f = match op {
-            OpCode::Add => acc_f + cur_f,
-            OpCode::Multiply => acc_f * cur_f,
-            OpCode::Subtract if acc_is_lhs => acc_f - cur_f,
-            OpCode::Subtract => cur_f - acc_f,
-            _ => return None,
-        };
+        let cur = *current_val.as_number()?;
+        acc = fold_number(op, acc_is_lhs, acc, cur);
     }
-    Some(arena.alloc(DataValue::Number(datavalue::NumberValue::from_f64(acc_f))))
+    Some(arena.alloc(DataValue::Number(acc)))
 }
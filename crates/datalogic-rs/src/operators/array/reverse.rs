@@ -0,0 +1,30 @@
+//! `reverse` — array items or string chars in reverse order.
+
+use crate::arena::{ContextStack, DataValue};
+use crate::{CompiledNode, Engine, Result};
+use bumpalo::Bump;
+
+use super::slice::{slice_array, slice_string};
+
+/// Arena-mode `reverse`. Shorthand for `{"slice": [x, null, null, -1]}` and
+/// shares its whole-collection reversal path: arrays reverse item order,
+/// strings reverse by char (not byte), matching `slice`. Null passes through;
+/// any other input is an error.
+#[inline]
+pub(crate) fn evaluate_reverse<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    if args.len() != 1 {
+        return Err(crate::Error::invalid_args());
+    }
+
+    match engine.dispatch_node(&args[0], ctx, arena)? {
+        DataValue::Array(items) => Ok(slice_array(items, None, None, -1, arena)),
+        DataValue::String(s) => Ok(slice_string(s, None, None, -1, arena)),
+        DataValue::Null => Ok(crate::arena::singletons::singleton_null()),
+        _ => Err(crate::Error::invalid_args()),
+    }
+}
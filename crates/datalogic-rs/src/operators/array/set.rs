@@ -0,0 +1,109 @@
+//! `set` — copy an object or array with one value written at a path.
+
+use crate::arena::{ContextStack, DataValue, bvec};
+use crate::compile::path_segments::{parse_path_segments, segment_from_value};
+use crate::node::PathSegment;
+use crate::{CompiledNode, Engine, Result};
+use bumpalo::Bump;
+
+/// Arena-mode `set`. `{"set": [target, path, value]}` returns a copy of
+/// `target` with `value` written at `path`; the input is never mutated.
+/// `path` is a dot-separated string parsed exactly like a `var` path, or an
+/// array of keys and indices for keys that contain dots.
+///
+/// Only the containers along the path are rebuilt — everything beside it is
+/// re-borrowed from `target`. Missing or null levels are created as
+/// objects. A numeric segment indexes an array (an index equal to the
+/// length appends) and is an ordinary key on an object. An empty path
+/// returns `value`; a null `target` starts from `{}`. Stepping into a
+/// scalar, a non-numeric segment on an array, or an index past the end is
+/// an error.
+#[inline]
+pub(crate) fn evaluate_set<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    if args.len() != 3 {
+        return Err(crate::Error::invalid_args());
+    }
+
+    let target = engine.dispatch_node(&args[0], ctx, arena)?;
+    let segments = match engine.dispatch_node(&args[1], ctx, arena)? {
+        DataValue::String(path) => parse_path_segments(path),
+        DataValue::Array(items) => items
+            .iter()
+            .map(|item| segment_from_value(item).ok_or_else(crate::Error::invalid_args))
+            .collect::<Result<Vec<_>>>()?,
+        _ => return Err(crate::Error::invalid_args()),
+    };
+    let value = engine.dispatch_node(&args[2], ctx, arena)?;
+    if segments.is_empty() {
+        return Ok(value);
+    }
+
+    let updated = set_at(Some(target), &segments, *value, arena)?;
+    Ok(arena.alloc(updated))
+}
+
+/// Rebuild `cur` with `value` written at `segments`, recursing one level
+/// per segment.
+fn set_at<'a>(
+    cur: Option<&'a DataValue<'a>>,
+    segments: &[PathSegment],
+    value: DataValue<'a>,
+    arena: &'a Bump,
+) -> Result<DataValue<'a>> {
+    let Some((seg, rest)) = segments.split_first() else {
+        return Ok(value);
+    };
+    match cur {
+        Some(DataValue::Object(pairs)) => {
+            let key = segment_key(seg);
+            let mut out = bvec::<(&'a str, DataValue<'a>)>(arena, pairs.len() + 1);
+            out.extend_from_slice(pairs);
+            match pairs.iter().position(|(k, _)| *k == key) {
+                Some(i) => out[i].1 = set_at(Some(&pairs[i].1), rest, value, arena)?,
+                None => out.push((arena.alloc_str(&key), set_at(None, rest, value, arena)?)),
+            }
+            Ok(DataValue::Object(out.into_bump_slice()))
+        }
+        Some(DataValue::Array(items)) => {
+            let idx = match seg {
+                PathSegment::Index(idx) | PathSegment::FieldOrIndex(_, idx) => *idx,
+                PathSegment::FieldOrIndexFromEnd(_, back) => items
+                    .len()
+                    .checked_sub(*back)
+                    .ok_or_else(crate::Error::invalid_args)?,
+                PathSegment::Field(_) => return Err(crate::Error::invalid_args()),
+            };
+            if idx > items.len() {
+                return Err(crate::Error::invalid_args());
+            }
+            let mut out = bvec::<DataValue<'a>>(arena, items.len() + 1);
+            out.extend_from_slice(items);
+            match items.get(idx) {
+                Some(item) => out[idx] = set_at(Some(item), rest, value, arena)?,
+                None => out.push(set_at(None, rest, value, arena)?),
+            }
+            Ok(DataValue::Array(out.into_bump_slice()))
+        }
+        None | Some(DataValue::Null) => {
+            let key = arena.alloc_str(&segment_key(seg));
+            let pair = arena.alloc([(&*key, set_at(None, rest, value, arena)?)]);
+            Ok(DataValue::Object(pair))
+        }
+        Some(_) => Err(crate::Error::invalid_args()),
+    }
+}
+
+/// The object key a segment addresses.
+fn segment_key(seg: &PathSegment) -> std::borrow::Cow<'_, str> {
+    match seg {
+        PathSegment::Field(key)
+        | PathSegment::FieldOrIndex(key, _)
+        | PathSegment::FieldOrIndexFromEnd(key, _) => key.as_ref().into(),
+        PathSegment::Index(idx) => idx.to_string().into(),
+    }
+}
@@ -57,7 +57,7 @@ pub(crate) fn evaluate_slice<'a>(
 
 /// Composite arena array — slice through the arena items.
 #[inline]
-fn slice_array<'a>(
+pub(super) fn slice_array<'a>(
     items: &'a [DataValue<'a>],
     start: Option<i64>,
     end: Option<i64>,
@@ -78,6 +78,16 @@ fn slice_array<'a>(
         return arena.alloc(DataValue::Array(&items[s as usize..e as usize]));
     }
 
+    // Whole-array reversal (`[::-1]`, and `reverse`): copy the items back to
+    // front without building an index Vec.
+    if step == -1 && start.is_none() && end.is_none() {
+        if items.is_empty() {
+            return crate::arena::singletons::singleton_empty_array();
+        }
+        let out = arena.alloc_slice_fill_iter(items.iter().rev().copied());
+        return arena.alloc(DataValue::Array(out));
+    }
+
     let indices = slice_indices(len, start, end, step);
     if indices.is_empty() {
         return crate::arena::singletons::singleton_empty_array();
@@ -92,7 +102,7 @@ fn slice_array<'a>(
 /// String slice — char-indexed; the contiguous `step == 1` case borrows a
 /// sub-slice of the source, other steps allocate the result in the arena.
 #[inline]
-fn slice_string<'a>(
+pub(super) fn slice_string<'a>(
     s: &'a str,
     start: Option<i64>,
     end: Option<i64>,
@@ -114,6 +124,17 @@ fn slice_string<'a>(
         return arena.alloc(DataValue::String(&s[byte_a..byte_b]));
     }
 
+    // Whole-string reversal: push chars back to front straight into the
+    // arena, skipping the char and index Vecs.
+    if step == -1 && start.is_none() && end.is_none() {
+        if s.is_empty() {
+            return crate::arena::singletons::singleton_empty_string();
+        }
+        let mut buf = bumpalo::collections::String::with_capacity_in(s.len(), arena);
+        buf.extend(s.chars().rev());
+        return arena.alloc(DataValue::String(buf.into_bump_str()));
+    }
+
     let chars: Vec<char> = s.chars().collect();
     let indices = slice_indices(chars.len() as i64, start, end, step);
     let result_string: String = indices.iter().map(|&i| chars[i as usize]).collect();
@@ -122,7 +143,7 @@ fn slice_string<'a>(
 }
 
 #[inline]
-fn extract_opt_i64_arena<'a>(
+pub(super) fn extract_opt_i64_arena<'a>(
     node: &'a CompiledNode,
     ctx: &mut ContextStack<'a>,
     engine: &Engine,
@@ -182,7 +203,7 @@ fn slice_indices(len: i64, start: Option<i64>, end: Option<i64>, step: i64) -> V
 
 /// Normalize slice indices with overflow protection.
 #[inline]
-fn normalize_index(index: i64, len: i64) -> i64 {
+pub(super) fn normalize_index(index: i64, len: i64) -> i64 {
     if index < 0 {
         // Use saturating_add to prevent overflow when index is very negative
         let adjusted = len.saturating_add(index);
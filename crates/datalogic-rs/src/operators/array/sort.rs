@@ -14,6 +14,10 @@ use super::helpers::{IterArgKind, IterSrc, ResolvedInput, resolve_iter_input};
 /// the original arena items in their sorted order — avoids a deep-clone of
 /// the input array, which dominates for object arrays.
 ///
+/// Every mode is stable: items whose keys compare equal keep their input
+/// order. An array second argument selects [`sort_multi_key`] instead of
+/// a direction flag, and `"compare"` selects [`sort_comparator`].
+///
 /// Fast path (extractor is a root-scope `var`): keys come from
 /// `traverse_segments` returning `&DataValue` directly, no key clones.
 ///
@@ -55,7 +59,28 @@ pub(crate) fn evaluate_sort<'a>(
         return Ok(crate::arena::singletons::singleton_empty_array());
     }
 
-    let ascending = sort_direction(args, ctx, engine, arena)?;
+    // An array second argument is a multi-key spec in place of the
+    // direction flag; it doesn't combine with an extractor.
+    let direction = match args.get(1) {
+        Some(node) => Some(engine.dispatch_node(node, ctx, arena)?),
+        None => None,
+    };
+    if let Some(DataValue::Array(spec)) = direction {
+        if args.len() > 2 {
+            return Err(crate::Error::invalid_args());
+        }
+        return sort_multi_key(&src, spec, arena);
+    }
+    // `"compare"` in the direction slot makes the third argument an `a`/`b`
+    // comparator instead of a key extractor.
+    if let Some(DataValue::String("compare")) = direction {
+        if args.len() != 3 {
+            return Err(crate::Error::invalid_args());
+        }
+        return sort_comparator(&src, &args[2], ctx, engine, arena);
+    }
+    // Any non-boolean direction falls back to ascending.
+    let ascending = !matches!(direction, Some(DataValue::Bool(false)));
 
     // No extractor — sort items directly by DataValue order.
     if args.len() <= 2 {
@@ -65,34 +90,18 @@ pub(crate) fn evaluate_sort<'a>(
     let extractor = &args[2];
 
     // Fast path: extractor is a root-scope `var` over non-empty segments —
-    // keys come from `traverse_segments` directly.
-    if let Some(result) = sort_fast_path_var_extractor(&src, extractor, ascending, arena) {
-        return Ok(result);
+    // keys come from `traverse_segments` directly. A missing key sorts
+    // first there, so `strict_variables` takes the general path instead.
+    if !engine.config().strict_variables {
+        if let Some(result) = sort_fast_path_var_extractor(&src, extractor, ascending, arena) {
+            return Ok(result);
+        }
     }
 
     // General extractor — push each item, evaluate, collect keys, sort indices.
     sort_general_extractor(&src, extractor, ascending, ctx, engine, arena)
 }
 
-/// Read the optional `args[1]` direction flag — defaults to ascending.
-#[inline]
-fn sort_direction<'a>(
-    args: &'a [CompiledNode],
-    ctx: &mut ContextStack<'a>,
-    engine: &Engine,
-    arena: &'a Bump,
-) -> Result<bool> {
-    if args.len() > 1 {
-        let dir = engine.dispatch_node(&args[1], ctx, arena)?;
-        Ok(match dir {
-            DataValue::Bool(b) => *b,
-            _ => true,
-        })
-    } else {
-        Ok(true)
-    }
-}
-
 #[inline]
 fn sort_no_extractor<'a>(src: &IterSrc<'a>, ascending: bool, arena: &'a Bump) -> &'a DataValue<'a> {
     let len = src.len();
@@ -169,12 +178,7 @@ fn sort_fast_path_var_extractor<'a>(
         )
     }));
     keyed.sort_by(|(_, ka), (_, kb)| {
-        let cmp = match (ka, kb) {
-            (Some(a), Some(b)) => compare_values(a, b),
-            (Some(_), None) => Ordering::Greater,
-            (None, Some(_)) => Ordering::Less,
-            (None, None) => Ordering::Equal,
-        };
+        let cmp = compare_keys(*ka, *kb);
         if ascending { cmp } else { cmp.reverse() }
     });
     let slice = arena.alloc_slice_fill_iter(keyed.iter().map(|&(i, _)| *src.get(i)));
@@ -211,10 +215,179 @@ fn sort_general_extractor<'a>(
     Ok(arena.alloc(DataValue::Array(slice)))
 }
 
+/// One `[field, direction]` entry of a multi-key sort spec.
+struct SortKey<'a> {
+    /// Dotted path into each item, resolved like `{"var": path}`.
+    path: &'a str,
+    ascending: bool,
+}
+
+/// Parse a multi-key spec: each entry is `[field, direction]` or a bare
+/// `field` (ascending). `direction` is `"asc"` / `"desc"` or a boolean like
+/// the single-key direction flag. Anything else is `InvalidArguments`.
+fn parse_sort_keys<'a>(
+    spec: &'a [DataValue<'a>],
+    arena: &'a Bump,
+) -> Result<bumpalo::collections::Vec<'a, SortKey<'a>>> {
+    let mut keys = bvec::<SortKey<'a>>(arena, spec.len());
+    for entry in spec {
+        let (field, direction) = match entry {
+            DataValue::String(field) => (field, None),
+            DataValue::Array([DataValue::String(field)]) => (field, None),
+            DataValue::Array([DataValue::String(field), direction]) => (field, Some(direction)),
+            _ => return Err(crate::Error::invalid_args()),
+        };
+        let ascending = match direction {
+            None | Some(DataValue::Bool(true)) => true,
+            Some(DataValue::Bool(false)) => false,
+            Some(DataValue::String(d)) if d.eq_ignore_ascii_case("asc") => true,
+            Some(DataValue::String(d)) if d.eq_ignore_ascii_case("desc") => false,
+            Some(_) => return Err(crate::Error::invalid_args()),
+        };
+        keys.push(SortKey {
+            path: field,
+            ascending,
+        });
+    }
+    Ok(keys)
+}
+
+/// Multi-key sort: `{"sort": [array, [["a", "asc"], ["b", "desc"]]]}`.
+/// Items order by the first key; ties fall through to the next key, each
+/// with its own direction, and items tied on every key keep their input
+/// order. Keys are read once per item up front, so the comparator only
+/// compares. A missing field sorts before any value, as in the `var`
+/// extractor fast path.
+fn sort_multi_key<'a>(
+    src: &IterSrc<'a>,
+    spec: &'a [DataValue<'a>],
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    let keys = parse_sort_keys(spec, arena)?;
+    let len = src.len();
+    let width = keys.len();
+
+    // Row-major: the keys of item `i` are `values[i * width..][..width]`.
+    let mut values = bvec::<Option<&'a DataValue<'a>>>(arena, len * width);
+    for i in 0..len {
+        let item = src.get(i);
+        values.extend(
+            keys.iter()
+                .map(|key| crate::arena::value::access_path_str_ref(item, key.path)),
+        );
+    }
+
+    let mut indices = bvec::<usize>(arena, len);
+    indices.extend(0..len);
+    indices.sort_by(|&a, &b| {
+        let (row_a, row_b) = (&values[a * width..][..width], &values[b * width..][..width]);
+        keys.iter()
+            .zip(row_a.iter().zip(row_b))
+            .map(|(key, (ka, kb))| {
+                let cmp = compare_keys(*ka, *kb);
+                if key.ascending { cmp } else { cmp.reverse() }
+            })
+            .find(|cmp| cmp.is_ne())
+            .unwrap_or(Ordering::Equal)
+    });
+    let slice = arena.alloc_slice_fill_iter(indices.iter().map(|&i| *src.get(i)));
+    Ok(arena.alloc(DataValue::Array(slice)))
+}
+
+/// Comparator sort: `{"sort": [array, "compare", comparator]}`. The
+/// comparator runs with `{"var": "a"}` and `{"var": "b"}` bound to the two
+/// items and returns a number: negative puts `a` first, positive puts `b`
+/// first, zero keeps their input order. A non-numeric result is a `NaN`
+/// error.
+///
+/// Sorts with [`merge_sort_by`] rather than `slice::sort_by`, so a
+/// comparator error stops the sort and an inconsistent comparator yields
+/// some order instead of a panic.
+fn sort_comparator<'a>(
+    src: &IterSrc<'a>,
+    comparator: &'a CompiledNode,
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    let len = src.len();
+    let mut indices = bvec::<usize>(arena, len);
+    indices.extend(0..len);
+    let mut scratch = bvec::<usize>(arena, len);
+    scratch.extend(0..len);
+
+    merge_sort_by(&mut indices, &mut scratch, &mut |a, b| {
+        let pair: &'a [(&'a str, DataValue<'a>)] =
+            arena.alloc([("a", *src.get(a)), ("b", *src.get(b))]);
+        ctx.push(arena.alloc(DataValue::Object(pair)));
+        // Pop before propagating, so a caught error doesn't leave the
+        // comparator frame on the stack.
+        let result = engine.dispatch_node(comparator, ctx, arena);
+        ctx.pop();
+        match result? {
+            DataValue::Number(n) => Ok(n.as_f64().partial_cmp(&0.0).unwrap_or(Ordering::Equal)),
+            _ => Err(crate::Error::nan()),
+        }
+    })?;
+
+    let slice = arena.alloc_slice_fill_iter(indices.iter().map(|&i| *src.get(i)));
+    Ok(arena.alloc(DataValue::Array(slice)))
+}
+
+/// Stable top-down merge sort of `items` by a fallible comparator, using
+/// `scratch` (same length) as the merge buffer. Returns the first
+/// comparator error. Each merge takes from the right run only on a strict
+/// `Less`, so equal items keep their order.
+fn merge_sort_by<T: Copy>(
+    items: &mut [T],
+    scratch: &mut [T],
+    cmp: &mut impl FnMut(T, T) -> Result<Ordering>,
+) -> Result<()> {
+    let len = items.len();
+    if len <= 1 {
+        return Ok(());
+    }
+    let mid = len / 2;
+    {
+        let (left, right) = items.split_at_mut(mid);
+        let (scratch_left, scratch_right) = scratch.split_at_mut(mid);
+        merge_sort_by(left, scratch_left, cmp)?;
+        merge_sort_by(right, scratch_right, cmp)?;
+    }
+    let (mut i, mut j, mut k) = (0, mid, 0);
+    while i < mid && j < len {
+        if cmp(items[j], items[i])? == Ordering::Less {
+            scratch[k] = items[j];
+            j += 1;
+        } else {
+            scratch[k] = items[i];
+            i += 1;
+        }
+        k += 1;
+    }
+    // The rest of the left run goes next; the rest of the right run is
+    // already in place at `items[j..]`, and `k == j` after this copy.
+    scratch[k..k + (mid - i)].copy_from_slice(&items[i..mid]);
+    k += mid - i;
+    items[..k].copy_from_slice(&scratch[..k]);
+    Ok(())
+}
+
+/// Compare two extracted keys, with a missing key before any value.
+#[inline]
+fn compare_keys(a: Option<&DataValue<'_>>, b: Option<&DataValue<'_>>) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => compare_values(a, b),
+        (Some(_), None) => Ordering::Greater,
+        (None, Some(_)) => Ordering::Less,
+        (None, None) => Ordering::Equal,
+    }
+}
+
 /// Compare arena values for sorting.
 /// Type order: null < bool < number < string < array < object.
 #[inline]
-fn compare_values(a: &DataValue<'_>, b: &DataValue<'_>) -> Ordering {
+pub(super) fn compare_values(a: &DataValue<'_>, b: &DataValue<'_>) -> Ordering {
     #[inline]
     fn type_rank(v: &DataValue<'_>) -> u8 {
         match v {
@@ -0,0 +1,52 @@
+//! `take` / `drop` — head and tail slices by element count.
+
+use crate::arena::{ContextStack, DataValue};
+use crate::{CompiledNode, Engine, Result};
+use bumpalo::Bump;
+
+use super::slice::{extract_opt_i64_arena, slice_array, slice_string};
+
+/// `take` / `drop` discriminant for the shared entry point.
+#[derive(Clone, Copy)]
+pub(crate) enum TakeDropOp {
+    Take,
+    Drop,
+}
+
+/// Arena-mode `take` / `drop`. `{"take": [x, n]}` keeps the first `n`
+/// elements and `{"drop": [x, n]}` everything after them; a negative `n`
+/// counts from the end instead (`take` keeps the last `|n|`, `drop` removes
+/// them), so `take` and `drop` with the same `n` always partition the input.
+///
+/// Both lower onto `slice` with a step of 1, so counts past either end clamp
+/// to an empty or full result, arrays come back as views over the input, and
+/// strings are cut by char. Null passes through, as in `slice`.
+#[inline]
+pub(crate) fn take_drop<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+    op: TakeDropOp,
+) -> Result<&'a DataValue<'a>> {
+    if args.len() != 2 {
+        return Err(crate::Error::invalid_args());
+    }
+
+    let coll = engine.dispatch_node(&args[0], ctx, arena)?;
+    let Some(n) = extract_opt_i64_arena(&args[1], ctx, engine, arena)? else {
+        return Err(crate::Error::invalid_args());
+    };
+    // Head `[..n]` vs tail `[n..]`, in slice's index convention.
+    let (start, end) = match (op, n >= 0) {
+        (TakeDropOp::Take, true) | (TakeDropOp::Drop, false) => (None, Some(n)),
+        (TakeDropOp::Take, false) | (TakeDropOp::Drop, true) => (Some(n), None),
+    };
+
+    match coll {
+        DataValue::Array(items) => Ok(slice_array(items, start, end, 1, arena)),
+        DataValue::String(s) => Ok(slice_string(s, start, end, 1, arena)),
+        DataValue::Null => Ok(crate::arena::singletons::singleton_null()),
+        _ => Err(crate::Error::invalid_args()),
+    }
+}
@@ -0,0 +1,55 @@
+//! `unique` — drop duplicate array items, keeping first-seen order.
+
+use crate::arena::{ContextStack, DataValue, bvec};
+use crate::operators::comparison::compare_equals;
+use crate::{CompiledNode, Engine, Result};
+use bumpalo::Bump;
+
+/// Arena-mode `unique`. Keeps the first occurrence of each item under
+/// strict (`===`) equality — `1` and `1.0` collapse, `1` and `"1"` stay
+/// distinct — and re-borrows the surviving items rather than cloning them.
+///
+/// Duplicate detection is a linear scan over the items kept so far (equality
+/// is not hashable across numeric representations). Inputs without
+/// duplicates return the original value instead of the rebuilt copy. Null
+/// yields `[]`; any other non-array input is an error.
+#[inline]
+pub(crate) fn evaluate_unique<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    if args.len() != 1 {
+        return Err(crate::Error::invalid_args());
+    }
+
+    let input = engine.dispatch_node(&args[0], ctx, arena)?;
+    let items = match input {
+        DataValue::Array(items) => *items,
+        DataValue::Null => return Ok(crate::arena::singletons::singleton_empty_array()),
+        _ => return Err(crate::Error::invalid_args()),
+    };
+    if items.len() < 2 {
+        return Ok(input);
+    }
+
+    let mut kept = bvec::<DataValue<'a>>(arena, items.len());
+    for item in items {
+        let mut seen = false;
+        for prior in kept.iter() {
+            if compare_equals(prior, item, true, engine)? {
+                seen = true;
+                break;
+            }
+        }
+        if !seen {
+            kept.push(*item);
+        }
+    }
+
+    if kept.len() == items.len() {
+        return Ok(input);
+    }
+    Ok(arena.alloc(DataValue::Array(kept.into_bump_slice())))
+}
@@ -0,0 +1,47 @@
+//! `window` — consecutive overlapping sub-arrays of a fixed size.
+
+use crate::arena::{ContextStack, DataValue, bvec};
+use crate::{CompiledNode, Engine, Result};
+use bumpalo::Bump;
+
+use super::slice::extract_opt_i64_arena;
+
+/// Arena-mode `window`. `{"window": [array, size]}` returns every run of
+/// `size` consecutive items, stepping one item at a time, so `[1, 2, 3]`
+/// with size 2 gives `[[1, 2], [2, 3]]`. Each window is a view over the
+/// input items, so nothing is cloned.
+///
+/// `size` must be a positive integer; a size past the array length yields
+/// `[]`. Null or empty input yields `[]`; any other non-array input is an
+/// error.
+#[inline]
+pub(crate) fn evaluate_window<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    if args.len() != 2 {
+        return Err(crate::Error::invalid_args());
+    }
+
+    let input = engine.dispatch_node(&args[0], ctx, arena)?;
+    let size = match extract_opt_i64_arena(&args[1], ctx, engine, arena)? {
+        Some(n) if n > 0 => n as usize,
+        _ => return Err(crate::Error::invalid_args()),
+    };
+    let items = match input {
+        DataValue::Array(items) => *items,
+        DataValue::Null => return Ok(crate::arena::singletons::singleton_empty_array()),
+        _ => return Err(crate::Error::invalid_args()),
+    };
+    if items.len() < size {
+        return Ok(crate::arena::singletons::singleton_empty_array());
+    }
+
+    let mut windows = bvec::<DataValue<'a>>(arena, items.len() - size + 1);
+    for window in items.windows(size) {
+        windows.push(DataValue::Array(window));
+    }
+    Ok(arena.alloc(DataValue::Array(windows.into_bump_slice())))
+}
@@ -0,0 +1,46 @@
+//! `zip` — combine parallel arrays into an array of tuples.
+
+use crate::arena::{ContextStack, DataValue, bvec};
+use crate::{CompiledNode, Engine, Result};
+use bumpalo::Bump;
+
+/// Arena-mode `zip`. `{"zip": [a, b, ...]}` returns `[[a[0], b[0], ...],
+/// [a[1], b[1], ...], ...]`, truncated to the shortest input. Null arguments
+/// count as empty arrays, so they yield `[]`; any other non-array argument
+/// is an error.
+///
+/// Tuple items are re-borrowed from the inputs, never cloned.
+#[inline]
+pub(crate) fn evaluate_zip<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    if args.is_empty() {
+        return Ok(crate::arena::singletons::singleton_empty_array());
+    }
+
+    // Resolve every argument before truncating so a bad argument is
+    // reported even when an earlier one is empty.
+    let mut inputs = bvec::<&'a [DataValue<'a>]>(arena, args.len());
+    for arg in args {
+        match engine.dispatch_node(arg, ctx, arena)? {
+            DataValue::Array(items) => inputs.push(items),
+            DataValue::Null => inputs.push(&[]),
+            _ => return Err(crate::Error::invalid_args()),
+        }
+    }
+
+    let len = inputs.iter().map(|items| items.len()).min().unwrap_or(0);
+    if len == 0 {
+        return Ok(crate::arena::singletons::singleton_empty_array());
+    }
+
+    let mut rows = bvec::<DataValue<'a>>(arena, len);
+    for i in 0..len {
+        let row = arena.alloc_slice_fill_iter(inputs.iter().map(|items| items[i]));
+        rows.push(DataValue::Array(row));
+    }
+    Ok(arena.alloc(DataValue::Array(rows.into_bump_slice())))
+}
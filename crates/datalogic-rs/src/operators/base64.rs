@@ -0,0 +1,123 @@
+//! `base64_encode` / `base64_decode` (`feature = "ext-string"`).
+//!
+//! Standard alphabet (RFC 4648 §4) with `=` padding. The codec is vendored
+//! — ~60 lines of safe Rust — rather than pulled in as a dependency, so
+//! `ext-string` stays dependency-free like the rest of the operator
+//! families.
+//!
+//! Decoding is strict: characters outside the alphabet (whitespace
+//! included), a misplaced `=`, an impossible length, or non-zero unused
+//! trailing bits are all rejected. Missing padding is accepted, since
+//! unpadded base64 is common in URLs and tokens.
+
+use bumpalo::Bump;
+use datavalue::DataValue;
+
+use crate::arena::{ContextStack, bvec, data_to_str};
+use crate::engine::Engine;
+use crate::node::CompiledNode;
+use crate::{Error, Result};
+
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Which direction the shared operator body runs.
+#[derive(Clone, Copy)]
+pub(crate) enum Base64Op {
+    Encode,
+    Decode,
+}
+
+/// Native arena-mode `base64_encode` / `base64_decode`. Stringifies the
+/// argument like `cat`. Encoding works on the UTF-8 bytes; decoding must
+/// produce valid UTF-8, since the result is a string. Malformed input is an
+/// `InvalidArguments("Invalid base64")` error, and a decoded payload that
+/// is not UTF-8 is `InvalidArguments("Invalid UTF-8 in decoded base64")`.
+#[inline]
+pub(crate) fn evaluate_base64<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+    op: Base64Op,
+) -> Result<&'a DataValue<'a>> {
+    if args.len() != 1 {
+        return Err(Error::invalid_args());
+    }
+    let s = data_to_str(engine.dispatch_node(&args[0], ctx, arena)?, arena);
+    if s.is_empty() {
+        return Ok(crate::arena::singletons::singleton_empty_string());
+    }
+    let out = match op {
+        Base64Op::Encode => encode(s.as_bytes(), arena),
+        Base64Op::Decode => decode(s.as_bytes(), arena)?,
+    };
+    Ok(arena.alloc(DataValue::String(out)))
+}
+
+fn encode<'a>(bytes: &[u8], arena: &'a Bump) -> &'a str {
+    let mut out =
+        bumpalo::collections::String::with_capacity_in(bytes.len().div_ceil(3) * 4, arena);
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = (u32::from(b[0]) << 16) | (u32::from(b[1]) << 8) | u32::from(b[2]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[(n >> (18 - 6 * i)) as usize & 0x3f] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out.into_bump_str()
+}
+
+fn sextet(c: u8) -> Option<u32> {
+    let v = match c {
+        b'A'..=b'Z' => c - b'A',
+        b'a'..=b'z' => c - b'a' + 26,
+        b'0'..=b'9' => c - b'0' + 52,
+        b'+' => 62,
+        b'/' => 63,
+        _ => return None,
+    };
+    Some(u32::from(v))
+}
+
+fn decode<'a>(input: &[u8], arena: &'a Bump) -> Result<&'a str> {
+    let invalid = || Error::invalid_arguments("Invalid base64");
+
+    // Padding may only appear as the last one or two chars of a
+    // four-aligned input.
+    let data = match input {
+        [rest @ .., b'=', b'='] | [rest @ .., b'='] if input.len() % 4 == 0 => rest,
+        _ => input,
+    };
+    if data.len() % 4 == 1 {
+        return Err(invalid());
+    }
+
+    let mut out = bvec::<u8>(arena, data.len() / 4 * 3 + 2);
+    for chunk in data.chunks(4) {
+        let mut n = 0u32;
+        for (i, &c) in chunk.iter().enumerate() {
+            n |= sextet(c).ok_or_else(invalid)? << (18 - 6 * i);
+        }
+        let produced = chunk.len() - 1;
+        // Bits of a short final chunk that don't fill a whole byte must be
+        // zero; otherwise two distinct inputs would decode to the same
+        // bytes.
+        if n & (0x00ff_ffff >> (8 * produced)) != 0 {
+            return Err(invalid());
+        }
+        for i in 0..produced {
+            out.push((n >> (16 - 8 * i)) as u8);
+        }
+    }
+
+    std::str::from_utf8(out.into_bump_slice())
+        .map_err(|_| Error::invalid_arguments("Invalid UTF-8 in decoded base64"))
+}
@@ -15,6 +15,7 @@
 //! | `>=` | Greater than or equal | `{">=": [5, 5]}` → `true` |
 //! | `<` | Less than | `{"<": [3, 5]}` → `true` |
 //! | `<=` | Less than or equal | `{"<=": [5, 5]}` → `true` |
+//! | `between` | Range check | `{"between": [5, 1, 10]}` → `true` |
 //!
 //! # Comparison Precedence
 //!
@@ -27,14 +28,20 @@
 //!
 //! # Chained Comparisons
 //!
-//! All comparison operators support chained comparisons with 3+ arguments:
+//! `<`, `<=`, `>`, `>=`, `==`, and `===` accept 3+ arguments:
 //!
 //! ```json
 //! {"<": [1, 2, 3]}  // Equivalent to: 1 < 2 && 2 < 3, returns true
 //! {"<": [1, 5, 3]}  // Equivalent to: 1 < 5 && 5 < 3, returns false
+//! {"==": [1, "1", 1.0]}  // Every argument equals the first, returns true
 //! ```
 //!
-//! Chained comparisons use short-circuit evaluation - they stop at the first `false` result.
+//! Ordering checks each adjacent pair; equality checks each argument against
+//! the first. Every pair follows the two-argument rules above, so datetimes,
+//! durations, and mixed types chain the same way they compare. Arguments are
+//! evaluated left to right and the chain stops at the first failing pair:
+//! later arguments are never evaluated and can't raise errors. `!=` and
+//! `!==` compare only their first two arguments.
 //!
 //! # Error Handling
 //!
@@ -482,6 +489,8 @@ pub(crate) fn evaluate_not_equals<'a>(
     not_equals_pair(args, ctx, engine, arena, false)
 }
 
+/// Chained ordering: every adjacent pair must satisfy `op`. Arguments are
+/// evaluated lazily, so a failing pair skips everything after it.
 #[inline]
 fn evaluate_ord<'a>(
     args: &'a [CompiledNode],
@@ -544,6 +553,37 @@ pub(crate) fn evaluate_less_than_equal<'a>(
     evaluate_ord(args, ctx, engine, arena, OrdOp::Lte)
 }
 
+/// Range check: `{"between": [value, low, high]}` is `low <= value <=
+/// high`. An optional fourth boolean (default `true`) makes the upper
+/// bound inclusive; `false` gives the half-open `low <= value < high`.
+/// Each bound goes through [`compare_ordered`], so datetimes and durations
+/// compare the same way they do under `<=`.
+#[inline]
+pub(crate) fn evaluate_between<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    if !(3..=4).contains(&args.len()) {
+        return Err(crate::Error::invalid_args());
+    }
+    let value = engine.dispatch_node(&args[0], ctx, arena)?;
+    let low = engine.dispatch_node(&args[1], ctx, arena)?;
+    let high = engine.dispatch_node(&args[2], ctx, arena)?;
+    let upper_op = match args.get(3) {
+        None => OrdOp::Lte,
+        Some(arg) => match engine.dispatch_node(arg, ctx, arena)? {
+            DataValue::Bool(true) => OrdOp::Lte,
+            DataValue::Bool(false) => OrdOp::Lt,
+            _ => return Err(crate::Error::invalid_args()),
+        },
+    };
+    let inside = compare_ordered(low, value, OrdOp::Lte, engine)?
+        && compare_ordered(value, high, upper_op, engine)?;
+    Ok(crate::arena::singletons::singleton_bool(inside))
+}
+
 #[cfg(all(test, feature = "datetime"))]
 mod iso_fastpath_tests {
     use super::*;
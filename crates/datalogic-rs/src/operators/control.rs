@@ -4,6 +4,8 @@ use crate::{CompiledNode, Engine, Result};
 // Arena-mode control operators
 // =============================================================================
 
+#[cfg(feature = "ext-control")]
+use crate::arena::truthy_js_arena;
 use crate::arena::{ContextStack, DataValue, truthy_arena};
 use bumpalo::Bump;
 
@@ -60,6 +62,114 @@ pub(crate) fn evaluate_coalesce<'a>(
     Ok(crate::arena::singletons::singleton_null())
 }
 
+/// `default`. `{"default": [a, b, ...]}` returns the first argument that
+/// is not empty-or-falsy — `null`, `""`, `[]`, `{}`, `0`, `NaN`, or
+/// `false` — falling back to the last argument. Like `??`, arguments are
+/// evaluated left to right and evaluation stops at the first hit.
+///
+/// The skip set is the default JavaScript-style falsy set regardless of
+/// the engine's configured truthiness, so a rule reads the same on every
+/// engine. (`or` follows the configured truthiness instead.)
+#[cfg(feature = "ext-control")]
+#[inline]
+pub(crate) fn evaluate_default<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    let Some((last, rest)) = args.split_last() else {
+        return Ok(crate::arena::singletons::singleton_null());
+    };
+    for arg in rest {
+        let v = engine.dispatch_node(arg, ctx, arena)?;
+        if truthy_js_arena(v) {
+            return Ok(v);
+        }
+    }
+    engine.dispatch_node(last, ctx, arena)
+}
+
+/// `let`. `{"let": [{"x": <expr>, "y": <expr>}, <body>]}` evaluates each
+/// binding once and then evaluates `body` with the bindings in scope.
+///
+/// The compiler flattens the bindings object into the argument list, so
+/// `args` arrives as `[name, expr, name, expr, ..., body]` with each name
+/// a literal string. Every binding is evaluated against the enclosing
+/// scope, so one binding can't see another; nest a second `let` for that.
+///
+/// The body runs under a pushed frame whose data is the current frame's
+/// object with the bindings overlaid: a binding shadows a same-named
+/// field, every other field still resolves, and `{"val": [[1], ...]}`
+/// reaches the unshadowed data. Inside a `reduce` body the frame keeps
+/// `current`, `accumulator`, and `index` readable. The frame is popped on every
+/// path out of the body, errors included.
+#[cfg(feature = "ext-control")]
+#[inline]
+pub(crate) fn evaluate_let<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    use crate::arena::context::{ContextFrame, ContextRef};
+    let Some((body, bindings)) = args.split_last() else {
+        return Err(crate::Error::invalid_args());
+    };
+    if bindings.len() % 2 != 0 {
+        return Err(crate::Error::invalid_args());
+    }
+
+    let mut pairs: bumpalo::collections::Vec<'a, (&'a str, DataValue<'a>)> =
+        bumpalo::collections::Vec::with_capacity_in(bindings.len() / 2, arena);
+    for binding in bindings.chunks_exact(2) {
+        let CompiledNode::Value {
+            value: datavalue::OwnedDataValue::String(name),
+            ..
+        } = &binding[0]
+        else {
+            return Err(crate::Error::invalid_args());
+        };
+        let value = engine.dispatch_node(&binding[1], ctx, arena)?;
+        pairs.push((name.as_str(), *value));
+    }
+
+    let reduce_pairs;
+    let outer: &[(&'a str, DataValue<'a>)] = match ctx.current() {
+        ContextRef::Frame(ContextFrame::Reduce {
+            current,
+            accumulator,
+            index,
+            ..
+        }) => {
+            reduce_pairs = [
+                ("current", **current),
+                ("accumulator", **accumulator),
+                (
+                    "index",
+                    DataValue::Number(datavalue::NumberValue::Integer(*index as i64)),
+                ),
+            ];
+            &reduce_pairs
+        }
+        current => match current.data() {
+            DataValue::Object(outer) => outer,
+            _ => &[],
+        },
+    };
+    let bound = pairs.len();
+    for &(key, value) in outer {
+        if !pairs[..bound].iter().any(|(name, _)| *name == key) {
+            pairs.push((key, value));
+        }
+    }
+
+    ctx.push(arena.alloc(DataValue::Object(pairs.into_bump_slice())));
+    let result = engine.dispatch_node(body, ctx, arena);
+    ctx.pop();
+    result
+}
+
 #[cfg(feature = "ext-control")]
 #[inline]
 pub(crate) fn evaluate_switch<'a>(
@@ -0,0 +1,230 @@
+//! Explicit type conversion and type predicates (`feature = "ext-control"`).
+//!
+//! `to_number`, `to_int`, `to_string`, and `to_bool` surface the same
+//! coercions the engine applies implicitly — arithmetic's numeric
+//! coercion, `cat`'s stringification, and the configured truthiness — as
+//! operators, so a rule can convert a value once and say so.
+//!
+//! The numeric conversions never fail on a value they can't convert:
+//! they return `null`, which composes with `??` for a fallback
+//! (`{"??": [{"to_number": {"var": "qty"}}, 0]}`).
+//!
+//! `parse_int` and `parse_float` read a number out of text rather than
+//! coercing: the whole string (minus surrounding whitespace) must be a
+//! number, and anything else is `null` in the same composable way.
+//!
+//! `is_null` and `is_empty` are the matching predicates. Unlike
+//! `{"==": [{"length": x}, 0]}`, `is_empty` accepts any value, so it
+//! doesn't error on `null` or numbers.
+
+use bumpalo::Bump;
+use datavalue::{DataValue, NumberValue};
+
+use crate::arena::singletons::{singleton_bool, singleton_null};
+use crate::arena::{
+    ContextStack, coerce_to_number_cfg, data_to_str, truthy_arena, try_coerce_to_integer_cfg,
+};
+use crate::engine::Engine;
+use crate::node::CompiledNode;
+use crate::{Error, Result};
+
+/// Which conversion the shared operator body performs.
+#[derive(Clone, Copy)]
+pub(crate) enum Conversion {
+    Number,
+    Int,
+    String,
+    Bool,
+}
+
+/// Native arena-mode `to_number` / `to_int` / `to_string` / `to_bool`.
+/// Each takes exactly one argument.
+///
+/// - `to_number` coerces like an arithmetic operand and honours
+///   [`EvaluationConfig::numeric_coercion`](crate::EvaluationConfig::numeric_coercion):
+///   by default `""`, `null` and `false` are `0` and `true` is `1`, and
+///   the strict settings make them `null`. Numbers pass through
+///   unchanged. Strings must be decimal number text with no surrounding
+///   whitespace. Anything else is `null`: an unparseable or
+///   whitespace-only string, text that isn't a finite number (`"inf"`,
+///   `"NaN"`, `"1e400"`), an array, or an object.
+/// - `to_int` is `to_number` truncated toward zero. Integer strings are
+///   parsed exactly, so large ids don't lose precision through `f64`. A
+///   value outside the i64 range is `null`.
+/// - `to_string` stringifies like `cat`: `null` is `""` and arrays and
+///   objects render as JSON.
+/// - `to_bool` applies the engine's configured
+///   [`TruthyEvaluator`](crate::TruthyEvaluator), exactly as `if` and
+///   `!!` do.
+#[inline]
+pub(crate) fn evaluate_conversion<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+    op: Conversion,
+) -> Result<&'a DataValue<'a>> {
+    if args.len() != 1 {
+        return Err(Error::invalid_args());
+    }
+    let av = engine.dispatch_node(&args[0], ctx, arena)?;
+    Ok(match op {
+        Conversion::Number => match av {
+            DataValue::Number(_) => av,
+            _ => match coerce_to_number_cfg(av, engine).filter(|f| f.is_finite()) {
+                Some(f) => arena.alloc(DataValue::Number(NumberValue::from_f64(f))),
+                None => singleton_null(),
+            },
+        },
+        Conversion::Int => match av {
+            DataValue::Number(NumberValue::Integer(_)) => av,
+            _ => match to_int(av, engine) {
+                Some(i) => arena.alloc(DataValue::Number(NumberValue::from_i64(i))),
+                None => singleton_null(),
+            },
+        },
+        Conversion::String => match av {
+            DataValue::String(_) => av,
+            _ => arena.alloc(DataValue::String(data_to_str(av, arena))),
+        },
+        Conversion::Bool => singleton_bool(truthy_arena(av, engine)),
+    })
+}
+
+/// Native arena-mode `parse_int`: `[text]` or `[text, radix]`, radix 2–36
+/// (default 10). Surrounding whitespace, one sign, and a `0x` / `0b` /
+/// `0o` prefix matching a radix of 16 / 2 / 8 are accepted; the rest must
+/// be digits of the radix. Anything else — including a value outside the
+/// i64 range or an input that is not a string or number — is `null`. A
+/// radix that is not an integer in range is `Invalid Arguments`.
+#[inline]
+pub(crate) fn evaluate_parse_int<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    if args.is_empty() || args.len() > 2 {
+        return Err(Error::invalid_args());
+    }
+    let av = engine.dispatch_node(&args[0], ctx, arena)?;
+    let radix = match args.get(1) {
+        Some(node) => try_coerce_to_integer_cfg(engine.dispatch_node(node, ctx, arena)?, engine)
+            .and_then(|r| u32::try_from(r).ok())
+            .filter(|r| (2..=36).contains(r))
+            .ok_or_else(Error::invalid_args)?,
+        None => 10,
+    };
+    Ok(
+        match parse_text(av, arena).and_then(|s| parse_int(s, radix)) {
+            Some(i) => arena.alloc(DataValue::Number(NumberValue::from_i64(i))),
+            None => singleton_null(),
+        },
+    )
+}
+
+/// Native arena-mode `parse_float`. Takes exactly one argument. The text,
+/// minus surrounding whitespace, must be a decimal number with optional
+/// exponent; `inf`, `NaN`, and values that overflow to infinity are
+/// `null`, as is anything unparseable or not a string or number.
+#[inline]
+pub(crate) fn evaluate_parse_float<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    if args.len() != 1 {
+        return Err(Error::invalid_args());
+    }
+    let av = engine.dispatch_node(&args[0], ctx, arena)?;
+    if let DataValue::Number(_) = av {
+        return Ok(av);
+    }
+    let parsed = parse_text(av, arena)
+        .and_then(|s| s.parse::<f64>().ok())
+        .filter(|f| f.is_finite());
+    Ok(match parsed {
+        Some(f) => arena.alloc(DataValue::Number(NumberValue::from_f64(f))),
+        None => singleton_null(),
+    })
+}
+
+/// Trimmed text of a string or number operand of `parse_int` /
+/// `parse_float`; `None` for every other type.
+fn parse_text<'a>(av: &'a DataValue<'a>, arena: &'a Bump) -> Option<&'a str> {
+    match av {
+        DataValue::String(_) | DataValue::Number(_) => Some(data_to_str(av, arena).trim()),
+        _ => None,
+    }
+}
+
+fn parse_int(s: &str, radix: u32) -> Option<i64> {
+    let (negative, s) = match s.as_bytes().first()? {
+        b'-' => (true, &s[1..]),
+        b'+' => (false, &s[1..]),
+        _ => (false, s),
+    };
+    let prefix = match radix {
+        16 => Some(["0x", "0X"]),
+        2 => Some(["0b", "0B"]),
+        8 => Some(["0o", "0O"]),
+        _ => None,
+    };
+    let digits = prefix
+        .and_then(|p| s.strip_prefix(p[0]).or_else(|| s.strip_prefix(p[1])))
+        .unwrap_or(s);
+    // `from_str_radix` takes its own sign; one was already consumed above.
+    if digits.starts_with(['+', '-']) {
+        return None;
+    }
+    let magnitude = i128::from(u64::from_str_radix(digits, radix).ok()?);
+    i64::try_from(if negative { -magnitude } else { magnitude }).ok()
+}
+
+/// Which check the shared predicate body performs.
+#[derive(Clone, Copy)]
+pub(crate) enum TypePredicate {
+    Empty,
+    Null,
+}
+
+/// Native arena-mode `is_empty` / `is_null`. Each takes exactly one
+/// argument.
+///
+/// `is_null` is true only for `null`. `is_empty` is true for `null`, `""`,
+/// `[]`, and `{}` — the empty values among the default falsy set. `0` and
+/// `false` are falsy but not empty, and a whitespace-only string is not
+/// empty.
+#[inline]
+pub(crate) fn evaluate_type_predicate<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+    op: TypePredicate,
+) -> Result<&'a DataValue<'a>> {
+    if args.len() != 1 {
+        return Err(Error::invalid_args());
+    }
+    let av = engine.dispatch_node(&args[0], ctx, arena)?;
+    Ok(singleton_bool(match op {
+        TypePredicate::Null => matches!(av, DataValue::Null),
+        TypePredicate::Empty => match av {
+            DataValue::Null => true,
+            DataValue::String(s) => s.is_empty(),
+            DataValue::Array(items) => items.is_empty(),
+            DataValue::Object(pairs) => pairs.is_empty(),
+            _ => false,
+        },
+    }))
+}
+
+fn to_int(av: &DataValue<'_>, engine: &Engine) -> Option<i64> {
+    if let Some(i) = try_coerce_to_integer_cfg(av, engine) {
+        return Some(i);
+    }
+    let t = coerce_to_number_cfg(av, engine)?.trunc();
+    // `i64::MAX as f64` rounds up to 2^63, so the upper bound is strict.
+    (t >= i64::MIN as f64 && t < i64::MAX as f64).then_some(t as i64)
+}
@@ -117,18 +117,20 @@ pub(crate) fn datetime_multiply<'a>(
 }
 
 /// `Duration / Number` → scaled `Duration`. Returns `None` for non-duration
-/// LHS so the generic numeric path handles regular division.
+/// LHS so the generic numeric path handles regular division. A zero divisor
+/// fails with the caller's `zero_divisor` error, so it matches numeric `/`.
 #[inline]
 pub(crate) fn datetime_divide<'a>(
     a_av: &'a DataValue<'a>,
     b_av: &'a DataValue<'a>,
     arena: &'a Bump,
+    zero_divisor: impl FnOnce() -> crate::Error,
 ) -> Option<crate::Result<&'a DataValue<'a>>> {
     let (_, a_dur) = extract_dt_dur(a_av);
     let a_dur = a_dur?;
     let divisor = coerce_to_number(b_av)?;
     if divisor == 0.0 {
-        return Some(Err(crate::Error::nan()));
+        return Some(Err(zero_divisor()));
     }
     Some(Ok(write_into_arena(arena, a_dur.divide(divisor))))
 }
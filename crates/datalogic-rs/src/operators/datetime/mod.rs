@@ -13,14 +13,25 @@
 //!
 //! - `datetime` - Parse or validate a datetime value
 //! - `timestamp` - Parse or validate a duration value
+//! - `duration` - Parse a duration, including ISO 8601 (`P1DT2H`) form
 //! - `parse_date` - Parse a date string with a custom format
 //! - `format_date` - Format a datetime with a custom format string
+//! - `date_format` - Format a datetime with a raw strftime pattern
+//! - `date_parse` - Parse a string with a raw strftime pattern
 //! - `date_diff` - Calculate the difference between two dates
+//! - `date_add`, `date_sub` - Move a datetime by an amount of a calendar unit
+//! - `to_timezone` - Re-express a datetime in a fixed UTC offset
+//! - `start_of`, `end_of` - First / last instant of the hour, day, month, or
+//!   year containing a datetime
+//! - `year`, `month`, `day`, `hour`, `minute`, `weekday` - Extract one
+//!   component of a datetime as an integer
 //! - `now` - Get the current UTC datetime
 //!
 //! # Format String Conversion
 //!
-//! Format strings use a simplified syntax that is converted to chrono format internally:
+//! `parse_date` and `format_date` take a simplified syntax that is converted
+//! to chrono format internally (`date_format` and `date_parse` take chrono's
+//! `%` specifiers as-is):
 //!
 //! | Input | Chrono | Description |
 //! |-------|--------|-------------|
@@ -46,7 +57,10 @@
 
 pub(crate) mod arith;
 
-use chrono::Utc;
+use std::fmt::Write;
+
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, Datelike, FixedOffset, Months, NaiveDate, TimeDelta, Timelike, Utc};
 
 use crate::{CompiledNode, Engine, Error, Result};
 use datavalue::{DataDateTime, DataDuration};
@@ -89,12 +103,12 @@ pub(crate) fn extract_datetime(av: &DataValue<'_>) -> Option<DataDateTime> {
 pub(crate) fn extract_duration(av: &DataValue<'_>) -> Option<DataDuration> {
     match av {
         DataValue::Duration(d) => Some(*d),
-        DataValue::String(s) => DataDuration::parse(s),
+        DataValue::String(s) => parse_duration(s),
         DataValue::Object(pairs) => {
             for (k, v) in *pairs {
                 if *k == "timestamp" {
                     if let DataValue::String(s) = v {
-                        return DataDuration::parse(s);
+                        return parse_duration(s);
                     }
                 }
             }
@@ -104,6 +118,82 @@ pub(crate) fn extract_duration(av: &DataValue<'_>) -> Option<DataDuration> {
     }
 }
 
+/// Parse a duration string in either the native `1d:2h:3m:4s` form or the
+/// ISO 8601 `P[n]Y[n]M[n]W[n]DT[n]H[n]M[n]S` form.
+#[inline]
+pub(crate) fn parse_duration(s: &str) -> Option<DataDuration> {
+    DataDuration::parse(s).or_else(|| parse_iso_duration(s))
+}
+
+/// ISO 8601 duration: `[-]P` followed by whole-number date components
+/// (`Y`, `M`, `W`, `D`) and, after a `T`, time components (`H`, `M`, `S`).
+/// Components must appear in that order, each at most once, and at least
+/// one is required. Fractional values are not accepted.
+///
+/// A duration here is a fixed span of seconds, so the calendar-relative
+/// units are approximated: a year is 365 days and a month is 30 days.
+fn parse_iso_duration(s: &str) -> Option<DataDuration> {
+    const DATE_UNITS: [(u8, i64); 4] = [
+        (b'Y', 365 * 86_400),
+        (b'M', 30 * 86_400),
+        (b'W', 7 * 86_400),
+        (b'D', 86_400),
+    ];
+    const TIME_UNITS: [(u8, i64); 3] = [(b'H', 3_600), (b'M', 60), (b'S', 1)];
+
+    let (negative, rest) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s),
+    };
+    let rest = rest.strip_prefix('P')?;
+    let (date, time) = match rest.split_once('T') {
+        Some((date, time)) => (date, Some(time)),
+        None => (rest, None),
+    };
+
+    let mut total: i64 = 0;
+    let date_count = iso_components(date, &DATE_UNITS, &mut total)?;
+    let time_count = match time {
+        Some(time) => match iso_components(time, &TIME_UNITS, &mut total)? {
+            0 => return None,
+            n => n,
+        },
+        None => 0,
+    };
+    if date_count + time_count == 0 {
+        return None;
+    }
+    if negative {
+        total = -total;
+    }
+    TimeDelta::try_seconds(total).map(DataDuration)
+}
+
+/// Add the `<digits><unit>` pairs of one ISO duration part (date or time)
+/// to `total` in seconds, returning how many were read. Units must follow
+/// the order of `units`, each at most once.
+fn iso_components(part: &str, units: &[(u8, i64)], total: &mut i64) -> Option<usize> {
+    let mut bytes = part.as_bytes();
+    let mut next_unit = 0;
+    let mut count = 0;
+    while !bytes.is_empty() {
+        let digits = bytes.iter().take_while(|b| b.is_ascii_digit()).count();
+        if digits == 0 || digits == bytes.len() {
+            return None;
+        }
+        let value: i64 = std::str::from_utf8(&bytes[..digits]).ok()?.parse().ok()?;
+        let offset = units[next_unit..]
+            .iter()
+            .position(|&(u, _)| u == bytes[digits])?;
+        let (_, seconds) = units[next_unit + offset];
+        *total = total.checked_add(value.checked_mul(seconds)?)?;
+        next_unit += offset + 1;
+        count += 1;
+        bytes = &bytes[digits + 1..];
+    }
+    Some(count)
+}
+
 /// Resolve an arg as an arena string. Returns `None` if not string-like.
 #[inline]
 fn arg_as_str<'a>(av: &'a DataValue<'a>) -> Option<&'a str> {
@@ -173,7 +263,7 @@ pub(crate) fn evaluate_timestamp<'a>(
     }
 
     if let Some(s) = arg_as_str(av) {
-        if let Some(duration) = DataDuration::parse(s) {
+        if let Some(duration) = parse_duration(s) {
             // `DataDuration` has a streaming `Display`, so render it straight
             // into the arena rather than through a heap `String`.
             return Ok(arith::write_into_arena(arena, duration));
@@ -183,6 +273,26 @@ pub(crate) fn evaluate_timestamp<'a>(
     Err(Error::invalid_arguments("Invalid duration format"))
 }
 
+/// Native arena-mode `duration`. Parses a duration string — the native
+/// `1d:2h:3m:4s` form or ISO 8601 `P1DT2H` — and returns it normalized to
+/// the native form, so the result feeds duration arithmetic and comparison.
+#[inline]
+pub(crate) fn evaluate_duration<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    let [arg] = args else {
+        return Err(Error::invalid_args());
+    };
+    let av = engine.dispatch_node(arg, ctx, arena)?;
+    match arg_as_str(av).and_then(parse_duration) {
+        Some(duration) => Ok(arith::write_into_arena(arena, duration)),
+        None => Err(Error::invalid_arguments("Invalid duration format")),
+    }
+}
+
 /// Convert a JSONLogic format spec ("yyyy-MM-dd HH:mm:ss") to a chrono format.
 #[inline]
 fn jsonlogic_to_chrono_format(format: &str) -> String {
@@ -257,6 +367,347 @@ pub(crate) fn evaluate_format_date<'a>(
     Err(Error::invalid_arguments("Failed to format date"))
 }
 
+/// Native arena-mode `date_format`. Unlike `format_date`, the pattern is
+/// passed to chrono's `strftime` untranslated (`%Y-%m-%d %H:%M %z`), and the
+/// value is rendered in its original offset — so `%H` and `%z` reflect the
+/// input's timezone. Input without an offset renders as UTC.
+#[inline]
+pub(crate) fn evaluate_date_format<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    if args.len() != 2 {
+        return Err(Error::invalid_args());
+    }
+    let dt_av = engine.dispatch_node(&args[0], ctx, arena)?;
+    let fmt_av = engine.dispatch_node(&args[1], ctx, arena)?;
+
+    let datetime = extract_datetime(dt_av)
+        .ok_or_else(|| Error::invalid_arguments("Invalid datetime format"))?;
+    let fmt = arg_as_str(fmt_av).ok_or_else(Error::invalid_args)?;
+
+    let items = strftime_items(fmt)?;
+    let mut formatted = String::new();
+    write!(
+        formatted,
+        "{}",
+        local_datetime(&datetime)?.format_with_items(items.iter())
+    )
+    .map_err(|_| Error::invalid_arguments("Invalid format string"))?;
+
+    let s: &'a str = arena.alloc_str(&formatted);
+    Ok(arena.alloc(DataValue::String(s)))
+}
+
+/// Native arena-mode `date_parse`. Parses a string with a chrono `strftime`
+/// pattern (`%d/%m/%Y`) into a datetime value — the explicit-format
+/// counterpart to the ISO-only parsing every other datetime operator does
+/// implicitly. A pattern with an offset specifier (`%z`) keeps the parsed
+/// offset; otherwise the value is taken as UTC, and a date-only pattern
+/// lands at midnight.
+#[inline]
+pub(crate) fn evaluate_date_parse<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    if args.len() != 2 {
+        return Err(Error::invalid_args());
+    }
+    let date_av = engine.dispatch_node(&args[0], ctx, arena)?;
+    let fmt_av = engine.dispatch_node(&args[1], ctx, arena)?;
+
+    let (Some(date), Some(fmt)) = (arg_as_str(date_av), arg_as_str(fmt_av)) else {
+        return Err(Error::invalid_args());
+    };
+    strftime_items(fmt)?;
+
+    let parsed = match DateTime::parse_from_str(date, fmt) {
+        Ok(dt) => Some(DataDateTime {
+            dt: dt.with_timezone(&Utc),
+            original_offset: Some(dt.offset().local_minus_utc()),
+        }),
+        Err(_) => DataDateTime::parse_with_format(date, fmt),
+    };
+    match parsed {
+        Some(dt) => Ok(arena.alloc(DataValue::DateTime(dt))),
+        None => Err(Error::invalid_arguments("Failed to parse date")),
+    }
+}
+
+/// Split a chrono `strftime` pattern into items, rejecting unknown
+/// specifiers. chrono only reports a bad specifier when the formatter is
+/// written out (as a `fmt::Error`, which `to_string` turns into a panic) or
+/// as a generic parse failure, so `date_format` and `date_parse` validate
+/// the pattern up front.
+fn strftime_items(fmt: &str) -> Result<Vec<Item<'_>>> {
+    let items: Vec<Item<'_>> = StrftimeItems::new(fmt).collect();
+    if items.iter().any(|item| matches!(item, Item::Error)) {
+        return Err(Error::invalid_arguments("Invalid format string"));
+    }
+    Ok(items)
+}
+
+/// `year` / `month` / `day` / `hour` / `minute` / `weekday` discriminant
+/// for the shared component extractor.
+#[derive(Clone, Copy)]
+pub(crate) enum DatePart {
+    Year,
+    Month,
+    Day,
+    Hour,
+    Minute,
+    Weekday,
+}
+
+/// Native arena-mode component extractors. `{"month": dt}` returns one
+/// calendar field of a datetime (or ISO string) as an integer, read in the
+/// offset the value was written with — so `2024-03-05T23:30:00-05:00` is
+/// day 5, hour 23 — like `date_format`. Months and days count from 1;
+/// `weekday` is ISO 8601, 1 = Monday through 7 = Sunday.
+#[inline]
+pub(crate) fn evaluate_date_part<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+    part: DatePart,
+) -> Result<&'a DataValue<'a>> {
+    let [arg] = args else {
+        return Err(Error::invalid_args());
+    };
+    let av = engine.dispatch_node(arg, ctx, arena)?;
+    let datetime =
+        extract_datetime(av).ok_or_else(|| Error::invalid_arguments("Invalid datetime format"))?;
+    let local = local_datetime(&datetime)?;
+    let value = match part {
+        DatePart::Year => i64::from(local.year()),
+        DatePart::Month => i64::from(local.month()),
+        DatePart::Day => i64::from(local.day()),
+        DatePart::Hour => i64::from(local.hour()),
+        DatePart::Minute => i64::from(local.minute()),
+        DatePart::Weekday => i64::from(local.weekday().number_from_monday()),
+    };
+    Ok(crate::arena::singletons::singleton_small_int(value)
+        .unwrap_or_else(|| arena.alloc(DataValue::from_i64(value))))
+}
+
+/// `date_add` / `date_sub` discriminant for the shared calendar shift.
+#[derive(Clone, Copy)]
+pub(crate) enum DateShift {
+    Add,
+    Sub,
+}
+
+/// Native arena-mode `date_add` / `date_sub`. `{"date_add": [dt, 3,
+/// "months"]}` moves a datetime (or ISO string) by a whole number of
+/// `years`, `months`, `weeks`, `days`, `hours`, `minutes`, `seconds`, or
+/// `milliseconds` and returns a datetime value; `date_sub` moves it back.
+/// The amount may be negative.
+///
+/// `years` and `months` are calendar steps: the day of month is kept and
+/// clamped to the last day of a shorter month, so Jan 31 + 1 month is
+/// Feb 29 in a leap year. The other units are fixed lengths of time. The
+/// shift happens in the offset the value was written with, which the
+/// result keeps.
+#[inline]
+pub(crate) fn evaluate_date_shift<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+    shift: DateShift,
+) -> Result<&'a DataValue<'a>> {
+    if args.len() != 3 {
+        return Err(Error::invalid_args());
+    }
+    let dt_av = engine.dispatch_node(&args[0], ctx, arena)?;
+    let amount_av = engine.dispatch_node(&args[1], ctx, arena)?;
+    let unit_av = engine.dispatch_node(&args[2], ctx, arena)?;
+
+    let datetime = extract_datetime(dt_av)
+        .ok_or_else(|| Error::invalid_arguments("Invalid datetime format"))?;
+    let amount = amount_av.as_i64().ok_or_else(Error::invalid_args)?;
+    let unit = arg_as_str(unit_av).ok_or_else(Error::invalid_args)?;
+    let amount = match shift {
+        DateShift::Add => Some(amount),
+        DateShift::Sub => amount.checked_neg(),
+    }
+    .ok_or_else(|| Error::invalid_arguments("Date out of range"))?;
+
+    let local = local_datetime(&datetime)?;
+    let shifted = match unit {
+        "years" | "months" => {
+            let months = if unit == "years" {
+                amount.checked_mul(12)
+            } else {
+                Some(amount)
+            };
+            months.and_then(|m| {
+                let step = Months::new(u32::try_from(m.unsigned_abs()).ok()?);
+                if m < 0 {
+                    local.checked_sub_months(step)
+                } else {
+                    local.checked_add_months(step)
+                }
+            })
+        }
+        _ => {
+            let step = match unit {
+                "weeks" => TimeDelta::try_weeks(amount),
+                "days" => TimeDelta::try_days(amount),
+                "hours" => TimeDelta::try_hours(amount),
+                "minutes" => TimeDelta::try_minutes(amount),
+                "seconds" => TimeDelta::try_seconds(amount),
+                "milliseconds" => TimeDelta::try_milliseconds(amount),
+                _ => return Err(Error::invalid_args()),
+            };
+            step.and_then(|step| local.checked_add_signed(step))
+        }
+    }
+    .ok_or_else(|| Error::invalid_arguments("Date out of range"))?;
+
+    Ok(arena.alloc(DataValue::DateTime(DataDateTime {
+        dt: shifted.with_timezone(&Utc),
+        original_offset: datetime.original_offset,
+    })))
+}
+
+/// Native arena-mode `to_timezone`. `{"to_timezone": [dt, "+05:30"]}`
+/// returns the same instant re-tagged with a fixed UTC offset, so
+/// `date_format` and the component operators read it in that zone.
+/// Comparisons use the instant alone, so the result still `==` the input.
+///
+/// The offset is `Z` / `UTC`, or a sign followed by `HH`, `HHMM`, or
+/// `HH:MM`, less than 24 hours either way.
+#[inline]
+pub(crate) fn evaluate_to_timezone<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    if args.len() != 2 {
+        return Err(Error::invalid_args());
+    }
+    let dt_av = engine.dispatch_node(&args[0], ctx, arena)?;
+    let tz_av = engine.dispatch_node(&args[1], ctx, arena)?;
+
+    let datetime = extract_datetime(dt_av)
+        .ok_or_else(|| Error::invalid_arguments("Invalid datetime format"))?;
+    let offset = arg_as_str(tz_av)
+        .and_then(parse_utc_offset)
+        .ok_or_else(|| Error::invalid_arguments("Invalid timezone offset"))?;
+
+    Ok(arena.alloc(DataValue::DateTime(DataDateTime {
+        dt: datetime.dt,
+        original_offset: Some(offset.local_minus_utc()),
+    })))
+}
+
+/// Parse `Z`, `UTC`, `±HH`, `±HHMM`, or `±HH:MM` into a fixed offset.
+fn parse_utc_offset(s: &str) -> Option<FixedOffset> {
+    if s == "Z" || s == "UTC" {
+        return FixedOffset::east_opt(0);
+    }
+    let (sign, rest) = match s.as_bytes().first()? {
+        b'+' => (1, &s[1..]),
+        b'-' => (-1, &s[1..]),
+        _ => return None,
+    };
+    // ASCII-only from here, so the byte slicing below stays on char
+    // boundaries; a misplaced `:` then fails the integer parse.
+    if !rest.bytes().all(|b| b.is_ascii_digit() || b == b':') {
+        return None;
+    }
+    let (hours, minutes) = match rest.len() {
+        2 => (rest, "00"),
+        4 => rest.split_at(2),
+        5 if rest.as_bytes()[2] == b':' => (&rest[..2], &rest[3..]),
+        _ => return None,
+    };
+    let hours: i32 = hours.parse().ok()?;
+    let minutes: i32 = minutes.parse().ok()?;
+    if minutes >= 60 {
+        return None;
+    }
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+}
+
+/// `start_of` / `end_of` discriminant for the shared truncation.
+#[derive(Clone, Copy)]
+pub(crate) enum DateBoundary {
+    Start,
+    End,
+}
+
+/// Native arena-mode `start_of` / `end_of`. `{"start_of": [dt, "month"]}`
+/// returns the first instant of the `hour`, `day`, `month`, or `year`
+/// containing `dt`; `end_of` returns its last nanosecond, which renders as
+/// `…:59:59` and still sorts after any fractional time in the unit. Both
+/// return a datetime value.
+///
+/// The unit is taken in the offset the value was written with, which the
+/// result keeps — `start_of day` of `2024-03-05T01:00:00+09:00` is local
+/// midnight, `2024-03-04T15:00:00Z`.
+#[inline]
+pub(crate) fn evaluate_date_boundary<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+    boundary: DateBoundary,
+) -> Result<&'a DataValue<'a>> {
+    if args.len() != 2 {
+        return Err(Error::invalid_args());
+    }
+    let dt_av = engine.dispatch_node(&args[0], ctx, arena)?;
+    let unit_av = engine.dispatch_node(&args[1], ctx, arena)?;
+
+    let datetime = extract_datetime(dt_av)
+        .ok_or_else(|| Error::invalid_arguments("Invalid datetime format"))?;
+    let unit = arg_as_str(unit_av).ok_or_else(Error::invalid_args)?;
+    let local = local_datetime(&datetime)?;
+
+    let date = local.date_naive();
+    let start = match unit {
+        "hour" => date.and_hms_opt(local.hour(), 0, 0),
+        "day" => date.and_hms_opt(0, 0, 0),
+        "month" => date.with_day(1).and_then(|d| d.and_hms_opt(0, 0, 0)),
+        "year" => NaiveDate::from_ymd_opt(date.year(), 1, 1).and_then(|d| d.and_hms_opt(0, 0, 0)),
+        _ => return Err(Error::invalid_args()),
+    }
+    .and_then(|naive| naive.and_local_timezone(*local.offset()).single());
+    let bound = match boundary {
+        DateBoundary::Start => start,
+        DateBoundary::End => start
+            .and_then(|start| match unit {
+                "hour" => start.checked_add_signed(TimeDelta::hours(1)),
+                "day" => start.checked_add_signed(TimeDelta::days(1)),
+                "month" => start.checked_add_months(Months::new(1)),
+                _ => start.checked_add_months(Months::new(12)),
+            })
+            .and_then(|next| next.checked_sub_signed(TimeDelta::nanoseconds(1))),
+    }
+    .ok_or_else(|| Error::invalid_arguments("Date out of range"))?;
+
+    Ok(arena.alloc(DataValue::DateTime(DataDateTime {
+        dt: bound.with_timezone(&Utc),
+        original_offset: datetime.original_offset,
+    })))
+}
+
+/// A datetime in the offset it was written with; input that carried no
+/// offset stays in UTC.
+fn local_datetime(datetime: &DataDateTime) -> Result<DateTime<FixedOffset>> {
+    let offset = FixedOffset::east_opt(datetime.original_offset.unwrap_or(0))
+        .ok_or_else(|| Error::invalid_arguments("Invalid datetime format"))?;
+    Ok(datetime.dt.with_timezone(&offset))
+}
+
 /// Native arena-mode `date_diff`.
 #[inline]
 pub(crate) fn evaluate_date_diff<'a>(
@@ -1,9 +1,11 @@
 //! Error-handling operators: `throw` and `try`.
 //!
-//! `throw` raises a structured error; `try` evaluates expressions in
+//! `throw` raises a structured error — a string becomes `{"type": s}`, and
+//! an object (written inline with two or more keys, or produced by an
+//! expression) is thrown as-is; `try` evaluates expressions in
 //! sequence until one succeeds (the final arm receives the caught error
 //! object as its context, so the catch body can inspect error fields via
-//! `var` / `val`).
+//! `var` / `val`, and can also read it as `{"var": "error"}`).
 //!
 //! # Syntax
 //!
@@ -173,6 +175,11 @@ pub(crate) fn evaluate_try<'a>(
         ctx.exit_catch_scope();
         match result {
             Ok(v) => return Ok(v),
+            // A spent step budget is not recoverable: catching it would
+            // let the rule keep running past the limit it was given.
+            Err(e) if matches!(e.kind, crate::ErrorKind::StepLimitExceeded { .. }) => {
+                return Err(e);
+            }
             Err(e) => {
                 ctx.truncate_error_path(saved_len);
                 last_err = Some(e);
@@ -182,14 +189,24 @@ pub(crate) fn evaluate_try<'a>(
     Err(last_err.unwrap_or_else(Error::invalid_args))
 }
 
-/// Pushes the thrown error object onto the arena context stack as the
-/// current frame so the catch arm's `var`/`val` lookups see error fields.
+/// Runs the catch arm under a [`ContextFrame::Caught`] frame so the arm can
+/// inspect what failed.
+///
+/// For a `Thrown` error the frame's data is the thrown object, so the
+/// arm's `var`/`val` lookups see error fields directly; it is also the
+/// frame's error, reachable as `{"var": "error"}`. Payload source, in
+/// order: the context's thrown slot when the failing arm deferred it
+/// (already arena-resident — no conversion), otherwise the owned payload
+/// inside the error, deep-converted into the arena.
 ///
-/// Payload source, in order: the context's thrown slot when the failing
-/// arm deferred it (already arena-resident — no conversion), otherwise the
-/// owned payload inside the error, deep-converted into the arena. A
-/// literal catch arm can't read the context at all, so the push (and any
+/// Any other error leaves the data unchanged and exposes
+/// `{"type": <tag>, "message": <message>}` as `error`, e.g.
+/// `{"type": "InvalidArguments", "message": "Invalid arguments: ..."}`.
+///
+/// A literal catch arm can't read the context at all, so the push (and any
 /// materialization) is skipped entirely.
+///
+/// [`ContextFrame::Caught`]: crate::arena::context::ContextFrame::Caught
 #[inline]
 fn try_last_with_error_context<'a>(
     arg: &'a CompiledNode,
@@ -201,23 +218,28 @@ fn try_last_with_error_context<'a>(
     // Consume the slot unconditionally: it either pairs with `last_error`
     // (set during the same failing arm) or must not leak past this catch.
     let slot = ctx.take_thrown_slot();
-    if let Some(Error {
-        kind: crate::ErrorKind::Thrown(error_obj),
-        ..
-    }) = last_error.take()
-    {
-        if matches!(arg, CompiledNode::Value { .. }) {
-            return engine.dispatch_node(arg, ctx, arena);
-        }
+    let Some(err) = last_error.take() else {
+        return engine.dispatch_node(arg, ctx, arena);
+    };
+    if matches!(arg, CompiledNode::Value { .. }) {
+        return engine.dispatch_node(arg, ctx, arena);
+    }
+    if let crate::ErrorKind::Thrown(error_obj) = &err.kind {
         let av: &'a DataValue<'a> = match slot {
             Some(av) => av,
             None => arena.alloc(error_obj.to_arena(arena)),
         };
-        ctx.push(av);
-        let result = engine.dispatch_node(arg, ctx, arena);
-        ctx.pop();
-        result
+        ctx.push_caught(av, av);
     } else {
-        engine.dispatch_node(arg, ctx, arena)
+        let message = arena.alloc_str(&err.kind_message().to_string());
+        let fields = arena.alloc([
+            ("type", DataValue::String(err.tag())),
+            ("message", DataValue::String(message)),
+        ]);
+        let error_av = arena.alloc(DataValue::Object(&fields[..]));
+        ctx.push_caught(ctx.current().data(), error_av);
     }
+    let result = engine.dispatch_node(arg, ctx, arena);
+    ctx.pop();
+    result
 }
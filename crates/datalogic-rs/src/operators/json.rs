@@ -0,0 +1,54 @@
+//! `json_parse` / `json_stringify` (`feature = "ext-string"`).
+//!
+//! Both sit on the same `datavalue` parser and emitter the engine uses at
+//! its own boundaries, so a string produced by `json_stringify` is exactly
+//! what `eval_str` would have emitted for that value, and `json_parse`
+//! accepts exactly what the data-input path accepts (including its
+//! nesting-depth limit).
+
+use bumpalo::Bump;
+use datavalue::DataValue;
+
+use crate::arena::ContextStack;
+use crate::engine::Engine;
+use crate::node::CompiledNode;
+use crate::{Error, Result};
+
+/// `json_parse`. `{"json_parse": text}` parses a JSON document into a
+/// value. Null passes through as null; any other non-string input is an
+/// error. Malformed JSON is a `ParseError` carrying the parser's message.
+///
+/// The parsed tree lives in the evaluation arena, and unescaped strings
+/// borrow from `text` rather than being copied.
+#[inline]
+pub(crate) fn evaluate_json_parse<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    if args.len() != 1 {
+        return Err(Error::invalid_args());
+    }
+    match engine.dispatch_node(&args[0], ctx, arena)? {
+        DataValue::String(text) => Ok(arena.alloc(DataValue::from_str(text, arena)?)),
+        DataValue::Null => Ok(crate::arena::singletons::singleton_null()),
+        _ => Err(Error::invalid_args()),
+    }
+}
+
+/// `json_stringify`. `{"json_stringify": value}` serializes any value to
+/// compact JSON text (no whitespace, object keys in their stored order).
+#[inline]
+pub(crate) fn evaluate_json_stringify<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    if args.len() != 1 {
+        return Err(Error::invalid_args());
+    }
+    let av = engine.dispatch_node(&args[0], ctx, arena)?;
+    Ok(arena.alloc(DataValue::String(av.to_json_str_in(arena))))
+}
@@ -3,7 +3,9 @@ use crate::{CompiledNode, Engine, Result};
 // =============================================================================
 // Arena-mode missing / missing_some
 //
-// Path lookups walk `&DataValue` natively via `path_exists_*`.
+// Path lookups walk `&DataValue` natively via `path_exists_*`. A path with a
+// `*` segment takes the wildcard walk `var` uses, and only counts as present
+// when every element at the `*` has the rest of it.
 // =============================================================================
 
 use crate::arena::{ContextStack, DataValue};
@@ -88,6 +90,17 @@ pub(crate) fn evaluate_missing_some<'a>(
     Ok(arena.alloc(DataValue::Array(missing.into_bump_slice())))
 }
 
+/// `path_exists_str`, with a detour through the wildcard walk when the
+/// runtime path has a `*` segment.
+#[inline]
+fn path_exists<'a>(lookup: &'a DataValue<'a>, path: &str) -> bool {
+    if path.split('.').any(|segment| segment == "*") {
+        let segments = crate::compile::path_segments::parse_path_segments(path);
+        return crate::arena::value::path_exists_wildcard_segments(lookup, &segments);
+    }
+    crate::arena::value::path_exists_str(lookup, path)
+}
+
 #[inline]
 fn value_as_str<'a>(av: &'a DataValue<'a>) -> Option<&'a str> {
     match av {
@@ -126,7 +139,7 @@ pub(crate) fn evaluate_compiled_missing<'a>(
     for arg in data.args.iter() {
         match arg {
             CompiledMissingArg::Now((path, segments)) => {
-                if !crate::arena::value::path_exists_segments(lookup, segments) {
+                if !crate::arena::value::path_exists_wildcard_segments(lookup, segments) {
                     missing
                         .get_or_insert_with(|| {
                             bumpalo::collections::Vec::with_capacity_in(data.args.len(), arena)
@@ -177,7 +190,7 @@ pub(crate) fn evaluate_compiled_missing_some<'a>(
             let mut missing: Option<bumpalo::collections::Vec<'a, DataValue<'a>>> = None;
             let mut present = 0usize;
             for (path, segments) in paths.iter() {
-                if crate::arena::value::path_exists_segments(lookup, segments) {
+                if crate::arena::value::path_exists_wildcard_segments(lookup, segments) {
                     present += 1;
                     if present >= min_present {
                         return Ok(crate::arena::singletons::singleton_empty_array());
@@ -227,7 +240,7 @@ fn check_path<'a>(
     min_present: usize,
     arena: &'a Bump,
 ) -> bool {
-    if !crate::arena::value::path_exists_str(lookup, path) {
+    if !path_exists(lookup, path) {
         missing.push(DataValue::String(arena.alloc_str(path)));
     } else {
         *present += 1;
@@ -249,13 +262,13 @@ fn accumulate_dynamic_missing<'a>(
         DataValue::Array(items) => {
             for it in *items {
                 if let Some(path) = value_as_str(it) {
-                    if !crate::arena::value::path_exists_str(lookup, path) {
+                    if !path_exists(lookup, path) {
                         missing.push(DataValue::String(arena.alloc_str(path)));
                     }
                 }
             }
         }
-        DataValue::String(s) if !crate::arena::value::path_exists_str(lookup, s) => {
+        DataValue::String(s) if !path_exists(lookup, s) => {
             missing.push(DataValue::String(arena.alloc_str(s)));
         }
         _ => {}
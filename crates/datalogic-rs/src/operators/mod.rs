@@ -14,35 +14,38 @@
 //! | Operator(s) | Required feature |
 //! |---|---|
 //! | `var`, `val` | *baseline* (always available) |
-//! | `==`, `===`, `!=`, `!==`, `>`, `>=`, `<`, `<=` | *baseline* |
+//! | `==`, `===`, `!=`, `!==`, `>`, `>=`, `<`, `<=`, `between` | *baseline* |
 //! | `and`, `or`, `!`, `!!`, `if`, `?:` | *baseline* |
 //! | `+`, `-`, `*`, `/`, `%`, `min`, `max` | *baseline* |
 //! | `cat`, `substr`, `in` | *baseline* |
 //! | `map`, `filter`, `reduce`, `merge`, `all`, `some`, `none` | *baseline* |
 //! | `missing`, `missing_some` | *baseline* |
-//! | `length`, `starts_with`, `ends_with`, `upper`, `lower`, `trim`, `split` | `ext-string` |
-//! | `sort`, `slice` | `ext-array` |
-//! | `abs`, `ceil`, `floor` | `ext-math` |
-//! | `exists`, `??`, `switch`/`match`, `type` | `ext-control` |
+//! | `length`, `starts_with`, `ends_with`, `upper`, `lower`, `trim`, `split`, `join`, `pad_start`, `pad_end`, `repeat`, `capitalize`, `title_case`, `char_at`, `code_point_at`, `base64_encode`, `base64_decode`, `json_parse`, `json_stringify`, `number_format`, `slugify`, `format`, `template`, `starts_with_i`, `ends_with_i`, `in_i`, `trim_start`, `trim_end` | `ext-string` |
+//! | `sort`, `slice`, `flatten`, `unique`/`distinct`, `group_by`, `find`, `index_of`, `zip`, `chunk`, `count`, `flat_map`, `reverse`, `take`, `drop`, `contains`/`includes`, `merge_objects`, `pick`, `omit`, `set`, `all_values`, `some_values`, `none_values`, `min_by`, `max_by`, `window`, `reduce_while` | `ext-array` |
+//! | `abs`, `ceil`, `floor`, `sum`, `avg`, `pow`, `sqrt`, `sign`, `clamp`, `log`, `exp`, `sin`, `cos`, `tan`, `gcd`, `lcm`, `random`, `bitand`, `bitor`, `bitxor`, `bitnot`, `lshift`, `rshift`, `to_fixed` | `ext-math` |
+//! | `exists`, `get`, `has`, `??`, `default`, `let`, `switch`/`match`, `type`, `to_number`, `to_int`, `to_string`, `to_bool`, `is_empty`, `is_null`, `parse_int`, `parse_float` | `ext-control` |
 //! | `try`, `throw` | `error-handling` |
-//! | `datetime`, `timestamp`, `parse_date`, `format_date`, `date_diff`, `now` | `datetime` |
+//! | `datetime`, `timestamp`, `parse_date`, `format_date`, `date_format`, `date_parse`, `date_diff`, `now`, `year`, `month`, `day`, `hour`, `minute`, `weekday`, `date_add`, `date_sub`, `to_timezone`, `start_of`, `end_of`, `duration` | `datetime` |
 //! | `fractional`, `sem_ver` ([flagd-compat][flagd]) | `flagd` |
+//! | `regex_match`, `regex_replace` | `regex` |
 //!
 //! [flagd]: https://flagd.dev/reference/custom-operations/
 //!
 //! # Operator Categories
 //!
-//! - **Variable Access**: `var`, `val`, `exists` - Access data from context
-//! - **Comparison**: `==`, `===`, `!=`, `!==`, `>`, `>=`, `<`, `<=` - Compare values
+//! - **Variable Access**: `var`, `val`, `exists`, `get`, `has` - Access data from context
+//! - **Comparison**: `==`, `===`, `!=`, `!==`, `>`, `>=`, `<`, `<=`, `between` - Compare values
 //! - **Logical**: `and`, `or`, `!`, `!!` - Boolean logic operations
-//! - **Control Flow**: `if`, `?:`, `??` - Conditional evaluation
-//! - **Arithmetic**: `+`, `-`, `*`, `/`, `%`, `min`, `max`, `abs`, `ceil`, `floor`
-//! - **String**: `cat`, `substr`, `in`, `length`, `starts_with`, `ends_with`, `upper`, `lower`, `trim`, `split`
-//! - **Array**: `map`, `filter`, `reduce`, `merge`, `all`, `some`, `none`, `sort`, `slice`
-//! - **DateTime**: `datetime`, `timestamp`, `parse_date`, `format_date`, `date_diff`, `now`
+//! - **Control Flow**: `if`, `?:`, `??`, `default`, `let` - Conditional evaluation and local bindings
+//! - **Arithmetic**: `+`, `-`, `*`, `/`, `%`, `min`, `max`, `abs`, `ceil`, `floor`, `sum`, `avg`, `pow`, `sqrt`, `sign`, `clamp`, `log`, `exp`, `sin`, `cos`, `tan`, `gcd`, `lcm`, `random`, `bitand`, `bitor`, `bitxor`, `bitnot`, `lshift`, `rshift`, `to_fixed`
+//! - **String**: `cat`, `substr`, `in`, `length`, `starts_with`, `ends_with`, `upper`, `lower`, `trim`, `split`, `join`, `pad_start`, `pad_end`, `repeat`, `capitalize`, `title_case`, `char_at`, `code_point_at`, `base64_encode`, `base64_decode`, `json_parse`, `json_stringify`, `number_format`, `slugify`, `format`, `template`, `starts_with_i`, `ends_with_i`, `in_i`, `trim_start`, `trim_end`
+//! - **Array**: `map`, `filter`, `reduce`, `merge`, `all`, `some`, `none`, `sort`, `slice`, `flatten`, `unique`, `group_by`, `find`, `index_of`, `zip`, `chunk`, `count`, `flat_map`, `reverse`, `take`, `drop`, `contains`, `merge_objects`, `pick`, `omit`, `set`, `all_values`, `some_values`, `none_values`, `min_by`, `max_by`, `window`, `reduce_while`
+//! - **DateTime**: `datetime`, `timestamp`, `parse_date`, `format_date`, `date_format`, `date_parse`, `date_diff`, `now`, `year`, `month`, `day`, `hour`, `minute`, `weekday`, `date_add`, `date_sub`, `to_timezone`, `start_of`, `end_of`, `duration`
 //! - **Error Handling**: `try`, `throw` - Exception-like error handling
-//! - **Type**: `type` - Runtime type inspection
+//! - **Type**: `type`, `to_number`, `to_int`, `to_string`, `to_bool`, `is_empty`, `is_null`, `parse_int`, `parse_float` - Runtime type inspection, explicit conversion, and type predicates
 //! - **Missing**: `missing`, `missing_some` - Check for missing fields
+//! - **Regex**: `regex_match`, `regex_replace` - Regular-expression matching and
+//!   substitution (gated on `regex`)
 //! - **flagd-compat**: `fractional`, `sem_ver` — feature-flagging operators
 //!   from the [OpenFeature flagd in-process provider
 //!   spec](https://flagd.dev/reference/custom-operations/), implemented to
@@ -67,6 +70,10 @@ pub(crate) mod string;
 pub(crate) mod variable;
 
 // Feature-gated extended operators
+#[cfg(feature = "ext-string")]
+pub(crate) mod base64;
+#[cfg(feature = "ext-control")]
+pub(crate) mod convert;
 #[cfg(feature = "datetime")]
 pub(crate) mod datetime;
 #[cfg(feature = "error-handling")]
@@ -75,3 +82,9 @@ pub(crate) mod error_handling;
 pub(crate) mod flagd;
 #[cfg(feature = "ext-control")]
 pub(crate) mod inspect;
+#[cfg(feature = "ext-string")]
+pub(crate) mod json;
+#[cfg(feature = "ext-math")]
+pub(crate) mod random;
+#[cfg(feature = "regex")]
+pub(crate) mod regex;
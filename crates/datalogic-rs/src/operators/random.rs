@@ -0,0 +1,141 @@
+//! `random` (`feature = "ext-math"`).
+//!
+//! A seeded pseudo-random number in a half-open range, meant for
+//! reproducible bucketing and sampling rather than anything
+//! security-sensitive. With a seed argument the output is a pure function
+//! of `(seed, min, max)`: the seed goes through one round of SplitMix64
+//! and the resulting 64 bits are scaled into the range, so the same seed
+//! yields the same number on every evaluation, engine, and target.
+//!
+//! Without a seed argument the call draws from a SplitMix64 stream seeded
+//! by [`EvaluationConfig::random_seed`](crate::EvaluationConfig::random_seed).
+//! The stream lives for one evaluation and advances on every call, so
+//! `{"map": [xs, {"random": [0, 10]}]}` gives each item its own value and
+//! the whole sequence repeats on the next run. Without an engine seed the
+//! call uses per-call entropy — unless
+//! [`EvaluationConfig::deterministic_mode`](crate::EvaluationConfig::deterministic_mode)
+//! is set, in which case an unseeded call is an error. Because the result
+//! can depend on engine config (or on nothing at all), `random` is never
+//! constant-folded or memoized.
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+
+use bumpalo::Bump;
+use datavalue::{DataValue, NumberValue};
+
+use crate::arena::{ContextStack, coerce_to_number_cfg};
+use crate::engine::Engine;
+use crate::node::CompiledNode;
+use crate::{Error, Result};
+
+/// SplitMix64's state increment (the 64-bit golden ratio).
+pub(crate) const SPLITMIX64_GAMMA: u64 = 0x9e37_79b9_7f4a_7c15;
+
+/// SplitMix64 output for `state`. One step is enough to spread
+/// consecutive seeds (`1`, `2`, `3`, …) across the whole 64-bit range.
+fn splitmix64(state: u64) -> u64 {
+    let mut z = state.wrapping_add(SPLITMIX64_GAMMA);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+/// 64-bit FNV-1a, used to turn string seeds (user ids, bucketing keys)
+/// into a numeric seed without pulling in a hashing dependency.
+fn fnv1a64(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for &b in bytes {
+        hash ^= u64::from(b);
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    hash
+}
+
+/// Map a seed argument to 64 bits. `null` means "no seed" so a missing
+/// `var` falls through to the engine seed; numbers and strings are
+/// accepted; anything else is `Invalid Arguments`.
+fn seed_bits(seed: &DataValue<'_>) -> Result<Option<u64>> {
+    match seed {
+        DataValue::Null => Ok(None),
+        DataValue::Number(NumberValue::Integer(i)) => Ok(Some(*i as u64)),
+        DataValue::Number(NumberValue::Float(f)) => Ok(Some(f.to_bits())),
+        DataValue::String(s) => Ok(Some(fnv1a64(s.as_bytes()))),
+        _ => Err(Error::invalid_args()),
+    }
+}
+
+/// `random`. `{"random": [min, max]}` or `{"random": [min, max, seed]}`
+/// returns a pseudo-random number in `[min, max)`.
+///
+/// When both bounds are integers the result is an integer; otherwise it
+/// is a float. Bounds are coerced like arithmetic operands — a
+/// non-numeric bound is a `NaN` error — and `min >= max` is
+/// `Invalid Arguments`. A missing or `null` seed draws from the engine
+/// seed's per-evaluation stream; see the module docs.
+#[inline]
+pub(crate) fn evaluate_random<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    if !(2..=3).contains(&args.len()) {
+        return Err(Error::invalid_args());
+    }
+    let min_av = engine.dispatch_node(&args[0], ctx, arena)?;
+    let max_av = engine.dispatch_node(&args[1], ctx, arena)?;
+    let seed = match args.get(2) {
+        Some(arg) => seed_bits(engine.dispatch_node(arg, ctx, arena)?)?,
+        None => None,
+    };
+
+    let config = engine.config();
+    let state = match (seed, config.random_seed) {
+        (Some(seed), _) => seed,
+        (None, Some(seed)) => ctx.next_random_state(seed),
+        (None, None) if config.deterministic_mode => {
+            return Err(Error::configuration_error(
+                "random requires a seed when deterministic_mode is enabled",
+            ));
+        }
+        (None, None) => RandomState::new().build_hasher().finish(),
+    };
+    let bits = splitmix64(state);
+
+    if let (
+        DataValue::Number(NumberValue::Integer(min)),
+        DataValue::Number(NumberValue::Integer(max)),
+    ) = (min_av, max_av)
+    {
+        if min >= max {
+            return Err(Error::invalid_args());
+        }
+        // Multiply-shift maps the 64 random bits onto `[0, span)`; the
+        // span of any two i64s fits in a u64.
+        let span = (i128::from(*max) - i128::from(*min)) as u128;
+        let offset = (u128::from(bits) * span) >> 64;
+        let n = (i128::from(*min) + offset as i128) as i64;
+        return Ok(arena.alloc(DataValue::Number(NumberValue::from_i64(n))));
+    }
+
+    let bound = |av: &DataValue<'_>, ctx: &mut ContextStack<'a>| {
+        coerce_to_number_cfg(av, engine)
+            .filter(|f| f.is_finite())
+            .ok_or_else(|| Error::nan_at(ctx))
+    };
+    let min = bound(min_av, ctx)?;
+    let max = bound(max_av, ctx)?;
+    let span = max - min;
+    if min >= max || !span.is_finite() {
+        return Err(Error::invalid_args());
+    }
+    // Top 53 bits → a uniform float in [0, 1).
+    let unit = (bits >> 11) as f64 / (1u64 << 53) as f64;
+    let mut n = min + unit * span;
+    if n >= max {
+        // Rounding can land exactly on `max`; keep the range half-open.
+        n = min;
+    }
+    Ok(arena.alloc(DataValue::Number(NumberValue::from_f64(n))))
+}
@@ -0,0 +1,145 @@
+//! Regular-expression operators (`feature = "regex"`).
+//!
+//! Exposes:
+//!
+//! - [`evaluate_regex_match`] — first match of a pattern against a string,
+//!   returned as `[full_match, group1, group2, ...]`, or `null` when the
+//!   pattern does not match.
+//! - [`evaluate_regex_replace`] — replace every match of a pattern, with
+//!   `$1` / `${name}` group references in the replacement.
+//!
+//! The canonical name is `regex_match` rather than the bare `match`:
+//! `match` is already the `ext-control` alias of `switch`.
+//!
+//! Patterns use the [`regex`](https://docs.rs/regex) crate's syntax, which
+//! guarantees linear-time matching — no backtracking, so a user-supplied
+//! pattern cannot stall evaluation. Compiled patterns are kept in a small
+//! per-thread cache keyed by pattern text, so a rule evaluated in a loop
+//! compiles its pattern once rather than on every call.
+
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use bumpalo::Bump;
+use datavalue::DataValue;
+
+use crate::arena::{ContextStack, bvec, data_to_str};
+use crate::engine::Engine;
+use crate::node::CompiledNode;
+use crate::{Error, Result};
+
+/// Upper bound on cached compiled patterns per thread. Rules normally carry
+/// a handful of literal patterns; a data-driven pattern could otherwise grow
+/// the cache without limit, so it is cleared wholesale once full.
+const REGEX_CACHE_CAPACITY: usize = 64;
+
+thread_local! {
+    /// Per-thread compiled-pattern cache. `Regex` is cheap to clone (the
+    /// compiled program is shared), so a hit hands out a clone and releases
+    /// the borrow before matching.
+    static REGEX_CACHE: RefCell<HashMap<String, ::regex::Regex>> =
+        RefCell::new(HashMap::new());
+}
+
+/// Compile `pattern`, reusing a cached program when this thread has seen it
+/// before. An invalid pattern is an `InvalidArguments("Invalid regex
+/// pattern")` error and is not cached.
+fn compiled(pattern: &str) -> Result<::regex::Regex> {
+    REGEX_CACHE.with(|cache| {
+        if let Some(re) = cache.borrow().get(pattern) {
+            return Ok(re.clone());
+        }
+        let re = ::regex::Regex::new(pattern)
+            .map_err(|_| Error::invalid_arguments("Invalid regex pattern"))?;
+        let mut cache = cache.borrow_mut();
+        if cache.len() >= REGEX_CACHE_CAPACITY {
+            cache.clear();
+        }
+        cache.insert(pattern.to_owned(), re.clone());
+        Ok(re)
+    })
+}
+
+/// Resolve the pattern argument. It must evaluate to a string; anything
+/// else is rejected rather than stringified, since `"1"` as a regex is
+/// almost never what a numeric pattern argument meant.
+fn pattern_arg<'a>(
+    node: &'a CompiledNode,
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<::regex::Regex> {
+    match engine.dispatch_node(node, ctx, arena)? {
+        DataValue::String(p) => compiled(p),
+        _ => Err(Error::invalid_args()),
+    }
+}
+
+/// `regex_match`. `{"regex_match": [value, pattern]}` stringifies `value`
+/// like `cat` and returns its first match as `[full_match, group1, ...]`;
+/// groups that did not participate in the match are `null`. Returns `null`
+/// when nothing matches or `value` is null.
+///
+/// Matched text is sliced from the subject, never copied.
+#[inline]
+pub(crate) fn evaluate_regex_match<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    if args.len() != 2 {
+        return Err(Error::invalid_args());
+    }
+    let subject = engine.dispatch_node(&args[0], ctx, arena)?;
+    let re = pattern_arg(&args[1], ctx, engine, arena)?;
+    if matches!(subject, DataValue::Null) {
+        return Ok(crate::arena::singletons::singleton_null());
+    }
+    let text = data_to_str(subject, arena);
+
+    let Some(caps) = re.captures(text) else {
+        return Ok(crate::arena::singletons::singleton_null());
+    };
+    let mut out = bvec::<DataValue<'a>>(arena, caps.len());
+    for group in caps.iter() {
+        out.push(match group {
+            Some(m) => DataValue::String(&text[m.range()]),
+            None => DataValue::Null,
+        });
+    }
+    Ok(arena.alloc(DataValue::Array(out.into_bump_slice())))
+}
+
+/// `regex_replace`. `{"regex_replace": [value, pattern, replacement]}`
+/// stringifies `value` like `cat` and replaces every non-overlapping match
+/// of `pattern` with `replacement`. The replacement may reference groups as
+/// `$1` or `${name}` (`$$` for a literal `$`); an unknown group expands to
+/// nothing. Null `value` passes through as null.
+///
+/// A subject with no match is returned without rebuilding it.
+#[inline]
+pub(crate) fn evaluate_regex_replace<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    if args.len() != 3 {
+        return Err(Error::invalid_args());
+    }
+    let subject = engine.dispatch_node(&args[0], ctx, arena)?;
+    let re = pattern_arg(&args[1], ctx, engine, arena)?;
+    let replacement = data_to_str(engine.dispatch_node(&args[2], ctx, arena)?, arena);
+    if matches!(subject, DataValue::Null) {
+        return Ok(crate::arena::singletons::singleton_null());
+    }
+    let text = data_to_str(subject, arena);
+
+    match re.replace_all(text, replacement) {
+        Cow::Borrowed(_) if matches!(subject, DataValue::String(_)) => Ok(subject),
+        Cow::Borrowed(unchanged) => Ok(arena.alloc(DataValue::String(unchanged))),
+        Cow::Owned(replaced) => Ok(arena.alloc(DataValue::String(arena.alloc_str(&replaced)))),
+    }
+}
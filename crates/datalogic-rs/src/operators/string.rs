@@ -21,19 +21,54 @@ pub(crate) fn evaluate_concat<'a>(
     let mut buf = bumpalo::collections::String::new_in(arena);
     for arg in args {
         let av = engine.dispatch_node(arg, ctx, arena)?;
-        match av {
-            // For arrays, concat each item's string form.
-            DataValue::Array(items) => {
-                for it in *items {
-                    buf.push_str(data_to_str(it, arena));
-                }
-            }
-            _ => buf.push_str(data_to_str(av, arena)),
+        push_concat_operand(&mut buf, av, arena);
+    }
+    Ok(arena.alloc(DataValue::String(buf.into_bump_str())))
+}
+
+/// `cat`'s separator form, compiled as `[sep, item, ...]`. The separator
+/// is evaluated once, before the items, and goes between consecutive
+/// items; each item renders as a `cat` operand would.
+#[inline]
+pub(crate) fn evaluate_concat_sep<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    let Some((sep, items)) = args.split_first() else {
+        return Ok(crate::arena::singletons::singleton_empty_string());
+    };
+    let sep = data_to_str(engine.dispatch_node(sep, ctx, arena)?, arena);
+    let mut buf = bumpalo::collections::String::new_in(arena);
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 {
+            buf.push_str(sep);
         }
+        let av = engine.dispatch_node(item, ctx, arena)?;
+        push_concat_operand(&mut buf, av, arena);
     }
     Ok(arena.alloc(DataValue::String(buf.into_bump_str())))
 }
 
+/// Append one `cat` operand's string form; an array contributes each of
+/// its items in turn.
+#[inline]
+fn push_concat_operand<'a>(
+    buf: &mut bumpalo::collections::String<'a>,
+    av: &'a DataValue<'a>,
+    arena: &'a Bump,
+) {
+    match av {
+        DataValue::Array(items) => {
+            for it in *items {
+                buf.push_str(data_to_str(it, arena));
+            }
+        }
+        _ => buf.push_str(data_to_str(av, arena)),
+    }
+}
+
 /// `substr` — char-indexed substring extraction. Negative start counts from
 /// end; negative length is treated as an end position.
 #[inline]
@@ -177,8 +212,20 @@ pub(crate) fn evaluate_in<'a>(
     }
     let needle = engine.dispatch_node(&args[0], ctx, arena)?;
     let haystack = engine.dispatch_node(&args[1], ctx, arena)?;
+    Ok(crate::arena::singletons::singleton_bool(haystack_contains(
+        haystack, needle, engine,
+    )))
+}
 
-    let result = match haystack {
+/// Membership test behind `in` and `contains`, with the semantics documented
+/// on [`evaluate_in`]. Any other haystack never matches.
+#[inline]
+pub(crate) fn haystack_contains(
+    haystack: &DataValue<'_>,
+    needle: &DataValue<'_>,
+    engine: &Engine,
+) -> bool {
+    match haystack {
         // String haystack — substring check (needle must be a string).
         DataValue::String(h) => match needle {
             DataValue::String(n) => h.contains(*n),
@@ -190,8 +237,7 @@ pub(crate) fn evaluate_in<'a>(
             crate::operators::comparison::compare_equals(it, needle, true, engine).unwrap_or(false)
         }),
         _ => false,
-    };
-    Ok(crate::arena::singletons::singleton_bool(result))
+    }
 }
 
 #[cfg(feature = "ext-string")]
@@ -202,16 +248,8 @@ pub(crate) fn evaluate_starts_with<'a>(
     engine: &Engine,
     arena: &'a Bump,
 ) -> Result<&'a DataValue<'a>> {
-    if args.len() < 2 {
-        return Err(crate::Error::invalid_args());
-    }
-    let s = engine.dispatch_node(&args[0], ctx, arena)?;
-    let p = engine.dispatch_node(&args[1], ctx, arena)?;
-    let s_str = data_to_str(s, arena);
-    let p_str = data_to_str(p, arena);
-    Ok(crate::arena::singletons::singleton_bool(
-        s_str.starts_with(p_str),
-    ))
+    let (s, p) = affix_args(args, ctx, engine, arena, false)?;
+    Ok(crate::arena::singletons::singleton_bool(s.starts_with(p)))
 }
 
 #[cfg(feature = "ext-string")]
@@ -221,17 +259,104 @@ pub(crate) fn evaluate_ends_with<'a>(
     ctx: &mut ContextStack<'a>,
     engine: &Engine,
     arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    let (s, p) = affix_args(args, ctx, engine, arena, false)?;
+    Ok(crate::arena::singletons::singleton_bool(s.ends_with(p)))
+}
+
+/// Native arena-mode `starts_with_i`: `starts_with` with both strings
+/// lowercased first, so `{"starts_with_i": ["HELLO", "he"]}` is `true`.
+#[cfg(feature = "ext-string")]
+#[inline]
+pub(crate) fn evaluate_starts_with_i<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    let (s, p) = affix_args(args, ctx, engine, arena, true)?;
+    Ok(crate::arena::singletons::singleton_bool(s.starts_with(p)))
+}
+
+/// Native arena-mode `ends_with_i`: `ends_with` with both strings
+/// lowercased first.
+#[cfg(feature = "ext-string")]
+#[inline]
+pub(crate) fn evaluate_ends_with_i<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    let (s, p) = affix_args(args, ctx, engine, arena, true)?;
+    Ok(crate::arena::singletons::singleton_bool(s.ends_with(p)))
+}
+
+/// Native arena-mode `in_i`. Same as [`evaluate_in`] except that strings
+/// compare case-insensitively: a string haystack is searched for the
+/// needle with both lowercased, and string elements of an array
+/// haystack match a string needle regardless of case. Other array
+/// elements still use strict equality.
+#[cfg(feature = "ext-string")]
+#[inline]
+pub(crate) fn evaluate_in_i<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
 ) -> Result<&'a DataValue<'a>> {
+    if args.len() < 2 {
+        return Ok(crate::arena::singletons::singleton_false());
+    }
+    let needle = engine.dispatch_node(&args[0], ctx, arena)?;
+    let haystack = engine.dispatch_node(&args[1], ctx, arena)?;
+    let DataValue::String(n) = needle else {
+        return Ok(crate::arena::singletons::singleton_bool(haystack_contains(
+            haystack, needle, engine,
+        )));
+    };
+    let n = lowercase(n, arena);
+    let found = match haystack {
+        DataValue::String(h) => lowercase(h, arena).contains(n),
+        DataValue::Array(items) => items
+            .iter()
+            .any(|it| matches!(it, DataValue::String(s) if lowercase(s, arena) == n)),
+        _ => false,
+    };
+    Ok(crate::arena::singletons::singleton_bool(found))
+}
+
+/// Evaluate and stringify the value and affix arguments shared by
+/// `starts_with` / `ends_with` and their `_i` variants, lowercasing both
+/// when `fold_case` is set.
+#[cfg(feature = "ext-string")]
+#[inline]
+fn affix_args<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+    fold_case: bool,
+) -> Result<(&'a str, &'a str)> {
     if args.len() < 2 {
         return Err(crate::Error::invalid_args());
     }
-    let s = engine.dispatch_node(&args[0], ctx, arena)?;
-    let p = engine.dispatch_node(&args[1], ctx, arena)?;
-    let s_str = data_to_str(s, arena);
-    let p_str = data_to_str(p, arena);
-    Ok(crate::arena::singletons::singleton_bool(
-        s_str.ends_with(p_str),
-    ))
+    let s = data_to_str(engine.dispatch_node(&args[0], ctx, arena)?, arena);
+    let p = data_to_str(engine.dispatch_node(&args[1], ctx, arena)?, arena);
+    if fold_case {
+        return Ok((lowercase(s, arena), lowercase(p, arena)));
+    }
+    Ok((s, p))
+}
+
+/// Unicode lowercase of `s`, borrowing it unchanged when it has no
+/// uppercase characters.
+#[cfg(feature = "ext-string")]
+fn lowercase<'a>(s: &'a str, arena: &'a Bump) -> &'a str {
+    if !s.chars().any(char::is_uppercase) {
+        return s;
+    }
+    arena.alloc_str(&s.to_lowercase())
 }
 
 #[cfg(feature = "ext-string")]
@@ -286,6 +411,184 @@ pub(crate) fn evaluate_lower<'a>(
     Ok(arena.alloc(DataValue::String(buf.into_bump_str())))
 }
 
+/// Casing applied by `capitalize` / `title_case`.
+#[cfg(feature = "ext-string")]
+#[derive(Clone, Copy)]
+pub(crate) enum CaseStyle {
+    /// Uppercase the first char, lowercase the rest.
+    Capitalize,
+    /// Uppercase the first char of each whitespace-delimited word; other
+    /// chars are left as they are.
+    Title,
+}
+
+/// Native arena-mode `capitalize` / `title_case`. Stringifies the argument
+/// like `cat` and walks it by `char`, so multi-byte letters (`é` → `É`) and
+/// case mappings that expand (`ß` → `SS`) are handled the same way as
+/// `upper` / `lower`.
+#[cfg(feature = "ext-string")]
+#[inline]
+pub(crate) fn evaluate_case<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+    style: CaseStyle,
+) -> Result<&'a DataValue<'a>> {
+    if args.len() != 1 {
+        return Err(crate::Error::invalid_args());
+    }
+    let av = engine.dispatch_node(&args[0], ctx, arena)?;
+    let s = data_to_str(av, arena);
+    let mut buf = bumpalo::collections::String::with_capacity_in(s.len(), arena);
+    let mut word_start = true;
+    for (i, c) in s.chars().enumerate() {
+        let first = match style {
+            CaseStyle::Capitalize => i == 0,
+            CaseStyle::Title => word_start,
+        };
+        if first {
+            buf.extend(c.to_uppercase());
+        } else if matches!(style, CaseStyle::Capitalize) {
+            buf.extend(c.to_lowercase());
+        } else {
+            buf.push(c);
+        }
+        word_start = c.is_whitespace();
+    }
+    Ok(arena.alloc(DataValue::String(buf.into_bump_str())))
+}
+
+/// Native arena-mode `slugify`. Stringifies the argument like `cat`,
+/// lowercases it, folds accented Latin letters to ASCII (`é` → `e`,
+/// `ß` → `ss`), and collapses every run of other characters into a single
+/// `-`, with none left at either end. Letters and digits that have no ASCII
+/// folding (`ж`, `東`) are kept lowercased; combining marks are dropped.
+#[cfg(feature = "ext-string")]
+#[inline]
+pub(crate) fn evaluate_slugify<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    if args.len() != 1 {
+        return Err(crate::Error::invalid_args());
+    }
+    let s = data_to_str(engine.dispatch_node(&args[0], ctx, arena)?, arena);
+    let mut buf = bumpalo::collections::String::with_capacity_in(s.len(), arena);
+    let mut pending_hyphen = false;
+    for c in s.chars().flat_map(char::to_lowercase) {
+        if ('\u{300}'..='\u{36f}').contains(&c) {
+            continue;
+        }
+        let folded = fold_to_ascii(c);
+        if folded.is_none() && !c.is_alphanumeric() {
+            pending_hyphen = true;
+            continue;
+        }
+        if pending_hyphen && !buf.is_empty() {
+            buf.push('-');
+        }
+        pending_hyphen = false;
+        match folded {
+            Some(ascii) => buf.push_str(ascii),
+            None => buf.push(c),
+        }
+    }
+    Ok(arena.alloc(DataValue::String(buf.into_bump_str())))
+}
+
+/// ASCII spelling of an accented lowercase Latin-1 / Latin Extended-A
+/// letter for `slugify`: its base letter, or a digraph for `ß`, `æ`, `œ`,
+/// `þ`. `None` for everything else, ASCII included.
+#[cfg(feature = "ext-string")]
+fn fold_to_ascii(c: char) -> Option<&'static str> {
+    Some(match c {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => "a",
+        'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => "c",
+        'ď' | 'đ' | 'ð' => "d",
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => "e",
+        'ĝ' | 'ğ' | 'ġ' | 'ģ' => "g",
+        'ĥ' | 'ħ' => "h",
+        'ì' | 'í' | 'î' | 'ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' => "i",
+        'ĵ' => "j",
+        'ķ' => "k",
+        'ĺ' | 'ļ' | 'ľ' | 'ŀ' | 'ł' => "l",
+        'ñ' | 'ń' | 'ņ' | 'ň' => "n",
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ŏ' | 'ő' => "o",
+        'ŕ' | 'ŗ' | 'ř' => "r",
+        'ś' | 'ŝ' | 'ş' | 'š' => "s",
+        'ţ' | 'ť' | 'ŧ' => "t",
+        'ù' | 'ú' | 'û' | 'ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' => "u",
+        'ŵ' => "w",
+        'ý' | 'ÿ' | 'ŷ' => "y",
+        'ź' | 'ż' | 'ž' => "z",
+        'ß' => "ss",
+        'æ' => "ae",
+        'œ' => "oe",
+        'þ' => "th",
+        _ => return None,
+    })
+}
+
+/// What `char_at` / `code_point_at` return for the selected char.
+#[cfg(feature = "ext-string")]
+#[derive(Clone, Copy)]
+pub(crate) enum CharLookup {
+    /// The char as a one-char string; `""` when out of range.
+    Char,
+    /// The char's Unicode scalar value; `null` when out of range.
+    CodePoint,
+}
+
+/// Native arena-mode `char_at` / `code_point_at`. Indexes the stringified
+/// first argument by char, like `substr`; a negative index counts from the
+/// end. A non-integer index is an error.
+///
+/// `char_at` re-borrows the char from the arena-resident string rather than
+/// copying it.
+#[cfg(feature = "ext-string")]
+#[inline]
+pub(crate) fn evaluate_char_at<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+    lookup: CharLookup,
+) -> Result<&'a DataValue<'a>> {
+    if args.len() != 2 {
+        return Err(crate::Error::invalid_args());
+    }
+    let s = data_to_str(engine.dispatch_node(&args[0], ctx, arena)?, arena);
+    let index = engine
+        .dispatch_node(&args[1], ctx, arena)?
+        .as_i64()
+        .ok_or_else(crate::Error::invalid_args)?;
+
+    // Only a negative index needs the full decode walk to anchor it.
+    let pos = if index < 0 {
+        s.chars().count().checked_sub(index.unsigned_abs() as usize)
+    } else {
+        Some(index as usize)
+    };
+    let found = pos.and_then(|n| s.char_indices().nth(n));
+    Ok(match (found, lookup) {
+        (Some((b, c)), CharLookup::Char) => arena.alloc(DataValue::String(&s[b..b + c.len_utf8()])),
+        (Some((_, c)), CharLookup::CodePoint) => {
+            let cp = c as i64;
+            crate::arena::singletons::singleton_small_int(cp).unwrap_or_else(|| {
+                arena.alloc(DataValue::Number(datavalue::NumberValue::from_i64(cp)))
+            })
+        }
+        (None, CharLookup::Char) => crate::arena::singletons::singleton_empty_string(),
+        (None, CharLookup::CodePoint) => crate::arena::singletons::singleton_null(),
+    })
+}
+
+/// Native arena-mode `trim`. An optional second argument is the set of
+/// characters to strip (each `char` of its string form); without it, or
+/// when it is `null`, Unicode whitespace is stripped.
 #[cfg(feature = "ext-string")]
 #[inline]
 pub(crate) fn evaluate_trim<'a>(
@@ -293,15 +596,75 @@ pub(crate) fn evaluate_trim<'a>(
     ctx: &mut ContextStack<'a>,
     engine: &Engine,
     arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    trim_sides(args, ctx, engine, arena, TrimSides::Both)
+}
+
+/// Native arena-mode `trim_start`: `trim` on the leading end only.
+#[cfg(feature = "ext-string")]
+#[inline]
+pub(crate) fn evaluate_trim_start<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    trim_sides(args, ctx, engine, arena, TrimSides::Start)
+}
+
+/// Native arena-mode `trim_end`: `trim` on the trailing end only.
+#[cfg(feature = "ext-string")]
+#[inline]
+pub(crate) fn evaluate_trim_end<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    trim_sides(args, ctx, engine, arena, TrimSides::End)
+}
+
+/// Which ends of the string `trim_sides` strips.
+#[cfg(feature = "ext-string")]
+#[derive(Clone, Copy)]
+enum TrimSides {
+    Both,
+    Start,
+    End,
+}
+
+#[cfg(feature = "ext-string")]
+#[inline]
+fn trim_sides<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+    sides: TrimSides,
 ) -> Result<&'a DataValue<'a>> {
     if args.is_empty() {
         return Err(crate::Error::invalid_args());
     }
     let av = engine.dispatch_node(&args[0], ctx, arena)?;
     let s = data_to_str(av, arena);
-    // `s` is already arena-resident, so `s.trim()` is an arena `&'a str`
+    let set = match args.get(1) {
+        Some(node) => match engine.dispatch_node(node, ctx, arena)? {
+            DataValue::Null => None,
+            chars => Some(data_to_str(chars, arena)),
+        },
+        None => None,
+    };
+    // `s` is already arena-resident, so every trim is an arena `&'a str`
     // sub-slice; no re-copy needed.
-    Ok(arena.alloc(DataValue::String(s.trim())))
+    let trimmed = match (set, sides) {
+        (None, TrimSides::Both) => s.trim(),
+        (None, TrimSides::Start) => s.trim_start(),
+        (None, TrimSides::End) => s.trim_end(),
+        (Some(set), TrimSides::Both) => s.trim_matches(|c| set.contains(c)),
+        (Some(set), TrimSides::Start) => s.trim_start_matches(|c| set.contains(c)),
+        (Some(set), TrimSides::End) => s.trim_end_matches(|c| set.contains(c)),
+    };
+    Ok(arena.alloc(DataValue::String(trimmed)))
 }
 
 /// Native arena-mode `split`. Splits text by a plain string delimiter,
@@ -369,3 +732,338 @@ fn split_arena_normal<'a>(text: &str, delim: &str, arena: &'a Bump) -> Result<&'
     }
     Ok(arena.alloc(DataValue::Array(items.into_bump_slice())))
 }
+
+/// Native arena-mode `join`. Renders each array item with the same string
+/// coercion as `cat` and joins them with the separator (default `""`),
+/// building the result directly in the arena. Null or empty input yields
+/// `""`; any other non-array input is an error.
+#[cfg(feature = "ext-string")]
+#[inline]
+pub(crate) fn evaluate_join<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    if args.is_empty() || args.len() > 2 {
+        return Err(crate::Error::invalid_args());
+    }
+    let items = match engine.dispatch_node(&args[0], ctx, arena)? {
+        DataValue::Array(items) => *items,
+        DataValue::Null => return Ok(crate::arena::singletons::singleton_empty_string()),
+        _ => return Err(crate::Error::invalid_args()),
+    };
+    let sep: &'a str = match args.get(1) {
+        Some(node) => data_to_str(engine.dispatch_node(node, ctx, arena)?, arena),
+        None => "",
+    };
+
+    match items {
+        [] => Ok(crate::arena::singletons::singleton_empty_string()),
+        [only] => Ok(arena.alloc(DataValue::String(data_to_str(only, arena)))),
+        [first, rest @ ..] => {
+            let mut buf = bumpalo::collections::String::new_in(arena);
+            buf.push_str(data_to_str(first, arena));
+            for it in rest {
+                buf.push_str(sep);
+                buf.push_str(data_to_str(it, arena));
+            }
+            Ok(arena.alloc(DataValue::String(buf.into_bump_str())))
+        }
+    }
+}
+
+/// Which end `pad_start` / `pad_end` fills.
+#[cfg(feature = "ext-string")]
+#[derive(Clone, Copy)]
+pub(crate) enum PadSide {
+    Start,
+    End,
+}
+
+/// Upper bound on the chars `pad_start` / `pad_end` and `repeat` may
+/// produce. Rules are often user-supplied, so a data-driven length or count
+/// must not be able to exhaust memory.
+#[cfg(feature = "ext-string")]
+const MAX_GENERATED_CHARS: usize = 1_000_000;
+
+/// Native arena-mode `pad_start` / `pad_end`. Stringifies the first argument
+/// like `cat`, then fills it to the target length in chars (matching
+/// `length`) by repeating the pad string (default `" "`) and clipping the
+/// last repetition to fit. Inputs already at or past the target length, and
+/// an empty pad string, return the stringified input unchanged. A target
+/// length above [`MAX_GENERATED_CHARS`] is rejected rather than allocated.
+#[cfg(feature = "ext-string")]
+#[inline]
+pub(crate) fn evaluate_pad<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+    side: PadSide,
+) -> Result<&'a DataValue<'a>> {
+    if args.len() < 2 || args.len() > 3 {
+        return Err(crate::Error::invalid_args());
+    }
+    let text_av = engine.dispatch_node(&args[0], ctx, arena)?;
+    let text = data_to_str(text_av, arena);
+    let target = match engine.dispatch_node(&args[1], ctx, arena)?.as_i64() {
+        Some(n) if n >= 0 && n as u64 <= MAX_GENERATED_CHARS as u64 => n as usize,
+        _ => return Err(crate::Error::invalid_args()),
+    };
+    let pad: &'a str = match args.get(2) {
+        Some(node) => data_to_str(engine.dispatch_node(node, ctx, arena)?, arena),
+        None => " ",
+    };
+
+    let len = text.chars().count();
+    if len >= target || pad.is_empty() {
+        return Ok(match text_av {
+            DataValue::String(_) => text_av,
+            _ => arena.alloc(DataValue::String(text)),
+        });
+    }
+
+    let fill = pad.chars().cycle().take(target - len);
+    let mut buf = bumpalo::collections::String::with_capacity_in(text.len() + target - len, arena);
+    match side {
+        PadSide::Start => {
+            buf.extend(fill);
+            buf.push_str(text);
+        }
+        PadSide::End => {
+            buf.push_str(text);
+            buf.extend(fill);
+        }
+    }
+    Ok(arena.alloc(DataValue::String(buf.into_bump_str())))
+}
+
+/// Native arena-mode `repeat`. Stringifies the first argument like `cat` and
+/// repeats it `n` times. `n` goes through the engine's numeric coercion and
+/// is truncated towards zero; `n <= 0` yields `""`. A result longer than
+/// [`MAX_GENERATED_CHARS`] chars is rejected rather than allocated.
+#[cfg(feature = "ext-string")]
+#[inline]
+pub(crate) fn evaluate_repeat<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    if args.len() != 2 {
+        return Err(crate::Error::invalid_args());
+    }
+    let text = data_to_str(engine.dispatch_node(&args[0], ctx, arena)?, arena);
+    let n = crate::arena::coerce_to_number_cfg(engine.dispatch_node(&args[1], ctx, arena)?, engine)
+        .filter(|n| !n.is_nan())
+        .ok_or_else(crate::Error::invalid_args)?
+        .trunc();
+    if n <= 0.0 || text.is_empty() {
+        return Ok(crate::arena::singletons::singleton_empty_string());
+    }
+    let chars = text.chars().count() as f64;
+    if chars * n > MAX_GENERATED_CHARS as f64 {
+        return Err(crate::Error::invalid_args());
+    }
+
+    let n = n as usize;
+    let mut buf = bumpalo::collections::String::with_capacity_in(text.len() * n, arena);
+    for _ in 0..n {
+        buf.push_str(text);
+    }
+    Ok(arena.alloc(DataValue::String(buf.into_bump_str())))
+}
+
+/// Native arena-mode `format`. Stringifies the first argument as the
+/// template and every following argument the way `join` renders an item
+/// (`null` → `""`, arrays and objects as JSON), then replaces each
+/// `{n}` with argument `n` (0-based, after the template). A placeholder
+/// whose index has no argument, or whose braces hold anything but digits,
+/// is copied through literally, as is an unmatched brace; `{{` and `}}`
+/// write a single literal brace. Substituted text is never re-scanned.
+#[cfg(feature = "ext-string")]
+#[inline]
+pub(crate) fn evaluate_format<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    let [template, values @ ..] = args else {
+        return Err(crate::Error::invalid_args());
+    };
+    let template = data_to_str(engine.dispatch_node(template, ctx, arena)?, arena);
+    let mut parts = crate::arena::bvec::<&'a str>(arena, values.len());
+    for node in values {
+        parts.push(data_to_str(engine.dispatch_node(node, ctx, arena)?, arena));
+    }
+
+    Ok(fill_placeholders(template, arena, |inner| {
+        if inner.is_empty() || !inner.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        parts.get(inner.parse::<usize>().ok()?).copied()
+    }))
+}
+
+/// Native arena-mode `template`. `{"template": [text, data?, missing?]}`
+/// replaces each `{path}` in `text` with the value at the dot-notation
+/// `path` inside `data` (the current context when omitted), stringified
+/// the way `format` renders an argument. A `*` segment walks every array
+/// element, as in `var`. A path that doesn't resolve
+/// renders as `missing`, `""` by default; a path that resolves to `null`
+/// is present and renders as `""`. Brace handling matches `format`: `{}`
+/// and unmatched braces are literal, `{{` / `}}` write a single brace.
+#[cfg(feature = "ext-string")]
+#[inline]
+pub(crate) fn evaluate_template<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    if args.is_empty() || args.len() > 3 {
+        return Err(crate::Error::invalid_args());
+    }
+    let template = data_to_str(engine.dispatch_node(&args[0], ctx, arena)?, arena);
+    let data = match args.get(1) {
+        Some(node) => engine.dispatch_node(node, ctx, arena)?,
+        None => ctx.current().data(),
+    };
+    let missing = match args.get(2) {
+        Some(node) => data_to_str(engine.dispatch_node(node, ctx, arena)?, arena),
+        None => "",
+    };
+
+    Ok(fill_placeholders(template, arena, |path| {
+        if path.is_empty() {
+            return None;
+        }
+        Some(match template_lookup(data, path, arena) {
+            Some(value) => data_to_str(value, arena),
+            None => missing,
+        })
+    }))
+}
+
+/// `access_path_str_ref`, with a detour through the wildcard walk when the
+/// placeholder path has a `*` segment.
+#[cfg(feature = "ext-string")]
+#[inline]
+fn template_lookup<'a>(
+    data: &'a DataValue<'a>,
+    path: &str,
+    arena: &'a Bump,
+) -> Option<&'a DataValue<'a>> {
+    if path.split('.').any(|segment| segment == "*") {
+        let segments = crate::compile::path_segments::parse_path_segments(path);
+        return crate::arena::value::traverse_wildcard_segments(data, &segments, arena);
+    }
+    crate::arena::value::access_path_str_ref(data, path)
+}
+
+/// The placeholder paths `template` looks up in `text`, in order. Static
+/// analysis only, hence the throwaway arena.
+#[cfg(feature = "ext-string")]
+pub(crate) fn template_paths(text: &str) -> Vec<&str> {
+    let mut paths = Vec::new();
+    fill_placeholders(text, &Bump::new(), |path| {
+        if !path.is_empty() {
+            paths.push(path);
+        }
+        None
+    });
+    paths
+}
+
+/// Shared placeholder scanner for `format` and `template`. `resolve` gets
+/// the text between a `{` and the next `}` and returns the replacement,
+/// or `None` to copy the placeholder through literally. `{{` and `}}`
+/// write a single brace; substituted text is never re-scanned.
+#[cfg(feature = "ext-string")]
+fn fill_placeholders<'a, 't>(
+    template: &'t str,
+    arena: &'a Bump,
+    mut resolve: impl FnMut(&'t str) -> Option<&'a str>,
+) -> &'a DataValue<'a> {
+    let mut buf = bumpalo::collections::String::with_capacity_in(template.len(), arena);
+    let mut rest = template;
+    while let Some(pos) = rest.find(['{', '}']) {
+        buf.push_str(&rest[..pos]);
+        let tail = &rest[pos..];
+        if tail.starts_with("{{") || tail.starts_with("}}") {
+            buf.push_str(&tail[..1]);
+            rest = &tail[2..];
+            continue;
+        }
+        let placeholder = tail
+            .strip_prefix('{')
+            .and_then(|inner| Some(&inner[..inner.find('}')?]))
+            .filter(|inner| !inner.contains('{'));
+        if let Some((inner, value)) = placeholder.and_then(|inner| Some((inner, resolve(inner)?))) {
+            buf.push_str(value);
+            rest = &tail[inner.len() + 2..];
+            continue;
+        }
+        buf.push_str(&tail[..1]);
+        rest = &tail[1..];
+    }
+    buf.push_str(rest);
+    arena.alloc(DataValue::String(buf.into_bump_str()))
+}
+
+/// Native arena-mode `number_format`. Coerces the first argument like
+/// arithmetic does and renders it with exactly `decimals` places, grouping
+/// the integer digits in threes with the optional separator. The decimal
+/// point is always `.`.
+///
+/// Rounding is [`round_half_away`](crate::operators::arithmetic::round_half_away),
+/// the same as `to_fixed`: half away from zero on the value's shortest
+/// decimal form, so `1.005` rounds to `"1.01"`. A result that rounds to
+/// zero drops the minus sign.
+#[cfg(feature = "ext-string")]
+#[inline]
+pub(crate) fn evaluate_number_format<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    use crate::operators::arithmetic::{decimal_places, round_half_away};
+
+    if args.len() < 2 || args.len() > 3 {
+        return Err(crate::Error::invalid_args());
+    }
+    let value = engine.dispatch_node(&args[0], ctx, arena)?;
+    let decimals = decimal_places(engine.dispatch_node(&args[1], ctx, arena)?)?;
+    let separator: &'a str = match args.get(2) {
+        Some(node) => data_to_str(engine.dispatch_node(node, ctx, arena)?, arena),
+        None => "",
+    };
+
+    let rounded = round_half_away(value, decimals, ctx, engine, arena)?;
+    let digits = &rounded.digits;
+    let int_len = rounded.int_len();
+    let groups = (int_len - 1) / 3;
+    let mut buf = bumpalo::collections::String::with_capacity_in(
+        digits.len() + 2 + groups * separator.len(),
+        arena,
+    );
+    if rounded.negative {
+        buf.push('-');
+    }
+    for (i, &d) in digits[..int_len].iter().enumerate() {
+        if i > 0 && (int_len - i) % 3 == 0 {
+            buf.push_str(separator);
+        }
+        buf.push(d as char);
+    }
+    if decimals > 0 {
+        buf.push('.');
+        for &d in &digits[int_len..] {
+            buf.push(d as char);
+        }
+    }
+    Ok(arena.alloc(DataValue::String(buf.into_bump_str())))
+}
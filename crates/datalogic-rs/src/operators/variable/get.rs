@@ -0,0 +1,85 @@
+//! Arena-mode `get` and `has` evaluation.
+//!
+//! `get` is `var`'s path-plus-default lookup applied to any value rather
+//! than the data in scope. Unlike `??` / `default`, which also replace a
+//! present `null`, it only falls back when the path is absent. The lookup
+//! goes through `traverse_segments`, whose `None` / `Some(Null)` split is
+//! exactly that distinction. `has` reports that split as a boolean. The
+//! whole module is gated on `feature = "ext-control"` via the `mod get;`
+//! declaration in the parent.
+
+use bumpalo::Bump;
+
+use crate::arena::{ContextStack, DataValue};
+use crate::compile::path_segments::{parse_path_segments, segment_from_value};
+use crate::node::PathSegment;
+use crate::{CompiledNode, Engine, Result};
+
+/// Arena-mode `get`. `{"get": [source, path, default]}` returns the value at
+/// `path` inside `source`, or `default` when the path is absent. A path
+/// that resolves to an explicit `null` returns `null`; the default is not
+/// consulted, and is only evaluated when it is needed.
+///
+/// `path` is a dot-separated string parsed exactly like a `var` path, or an
+/// array of keys and indices for keys that contain dots. An empty path
+/// returns `source`. `default` is optional and falls back to `null`. The
+/// path is absent when any level is missing, an index is out of range, or a
+/// level is a scalar (including `null`) that can't be stepped into.
+#[inline]
+pub(crate) fn evaluate_get<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    if !(2..=3).contains(&args.len()) {
+        return Err(crate::Error::invalid_args());
+    }
+
+    let source = engine.dispatch_node(&args[0], ctx, arena)?;
+    let segments = path_segments(engine.dispatch_node(&args[1], ctx, arena)?)?;
+
+    if let Some(found) = crate::arena::value::traverse_segments(source, &segments) {
+        return Ok(found);
+    }
+    match args.get(2) {
+        Some(default) => engine.dispatch_node(default, ctx, arena),
+        None => Ok(crate::arena::singletons::singleton_null()),
+    }
+}
+
+/// Arena-mode `has`. `{"has": [source, path]}` is `true` when `path` is
+/// present inside `source` — the case where `get` would not use its
+/// default — and `false` otherwise. A path that resolves to an explicit
+/// `null` is present. Missing levels, out-of-range indices, and scalars
+/// in the middle of the path all give `false` rather than an error; only
+/// a `path` that is neither a string nor an array is `InvalidArguments`.
+#[inline]
+pub(crate) fn evaluate_has<'a>(
+    args: &'a [CompiledNode],
+    ctx: &mut ContextStack<'a>,
+    engine: &Engine,
+    arena: &'a Bump,
+) -> Result<&'a DataValue<'a>> {
+    if args.len() != 2 {
+        return Err(crate::Error::invalid_args());
+    }
+
+    let source = engine.dispatch_node(&args[0], ctx, arena)?;
+    let segments = path_segments(engine.dispatch_node(&args[1], ctx, arena)?)?;
+    let found = crate::arena::value::traverse_segments(source, &segments).is_some();
+    Ok(crate::arena::singletons::singleton_bool(found))
+}
+
+/// Parse an evaluated `get` / `has` path: a dot-separated string, parsed
+/// like a `var` path, or an array of keys and indices.
+fn path_segments(path: &DataValue<'_>) -> Result<Vec<PathSegment>> {
+    match path {
+        DataValue::String(path) => Ok(parse_path_segments(path)),
+        DataValue::Array(items) => items
+            .iter()
+            .map(|item| segment_from_value(item).ok_or_else(crate::Error::invalid_args))
+            .collect(),
+        _ => Err(crate::Error::invalid_args()),
+    }
+}
@@ -1,4 +1,4 @@
-//! Arena-mode variable access (`val` / `var` / `exists`).
+//! Arena-mode variable access (`val` / `var` / `exists` / `get` / `has`).
 //!
 //! Both `var` and `val` operator names normalize to `OpCode::Val`
 //! (see `OpCode::FromStr`); the var-specific arg shape (path + default
@@ -9,6 +9,9 @@
 //!   (`evaluate_val_compiled`), plus the four-stage resolution helpers.
 //! - [`exists`] — `evaluate_exists` and `evaluate_exists_compiled`
 //!   (gated on the `ext-control` feature).
+//! - [`get`] — `evaluate_get`, a path lookup whose default only applies to
+//!   absent paths, and `evaluate_has`, the same lookup as a presence check
+//!   (gated on the `ext-control` feature).
 //!
 //! Helpers shared by both flows live at module level here.
 
@@ -20,10 +23,14 @@ use crate::{CompiledNode, Result};
 
 #[cfg(feature = "ext-control")]
 mod exists;
+#[cfg(feature = "ext-control")]
+mod get;
 mod val;
 
 #[cfg(feature = "ext-control")]
 pub(crate) use exists::{evaluate_exists, evaluate_exists_compiled};
+#[cfg(feature = "ext-control")]
+pub(crate) use get::{evaluate_get, evaluate_has};
 pub(crate) use val::{evaluate_val, evaluate_val_compiled};
 
 /// Resolve a `[level]` + metadata-hint path (`"index"` / `"key"`) against
@@ -60,6 +67,37 @@ fn current_data<'a>(ctx: &ContextStack<'a>) -> &'a DataValue<'a> {
     ctx.current().data()
 }
 
+/// The caught error a `try` catch arm exposes as `error`, when the current
+/// frame is a catch arm and its data doesn't have an `error` field of its
+/// own (a real field always wins).
+#[cfg(feature = "error-handling")]
+#[inline]
+fn caught_error<'a>(ctx: &ContextStack<'a>) -> Option<&'a DataValue<'a>> {
+    let error = ctx.caught_error()?;
+    if let DataValue::Object(pairs) = current_data(ctx) {
+        if crate::arena::value::object_lookup_field(pairs, "error").is_some() {
+            return None;
+        }
+    }
+    Some(error)
+}
+
+/// The element index a `reduce` body reads as `index`, when the current
+/// frame is a reduce frame. Consulted only after the lookup misses on the
+/// element, so an `index` field of the element itself wins.
+#[inline]
+fn reduce_index<'a>(ctx: &ContextStack<'a>, arena: &'a Bump) -> Option<&'a DataValue<'a>> {
+    use crate::arena::context::{ContextFrame, ContextRef};
+    let ContextRef::Frame(ContextFrame::Reduce { index, .. }) = ctx.current() else {
+        return None;
+    };
+    let i = *index as i64;
+    Some(
+        crate::arena::singletons::singleton_small_int(i)
+            .unwrap_or_else(|| arena.alloc(DataValue::Number(datavalue::NumberValue::Integer(i)))),
+    )
+}
+
 /// Frame data at a given level (or `None` if the level walks past the root).
 #[inline]
 fn frame_data_at_level<'a>(ctx: &ContextStack<'a>, level: isize) -> Option<&'a DataValue<'a>> {
@@ -164,16 +202,48 @@ fn array_get<'a>(av: &'a DataValue<'a>, i: usize) -> Option<&'a DataValue<'a>> {
 }
 
 /// Resolve the var's `default_value` when the primary lookup misses, or
-/// fall back to a null singleton.
+/// fall back to a null singleton — unless
+/// [`EvaluationConfig::strict_variables`](crate::EvaluationConfig::strict_variables)
+/// is set, in which case a miss with no default is a `VariableNotFound`
+/// error naming the dotted path.
 #[inline]
 fn default_or_null<'a>(
     default_value: Option<&'a CompiledNode>,
+    segments: &[PathSegment],
     ctx: &mut ContextStack<'a>,
     engine: &crate::Engine,
     arena: &'a Bump,
 ) -> Result<&'a DataValue<'a>> {
     match default_value {
         Some(node) => engine.dispatch_node(node, ctx, arena),
+        None if engine.config().strict_variables => {
+            Err(crate::Error::variable_not_found(segments_to_path(segments)))
+        }
         None => Ok(crate::arena::singletons::singleton_null()),
     }
 }
+
+/// Render compiled path segments back to the dotted form the rule used.
+#[cold]
+fn segments_to_path(segments: &[PathSegment]) -> String {
+    segments
+        .iter()
+        .map(|seg| match seg {
+            PathSegment::Field(s)
+            | PathSegment::FieldOrIndex(s, _)
+            | PathSegment::FieldOrIndexFromEnd(s, _) => s.to_string(),
+            PathSegment::Index(i) => i.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+/// Null for a dynamic-path miss, or `VariableNotFound(path)` under
+/// [`EvaluationConfig::strict_variables`](crate::EvaluationConfig::strict_variables).
+#[inline]
+fn missing_or_null<'a>(path: &str, engine: &crate::Engine) -> Result<&'a DataValue<'a>> {
+    if engine.config().strict_variables {
+        return Err(crate::Error::variable_not_found(path.to_string()));
+    }
+    Ok(crate::arena::singletons::singleton_null())
+}
@@ -26,7 +26,13 @@ use crate::{CompiledNode, Error, Result};
 /// 3. **Root-scope fast path** (`scope_level == 0` at root depth) — arena
 ///    traversal straight from the input, no frame walk. This is the dominant
 ///    path in real workloads and stays inline for branch-prediction.
-/// 4. **General context-stack walk** — for non-root scopes (`{"val": [[1], …]}`).
+/// 4. **General context-stack walk** — for non-root scopes (`{"val": [[1], …]}`),
+///    and for lookups inside a `try` catch arm, where a missed `error` path
+///    falls back to the caught error, or a `reduce` body, where a missed
+///    `index` falls back to the element index.
+///
+/// Wildcard paths (`items.*.price`) branch off before stage 1 to
+/// [`resolve_wildcard`], which builds a fresh array.
 ///
 /// Each branch falls through to `default_or_null` on miss; the var's
 /// `default_value` (when present) is evaluated lazily there.
@@ -62,10 +68,14 @@ pub(crate) fn evaluate_val_compiled<'a>(
         };
         return match resolved {
             Some(av) => Ok(av),
-            None => default_or_null(default_value, ctx, engine, arena),
+            None => default_or_null(default_value, segments, ctx, engine, arena),
         };
     }
 
+    if metadata_hint == MetadataHint::Wildcard {
+        return resolve_wildcard(segments, ctx, engine, arena, default_value);
+    }
+
     if let Some(av) = resolve_metadata_hint(metadata_hint, ctx, arena) {
         return Ok(av);
     }
@@ -101,7 +111,42 @@ fn resolve_metadata_hint<'a>(
             .current()
             .get_key()
             .map(|key| &*arena.alloc(DataValue::String(key))),
-        MetadataHint::None => None,
+        MetadataHint::None | MetadataHint::Wildcard => None,
+    }
+}
+
+/// Wildcard paths — `{"var": "items.*.price"}`. Walks the current frame's
+/// data (or a reduce slot, for `current.` / `accumulator.` paths inside a
+/// reduce body) and maps the path after each `*` over the array found there;
+/// see [`traverse_wildcard_segments`](crate::arena::value::traverse_wildcard_segments).
+/// A miss before the first `*` falls back to the default.
+#[cold]
+#[inline(never)]
+fn resolve_wildcard<'a>(
+    segments: &[PathSegment],
+    ctx: &mut ContextStack<'a>,
+    engine: &crate::Engine,
+    arena: &'a Bump,
+    default_value: Option<&'a CompiledNode>,
+) -> Result<&'a DataValue<'a>> {
+    use crate::arena::context::ContextRef;
+
+    let mut base = (current_data(ctx), segments);
+    if let (ContextRef::Frame(f), Some(PathSegment::Field(first))) =
+        (ctx.current(), segments.first())
+    {
+        let slot = match first.as_ref() {
+            "current" => f.get_reduce_current(),
+            "accumulator" => f.get_reduce_accumulator(),
+            _ => None,
+        };
+        if let Some(slot) = slot {
+            base = (slot, &segments[1..]);
+        }
+    }
+    match crate::arena::value::traverse_wildcard_segments(base.0, base.1, arena) {
+        Some(av) => Ok(av),
+        None => default_or_null(default_value, segments, ctx, engine, arena),
     }
 }
 
@@ -143,7 +188,7 @@ fn resolve_reduce_hint<'a>(
             let resolved = crate::arena::value::traverse_segments(slot, &segments[1..]);
             Some(match resolved {
                 Some(av) => Ok(av),
-                None => default_or_null(default_value, ctx, engine, arena),
+                None => default_or_null(default_value, segments, ctx, engine, arena),
             })
         }
         ReduceHint::None => unreachable!(),
@@ -171,10 +216,29 @@ fn resolve_via_context_stack<'a>(
     if segments.is_empty() {
         return Ok(av);
     }
-    match crate::arena::value::traverse_segments(av, segments) {
-        Some(child) => Ok(child),
-        None => default_or_null(default_value, ctx, engine, arena),
+    if let Some(child) = crate::arena::value::traverse_segments(av, segments) {
+        return Ok(child);
     }
+    #[cfg(feature = "error-handling")]
+    if scope_level == 0 {
+        if let (PathSegment::Field(first), Some(error)) = (&segments[0], super::caught_error(ctx)) {
+            if &**first == "error" {
+                if let Some(child) = crate::arena::value::traverse_segments(error, &segments[1..]) {
+                    return Ok(child);
+                }
+            }
+        }
+    }
+    if scope_level == 0 {
+        if let [PathSegment::Field(name)] = segments {
+            if &**name == "index" {
+                if let Some(av) = super::reduce_index(ctx, arena) {
+                    return Ok(av);
+                }
+            }
+        }
+    }
+    default_or_null(default_value, segments, ctx, engine, arena)
 }
 
 /// Arena-native `val` operator. Mirrors the value-mode shape (level access,
@@ -212,7 +276,7 @@ pub(crate) fn evaluate_val<'a>(
     if let Some(arr_len) = array_len(path_av) {
         return eval_val_array_path(path_av, arr_len, ctx, arena);
     }
-    eval_val_scalar_path(path_av, ctx)
+    eval_val_scalar_path(path_av, ctx, engine, arena)
 }
 
 /// Multi-arg `val` form (`args.len() >= 2`). Evaluates `args[0]` once and
@@ -358,11 +422,16 @@ fn eval_val_array_path<'a>(
 
 /// Single-arg `val` where the path arg is a string or numeric scalar.
 /// Strings get the reduce-shortcut probe (`current` / `accumulator` /
-/// dotted siblings) and the "direct key wins over dotted-path" rule;
-/// non-negative integers index a numeric key on current data.
+/// dotted siblings), the "direct key wins over dotted-path" rule, and the
+/// catch-arm `error` and reduce-body `index` fallbacks;
+/// integers index a numeric key on current data (negative ones from the
+/// end of an array). A miss is
+/// `null`, or `VariableNotFound` under `strict_variables`.
 fn eval_val_scalar_path<'a>(
     path_av: &'a DataValue<'a>,
     ctx: &ContextStack<'a>,
+    engine: &crate::Engine,
+    arena: &'a Bump,
 ) -> Result<&'a DataValue<'a>> {
     use crate::arena::context::ContextRef;
     use crate::arena::value::access_path_str_ref;
@@ -398,23 +467,46 @@ fn eval_val_scalar_path<'a>(
                 return Ok(av);
             }
         }
-        return Ok(access_path_str_ref(cur, s)
-            .unwrap_or_else(|| crate::arena::singletons::singleton_null()));
+        if let Some(av) = access_path_str_ref(cur, s) {
+            return Ok(av);
+        }
+        #[cfg(feature = "error-handling")]
+        {
+            let rest = if s == "error" {
+                Some("")
+            } else {
+                s.strip_prefix("error.")
+            };
+            if let Some(av) = rest.and_then(|rest| {
+                super::caught_error(ctx).and_then(|error| access_path_str_ref(error, rest))
+            }) {
+                return Ok(av);
+            }
+        }
+        if s == "index" {
+            if let Some(av) = super::reduce_index(ctx, arena) {
+                return Ok(av);
+            }
+        }
+        return super::missing_or_null(s, engine);
     }
 
     if let Some(i) = path_av.as_i64() {
-        if i >= 0 {
-            let cur = current_data(ctx);
-            // Common small indices (0..100) hit the static `&'static str`
-            // cache; only larger keys pay the heap `String` allocation.
-            if let Some(static_key) = super::small_int_str(i) {
-                return Ok(access_path_str_ref(cur, static_key)
-                    .unwrap_or_else(|| crate::arena::singletons::singleton_null()));
-            }
-            let key = i.to_string();
-            return Ok(access_path_str_ref(cur, &key)
-                .unwrap_or_else(|| crate::arena::singletons::singleton_null()));
+        let cur = current_data(ctx);
+        // Common small indices (0..100) hit the static `&'static str`
+        // cache; only larger keys (and negative, from-the-end ones) pay the
+        // heap `String` allocation.
+        if let Some(static_key) = super::small_int_str(i) {
+            return match access_path_str_ref(cur, static_key) {
+                Some(av) => Ok(av),
+                None => super::missing_or_null(static_key, engine),
+            };
         }
+        let key = i.to_string();
+        return match access_path_str_ref(cur, &key) {
+            Some(av) => Ok(av),
+            None => super::missing_or_null(&key, engine),
+        };
     }
 
     Ok(crate::arena::singletons::singleton_null())
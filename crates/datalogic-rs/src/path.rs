@@ -56,7 +56,7 @@ impl Logic {
         }
 
         let mut index: HashMap<u32, NodeInfo> = HashMap::new();
-        walk(&self.root, None, None, "", &mut index);
+        walk(&self.root, None, String::new(), &mut index);
 
         let mut out = Vec::with_capacity(ids.len());
         // Breadcrumb is leaf-to-root; reverse for natural root-to-leaf reading.
@@ -75,29 +75,27 @@ impl Logic {
 }
 
 /// Depth-first walk of a [`CompiledNode`], recording (operator, arg_index,
-/// json_pointer) for every reachable node id. `parent_op` and
-/// `parent_pointer` describe how *this* node is reached from above.
+/// json_pointer) for every reachable node id. `arg_index` and
+/// `json_pointer` describe how *this* node is reached from above.
 ///
 /// Recursion delegates the "what are this node's children" question to
 /// [`CompiledNode::visit_indexed_children`] so the variant match lives in
 /// exactly one place.
 fn walk(
     node: &CompiledNode,
-    parent_op: Option<&str>,
     arg_index: Option<u32>,
-    parent_pointer: &str,
+    json_pointer: String,
     out: &mut HashMap<u32, NodeInfo>,
 ) {
     // CSE memo wrappers are path-transparent: delegate before the generic
     // body so the wrapped node's operator/pointer are recorded exactly as
     // in an unwrapped tree (no extra "/op/0" step for the wrapper).
     if let CompiledNode::Cse(data) = node {
-        return walk(&data.inner, parent_op, arg_index, parent_pointer, out);
+        return walk(&data.inner, arg_index, json_pointer, out);
     }
 
     let id = node.id();
     let operator = node.operator_name().map(|c| c.into_owned());
-    let json_pointer = build_pointer(parent_pointer, parent_op, arg_index);
 
     // Children of an `Array` form pointers like "/<idx>"; for every other
     // variant the current node's operator name is the pointer prefix.
@@ -111,7 +109,8 @@ fn walk(
     // both owned values into the map — node ids are unique, so insertion
     // order does not matter, and this avoids cloning them per node.
     node.visit_indexed_children(&mut |i, child| {
-        walk(child, child_parent_op, Some(i), &json_pointer, out);
+        let pointer = child_pointer(node, &json_pointer, child_parent_op, i);
+        walk(child, Some(i), pointer, out);
     });
 
     out.insert(
@@ -124,13 +123,46 @@ fn walk(
     );
 }
 
+/// Pointer to argument `index` of `parent`, which sits at `parent_pointer`.
 #[inline]
-fn build_pointer(parent_pointer: &str, parent_op: Option<&str>, arg_index: Option<u32>) -> String {
-    match (parent_op, arg_index) {
-        (Some(op), Some(idx)) => format!("{}/{}/{}", parent_pointer, op, idx),
+fn child_pointer(
+    parent: &CompiledNode,
+    parent_pointer: &str,
+    parent_op: Option<&str>,
+    index: u32,
+) -> String {
+    // `let`'s flattened `[name, expr, ..., body]` args map back onto the
+    // source `[{name: expr, ...}, body]`.
+    #[cfg(feature = "ext-control")]
+    if let CompiledNode::BuiltinOperator {
+        opcode: crate::opcode::OpCode::Let,
+        args,
+        ..
+    } = parent
+    {
+        use crate::compile::{LetOperand, let_operand};
+        match let_operand(args, index as usize) {
+            Some(LetOperand::Binding(name)) => return format!("{parent_pointer}/let/0/{name}"),
+            Some(LetOperand::Body) => return format!("{parent_pointer}/let/1"),
+            None => {}
+        }
+    }
+    // `cat`'s separator form compiles to `[sep, item, ...]` from the source
+    // `{"sep": ..., "items": [...]}`.
+    if let CompiledNode::BuiltinOperator {
+        opcode: crate::opcode::OpCode::ConcatSep,
+        ..
+    } = parent
+    {
+        return match index.checked_sub(1) {
+            None => format!("{parent_pointer}/cat/sep"),
+            Some(item) => format!("{parent_pointer}/cat/items/{item}"),
+        };
+    }
+    match parent_op {
+        Some(op) => format!("{}/{}/{}", parent_pointer, op, index),
         // Child of an Array (no operator key) — JSON pointer "/idx".
-        (None, Some(idx)) => format!("{}/{}", parent_pointer, idx),
-        _ => parent_pointer.to_string(),
+        None => format!("{}/{}", parent_pointer, index),
     }
 }
 
@@ -0,0 +1,656 @@
+//! Static data-path analysis — reports which input fields a compiled rule
+//! reads, without evaluating it.
+
+use std::borrow::Cow;
+use std::collections::BTreeSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::Logic;
+use crate::node::{
+    CompiledMissingArg, CompiledMissingMin, CompiledMissingPaths, CompiledNode, MetadataHint,
+    PathSegment,
+};
+use crate::opcode::OpCode;
+
+/// The data paths a compiled rule reads, as returned by
+/// [`crate::Logic::referenced_paths`].
+///
+/// Paths use the same dot notation as `var`, relative to the root of the
+/// data passed to evaluation. Paths read inside an iterator body
+/// (`map`, `filter`, `reduce`, ...) are anchored to the iterated array
+/// with a `*` segment standing for "every element": the `price` read by
+/// `{"map": [{"var": "items"}, {"var": "price"}]}` is reported as
+/// `items.*.price`. An empty string means the rule reads the whole data
+/// value.
+///
+/// `#[non_exhaustive]` so future fields can be added in 5.x without
+/// breaking downstream.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct ReferencedPaths {
+    /// Every statically known path, sorted and deduplicated.
+    pub paths: Vec<String>,
+    /// `true` when the rule also reads data at a path that is only known
+    /// at evaluation time, e.g. `{"var": {"cat": ["user.", {"var": "f"}]}}`,
+    /// or inside the body of an iterator whose input is not a plain path.
+    /// When set, [`Self::paths`] is a lower bound, not the full set.
+    pub has_dynamic: bool,
+}
+
+impl Logic {
+    /// Collect every data path this rule reads, without evaluating it.
+    ///
+    /// Walks the compiled tree once, gathering the literal paths of `var`,
+    /// `val`, `exists`, `missing` and `missing_some`, and the placeholders
+    /// of a literal `template` text. Useful for access
+    /// control (reject rules that read fields the caller may not see) and
+    /// for prefetching only the fields a rule needs.
+    ///
+    /// Reads whose path is computed at evaluation time can't be listed;
+    /// they set [`ReferencedPaths::has_dynamic`] instead. Custom operators
+    /// are not inspected beyond their arguments: a
+    /// [`crate::LazyOperator`] can read the data context directly, so
+    /// treat the result as advisory for rules that call one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use datalogic_rs::Engine;
+    ///
+    /// let engine = Engine::new();
+    /// let logic = engine
+    ///     .compile(r#"{"and": [
+    ///         {">=": [{"var": "user.age"}, 18]},
+    ///         {"some": [{"var": "orders"}, {">": [{"var": "total"}, 100]}]}
+    ///     ]}"#)
+    ///     .unwrap();
+    ///
+    /// let refs = logic.referenced_paths();
+    /// assert_eq!(refs.paths, ["orders", "orders.*.total", "user.age"]);
+    /// assert!(!refs.has_dynamic);
+    /// ```
+    pub fn referenced_paths(&self) -> ReferencedPaths {
+        let mut collector = Collector {
+            scopes: vec![Scope {
+                base: Some(String::new()),
+                reduce: false,
+                bindings: Vec::new(),
+            }],
+            paths: BTreeSet::new(),
+            has_dynamic: false,
+        };
+        collector.node(&self.root);
+        ReferencedPaths {
+            paths: collector.paths.into_iter().collect(),
+            has_dynamic: collector.has_dynamic,
+        }
+    }
+}
+
+/// One context frame as seen by the walk, mirroring the frames the
+/// evaluator pushes (iteration items, `let` bindings, `try` catch arms).
+struct Scope {
+    /// Path of this frame's data from the root; `None` when the frame
+    /// holds values that don't come from a known path.
+    base: Option<String>,
+    /// A `reduce` frame: only `current` (the element) reads data.
+    reduce: bool,
+    /// Names bound by `let`, which shadow same-named fields.
+    bindings: Vec<String>,
+}
+
+/// Where a variable read lands.
+enum Resolution {
+    /// A known path from the root.
+    Path(String),
+    /// A path the walk can't pin down.
+    Dynamic,
+    /// Not the input data at all (`accumulator`, a `let` binding, ...).
+    NotData,
+}
+
+struct Collector {
+    scopes: Vec<Scope>,
+    paths: BTreeSet<String>,
+    has_dynamic: bool,
+}
+
+impl Collector {
+    fn node(&mut self, node: &CompiledNode) {
+        match node {
+            CompiledNode::Var {
+                scope_level,
+                segments,
+                metadata_hint,
+                default_value,
+                ..
+            } => {
+                if let Some(default) = default_value {
+                    self.node(default);
+                }
+                // `index` / `key` metadata reads the iteration position,
+                // not the data.
+                if !matches!(metadata_hint, MetadataHint::Index | MetadataHint::Key) {
+                    let resolution = self.resolve(*scope_level, segments);
+                    self.record(resolution);
+                }
+            }
+            #[cfg(feature = "ext-control")]
+            CompiledNode::Exists(data) => {
+                let resolution = self.resolve(data.scope_level, &data.segments);
+                self.record(resolution);
+            }
+            CompiledNode::Missing(data) => {
+                for arg in data.args.iter() {
+                    match arg {
+                        CompiledMissingArg::Now((_, segments)) => {
+                            let resolution = self.resolve(0, segments);
+                            self.record(resolution);
+                        }
+                        CompiledMissingArg::Later(expr) => {
+                            self.has_dynamic = true;
+                            self.node(expr);
+                        }
+                    }
+                }
+            }
+            CompiledNode::MissingSome(data) => {
+                if let CompiledMissingMin::Later(expr) = &data.min_present {
+                    self.node(expr);
+                }
+                match &data.paths {
+                    CompiledMissingPaths::Now(paths) => {
+                        for (_, segments) in paths.iter() {
+                            let resolution = self.resolve(0, segments);
+                            self.record(resolution);
+                        }
+                    }
+                    CompiledMissingPaths::Later(expr) => {
+                        self.has_dynamic = true;
+                        self.node(expr);
+                    }
+                }
+            }
+            CompiledNode::BuiltinOperator { opcode, args, .. } => self.operator(*opcode, args),
+            _ => node.visit_indexed_children(&mut |_, child| self.node(child)),
+        }
+    }
+
+    fn operator(&mut self, opcode: OpCode, args: &[CompiledNode]) {
+        match opcode {
+            // `var` / `val` / `exists` only stay operators when their path
+            // isn't a literal (literal paths compile to `Var` / `Exists`).
+            OpCode::Val => self.has_dynamic = true,
+            #[cfg(feature = "ext-control")]
+            OpCode::Exists => self.has_dynamic = true,
+            // Without an explicit bucketing key, `fractional` hashes
+            // `$flagd.flagKey` + `targetingKey` from the root data.
+            #[cfg(feature = "flagd")]
+            OpCode::Fractional
+                if !matches!(
+                    args.first(),
+                    Some(CompiledNode::Value {
+                        value: datavalue::OwnedDataValue::String(_),
+                        ..
+                    })
+                ) =>
+            {
+                self.paths.insert("$flagd.flagKey".to_string());
+                self.paths.insert("targetingKey".to_string());
+            }
+            #[cfg(feature = "ext-control")]
+            OpCode::Let => return self.let_bindings(args),
+            #[cfg(feature = "ext-string")]
+            OpCode::Template => return self.template(args),
+            #[cfg(feature = "error-handling")]
+            OpCode::Try if args.len() > 1 => {
+                // The catch arm runs under a pushed frame over the same
+                // data (or the thrown payload, which can only over-report).
+                if let Some((catch, arms)) = args.split_last() {
+                    for arm in arms {
+                        self.node(arm);
+                    }
+                    let current = self.current();
+                    let scope = Scope {
+                        base: current.base.clone(),
+                        reduce: current.reduce,
+                        bindings: current.bindings.clone(),
+                    };
+                    self.in_scope(scope, catch);
+                }
+                return;
+            }
+            _ => {}
+        }
+
+        let body_indices = body_indices(opcode);
+        for (i, arg) in args.iter().enumerate() {
+            if !body_indices.contains(&i) {
+                self.node(arg);
+            }
+        }
+        for &i in body_indices {
+            if let Some(body) = args.get(i) {
+                let scope = Scope {
+                    base: args.first().and_then(|input| self.element_base(input)),
+                    reduce: is_reduce(opcode),
+                    bindings: Vec::new(),
+                };
+                self.in_scope(scope, body);
+            }
+        }
+    }
+
+    /// `let` arrives flattened as `[name, expr, ..., body]`: bindings are
+    /// evaluated in the enclosing scope, the body under a frame where the
+    /// bound names shadow the data.
+    #[cfg(feature = "ext-control")]
+    fn let_bindings(&mut self, args: &[CompiledNode]) {
+        let Some((body, bindings)) = args.split_last() else {
+            return;
+        };
+        let mut names = Vec::with_capacity(bindings.len() / 2);
+        for pair in bindings.chunks(2) {
+            if let CompiledNode::Value {
+                value: datavalue::OwnedDataValue::String(name),
+                ..
+            } = &pair[0]
+            {
+                names.push(name.clone());
+            }
+            if let Some(expr) = pair.get(1) {
+                self.node(expr);
+            }
+        }
+        let current = self.current();
+        let scope = Scope {
+            base: current.base.clone(),
+            reduce: current.reduce,
+            bindings: names,
+        };
+        self.in_scope(scope, body);
+    }
+
+    /// `template` reads each `{path}` of a literal template text from its
+    /// data argument, or from the current frame when there is none. A
+    /// computed text, or data that isn't a literal or a plain path, reads
+    /// paths the walk can't list.
+    #[cfg(feature = "ext-string")]
+    fn template(&mut self, args: &[CompiledNode]) {
+        for arg in args {
+            self.node(arg);
+        }
+        let Some(CompiledNode::Value {
+            value: datavalue::OwnedDataValue::String(text),
+            ..
+        }) = args.first()
+        else {
+            self.has_dynamic = true;
+            return;
+        };
+        let (scope_level, base): (u32, &[PathSegment]) = match args.get(1) {
+            None => (0, &[]),
+            // Literal data: the placeholders don't read the input.
+            Some(CompiledNode::Value { .. }) => return,
+            Some(CompiledNode::Var {
+                scope_level,
+                segments,
+                metadata_hint: MetadataHint::None,
+                default_value: None,
+                ..
+            }) => (*scope_level, segments),
+            Some(_) => {
+                self.has_dynamic = true;
+                return;
+            }
+        };
+        for path in crate::operators::string::template_paths(text) {
+            let mut segments = base.to_vec();
+            segments.extend(crate::compile::path_segments::parse_path_segments(path));
+            let resolution = self.resolve(scope_level, &segments);
+            self.record(resolution);
+        }
+    }
+
+    fn in_scope(&mut self, scope: Scope, body: &CompiledNode) {
+        self.scopes.push(scope);
+        self.node(body);
+        self.scopes.pop();
+    }
+
+    #[cfg(any(feature = "ext-control", feature = "error-handling"))]
+    fn current(&self) -> &Scope {
+        self.scopes.last().expect("root scope is never popped")
+    }
+
+    /// Path of each element an iterator walks: `<input>.*` when the input
+    /// is a plain path, or an operator that only reorders or drops
+    /// elements of one.
+    fn element_base(&self, input: &CompiledNode) -> Option<String> {
+        match input {
+            CompiledNode::Var {
+                scope_level,
+                segments,
+                metadata_hint: MetadataHint::None,
+                default_value: None,
+                ..
+            } => match self.resolve(*scope_level, segments) {
+                Resolution::Path(path) => Some(join(&path, "*")),
+                Resolution::Dynamic | Resolution::NotData => None,
+            },
+            CompiledNode::BuiltinOperator {
+                opcode: OpCode::Filter,
+                args,
+                ..
+            } => args.first().and_then(|inner| self.element_base(inner)),
+            #[cfg(feature = "ext-array")]
+            CompiledNode::BuiltinOperator {
+                opcode: OpCode::Sort,
+                args,
+                ..
+            } => args.first().and_then(|inner| self.element_base(inner)),
+            CompiledNode::Cse(data) => self.element_base(&data.inner),
+            _ => None,
+        }
+    }
+
+    /// Resolve a variable read `scope_level` frames up. Levels past the
+    /// outermost frame land on the root, as they do at evaluation time.
+    fn resolve(&self, scope_level: u32, segments: &[PathSegment]) -> Resolution {
+        let index = self
+            .scopes
+            .len()
+            .saturating_sub(1)
+            .saturating_sub(scope_level as usize);
+        let scope = &self.scopes[index];
+
+        let segments = if scope.reduce {
+            // A reduce frame holds `{current, accumulator}`; only
+            // `current` is an element of the input.
+            match segments.split_first() {
+                Some((first, rest)) if segment_name(first) == "current" => rest,
+                _ => return Resolution::NotData,
+            }
+        } else {
+            if let Some(first) = segments.first() {
+                if scope
+                    .bindings
+                    .iter()
+                    .any(|b| segment_name(first) == b.as_str())
+                {
+                    return Resolution::NotData;
+                }
+            }
+            segments
+        };
+
+        let Some(base) = &scope.base else {
+            return Resolution::Dynamic;
+        };
+        let mut path = base.clone();
+        for segment in segments {
+            path = join(&path, &segment_name(segment));
+        }
+        Resolution::Path(path)
+    }
+
+    fn record(&mut self, resolution: Resolution) {
+        match resolution {
+            Resolution::Path(path) => {
+                self.paths.insert(path);
+            }
+            Resolution::Dynamic => self.has_dynamic = true,
+            Resolution::NotData => {}
+        }
+    }
+}
+
+/// Whether an iterator's bodies run under a `{current, accumulator}` frame.
+fn is_reduce(opcode: OpCode) -> bool {
+    #[cfg(feature = "ext-array")]
+    if opcode == OpCode::ReduceWhile {
+        return true;
+    }
+    opcode == OpCode::Reduce
+}
+
+/// Argument positions of the per-element bodies for iterator operators.
+fn body_indices(opcode: OpCode) -> &'static [usize] {
+    #[cfg(feature = "ext-array")]
+    match opcode {
+        OpCode::GroupBy
+        | OpCode::Find
+        | OpCode::Count
+        | OpCode::FlatMap
+        | OpCode::AllValues
+        | OpCode::SomeValues
+        | OpCode::NoneValues
+        | OpCode::MinBy
+        | OpCode::MaxBy => return &[1],
+        // `sort`'s extractor follows the direction flag.
+        OpCode::Sort => return &[2],
+        // `reduce_while`'s condition runs per item, like its reducer.
+        OpCode::ReduceWhile => return &[1, 3],
+        _ => {}
+    }
+    match opcode {
+        OpCode::Map
+        | OpCode::Filter
+        | OpCode::Reduce
+        | OpCode::All
+        | OpCode::Some
+        | OpCode::None => &[1],
+        _ => &[],
+    }
+}
+
+fn segment_name(segment: &PathSegment) -> Cow<'_, str> {
+    match segment {
+        PathSegment::Field(name)
+        | PathSegment::FieldOrIndex(name, _)
+        | PathSegment::FieldOrIndexFromEnd(name, _) => Cow::Borrowed(name),
+        PathSegment::Index(i) => Cow::Owned(i.to_string()),
+    }
+}
+
+fn join(base: &str, segment: &str) -> String {
+    if base.is_empty() {
+        segment.to_string()
+    } else {
+        format!("{base}.{segment}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ReferencedPaths;
+
+    fn refs(rule: &str) -> ReferencedPaths {
+        crate::Engine::new()
+            .compile(rule)
+            .unwrap()
+            .referenced_paths()
+    }
+
+    #[test]
+    fn collects_plain_vars() {
+        let r = refs(r#"{"if": [{"var": "a.b"}, {"var": "c"}, {"var": ["d", {"var": "e"}]}]}"#);
+        assert_eq!(r.paths, ["a.b", "c", "d", "e"]);
+        assert!(!r.has_dynamic);
+    }
+
+    #[test]
+    fn constant_rule_reads_nothing() {
+        let r = refs(r#"{"+": [1, 2]}"#);
+        assert!(r.paths.is_empty());
+        assert!(!r.has_dynamic);
+    }
+
+    #[test]
+    fn map_body_vars_are_anchored_to_the_input() {
+        let r = refs(r#"{"map": [{"var": "items"}, {"*": [{"var": "price"}, {"var": "qty"}]}]}"#);
+        assert_eq!(r.paths, ["items", "items.*.price", "items.*.qty"]);
+        assert!(!r.has_dynamic);
+    }
+
+    #[test]
+    fn nested_predicates_and_parent_scope_reads() {
+        // `filter` inside `map`: the inner predicate reads the nested
+        // array's elements and, one level up, the outer element.
+        let r = refs(
+            r#"{"map": [
+                {"var": "orders"},
+                {"filter": [
+                    {"var": "lines"},
+                    {"==": [{"var": "sku"}, {"val": [[1], "featured"]}]}
+                ]}
+            ]}"#,
+        );
+        assert_eq!(
+            r.paths,
+            [
+                "orders",
+                "orders.*.featured",
+                "orders.*.lines",
+                "orders.*.lines.*.sku",
+            ]
+        );
+        assert!(!r.has_dynamic);
+    }
+
+    #[test]
+    fn wildcard_paths_use_the_element_notation() {
+        let r = refs(
+            r#"{"reduce": [
+                {"var": "orders"},
+                {"+": [{"var": "accumulator"}, {"max": {"var": "current.lines.*.qty"}}]},
+                0
+            ]}"#,
+        );
+        assert_eq!(r.paths, ["orders", "orders.*.lines.*.qty"]);
+        assert!(!r.has_dynamic);
+    }
+
+    #[test]
+    fn whole_item_and_root_reads() {
+        let r = refs(r#"{"filter": [{"var": "xs"}, {">": [{"var": ""}, {"val": [[2], "min"]}]}]}"#);
+        assert_eq!(r.paths, ["min", "xs", "xs.*"]);
+    }
+
+    #[test]
+    fn reduce_reads_current_not_accumulator() {
+        let r = refs(
+            r#"{"reduce": [
+                {"var": "items"},
+                {"+": [{"var": "accumulator"}, {"var": "current.amount"}]},
+                {"var": "start"}
+            ]}"#,
+        );
+        assert_eq!(r.paths, ["items", "items.*.amount", "start"]);
+        assert!(!r.has_dynamic);
+    }
+
+    #[test]
+    fn metadata_reads_are_not_data() {
+        let r = refs(r#"{"map": [{"var": "xs"}, {"val": [[1], "index"]}]}"#);
+        assert_eq!(r.paths, ["xs"]);
+    }
+
+    #[test]
+    fn missing_paths_are_collected() {
+        let r = refs(r#"{"or": [{"missing": ["a", "b.c"]}, {"missing_some": [1, ["d", "e"]]}]}"#);
+        assert_eq!(r.paths, ["a", "b.c", "d", "e"]);
+        assert!(!r.has_dynamic);
+    }
+
+    #[test]
+    fn computed_paths_are_flagged() {
+        let r = refs(r#"{"+": [{"var": {"cat": ["user.", {"var": "field"}]}}, {"var": "x"}]}"#);
+        assert_eq!(r.paths, ["field", "x"]);
+        assert!(r.has_dynamic);
+    }
+
+    #[test]
+    fn body_over_computed_input_is_flagged() {
+        // The input comes from `merge`, so its elements have no single path.
+        let r = refs(r#"{"map": [{"merge": [{"var": "a"}, {"var": "b"}]}, {"var": "id"}]}"#);
+        assert_eq!(r.paths, ["a", "b"]);
+        assert!(r.has_dynamic);
+    }
+
+    #[test]
+    fn filter_output_keeps_its_input_path() {
+        let r = refs(
+            r#"{"map": [
+                {"filter": [{"var": "users"}, {"var": "active"}]},
+                {"var": "email"}
+            ]}"#,
+        );
+        assert_eq!(r.paths, ["users", "users.*.active", "users.*.email"]);
+        assert!(!r.has_dynamic);
+    }
+
+    #[cfg(feature = "ext-array")]
+    #[test]
+    fn reduce_while_condition_reads_the_element() {
+        let r = refs(
+            r#"{"reduce_while": [
+                {"var": "items"},
+                {"+": [{"var": "accumulator"}, {"var": "current.amount"}]},
+                0,
+                {"and": [{"<": [{"var": "accumulator"}, 100]}, {"var": "current.open"}]}
+            ]}"#,
+        );
+        assert_eq!(r.paths, ["items", "items.*.amount", "items.*.open"]);
+        assert!(!r.has_dynamic);
+    }
+
+    #[cfg(feature = "ext-control")]
+    #[test]
+    fn let_bindings_shadow_data() {
+        let r = refs(
+            r#"{"let": [{"limit": {"var": "max"}}, {"<": [{"var": "n"}, {"var": "limit"}]}]}"#,
+        );
+        assert_eq!(r.paths, ["max", "n"]);
+    }
+
+    #[cfg(feature = "ext-string")]
+    #[test]
+    fn template_placeholders_are_collected() {
+        let r = refs(r#"{"template": ["{name} owes {account.balance}"]}"#);
+        assert_eq!(r.paths, ["account.balance", "name"]);
+        assert!(!r.has_dynamic);
+
+        let r = refs(r#"{"template": ["Hi {name}", {"var": "user"}, "N/A"]}"#);
+        assert_eq!(r.paths, ["user", "user.name"]);
+        assert!(!r.has_dynamic);
+
+        let r = refs(r#"{"map": [{"var": "people"}, {"template": ["{name} {{x}} {}"]}]}"#);
+        assert_eq!(r.paths, ["people", "people.*.name"]);
+        assert!(!r.has_dynamic);
+
+        let computed = refs(r#"{"template": [{"var": "text"}]}"#);
+        assert_eq!(computed.paths, ["text"]);
+        assert!(computed.has_dynamic);
+
+        let computed =
+            refs(r#"{"template": ["{name}", {"if": [{"var": "c"}, {"var": "a"}, {}]}]}"#);
+        assert_eq!(computed.paths, ["a", "c"]);
+        assert!(computed.has_dynamic);
+    }
+
+    #[cfg(feature = "flagd")]
+    #[test]
+    fn implicit_fractional_key_is_reported() {
+        let implicit = refs(r#"{"fractional": [["a", 50], ["b", 50]]}"#);
+        assert_eq!(implicit.paths, ["$flagd.flagKey", "targetingKey"]);
+
+        let explicit = refs(r#"{"fractional": [{"var": "email"}, ["a", 50], ["b", 50]]}"#);
+        assert_eq!(
+            explicit.paths,
+            ["$flagd.flagKey", "email", "targetingKey"],
+            "a computed key may still be null, falling back to the implicit key"
+        );
+    }
+}
@@ -72,6 +72,20 @@ impl<'engine> Session<'engine> {
         }
     }
 
+    /// Session over a caller-supplied arena — the [`Engine::scoped`]
+    /// entry point, which lends out an arena from a
+    /// [`crate::ArenaPool`].
+    #[inline]
+    pub(crate) fn with_arena(engine: &'engine Engine, arena: Bump) -> Self {
+        Self { engine, arena }
+    }
+
+    /// Hand the arena back (un-reset) so the pool can reuse it.
+    #[inline]
+    pub(crate) fn into_arena(self) -> Bump {
+        self.arena
+    }
+
     /// Reset the session's arena, returning every allocated chunk to the
     /// free list's start-of-chunk position without freeing OS memory.
     ///
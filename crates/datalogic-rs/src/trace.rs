@@ -13,9 +13,12 @@
 //! consumers (the Web UI, JSON exporters) need the JSON↔arena bridge to
 //! render steps. `--features trace` implicitly enables `serde_json`.
 
+use datavalue::OwnedDataValue;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+#[cfg(feature = "ext-control")]
+use crate::compile::LetOperand;
 use crate::node_serialize;
 use crate::{CompiledNode, Error};
 
@@ -26,6 +29,14 @@ pub struct ExpressionNode {
     pub id: u32,
     /// JSON string of this sub-expression
     pub expression: String,
+    /// RFC 6901 JSON pointer from the root of the submitted logic to this
+    /// sub-expression, e.g. `/and/1/==/0`. Empty for the root. Runs over a
+    /// pre-compiled [`crate::Logic`] no longer have the submitted rule, so
+    /// their pointers follow the canonical form instead: operator aliases
+    /// spelled canonically (`if` for `?:`) and every operand addressed as an
+    /// array element (`/!/0` for `{"!": x}`).
+    #[serde(default)]
+    pub pointer: String,
     /// Child nodes (arguments/operands that are operators, not literals)
     pub children: Vec<ExpressionNode>,
 }
@@ -36,108 +47,244 @@ impl ExpressionNode {
     /// Every tree node inherits its compile-time id from the source
     /// [`CompiledNode::id`]. No side-table is needed: both tracing and error
     /// reporting look the id up directly on the node.
-    pub(crate) fn build_from_compiled(node: &CompiledNode) -> ExpressionNode {
-        Self::build_node(node)
+    ///
+    /// `source` is the logic the node was compiled from, when the caller
+    /// still has it. Pointers then follow the submitted spelling (operator
+    /// aliases such as `?:`, bare single operands such as `{"!": x}`);
+    /// without it they follow the compiled, canonical shape.
+    pub(crate) fn build_from_compiled(
+        node: &CompiledNode,
+        source: Option<&OwnedDataValue>,
+    ) -> ExpressionNode {
+        Self::build_node(node, String::new(), source)
     }
 
-    fn build_node(node: &CompiledNode) -> ExpressionNode {
+    fn build_node(
+        node: &CompiledNode,
+        pointer: String,
+        src: Option<&OwnedDataValue>,
+    ) -> ExpressionNode {
         let id = node.id();
         match node {
-            CompiledNode::Value { value, .. } => Self::leaf(id, value.to_json_string()),
-            CompiledNode::Array { nodes, .. } => ExpressionNode {
-                id,
-                expression: node_serialize::node_to_json_string(node),
-                children: Self::op_children(nodes),
-            },
-            CompiledNode::BuiltinOperator { opcode, args, .. } => ExpressionNode {
-                id,
-                expression: node_serialize::builtin_to_json_string(opcode, args),
-                children: Self::op_children(args),
-            },
-            CompiledNode::CustomOperator(data) => ExpressionNode {
-                id,
-                expression: node_serialize::custom_to_json_string(&data.name, &data.args),
-                children: Self::op_children(&data.args),
-            },
+            CompiledNode::Value { value, .. } => Self::leaf(id, value.to_json_string(), pointer),
+            CompiledNode::Array { nodes, .. } => {
+                let items = match src {
+                    Some(OwnedDataValue::Array(items)) => Operands::Array(items),
+                    _ => Operands::Unknown,
+                };
+                ExpressionNode {
+                    id,
+                    expression: node_serialize::node_to_json_string(node),
+                    children: Self::op_children(nodes, &pointer, &items),
+                    pointer,
+                }
+            }
+            CompiledNode::BuiltinOperator { opcode, args, .. } => {
+                let (prefix, operands) = Operands::of(&pointer, opcode.as_str(), src);
+                let children = match opcode {
+                    #[cfg(feature = "ext-control")]
+                    crate::opcode::OpCode::Let => Self::let_children(args, &prefix, &operands),
+                    crate::opcode::OpCode::ConcatSep => {
+                        Self::concat_sep_children(args, &prefix, &operands)
+                    }
+                    _ => Self::op_children(args, &prefix, &operands),
+                };
+                ExpressionNode {
+                    id,
+                    expression: node_serialize::builtin_to_json_string(opcode, args),
+                    children,
+                    pointer,
+                }
+            }
+            CompiledNode::CustomOperator(data) => {
+                let (prefix, operands) = Operands::of(&pointer, &data.name, src);
+                ExpressionNode {
+                    id,
+                    expression: node_serialize::custom_to_json_string(&data.name, &data.args),
+                    children: Self::op_children(&data.args, &prefix, &operands),
+                    pointer,
+                }
+            }
             // Memo wrappers are invisible in the trace tree.
-            CompiledNode::Cse(data) => Self::build_node(&data.inner),
+            CompiledNode::Cse(data) => Self::build_node(&data.inner, pointer, src),
             #[cfg(feature = "templating")]
             CompiledNode::StructuredObject(data) => ExpressionNode {
                 id,
                 expression: node_serialize::structured_to_json_string(&data.fields),
-                children: Self::op_children_from_fields(&data.fields),
+                children: Self::op_children_from_fields(&data.fields, &pointer, src),
+                pointer,
             },
             CompiledNode::Var {
                 scope_level,
                 segments,
                 default_value,
                 ..
-            } => Self::build_compiled_var(id, *scope_level, segments, default_value.as_deref()),
+            } => Self::build_compiled_var(
+                id,
+                *scope_level,
+                segments,
+                default_value.as_deref(),
+                pointer,
+                src,
+            ),
             #[cfg(feature = "ext-control")]
             CompiledNode::Exists(data) => Self::leaf(
                 id,
                 node_serialize::compiled_exists_to_json_string(&data.segments),
+                pointer,
             ),
             #[cfg(feature = "error-handling")]
             CompiledNode::Throw(_) | CompiledNode::Missing(_) | CompiledNode::MissingSome(_) => {
-                Self::leaf(id, node_serialize::node_to_json_string(node))
+                Self::leaf(id, node_serialize::node_to_json_string(node), pointer)
             }
             #[cfg(not(feature = "error-handling"))]
             CompiledNode::Missing(_) | CompiledNode::MissingSome(_) => {
-                Self::leaf(id, node_serialize::node_to_json_string(node))
+                Self::leaf(id, node_serialize::node_to_json_string(node), pointer)
             }
             CompiledNode::InvalidArgs { .. } => {
-                Self::leaf(id, "{\"<invalid args>\": null}".to_string())
+                Self::leaf(id, "{\"<invalid args>\": null}".to_string(), pointer)
             }
         }
     }
 
     /// Build a leaf `ExpressionNode` (no children).
     #[inline]
-    fn leaf(id: u32, expression: String) -> ExpressionNode {
+    fn leaf(id: u32, expression: String, pointer: String) -> ExpressionNode {
         ExpressionNode {
             id,
             expression,
+            pointer,
             children: vec![],
         }
     }
 
     /// Recurse into a compiled-node slice, keeping only the operator nodes
-    /// (literals don't appear as flow-diagram children).
+    /// (literals don't appear as flow-diagram children). Each child is
+    /// addressed by its position in the full slice under `prefix`.
     #[inline]
-    fn op_children(nodes: &[CompiledNode]) -> Vec<ExpressionNode> {
+    fn op_children(
+        nodes: &[CompiledNode],
+        prefix: &str,
+        operands: &Operands<'_>,
+    ) -> Vec<ExpressionNode> {
         nodes
             .iter()
-            .filter(|n| Self::is_operator_node(n))
-            .map(Self::build_node)
+            .enumerate()
+            .filter(|(_, n)| Self::is_operator_node(n))
+            .map(|(i, n)| {
+                let (pointer, src) = operands.child(prefix, i);
+                Self::build_node(n, pointer, src)
+            })
+            .collect()
+    }
+
+    /// `op_children` for `let`, whose compiled args `[name, expr, ...,
+    /// body]` don't line up with the source `[{name: expr, ...}, body]`: a
+    /// binding sits at `/let/0/<name>` and the body at `/let/1`.
+    #[cfg(feature = "ext-control")]
+    fn let_children(
+        args: &[CompiledNode],
+        prefix: &str,
+        operands: &Operands<'_>,
+    ) -> Vec<ExpressionNode> {
+        let (bindings_pointer, bindings_src) = operands.child(prefix, 0);
+        args.iter()
+            .enumerate()
+            .filter(|(_, n)| Self::is_operator_node(n))
+            .filter_map(|(i, n)| match crate::compile::let_operand(args, i)? {
+                LetOperand::Binding(name) => {
+                    let src = match bindings_src {
+                        Some(OwnedDataValue::Object(pairs)) => {
+                            pairs.iter().find(|(k, _)| k == name).map(|(_, v)| v)
+                        }
+                        _ => None,
+                    };
+                    Some(Self::build_node(
+                        n,
+                        pointer_child(&bindings_pointer, name),
+                        src,
+                    ))
+                }
+                LetOperand::Body => {
+                    let (pointer, src) = operands.child(prefix, 1);
+                    Some(Self::build_node(n, pointer, src))
+                }
+            })
+            .collect()
+    }
+
+    /// `op_children` for `cat`'s separator form, whose compiled args
+    /// `[sep, item, ...]` come from the source `{"sep": ..., "items": [...]}`:
+    /// the separator sits at `/cat/sep` and item `i` at `/cat/items/i`.
+    fn concat_sep_children(
+        args: &[CompiledNode],
+        prefix: &str,
+        operands: &Operands<'_>,
+    ) -> Vec<ExpressionNode> {
+        let options = match operands {
+            Operands::Bare(OwnedDataValue::Object(pairs)) => pairs.as_slice(),
+            _ => &[],
+        };
+        let field = |key: &str| options.iter().find(|(k, _)| k == key).map(|(_, v)| v);
+        args.iter()
+            .enumerate()
+            .filter(|(_, n)| Self::is_operator_node(n))
+            .map(|(i, n)| {
+                let (pointer, src) = match i.checked_sub(1) {
+                    None => (pointer_child(prefix, "sep"), field("sep")),
+                    Some(item) => (
+                        format!("{prefix}/items/{item}"),
+                        match field("items") {
+                            Some(OwnedDataValue::Array(items)) => items.get(item),
+                            _ => None,
+                        },
+                    ),
+                };
+                Self::build_node(n, pointer, src)
+            })
             .collect()
     }
 
     /// `op_children` for the `(name, CompiledNode)` shape used by structured
-    /// object fields.
+    /// object fields, which are addressed by key rather than position.
     #[cfg(feature = "templating")]
     #[inline]
-    fn op_children_from_fields(fields: &[(String, CompiledNode)]) -> Vec<ExpressionNode> {
+    fn op_children_from_fields(
+        fields: &[(String, CompiledNode)],
+        pointer: &str,
+        src: Option<&OwnedDataValue>,
+    ) -> Vec<ExpressionNode> {
+        let src_fields = match src {
+            Some(OwnedDataValue::Object(pairs)) => pairs.as_slice(),
+            _ => &[],
+        };
         fields
             .iter()
             .filter(|(_, n)| Self::is_operator_node(n))
-            .map(|(_, n)| Self::build_node(n))
+            .map(|(k, n)| {
+                let field_src = src_fields.iter().find(|(sk, _)| sk == k).map(|(_, v)| v);
+                Self::build_node(n, pointer_child(pointer, k), field_src)
+            })
             .collect()
     }
 
     /// `CompiledVar`'s expression node — the only operator-shaped variant
     /// whose "child" is the optional default value rather than a fixed args
-    /// slice.
+    /// slice. The default sits at index 1 of the source `[path, default]`.
     fn build_compiled_var(
         id: u32,
         scope_level: u32,
         segments: &[crate::node::PathSegment],
         default_value: Option<&CompiledNode>,
+        pointer: String,
+        src: Option<&OwnedDataValue>,
     ) -> ExpressionNode {
         let mut children = Vec::new();
         if let Some(def) = default_value {
             if Self::is_operator_node(def) {
-                children.push(Self::build_node(def));
+                let (prefix, operands) = Operands::of(&pointer, "var", src);
+                let (def_pointer, def_src) = operands.child(&prefix, 1);
+                children.push(Self::build_node(def, def_pointer, def_src));
             }
         }
         ExpressionNode {
@@ -147,6 +294,7 @@ impl ExpressionNode {
                 segments,
                 default_value,
             ),
+            pointer,
             children,
         }
     }
@@ -157,6 +305,61 @@ impl ExpressionNode {
     }
 }
 
+/// How an operator's operands were written in the source logic, for
+/// pointing at them.
+enum Operands<'s> {
+    /// `{"op": [a, b, ...]}` — operand `i` sits at `/op/i`.
+    Array(&'s [OwnedDataValue]),
+    /// `{"op": a}` — the single operand sits at `/op` itself.
+    Bare(&'s OwnedDataValue),
+    /// No source available: assume the canonical array form.
+    Unknown,
+}
+
+impl<'s> Operands<'s> {
+    /// Operand prefix and shape for the operator node at `pointer`. The
+    /// key comes from the source when it's a single-key object, which keeps
+    /// aliases (`?:`, `var`, `match`) as written; otherwise `name`.
+    fn of(pointer: &str, name: &str, src: Option<&'s OwnedDataValue>) -> (String, Self) {
+        match src {
+            Some(OwnedDataValue::Object(pairs)) if pairs.len() == 1 => {
+                let (key, value) = &pairs[0];
+                let operands = match value {
+                    OwnedDataValue::Array(items) => Operands::Array(items),
+                    other => Operands::Bare(other),
+                };
+                (pointer_child(pointer, key), operands)
+            }
+            _ => (pointer_child(pointer, name), Operands::Unknown),
+        }
+    }
+
+    /// Pointer and source subtree for operand `index` under `prefix`.
+    fn child(&self, prefix: &str, index: usize) -> (String, Option<&'s OwnedDataValue>) {
+        match self {
+            Operands::Bare(value) if index == 0 => (prefix.to_string(), Some(*value)),
+            Operands::Array(items) => (format!("{prefix}/{index}"), items.get(index)),
+            _ => (format!("{prefix}/{index}"), None),
+        }
+    }
+}
+
+/// `{pointer}/{key}`, with the key escaped per RFC 6901 (`~` → `~0`, `/` →
+/// `~1`, so the `/` operator becomes `~1`).
+fn pointer_child(pointer: &str, key: &str) -> String {
+    let mut out = String::with_capacity(pointer.len() + key.len() + 1);
+    out.push_str(pointer);
+    out.push('/');
+    for c in key.chars() {
+        match c {
+            '~' => out.push_str("~0"),
+            '/' => out.push_str("~1"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
 /// Captures state at each evaluation step.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExecutionStep {
@@ -179,6 +382,10 @@ pub struct ExecutionStep {
     /// Total iteration count (only for iterator body evaluations)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub iteration_total: Option<u32>,
+    /// Wall-clock time spent evaluating this node, children included
+    /// (only when the run was traced with [`TracedSession::with_timings`])
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub elapsed_nanos: Option<u64>,
 }
 
 /// Collector for execution steps during traced evaluation.
@@ -189,6 +396,11 @@ pub(crate) struct TraceCollector {
     step_counter: u32,
     /// Stack of iteration info (index, total) for nested iterations
     iteration_stack: Vec<(u32, u32)>,
+    /// Whether steps carry `elapsed_nanos`
+    timings: bool,
+    /// Duration of the node about to be recorded, set by
+    /// [`Self::set_elapsed`] and consumed by the next step
+    pending_elapsed: Option<u64>,
 }
 
 impl TraceCollector {
@@ -198,9 +410,30 @@ impl TraceCollector {
             steps: Vec::new(),
             step_counter: 0,
             iteration_stack: Vec::new(),
+            timings: false,
+            pending_elapsed: None,
+        }
+    }
+
+    /// Create a trace collector that also times every step
+    pub(crate) fn with_timings() -> Self {
+        Self {
+            timings: true,
+            ..Self::new()
         }
     }
 
+    /// Whether the dispatcher should time nodes for this collector
+    #[inline]
+    pub(crate) fn timings(&self) -> bool {
+        self.timings
+    }
+
+    /// Attach a duration to the next recorded step
+    pub(crate) fn set_elapsed(&mut self, nanos: u64) {
+        self.pending_elapsed = Some(nanos);
+    }
+
     /// Record a successful execution step
     pub(crate) fn record_step(&mut self, node_id: u32, context: Value, result: Value) {
         self.record(node_id, context, Some(result), None);
@@ -212,8 +445,8 @@ impl TraceCollector {
     }
 
     /// Shared step constructor behind [`Self::record_step`] /
-    /// [`Self::record_error`]: stamp the step with the next sequential id
-    /// and the current iteration context.
+    /// [`Self::record_error`]: stamp the step with the next sequential id,
+    /// the current iteration context, and any pending duration.
     fn record(
         &mut self,
         node_id: u32,
@@ -230,6 +463,7 @@ impl TraceCollector {
             error,
             iteration_index,
             iteration_total,
+            elapsed_nanos: self.pending_elapsed.take(),
         });
         self.step_counter += 1;
     }
@@ -305,6 +539,7 @@ impl<R> TracedRun<R> {
 /// borrowed-result lifetime tied to the run).
 pub struct TracedSession<'e> {
     engine: &'e crate::Engine,
+    timings: bool,
 }
 
 impl<'e> TracedSession<'e> {
@@ -312,7 +547,36 @@ impl<'e> TracedSession<'e> {
     /// [`crate::Engine::trace`].
     #[inline]
     pub(crate) fn new(engine: &'e crate::Engine) -> Self {
-        Self { engine }
+        Self {
+            engine,
+            timings: false,
+        }
+    }
+
+    /// Record wall-clock timings: every [`ExecutionStep`] then carries
+    /// `elapsed_nanos`, the time its node took including its children.
+    /// Use it to find which operators dominate a slow rule. The durations
+    /// include the tracer's own bookkeeping (snapshotting each child's
+    /// context), so compare them with each other rather than against an
+    /// untraced run. Off by default; untraced evaluation never reads the
+    /// clock either way.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "trace")] {
+    /// use datalogic_rs::Engine;
+    ///
+    /// let engine = Engine::new();
+    /// let run = engine
+    ///     .trace()
+    ///     .with_timings(true)
+    ///     .eval_str(r#"{"+": [{"var": "a"}, 1]}"#, r#"{"a": 2}"#);
+    /// assert!(run.steps.iter().all(|s| s.elapsed_nanos.is_some()));
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_timings(mut self, on: bool) -> Self {
+        self.timings = on;
+        self
     }
 
     /// Traced evaluation of a pre-compiled [`crate::Logic`] returning
@@ -329,7 +593,7 @@ impl<'e> TracedSession<'e> {
             Err(e) => return Self::compile_failed(e),
         };
         let arena = bumpalo::Bump::new();
-        self.eval_borrowed_in(compiled, &owned_data, &arena)
+        self.eval_borrowed_in(compiled, None, &owned_data, &arena)
             .convert(|result| result.and_then(crate::FromDataValue::from_arena))
     }
 
@@ -342,12 +606,12 @@ impl<'e> TracedSession<'e> {
         R: crate::IntoLogic,
         D: crate::OwnedInput,
     {
-        let (compiled, owned_data) = match self.prepare(rule, data) {
+        let (source, compiled, owned_data) = match self.prepare(rule, data) {
             Ok(prepared) => prepared,
             Err(e) => return Self::compile_failed(e),
         };
         let arena = bumpalo::Bump::new();
-        self.eval_borrowed_in(&compiled, &owned_data, &arena)
+        self.eval_borrowed_in(&compiled, Some(&source), &owned_data, &arena)
             .convert(|result| result.map(|v| v.to_string()))
     }
 
@@ -361,12 +625,12 @@ impl<'e> TracedSession<'e> {
         R: crate::IntoLogic,
         D: crate::OwnedInput,
     {
-        let (compiled, owned_data) = match self.prepare(rule, data) {
+        let (source, compiled, owned_data) = match self.prepare(rule, data) {
             Ok(prepared) => prepared,
             Err(e) => return Self::compile_failed(e),
         };
         let arena = bumpalo::Bump::new();
-        self.eval_borrowed_in(&compiled, &owned_data, &arena)
+        self.eval_borrowed_in(&compiled, Some(&source), &owned_data, &arena)
             .convert(|result| {
                 result.and_then(|v| {
                     let value: serde_json::Value = crate::FromDataValue::from_arena(v)?;
@@ -379,11 +643,12 @@ impl<'e> TracedSession<'e> {
     /// ([`Self::eval_str`] / [`Self::eval_into`]): normalise the rule,
     /// compile it with the optimizer + constant-fold passes disabled, and
     /// normalise the data into an owned value the arena run can borrow.
+    /// The normalised rule is returned too, for the trace tree's pointers.
     fn prepare<R, D>(
         &self,
         rule: R,
         data: D,
-    ) -> crate::Result<(crate::Logic, datavalue::OwnedDataValue)>
+    ) -> crate::Result<(OwnedDataValue, crate::Logic, OwnedDataValue)>
     where
         R: crate::IntoLogic,
         D: crate::OwnedInput,
@@ -391,7 +656,7 @@ impl<'e> TracedSession<'e> {
         let owned = rule.into_owned_logic()?;
         let compiled = crate::Logic::compile_for_trace(&owned, self.engine)?;
         let owned_data = data.into_owned_input()?;
-        Ok((compiled, owned_data))
+        Ok((owned, compiled, owned_data))
     }
 
     /// Traced borrowed evaluation against a caller-owned arena. Mirrors
@@ -407,20 +672,22 @@ impl<'e> TracedSession<'e> {
     where
         D: crate::EvalInput<'a>,
     {
-        self.eval_borrowed_in(compiled, data, arena)
+        self.eval_borrowed_in(compiled, None, data, arena)
     }
 
-    /// Internal: shared body for the borrowed-result trace runs.
+    /// Internal: shared body for the borrowed-result trace runs. `source`
+    /// is the rule `compiled` came from, when the caller still has it.
     fn eval_borrowed_in<'a, D>(
         &self,
         compiled: &'a crate::Logic,
+        source: Option<&OwnedDataValue>,
         data: D,
         arena: &'a bumpalo::Bump,
     ) -> TracedRun<&'a crate::DataValue<'a>>
     where
         D: crate::EvalInput<'a>,
     {
-        let expression_tree = ExpressionNode::build_from_compiled(&compiled.root);
+        let expression_tree = ExpressionNode::build_from_compiled(&compiled.root, source);
         let _depth_guard = match self.engine.enter_dispatch_boundary() {
             Ok(g) => g,
             Err(e) => return Self::failed(expression_tree, e),
@@ -430,9 +697,16 @@ impl<'e> TracedSession<'e> {
             Err(e) => return Self::failed(expression_tree, e),
         };
         let mut ctx = crate::arena::ContextStack::new(data_ref);
-        ctx.attach_tracer(TraceCollector::new());
+        ctx.attach_tracer(if self.timings {
+            TraceCollector::with_timings()
+        } else {
+            TraceCollector::new()
+        });
 
-        let outcome = self.engine.dispatch_node(&compiled.root, &mut ctx, arena);
+        let outcome = self
+            .engine
+            .dispatch_node(&compiled.root, &mut ctx, arena)
+            .and_then(|av| self.engine.check_non_finite(av, arena));
         let result = match outcome {
             Ok(av) => Ok(av),
             Err(e) => Err(e.decorated(ctx.take_error_path(), compiled, false)),
@@ -463,6 +737,7 @@ impl<'e> TracedSession<'e> {
             ExpressionNode {
                 id: 0,
                 expression: String::new(),
+                pointer: String::new(),
                 children: Vec::new(),
             },
             error,
@@ -489,7 +764,7 @@ mod tests {
             iter_arg_kind: crate::operators::array::IterArgKind::General,
         };
 
-        let tree = ExpressionNode::build_from_compiled(&node);
+        let tree = ExpressionNode::build_from_compiled(&node, None);
 
         // Synthetic test nodes all share SYNTHETIC_ID, which surfaces as 0
         // through the public `ExpressionNode::id` (u32) shape; the
@@ -526,7 +801,7 @@ mod tests {
             iter_arg_kind: crate::operators::array::IterArgKind::General,
         };
 
-        let tree = ExpressionNode::build_from_compiled(&node);
+        let tree = ExpressionNode::build_from_compiled(&node, None);
 
         assert_eq!(tree.id, 0);
         assert!(tree.expression.contains(">="));
@@ -561,6 +836,19 @@ mod tests {
         assert_eq!(steps[0].iteration_total, Some(3));
     }
 
+    #[test]
+    fn test_trace_collector_elapsed_applies_to_next_step_only() {
+        let mut collector = TraceCollector::with_timings();
+
+        collector.set_elapsed(42);
+        collector.record_step(0, serde_json::json!(null), serde_json::json!(1));
+        collector.record_step(1, serde_json::json!(null), serde_json::json!(2));
+
+        let steps = collector.into_steps();
+        assert_eq!(steps[0].elapsed_nanos, Some(42));
+        assert_eq!(steps[1].elapsed_nanos, None);
+    }
+
     #[test]
     fn traced_session_evaluate_str_smoke() {
         let engine = crate::Engine::new();
@@ -3,9 +3,11 @@
 #![cfg(feature = "serde_json")]
 
 use bumpalo::Bump;
-use datalogic_rs::operator::EvalContext;
-use datalogic_rs::{ArenaExt, CustomOperator, DataValue, Engine, Result};
+use datalogic_rs::operator::{EvalContext, LazyArgs};
+use datalogic_rs::{ArenaExt, CustomOperator, DataValue, Engine, LazyOperator, Result};
 use serde_json::json;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
 
 /// Doubles the first numeric argument. Returns a fresh arena-allocated number.
 struct DoubleArena;
@@ -289,3 +291,425 @@ fn custom_operator_names_lists_registered_custom_ops() {
     names.sort();
     assert_eq!(names, vec!["double", "xcat"]);
 }
+
+/// Returns its configured label, ignoring the arguments.
+struct Label(&'static str);
+impl CustomOperator for Label {
+    fn evaluate<'a>(
+        &self,
+        _args: &[&'a DataValue<'a>],
+        _ctx: &mut EvalContext<'_, 'a>,
+        arena: &'a Bump,
+    ) -> Result<&'a DataValue<'a>> {
+        Ok(arena.string(self.0))
+    }
+}
+
+#[test]
+fn custom_operators_are_scoped_to_their_engine() {
+    let tenant_a = Engine::builder().add_operator("tenant", Label("a")).build();
+    let tenant_b = Engine::builder().add_operator("tenant", Label("b")).build();
+    let plain = Engine::new();
+
+    let rule = json!({"tenant": []});
+    let a = tenant_a.compile(&rule).unwrap();
+    let b = tenant_b.compile(&rule).unwrap();
+    let result_a: serde_json::Value = tenant_a.session().eval_into(&a, &json!({})).unwrap();
+    let result_b: serde_json::Value = tenant_b.session().eval_into(&b, &json!({})).unwrap();
+    assert_eq!(result_a, json!("a"));
+    assert_eq!(result_b, json!("b"));
+
+    // Neither registration leaks into an engine that didn't make it.
+    assert!(!plain.has_custom_operator("tenant"));
+    let unregistered = plain.compile(&rule).unwrap();
+    let err = plain.session().eval(&unregistered, &json!({})).unwrap_err();
+    assert_eq!(err.tag(), "InvalidOperator");
+}
+
+#[test]
+fn operator_overrides_replace_only_the_registered_builtin() {
+    let engine = Engine::builder()
+        .with_operator_overrides(true)
+        .add_operator("+", CatArena)
+        .build();
+
+    let compiled = engine
+        .compile(&json!([{"+": ["a", "b"]}, {"*": [2, 3]}]))
+        .unwrap();
+    let result: serde_json::Value = engine.session().eval_into(&compiled, &json!({})).unwrap();
+    assert_eq!(result, json!(["ab", 6]));
+}
+
+#[test]
+fn operator_overrides_apply_inside_iterators() {
+    let engine = Engine::builder()
+        .with_operator_overrides(true)
+        .add_operator("var", DoubleArena)
+        .build();
+
+    // The override replaces even `var`: the custom op receives the
+    // evaluated argument (the literal 21) rather than a path lookup.
+    let compiled = engine
+        .compile(&json!({"map": [[1, 2], {"var": 21}]}))
+        .unwrap();
+    let result: serde_json::Value = engine.session().eval_into(&compiled, &json!({})).unwrap();
+    assert_eq!(result, json!([42, 42]));
+}
+
+/// Lazy `or`: evaluates the second argument only when the first is
+/// falsy (`null`, `false`, `0` or `""` for this test's purposes).
+struct LazyOr;
+impl LazyOperator for LazyOr {
+    fn evaluate<'a>(
+        &self,
+        args: &mut LazyArgs<'_, 'a>,
+        _arena: &'a Bump,
+    ) -> Result<&'a DataValue<'a>> {
+        let first = args.evaluate(0)?;
+        let falsy = match first {
+            DataValue::Null => true,
+            DataValue::Bool(b) => !b,
+            DataValue::Number(_) => first.as_f64() == Some(0.0),
+            DataValue::String(s) => s.is_empty(),
+            _ => false,
+        };
+        if falsy { args.evaluate(1) } else { Ok(first) }
+    }
+}
+
+/// Counts its invocations, then returns its first argument.
+struct Counted(Arc<AtomicUsize>);
+impl CustomOperator for Counted {
+    fn evaluate<'a>(
+        &self,
+        args: &[&'a DataValue<'a>],
+        _ctx: &mut EvalContext<'_, 'a>,
+        arena: &'a Bump,
+    ) -> Result<&'a DataValue<'a>> {
+        self.0.fetch_add(1, Ordering::SeqCst);
+        Ok(args.first().copied().unwrap_or_else(|| arena.null()))
+    }
+}
+
+#[test]
+fn lazy_operator_evaluates_second_argument_only_when_first_is_falsy() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let engine = Engine::builder()
+        .add_lazy_operator("lazy_or", LazyOr)
+        .add_operator("counted", Counted(Arc::clone(&calls)))
+        .build();
+    let compiled = engine
+        .compile(&json!({"lazy_or": [{"var": "a"}, {"counted": "fallback"}]}))
+        .unwrap();
+
+    let result: serde_json::Value = engine
+        .session()
+        .eval_into(&compiled, &json!({"a": "set"}))
+        .unwrap();
+    assert_eq!(result, json!("set"));
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+
+    let result: serde_json::Value = engine
+        .session()
+        .eval_into(&compiled, &json!({"a": 0}))
+        .unwrap();
+    assert_eq!(result, json!("fallback"));
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn lazy_operator_skips_failing_arguments_and_reads_iteration_context() {
+    let engine = Engine::builder()
+        .add_lazy_operator("lazy_or", LazyOr)
+        .build();
+
+    // The unevaluated second argument would fail as an unknown operator.
+    let compiled = engine
+        .compile(&json!({"lazy_or": [true, {"no_such_op": []}]}))
+        .unwrap();
+    let result: serde_json::Value = engine.session().eval_into(&compiled, &json!({})).unwrap();
+    assert_eq!(result, json!(true));
+
+    // Arguments evaluate against the enclosing iteration frame.
+    let compiled = engine
+        .compile(&json!({"map": [{"var": "xs"}, {"lazy_or": [{"var": "name"}, "anonymous"]}]}))
+        .unwrap();
+    let result: serde_json::Value = engine
+        .session()
+        .eval_into(&compiled, &json!({"xs": [{"name": "ada"}, {}]}))
+        .unwrap();
+    assert_eq!(result, json!(["ada", "anonymous"]));
+}
+
+#[test]
+fn lazy_operator_out_of_range_argument_is_null() {
+    let engine = Engine::builder()
+        .add_lazy_operator("lazy_or", LazyOr)
+        .build();
+    let compiled = engine.compile(&json!({"lazy_or": [null]})).unwrap();
+    let result: serde_json::Value = engine.session().eval_into(&compiled, &json!({})).unwrap();
+    assert_eq!(result, json!(null));
+}
+
+/// Like [`Counted`], but declares itself pure so the compiler may hoist
+/// calls with loop-invariant arguments out of iterator bodies.
+struct PureCounted(Arc<AtomicUsize>);
+impl CustomOperator for PureCounted {
+    fn evaluate<'a>(
+        &self,
+        args: &[&'a DataValue<'a>],
+        ctx: &mut EvalContext<'_, 'a>,
+        arena: &'a Bump,
+    ) -> Result<&'a DataValue<'a>> {
+        Counted(Arc::clone(&self.0)).evaluate(args, ctx, arena)
+    }
+
+    fn is_pure(&self) -> bool {
+        true
+    }
+}
+
+#[test]
+fn pure_operator_with_constant_args_runs_once_per_evaluation() {
+    let pure_calls = Arc::new(AtomicUsize::new(0));
+    let impure_calls = Arc::new(AtomicUsize::new(0));
+    let engine = Engine::builder()
+        .add_operator("pure", PureCounted(Arc::clone(&pure_calls)))
+        .add_operator("impure", Counted(Arc::clone(&impure_calls)))
+        .build();
+    let data = json!({"xs": [1, 2, 3, 4]});
+
+    let cases = [
+        (
+            json!({"map": [{"var": "xs"}, {"+": [{"var": ""}, {"pure": 10}]}]}),
+            json!([11, 12, 13, 14]),
+        ),
+        (
+            json!({"filter": [{"var": "xs"}, {">": [{"var": ""}, {"pure": 2}]}]}),
+            json!([3, 4]),
+        ),
+        (
+            json!({"reduce": [{"var": "xs"}, {"+": [{"var": "accumulator"}, {"var": "current"}, {"pure": 1}]}, 0]}),
+            json!(14),
+        ),
+    ];
+    for (rule, expected) in cases {
+        pure_calls.store(0, Ordering::SeqCst);
+        impure_calls.store(0, Ordering::SeqCst);
+        let impure_rule: serde_json::Value =
+            serde_json::from_str(&rule.to_string().replace("\"pure\"", "\"impure\"")).unwrap();
+
+        let hoisted = engine.compile(&rule).unwrap();
+        let per_item = engine.compile(&impure_rule).unwrap();
+        let result: serde_json::Value = engine.session().eval_into(&hoisted, &data).unwrap();
+        let baseline: serde_json::Value = engine.session().eval_into(&per_item, &data).unwrap();
+
+        assert_eq!(result, expected, "{rule}");
+        assert_eq!(baseline, expected, "{impure_rule}");
+        assert_eq!(pure_calls.load(Ordering::SeqCst), 1, "{rule}");
+        assert_eq!(impure_calls.load(Ordering::SeqCst), 4, "{impure_rule}");
+
+        // The memo lives for one evaluation: the next one computes again.
+        let _: serde_json::Value = engine.session().eval_into(&hoisted, &data).unwrap();
+        assert_eq!(pure_calls.load(Ordering::SeqCst), 2, "{rule}");
+    }
+}
+
+#[test]
+fn pure_operator_hoisting_follows_its_arguments() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let engine = Engine::builder()
+        .add_operator("pure", PureCounted(Arc::clone(&calls)))
+        .build();
+    let data = json!({"xs": [1, 2, 3]});
+    let run = |rule: serde_json::Value, data: &serde_json::Value| {
+        calls.store(0, Ordering::SeqCst);
+        let compiled = engine.compile(&rule).unwrap();
+        let result: serde_json::Value = engine.session().eval_into(&compiled, data).unwrap();
+        (result, calls.load(Ordering::SeqCst))
+    };
+
+    // An argument that reads the item keeps the call per item.
+    let (result, count) = run(
+        json!({"map": [{"var": "xs"}, {"pure": {"var": ""}}]}),
+        &data,
+    );
+    assert_eq!(result, json!([1, 2, 3]));
+    assert_eq!(count, 3);
+
+    // A nested map over a literal reads only its own item: the whole
+    // inner map is computed once, not once per outer item.
+    let (result, count) = run(
+        json!({"map": [{"var": "xs"}, {"in": [{"var": ""}, {"map": [[2, 3], {"pure": {"var": ""}}]}]}]}),
+        &data,
+    );
+    assert_eq!(result, json!([false, true, true]));
+    assert_eq!(count, 2);
+
+    // A body that never runs never computes the hoisted value.
+    let (result, count) = run(
+        json!({"map": [{"var": "xs"}, {"+": [{"var": ""}, {"pure": 10}]}]}),
+        &json!({"xs": []}),
+    );
+    assert_eq!(result, json!([]));
+    assert_eq!(count, 0);
+}
+
+/// Appends its first argument to a shared log, then returns it.
+struct Recorded(Arc<Mutex<Vec<String>>>);
+impl CustomOperator for Recorded {
+    fn evaluate<'a>(
+        &self,
+        args: &[&'a DataValue<'a>],
+        _ctx: &mut EvalContext<'_, 'a>,
+        arena: &'a Bump,
+    ) -> Result<&'a DataValue<'a>> {
+        let value = args.first().copied().unwrap_or_else(|| arena.null());
+        self.0.lock().unwrap().push(value.to_string());
+        Ok(value)
+    }
+}
+
+#[test]
+fn and_or_short_circuit_left_to_right_and_return_the_operand() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let folding = Engine::builder()
+        .add_operator("rec", Recorded(Arc::clone(&log)))
+        .build();
+    let plain = Engine::builder()
+        .add_operator("rec", Recorded(Arc::clone(&log)))
+        .with_constant_folding(false)
+        .build();
+
+    // (rule, data, expected result, operands recorded in order)
+    let cases = [
+        (
+            json!({"and": [false, {"rec": 1}]}),
+            json!({}),
+            json!(false),
+            vec![],
+        ),
+        (
+            json!({"or": [true, {"rec": 1}]}),
+            json!({}),
+            json!(true),
+            vec![],
+        ),
+        (
+            json!({"and": [{"var": "a"}, {"rec": "x"}]}),
+            json!({"a": 0}),
+            json!(0),
+            vec![],
+        ),
+        (
+            json!({"or": [{"var": "a"}, {"rec": "x"}]}),
+            json!({"a": "set"}),
+            json!("set"),
+            vec![],
+        ),
+        // Operands before a literal still run, and the falsy / truthy one
+        // is the result rather than the literal.
+        (
+            json!({"and": [{"rec": 0}, false]}),
+            json!({}),
+            json!(0),
+            vec!["0"],
+        ),
+        (
+            json!({"or": [{"rec": "hit"}, true]}),
+            json!({}),
+            json!("hit"),
+            vec!["\"hit\""],
+        ),
+        (
+            json!({"and": [{"rec": 1}, false, {"rec": 2}]}),
+            json!({}),
+            json!(false),
+            vec!["1"],
+        ),
+        // A trailing identity literal is the result when everything passes.
+        (
+            json!({"and": [{"rec": 5}, true]}),
+            json!({}),
+            json!(true),
+            vec!["5"],
+        ),
+        (
+            json!({"or": [{"rec": ""}, false]}),
+            json!({}),
+            json!(false),
+            vec!["\"\""],
+        ),
+        (
+            json!({"and": [{"rec": 1}, {"rec": 2}, {"rec": 0}, {"rec": 3}]}),
+            json!({}),
+            json!(0),
+            vec!["1", "2", "0"],
+        ),
+        (
+            json!({"or": [{"rec": null}, {"rec": [[]]}, {"rec": "a"}, {"rec": "b"}]}),
+            json!({}),
+            json!("a"),
+            vec!["null", "[]", "\"a\""],
+        ),
+    ];
+    for engine in [&folding, &plain] {
+        for (rule, data, expected, recorded) in &cases {
+            log.lock().unwrap().clear();
+            let compiled = engine.compile(rule).unwrap();
+            let result: serde_json::Value = engine.session().eval_into(&compiled, data).unwrap();
+            assert_eq!(&result, expected, "{rule}");
+            assert_eq!(*log.lock().unwrap(), *recorded, "{rule}");
+        }
+    }
+}
+
+#[cfg(feature = "ext-array")]
+#[test]
+fn reduce_while_stops_visiting_items_once_the_condition_fails() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let engine = Engine::builder()
+        .add_operator("counted", Counted(Arc::clone(&calls)))
+        .build();
+    let data = json!({"xs": (1..=1000).collect::<Vec<_>>()});
+
+    // Stops before the item that would take the sum past 10: 1 + 2 + 3 + 4.
+    let compiled = engine
+        .compile(&json!({"reduce_while": [
+            {"var": "xs"},
+            {"+": [{"var": "accumulator"}, {"counted": {"var": "current"}}]},
+            0,
+            {"<=": [{"+": [{"var": "accumulator"}, {"var": "current"}]}, 10]}
+        ]}))
+        .unwrap();
+    let result: serde_json::Value = engine.session().eval_into(&compiled, &data).unwrap();
+    assert_eq!(result, json!(10));
+    assert_eq!(calls.load(Ordering::SeqCst), 4);
+
+    // The condition itself also runs only up to the stopping item.
+    calls.store(0, Ordering::SeqCst);
+    let compiled = engine
+        .compile(&json!({"reduce_while": [
+            {"var": "xs"},
+            {"+": [{"var": "accumulator"}, {"var": "current"}]},
+            0,
+            {"<": [{"counted": {"var": "accumulator"}}, 10]}
+        ]}))
+        .unwrap();
+    let result: serde_json::Value = engine.session().eval_into(&compiled, &data).unwrap();
+    assert_eq!(result, json!(10));
+    assert_eq!(calls.load(Ordering::SeqCst), 5);
+
+    // Plain `reduce` visits every item.
+    calls.store(0, Ordering::SeqCst);
+    let compiled = engine
+        .compile(&json!({"reduce": [
+            {"var": "xs"},
+            {"+": [{"var": "accumulator"}, {"counted": {"var": "current"}}]},
+            0
+        ]}))
+        .unwrap();
+    let result: serde_json::Value = engine.session().eval_into(&compiled, &data).unwrap();
+    assert_eq!(result, json!(500500));
+    assert_eq!(calls.load(Ordering::SeqCst), 1000);
+}
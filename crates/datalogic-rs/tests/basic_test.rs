@@ -118,3 +118,65 @@ fn test_evaluate_with_arena_dispatch() {
         .unwrap();
     assert_eq!(result, json!(3));
 }
+
+#[cfg(feature = "ext-string")]
+#[test]
+fn test_json_parse_failure_carries_parser_message() {
+    let engine = Engine::new();
+    let logic = json!({"json_parse": {"var": "payload"}});
+    for bad in ["{\"a\": }", "[1, 2", "not json", "{\"a\":1} trailing"] {
+        let err = engine
+            .eval_into::<serde_json::Value, _, _>(&logic, &json!({"payload": bad}))
+            .unwrap_err();
+        match &err.kind {
+            datalogic_rs::ErrorKind::ParseError(msg) => {
+                assert!(!msg.is_empty(), "empty parse message for {bad:?}")
+            }
+            other => panic!("expected ParseError for {bad:?}, got {other:?}"),
+        }
+    }
+}
+
+#[test]
+fn map_over_integers_keeps_integer_results() {
+    let engine = Engine::new();
+    let data =
+        r#"{"xs": [1, 2, 3], "rows": [{"n": 1, "k": 2}, {"n": 2, "k": 2}, {"n": 3, "k": 2}]}"#;
+    // Each rule takes a different `map` path: the var-literal and var-var
+    // fast loops, the general per-item dispatch, and a literal input.
+    let cases = [
+        r#"{"map": [[1, 2, 3], {"*": [{"var": ""}, 2]}]}"#,
+        r#"{"map": [{"var": "xs"}, {"*": [{"var": ""}, 2]}]}"#,
+        r#"{"map": [{"var": "xs"}, {"*": [2, {"var": ""}]}]}"#,
+        r#"{"map": [{"var": "rows"}, {"*": [{"var": "n"}, {"var": "k"}]}]}"#,
+        r#"{"map": [{"var": "xs"}, {"*": [{"var": ""}, 2, 1]}]}"#,
+        r#"{"map": [{"var": "xs"}, {"+": [{"var": ""}, {"var": ""}]}]}"#,
+        r#"{"map": [{"var": "xs"}, {"-": [{"*": [{"var": ""}, 3]}, {"var": ""}]}]}"#,
+        r#"{"map": [{"var": "xs"}, {"/": [{"*": [{"var": ""}, 4]}, 2]}]}"#,
+    ];
+    for rule in cases {
+        assert_eq!(engine.eval_str(rule, data).unwrap(), "[2,4,6]", "{rule}");
+    }
+
+    // A fractional item moves only that item to floating point.
+    assert_eq!(
+        engine
+            .eval_str(
+                r#"{"map": [{"var": "xs"}, {"*": [{"var": ""}, 2]}]}"#,
+                r#"{"xs": [1, 1.25, 3]}"#
+            )
+            .unwrap(),
+        "[2,2.5,6]"
+    );
+
+    // Summing the mapped values stays integral too.
+    assert_eq!(
+        engine
+            .eval_str(
+                r#"{"reduce": [{"map": [{"var": "xs"}, {"*": [{"var": ""}, 2]}]}, {"+": [{"var": "accumulator"}, {"var": "current"}]}, 0]}"#,
+                data,
+            )
+            .unwrap(),
+        "12"
+    );
+}
@@ -8,7 +8,7 @@
 
 #![cfg(feature = "serde_json")]
 
-use datalogic_rs::Engine;
+use datalogic_rs::{Engine, ErrorKind};
 use serde_json::json;
 
 #[test]
@@ -36,3 +36,98 @@ fn test_deeply_nested_rule_errors_instead_of_overflowing() {
         "a shallow rule must still compile"
     );
 }
+
+/// `true` nested inside `levels` `!` operators.
+fn nested_not(levels: usize) -> serde_json::Value {
+    // Built by hand: `json!` would re-serialize the whole subtree at every
+    // level.
+    let mut rule = json!(true);
+    for _ in 0..levels {
+        let mut op = serde_json::Map::new();
+        op.insert("!".to_string(), serde_json::Value::Array(vec![rule]));
+        rule = serde_json::Value::Object(op);
+    }
+    rule
+}
+
+#[test]
+fn test_ten_thousand_levels_error_gracefully() {
+    // Building and dropping a 10k-deep `serde_json::Value` recurses once per
+    // level on its own, so give the test thread room for that. The engine
+    // must bail at its cap without recursing that far.
+    std::thread::Builder::new()
+        .stack_size(256 * 1024 * 1024)
+        .spawn(|| {
+            let engine = Engine::new();
+            let err = engine.compile(&nested_not(10_000)).unwrap_err();
+            assert!(
+                matches!(err.kind, ErrorKind::ConfigurationError(_)),
+                "expected ConfigurationError, got: {:?}",
+                err.kind
+            );
+            assert!(err.to_string().contains("maximum compile depth of 256"));
+
+            // The same nesting as a rule string stops at the parser's cap.
+            let text = serde_json::to_string(&nested_not(10_000)).unwrap();
+            assert!(engine.compile(text.as_str()).is_err());
+        })
+        .unwrap()
+        .join()
+        .unwrap();
+}
+
+#[test]
+fn test_max_depth_is_configurable() {
+    let strict = Engine::builder().with_max_depth(20).build();
+    let err = strict.compile(&nested_not(30)).unwrap_err();
+    assert!(err.to_string().contains("maximum compile depth of 20"));
+    assert!(strict.compile(&nested_not(10)).is_ok());
+
+    // Raising the cap admits a rule the default engine rejects.
+    let rule = nested_not(300);
+    assert!(Engine::new().compile(&rule).is_err());
+    let relaxed = Engine::builder().with_max_depth(400).build();
+    let compiled = relaxed.compile(&rule).unwrap();
+    let result: serde_json::Value = relaxed
+        .session()
+        .eval_into(&compiled, &json!(null))
+        .unwrap();
+    assert_eq!(result, json!(true));
+}
+
+#[test]
+fn test_zero_max_depth_is_a_configuration_error() {
+    // 0 can't admit even a literal, so it's reported as a bad setting
+    // rather than as a nesting overflow.
+    let engine = Engine::builder().with_max_depth(0).build();
+    for rule in [json!(true), nested_not(1)] {
+        let err = engine.compile(&rule).unwrap_err();
+        assert!(matches!(err.kind, ErrorKind::ConfigurationError(_)));
+        assert!(err.to_string().contains("max_depth must be at least 1"));
+    }
+
+    // 1 is the smallest usable cap: a literal compiles, an operator
+    // around it doesn't.
+    let engine = Engine::builder().with_max_depth(1).build();
+    assert!(engine.compile(&json!(true)).is_ok());
+    let err = engine.compile(&nested_not(1)).unwrap_err();
+    assert!(err.to_string().contains("maximum compile depth of 1"));
+}
+
+#[test]
+fn test_raised_max_depth_leaves_string_rules_at_parser_cap() {
+    // The parser's cap is fixed at 256 arrays/objects, so a raised
+    // `with_max_depth` admits a deep rule built as a value but not the
+    // same rule as a string.
+    let relaxed = Engine::builder().with_max_depth(400).build();
+    let rule = nested_not(300);
+    assert!(relaxed.compile(&rule).is_ok());
+
+    let text = serde_json::to_string(&rule).unwrap();
+    let err = relaxed.compile(text.as_str()).unwrap_err();
+    assert!(
+        matches!(err.kind, ErrorKind::ParseError(_)),
+        "expected ParseError, got: {:?}",
+        err.kind
+    );
+}
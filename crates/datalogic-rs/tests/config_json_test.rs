@@ -62,6 +62,27 @@ fn division_by_zero_throw_error() {
     assert!(engine.eval_str(r#"{"/": [1.5, 0]}"#, "null").is_err());
 }
 
+#[test]
+fn distinct_division_by_zero_is_applied() {
+    let engine = engine_with(r#"{"distinct_division_by_zero": true}"#);
+    let err = engine.eval_str(r#"{"/": [1, 0]}"#, "null").unwrap_err();
+    assert_eq!(err.tag(), "DivisionByZero");
+    let err = engine_with("{}")
+        .eval_str(r#"{"/": [1, 0]}"#, "null")
+        .unwrap_err();
+    assert_eq!(err.tag(), "Thrown");
+}
+
+#[test]
+fn strict_variables_is_applied() {
+    let err = engine_with(r#"{"strict_variables": true}"#)
+        .eval_str(r#"{"var": "nope"}"#, "{}")
+        .unwrap_err();
+    assert_eq!(err.tag(), "VariableNotFound");
+    let result = engine_with("{}").eval_str(r#"{"var": "nope"}"#, "{}");
+    assert_eq!(result.unwrap(), "null");
+}
+
 #[test]
 fn truthy_evaluator_strict_boolean() {
     // Under strict-boolean truthiness only null/false are falsy, so 0 is
@@ -101,6 +122,54 @@ fn max_recursion_depth_is_applied() {
     assert_eq!(engine.eval_str(r#"{"+": [1, 2]}"#, "null").unwrap(), "3");
 }
 
+#[test]
+fn step_limit_is_applied() {
+    let engine = engine_with(r#"{"step_limit": 5}"#);
+    assert_eq!(engine.eval_str(r#"{"+": [1, 2]}"#, "null").unwrap(), "3");
+    let err = engine
+        .eval_str(
+            r#"{"map": [{"var": ""}, {"*": [{"var": ""}, 2]}]}"#,
+            "[1, 2, 3, 4, 5, 6]",
+        )
+        .unwrap_err();
+    assert_eq!(err.tag(), "StepLimitExceeded");
+
+    let engine = engine_with(r#"{"step_limit": null}"#);
+    assert!(engine.config().step_limit.is_none());
+}
+
+#[test]
+fn non_finite_numbers_is_applied() {
+    let engine = engine_with(r#"{"non_finite_numbers": "throw_error"}"#);
+    let err = engine
+        .eval_str(r#"{"*": [1e308, 10]}"#, "null")
+        .unwrap_err();
+    assert_eq!(err.tag(), "NonFiniteNumber");
+
+    let engine = engine_with(r#"{"non_finite_numbers": "return_null"}"#);
+    assert_eq!(
+        engine
+            .eval_str(r#"[{"*": [1e308, 10]}, 1]"#, "null")
+            .unwrap(),
+        "[null,1]"
+    );
+}
+
+#[cfg(feature = "ext-math")]
+#[test]
+fn random_seed_and_deterministic_mode_are_applied() {
+    let engine = engine_with(r#"{"random_seed": 42, "deterministic_mode": true}"#);
+    assert_eq!(
+        engine.eval_str(r#"{"random": [0, 100]}"#, "null").unwrap(),
+        engine
+            .eval_str(r#"{"random": [0, 100, 42]}"#, "null")
+            .unwrap()
+    );
+
+    let engine = engine_with(r#"{"random_seed": null, "deterministic_mode": true}"#);
+    assert!(engine.eval_str(r#"{"random": [0, 100]}"#, "null").is_err());
+}
+
 // --- rejection cases ---
 
 fn parse_err(json: &str) -> String {
@@ -125,6 +194,7 @@ fn rejects_unknown_enum_value() {
     assert!(parse_err(r#"{"division_by_zero": "explode"}"#).contains("unknown division_by_zero"));
     assert!(parse_err(r#"{"preset": "fast"}"#).contains("unknown preset"));
     assert!(parse_err(r#"{"truthy_evaluator": "ruby"}"#).contains("unknown truthy_evaluator"));
+    assert!(parse_err(r#"{"non_finite_numbers": "zero"}"#).contains("unknown non_finite_numbers"));
 }
 
 #[test]
@@ -134,6 +204,11 @@ fn rejects_type_mismatches() {
     assert!(parse_err(r#"{"numeric_coercion": true}"#).contains("must be an object"));
     assert!(parse_err(r#"{"max_recursion_depth": 0}"#).contains("between 1 and"));
     assert!(parse_err(r#"{"max_recursion_depth": -4}"#).contains("between 1 and"));
+    assert!(parse_err(r#"{"random_seed": -1}"#).contains("non-negative integer or null"));
+    assert!(parse_err(r#"{"random_seed": "42"}"#).contains("non-negative integer or null"));
+    assert!(parse_err(r#"{"deterministic_mode": 1}"#).contains("must be a boolean"));
+    assert!(parse_err(r#"{"step_limit": 0}"#).contains("positive integer or null"));
+    assert!(parse_err(r#"{"step_limit": 1.5}"#).contains("positive integer or null"));
 }
 
 #[test]
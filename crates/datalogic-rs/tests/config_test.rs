@@ -4,8 +4,8 @@
 
 use datalogic_rs::datavalue::OwnedDataValue;
 use datalogic_rs::{
-    DivisionByZeroHandling, Engine, EvaluationConfig, NanHandling, NumericCoercionConfig,
-    TruthyEvaluator,
+    DivisionByZeroHandling, Engine, ErrorKind, EvaluationConfig, NanHandling, NonFiniteHandling,
+    NumericCoercionConfig, TruthyEvaluator,
 };
 use serde_json::json;
 use std::sync::Arc;
@@ -55,6 +55,276 @@ fn test_nan_handling_return_null() {
     assert_eq!(result, json!(null));
 }
 
+#[test]
+fn test_distinct_division_by_zero_separates_cause_from_nan() {
+    let nan = json!({"type": "NaN"});
+    let default_engine = Engine::new();
+    let distinct = Engine::builder()
+        .with_config(EvaluationConfig::default().with_distinct_division_by_zero(true))
+        .build();
+    let eval_err = |engine: &Engine, logic: serde_json::Value| {
+        engine
+            .eval_into::<serde_json::Value, _, _>(&logic, &json!({}))
+            .unwrap_err()
+    };
+
+    // By default both causes throw the JSONLogic NaN object.
+    for logic in [json!({"/": [1, 0]}), json!({"+": ["abc", 1]})] {
+        let err = eval_err(&default_engine, logic.clone());
+        assert_eq!(err.thrown_value(), Some(&(&nan).into()), "{logic}");
+    }
+
+    // Opted in, every erroring zero divisor gets its own kind — 2-arg,
+    // 1-arg reciprocal, array fold, variadic, and modulo …
+    for logic in [
+        json!({"/": [1, 0]}),
+        json!({"/": [0]}),
+        json!({"/": [[10, 0]]}),
+        json!({"/": [8, 2, 0]}),
+        json!({"%": [7, 0]}),
+    ] {
+        let err = eval_err(&distinct, logic.clone());
+        assert!(
+            matches!(err.kind, datalogic_rs::ErrorKind::DivisionByZero),
+            "{logic}: {err:?}"
+        );
+        assert_eq!(err.tag(), "DivisionByZero");
+    }
+    // … while bad numeric input is still NaN.
+    let err = eval_err(&distinct, json!({"+": ["abc", 1]}));
+    assert_eq!(err.thrown_value(), Some(&(&nan).into()));
+    let err = eval_err(&distinct, json!({"/": ["abc", 1]}));
+    assert_eq!(err.thrown_value(), Some(&(&nan).into()));
+
+    // Sentinel modes are unaffected: the float path still saturates.
+    assert_eq!(
+        distinct
+            .eval_into::<serde_json::Value, _, _>(&json!({"/": [1.5, 0]}), &json!({}))
+            .unwrap(),
+        json!(f64::MAX)
+    );
+}
+
+#[cfg(feature = "error-handling")]
+#[test]
+fn test_distinct_division_by_zero_is_catchable_by_type() {
+    let engine = Engine::builder()
+        .with_config(EvaluationConfig::default().with_distinct_division_by_zero(true))
+        .build();
+    let logic = json!({"try": [{"/": [{"var": "a"}, {"var": "b"}]}, {"var": "error.type"}]});
+    let caught = |data: serde_json::Value| {
+        engine
+            .eval_into::<serde_json::Value, _, _>(&logic, &data)
+            .unwrap()
+    };
+    assert_eq!(caught(json!({"a": 1, "b": 0})), json!("DivisionByZero"));
+    assert_eq!(caught(json!({"a": "x", "b": 1})), json!("NaN"));
+}
+
+#[test]
+fn test_non_finite_numbers_keep_by_default() {
+    let engine = Engine::new();
+    let arena = bumpalo::Bump::new();
+    // Overflow on every arithmetic path lands on ±Infinity, not a panic.
+    for (rule, expected) in [
+        (r#"{"*": [1e308, 10]}"#, f64::INFINITY),
+        (r#"{"+": [1e308, 1e308]}"#, f64::INFINITY),
+        (r#"{"-": [-1e308, 1e308]}"#, f64::NEG_INFINITY),
+        (r#"{"/": [1e308, 0.1]}"#, f64::INFINITY),
+    ] {
+        let compiled = engine.compile(rule).unwrap();
+        let result = engine.evaluate(&compiled, "null", &arena).unwrap();
+        assert_eq!(result.as_f64(), Some(expected), "{rule}");
+        // JSON output has no spelling for infinity and writes null.
+        assert_eq!(engine.eval_str(rule, "null").unwrap(), "null", "{rule}");
+    }
+}
+
+#[test]
+fn test_non_finite_numbers_return_null() {
+    let engine = Engine::builder()
+        .with_config(
+            EvaluationConfig::default().with_non_finite_numbers(NonFiniteHandling::ReturnNull),
+        )
+        .build();
+    let arena = bumpalo::Bump::new();
+    let eval = |rule: &str| {
+        let compiled = engine.compile(rule).unwrap();
+        engine
+            .evaluate(&compiled, "null", &arena)
+            .unwrap()
+            .to_string()
+    };
+
+    assert_eq!(eval(r#"{"*": [1e308, 10]}"#), "null");
+    // Nested values are replaced in place; finite neighbours are kept.
+    assert_eq!(
+        eval(r#"[1.5, {"*": [1e308, 10]}, [{"-": [-1e308, 1e308]}]]"#),
+        "[1.5,null,[null]]"
+    );
+    assert_eq!(
+        eval(r#"{"map": [[1, 1e308], {"*": [{"var": ""}, 10]}]}"#),
+        "[10,null]"
+    );
+    assert_eq!(eval(r#"{"*": [2.5, 2]}"#), "5");
+}
+
+#[test]
+fn test_non_finite_numbers_throw_error() {
+    let engine = Engine::builder()
+        .with_config(
+            EvaluationConfig::default().with_non_finite_numbers(NonFiniteHandling::ThrowError),
+        )
+        .build();
+    for logic in [
+        json!({"*": [1e308, 10]}),
+        json!({"/": [1e308, 0.1]}),
+        json!({"map": [[1, 1e308], {"*": [{"var": ""}, 10]}]}),
+        json!([1, [{"+": [1e308, 1e308]}]]),
+    ] {
+        let err = engine
+            .eval_into::<serde_json::Value, _, _>(&logic, &json!({}))
+            .unwrap_err();
+        assert!(
+            matches!(err.kind, ErrorKind::NonFiniteNumber),
+            "{logic}: {err:?}"
+        );
+        assert_eq!(err.tag(), "NonFiniteNumber");
+    }
+    // Finite results and overflow that never reaches the result pass.
+    let ok = |logic: serde_json::Value| {
+        engine
+            .eval_into::<serde_json::Value, _, _>(&logic, &json!({}))
+            .unwrap()
+    };
+    assert_eq!(ok(json!({"*": [1e307, 10]})), json!(1e308));
+    assert_eq!(ok(json!({">": [{"*": [1e308, 10]}, 0]})), json!(true));
+    // Division by zero keeps its own knob: the default saturates to MAX.
+    assert_eq!(ok(json!({"/": [1.5, 0]})), json!(f64::MAX));
+}
+
+#[cfg(feature = "ext-math")]
+#[test]
+fn test_non_finite_numbers_large_exponentiation() {
+    let keep = Engine::new();
+    let throw = Engine::builder()
+        .with_config(
+            EvaluationConfig::default().with_non_finite_numbers(NonFiniteHandling::ThrowError),
+        )
+        .build();
+
+    // `pow` itself rejects overflow, like `exp`, under every mode.
+    for engine in [&keep, &throw] {
+        let err = engine
+            .eval_str(r#"{"pow": [10, 400]}"#, "null")
+            .unwrap_err();
+        assert_eq!(
+            err.thrown_value(),
+            Some(&json!({"type": "NaN"}).into()),
+            "{err:?}"
+        );
+    }
+    assert_eq!(
+        throw.eval_str(r#"{"pow": [10, 300]}"#, "null").unwrap(),
+        "1e300"
+    );
+
+    // A large power that overflows later in plain arithmetic still goes
+    // through the non-finite handling.
+    let rule = r#"{"*": [{"pow": [10, 300]}, 1e10]}"#;
+    assert_eq!(keep.eval_str(rule, "null").unwrap(), "null");
+    let err = throw.eval_str(rule, "null").unwrap_err();
+    assert!(matches!(err.kind, ErrorKind::NonFiniteNumber), "{err:?}");
+}
+
+#[test]
+fn test_strict_variables_errors_on_missing_path() {
+    let data = json!({
+        "user": {"name": "Ada", "tags": ["a"]},
+        "zero": 0,
+        "rows": [{"a": 1}, {"b": 2}],
+    });
+    let strict = Engine::builder()
+        .with_config(EvaluationConfig::default().with_strict_variables(true))
+        .build();
+    let eval = |engine: &Engine, logic: serde_json::Value| {
+        engine.eval_into::<serde_json::Value, _, _>(&logic, &data)
+    };
+
+    // Lenient by default: a typo'd path quietly resolves to null.
+    assert_eq!(
+        eval(&Engine::new(), json!({"var": "user.nmae"})).unwrap(),
+        json!(null)
+    );
+
+    let err = eval(&strict, json!({"var": "user.nmae"})).unwrap_err();
+    assert_eq!(err.tag(), "VariableNotFound");
+    assert!(matches!(&err.kind, ErrorKind::VariableNotFound(p) if p == "user.nmae"));
+    let err = eval(&strict, json!({"val": ["user", "tags", 3]})).unwrap_err();
+    assert_eq!(err.tag(), "VariableNotFound");
+    // Dynamic paths are checked too.
+    let err = eval(&strict, json!({"var": {"cat": ["us", "r"]}})).unwrap_err();
+    assert!(matches!(&err.kind, ErrorKind::VariableNotFound(p) if p == "usr"));
+    // ... and so are lookups inside iterator bodies.
+    let err = eval(&strict, json!({"map": [[1, 2], {"var": "missing"}]})).unwrap_err();
+    assert_eq!(err.tag(), "VariableNotFound");
+    let err = eval(
+        &strict,
+        json!({"filter": [{"var": "rows"}, {"==": [{"var": "a"}, 1]}]}),
+    )
+    .unwrap_err();
+    assert_eq!(err.tag(), "VariableNotFound");
+    let err = eval(
+        &strict,
+        json!({"sort": [{"var": "rows"}, true, {"var": "a"}]}),
+    )
+    .unwrap_err();
+    assert_eq!(err.tag(), "VariableNotFound");
+
+    // Present paths, falsy values, explicit defaults, and `missing` still work.
+    assert_eq!(
+        eval(&strict, json!({"var": "user.name"})).unwrap(),
+        json!("Ada")
+    );
+    assert_eq!(eval(&strict, json!({"var": "zero"})).unwrap(), json!(0));
+    assert_eq!(
+        eval(&strict, json!({"var": ["user.nmae", "anon"]})).unwrap(),
+        json!("anon")
+    );
+    assert_eq!(
+        eval(&strict, json!({"missing": ["user.nmae"]})).unwrap(),
+        json!(["user.nmae"])
+    );
+    assert_eq!(
+        eval(&strict, json!({"map": [[1, 2], {"var": ""}]})).unwrap(),
+        json!([1, 2])
+    );
+}
+
+#[cfg(feature = "ext-math")]
+#[test]
+fn test_nan_handling_avg_divisor() {
+    let data = json!({"nums": [1, "not_a_number", 5]});
+    let logic = json!({"avg": {"var": "nums"}});
+
+    // Ignored elements drop out of the divisor.
+    let config = EvaluationConfig::default().with_arithmetic_nan_handling(NanHandling::IgnoreValue);
+    let engine = Engine::builder().with_config(config).build();
+    let result = engine
+        .eval_into::<serde_json::Value, _, _>(&logic, &data)
+        .unwrap();
+    assert_eq!(result, json!(3));
+
+    // Zero-coerced elements still count.
+    let config =
+        EvaluationConfig::default().with_arithmetic_nan_handling(NanHandling::CoerceToZero);
+    let engine = Engine::builder().with_config(config).build();
+    let result = engine
+        .eval_into::<serde_json::Value, _, _>(&logic, &data)
+        .unwrap();
+    assert_eq!(result, json!(2));
+}
+
 #[test]
 fn test_numeric_coercion_default() {
     let engine = Engine::new();
@@ -439,7 +709,9 @@ fn test_evaluation_config_fluent_setters() {
     let config = EvaluationConfig::default()
         .with_arithmetic_nan_handling(NanHandling::IgnoreValue)
         .with_division_by_zero(DivisionByZeroHandling::ReturnNull)
+        .with_distinct_division_by_zero(true)
         .with_loose_equality_errors(false)
+        .with_strict_variables(true)
         .with_truthy_evaluator(TruthyEvaluator::StrictBoolean)
         .with_numeric_coercion(
             NumericCoercionConfig::default()
@@ -448,17 +720,25 @@ fn test_evaluation_config_fluent_setters() {
                 .with_bool_to_number(false)
                 .with_reject_non_numeric(true),
         )
-        .with_max_recursion_depth(64);
+        .with_max_recursion_depth(64)
+        .with_random_seed(Some(7))
+        .with_deterministic_mode(true)
+        .with_step_limit(Some(1_000));
 
     assert_eq!(config.arithmetic_nan_handling, NanHandling::IgnoreValue);
     assert_eq!(config.division_by_zero, DivisionByZeroHandling::ReturnNull);
+    assert!(config.distinct_division_by_zero);
     assert!(!config.loose_equality_errors);
+    assert!(config.strict_variables);
     assert!(matches!(
         config.truthy_evaluator,
         TruthyEvaluator::StrictBoolean
     ));
     assert!(config.numeric_coercion.reject_non_numeric);
     assert_eq!(config.max_recursion_depth, 64);
+    assert_eq!(config.random_seed, Some(7));
+    assert!(config.deterministic_mode);
+    assert_eq!(config.step_limit, Some(1_000));
 
     // Engine-level smoke check: the chained config drives evaluation as
     // expected — `IgnoreValue` lets arithmetic skip the bad operand.
@@ -617,3 +897,286 @@ fn test_div_by_zero_fold_matches_two_arg_for_numeric_string_dividend() {
             .is_err()
     );
 }
+
+#[cfg(feature = "ext-math")]
+#[test]
+fn test_random_same_seed_same_sequence() {
+    // A seed fixes the whole sequence: two independent engines (and two
+    // evaluations on the same engine) agree call-for-call.
+    let logic = json!({"map": [{"var": "seeds"}, {"random": [0, 1000, {"var": ""}]}]});
+    let data = json!({"seeds": [1, 2, 3, "alice", "bob", 2.5]});
+
+    let first = Engine::new()
+        .eval_into::<serde_json::Value, _, _>(&logic, &data)
+        .unwrap();
+    let engine = Engine::new();
+    for _ in 0..3 {
+        let again = engine
+            .eval_into::<serde_json::Value, _, _>(&logic, &data)
+            .unwrap();
+        assert_eq!(again, first);
+    }
+
+    // Every draw lands in [min, max).
+    for n in first.as_array().unwrap() {
+        let n = n.as_i64().unwrap();
+        assert!((0..1000).contains(&n));
+    }
+}
+
+#[cfg(feature = "ext-math")]
+#[test]
+fn test_random_engine_seed_fallback() {
+    let seeded = Engine::builder()
+        .with_config(EvaluationConfig::default().with_random_seed(Some(42)))
+        .build();
+    let unseeded_call = seeded
+        .eval_into::<serde_json::Value, _, _>(&json!({"random": [0, 100]}), &json!(null))
+        .unwrap();
+    let explicit_seed = Engine::new()
+        .eval_into::<serde_json::Value, _, _>(&json!({"random": [0, 100, 42]}), &json!(null))
+        .unwrap();
+    assert_eq!(unseeded_call, explicit_seed);
+
+    // A null seed (e.g. a missing var) also falls back to the engine seed.
+    let missing_var = seeded
+        .eval_into::<serde_json::Value, _, _>(
+            &json!({"random": [0, 100, {"var": "user"}]}),
+            &json!({}),
+        )
+        .unwrap();
+    assert_eq!(missing_var, explicit_seed);
+}
+
+#[cfg(feature = "ext-math")]
+#[test]
+fn test_random_engine_seed_advances_per_call() {
+    // Calls that fall back to the engine seed draw from one stream per
+    // evaluation: values differ within a run, and the run is reproducible.
+    let logic = json!({"map": [[1, 2, 3, 4, 5, 6, 7, 8], {"random": [0, 1000]}]});
+    let seeded = |seed| {
+        Engine::builder()
+            .with_config(EvaluationConfig::default().with_random_seed(Some(seed)))
+            .build()
+    };
+
+    let engine = seeded(42);
+    let first = engine
+        .eval_into::<serde_json::Value, _, _>(&logic, &json!(null))
+        .unwrap();
+    let draws: std::collections::HashSet<i64> = first
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|n| n.as_i64().unwrap())
+        .collect();
+    assert!(draws.len() > 1, "{first}");
+    assert!(draws.iter().all(|n| (0..1000).contains(n)));
+
+    // The next evaluation, and another engine with the same seed, restart
+    // the stream.
+    for engine in [&engine, &seeded(42)] {
+        let again = engine
+            .eval_into::<serde_json::Value, _, _>(&logic, &json!(null))
+            .unwrap();
+        assert_eq!(again, first);
+    }
+    let other = seeded(7)
+        .eval_into::<serde_json::Value, _, _>(&logic, &json!(null))
+        .unwrap();
+    assert_ne!(other, first);
+
+    // An explicit seed argument stays a pure function of that seed.
+    let pinned = engine
+        .eval_into::<serde_json::Value, _, _>(
+            &json!({"map": [[1, 2, 3], {"random": [0, 1000, "user-1"]}]}),
+            &json!(null),
+        )
+        .unwrap();
+    let pinned = pinned.as_array().unwrap();
+    assert!(pinned.iter().all(|n| *n == pinned[0]));
+}
+
+#[cfg(feature = "ext-math")]
+#[test]
+fn test_random_deterministic_mode_requires_seed() {
+    let engine = Engine::builder()
+        .with_config(EvaluationConfig::default().with_deterministic_mode(true))
+        .build();
+
+    let err = engine
+        .eval_into::<serde_json::Value, _, _>(&json!({"random": [0, 100]}), &json!(null))
+        .unwrap_err();
+    assert!(matches!(
+        err.kind,
+        datalogic_rs::ErrorKind::ConfigurationError(_)
+    ));
+    assert!(
+        engine
+            .eval_into::<serde_json::Value, _, _>(
+                &json!({"random": [0, 100, {"var": "user"}]}),
+                &json!({}),
+            )
+            .is_err()
+    );
+
+    // An explicit seed satisfies deterministic mode.
+    assert!(
+        engine
+            .eval_into::<serde_json::Value, _, _>(&json!({"random": [0, 100, 1]}), &json!(null))
+            .is_ok()
+    );
+}
+
+#[cfg(feature = "ext-math")]
+#[test]
+fn test_random_unseeded_is_not_folded() {
+    // Without any seed the call draws fresh entropy, so it must stay a
+    // runtime operator rather than being baked in at compile time.
+    let engine = Engine::new();
+    let compiled = engine.compile(r#"{"random": [0, 1000000000]}"#).unwrap();
+    assert!(!compiled.is_constant());
+    let draws: std::collections::HashSet<String> = (0..8)
+        .map(|_| {
+            engine
+                .eval_str(r#"{"random": [0, 1000000000]}"#, "null")
+                .unwrap()
+        })
+        .collect();
+    assert!(draws.len() > 1);
+}
+
+#[cfg(feature = "ext-control")]
+#[test]
+fn test_conversion_operators_follow_config() {
+    let logic = json!([
+        {"to_number": null},
+        {"to_int": true},
+        {"to_bool": 0},
+        {"to_bool": ""}
+    ]);
+
+    let default = Engine::new()
+        .eval_into::<serde_json::Value, _, _>(&logic, &json!(null))
+        .unwrap();
+    assert_eq!(default, json!([0, 1, false, false]));
+
+    // Strict coercion turns the fabricated null/bool conversions into
+    // `null`; strict-boolean truthiness only treats null/false as falsy.
+    let config = EvaluationConfig::strict().with_truthy_evaluator(TruthyEvaluator::StrictBoolean);
+    let strict = Engine::builder().with_config(config).build();
+    let result = strict
+        .eval_into::<serde_json::Value, _, _>(&logic, &json!(null))
+        .unwrap();
+    assert_eq!(result, json!([null, null, true, true]));
+}
+
+#[cfg(feature = "ext-control")]
+#[test]
+fn test_default_operator_ignores_truthy_evaluator() {
+    // `default` always skips the JS-style empty/falsy set; `or` follows the
+    // configured truthiness, so the two diverge under StrictBoolean.
+    let config = EvaluationConfig::default().with_truthy_evaluator(TruthyEvaluator::StrictBoolean);
+    let engine = Engine::builder().with_config(config).build();
+    let result = engine
+        .eval_into::<serde_json::Value, _, _>(
+            &json!([{"default": [0, "", "x"]}, {"or": [0, "", "x"]}]),
+            &json!(null),
+        )
+        .unwrap();
+    assert_eq!(result, json!(["x", 0]));
+}
+
+/// `map` over `xs`, whose body maps over `xs` again: quadratic work from a
+/// tiny rule. With 1 000 items that is a million body evaluations.
+fn runaway_rule() -> (serde_json::Value, serde_json::Value) {
+    let rule = json!({"map": [
+        {"var": "xs"},
+        {"map": [{"val": [[1], "xs"]}, {"+": [{"var": ""}, 1]}]}
+    ]});
+    let data = json!({"xs": (0..1000).collect::<Vec<i64>>()});
+    (rule, data)
+}
+
+#[test]
+fn test_step_limit_stops_runaway_iteration() {
+    let (rule, data) = runaway_rule();
+    let engine = Engine::builder()
+        .with_config(EvaluationConfig::default().with_step_limit(Some(10_000)))
+        .build();
+
+    let err = engine
+        .eval_into::<serde_json::Value, _, _>(&rule, &data)
+        .unwrap_err();
+    assert!(matches!(
+        err.kind,
+        datalogic_rs::ErrorKind::StepLimitExceeded { limit: 10_000 }
+    ));
+    assert_eq!(err.tag(), "StepLimitExceeded");
+}
+
+#[test]
+fn test_step_limit_counts_fused_iteration() {
+    // A `{"+": [var, literal]}` body runs on a fused loop that never
+    // dispatches per item; the walked elements must still be charged.
+    let engine = Engine::builder()
+        .with_config(EvaluationConfig::default().with_step_limit(Some(100)))
+        .build();
+    let rule = json!({"map": [{"var": "xs"}, {"+": [{"var": ""}, 1]}]});
+
+    let small = json!({"xs": [1, 2, 3]});
+    assert_eq!(
+        engine
+            .eval_into::<serde_json::Value, _, _>(&rule, &small)
+            .unwrap(),
+        json!([2, 3, 4])
+    );
+
+    let large = json!({"xs": (0..1000).collect::<Vec<i64>>()});
+    let err = engine
+        .eval_into::<serde_json::Value, _, _>(&rule, &large)
+        .unwrap_err();
+    assert_eq!(err.tag(), "StepLimitExceeded");
+}
+
+#[cfg(feature = "error-handling")]
+#[test]
+fn test_step_limit_is_not_caught_by_try() {
+    let (rule, data) = runaway_rule();
+    let engine = Engine::builder()
+        .with_config(EvaluationConfig::default().with_step_limit(Some(10_000)))
+        .build();
+
+    let err = engine
+        .eval_into::<serde_json::Value, _, _>(&json!({"try": [rule, "fallback"]}), &data)
+        .unwrap_err();
+    assert_eq!(err.tag(), "StepLimitExceeded");
+}
+
+#[test]
+fn test_step_limit_is_per_evaluation() {
+    // The budget resets for every evaluation, so repeated cheap calls on
+    // one engine never accumulate toward the limit.
+    let engine = Engine::builder()
+        .with_config(EvaluationConfig::default().with_step_limit(Some(10)))
+        .build();
+    let logic = engine.compile(&json!({"+": [{"var": "a"}, 1]})).unwrap();
+    let mut session = engine.session();
+    for _ in 0..100 {
+        let result = session
+            .eval_into::<serde_json::Value, _>(&logic, &json!({"a": 1}))
+            .unwrap();
+        assert_eq!(result, json!(2));
+    }
+}
+
+#[test]
+fn test_no_step_limit_by_default() {
+    let (rule, data) = runaway_rule();
+    let result = Engine::new()
+        .eval_into::<serde_json::Value, _, _>(&rule, &data)
+        .unwrap();
+    assert_eq!(result.as_array().map(Vec::len), Some(1000));
+    assert_eq!(result[0].as_array().map(Vec::len), Some(1000));
+    assert_eq!(result[0][999], json!(1000));
+}
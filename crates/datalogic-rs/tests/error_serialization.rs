@@ -1,6 +1,6 @@
 #![cfg(feature = "serde_json")]
 
-use datalogic_rs::{Engine, Error};
+use datalogic_rs::{DataPathStep, Engine, Error};
 use serde_json::{Value, json};
 
 fn to_json(err: &Error) -> Value {
@@ -140,6 +140,37 @@ fn serialize_configuration_error() {
     );
 }
 
+#[test]
+fn serialize_step_limit_exceeded() {
+    let err = Error::step_limit_exceeded(500);
+    assert_eq!(
+        to_json(&err),
+        json!({
+            "type": "StepLimitExceeded",
+            "message": "Step limit exceeded: evaluation took more than 500 steps",
+            "limit": 500,
+        })
+    );
+}
+
+#[test]
+fn serialize_division_by_zero() {
+    let err = Error::division_by_zero();
+    assert_eq!(
+        to_json(&err),
+        json!({"type": "DivisionByZero", "message": "Division by zero"})
+    );
+}
+
+#[test]
+fn serialize_non_finite_number() {
+    let err = Error::non_finite_number();
+    assert_eq!(
+        to_json(&err),
+        json!({"type": "NonFiniteNumber", "message": "Result contains a non-finite number"})
+    );
+}
+
 #[test]
 fn structured_error_adds_operator_field() {
     let se = Error::arithmetic_error("divide by zero").with_operator("/");
@@ -190,6 +221,34 @@ fn evaluate_json_structured_reports_outer_operator() {
     assert_eq!(v["operator"], json!("throw"));
 }
 
+#[cfg(feature = "error-handling")]
+#[test]
+fn thrown_object_payload_is_preserved_intact() {
+    let engine = Engine::new();
+    let err = engine
+        .eval_str(
+            r#"{"throw": {"type": "validation", "field": {"var": "f"}}}"#,
+            r#"{"f": "email"}"#,
+        )
+        .expect_err("throw must produce an error");
+    // Operator-valued fields are literal data, not evaluated.
+    let thrown = err.thrown_value().expect("payload must be kept");
+    let thrown = serde_json::to_value(thrown).unwrap();
+    assert_eq!(thrown, json!({"type": "validation", "field": {"var": "f"}}));
+
+    let err = engine
+        .eval_str(
+            r#"{"throw": {"var": "failure"}}"#,
+            r#"{"failure": {"type": "validation", "field": "x", "codes": [1, 2]}}"#,
+        )
+        .expect_err("throw must produce an error");
+    let v = serde_json::to_value(&err).unwrap();
+    assert_eq!(
+        v["thrown"],
+        json!({"type": "validation", "field": "x", "codes": [1, 2]})
+    );
+}
+
 #[test]
 fn evaluate_json_structured_success_passes_through() {
     let engine = Engine::new();
@@ -441,3 +500,165 @@ fn wrap_preserves_node_ids_metadata() {
     let wrapped = Error::wrap(err);
     assert_eq!(wrapped.node_ids(), original_node_ids.as_slice());
 }
+
+// =====================================================================
+// Error data path — item indices of the enclosing iterators
+// =====================================================================
+
+/// Evaluate `rule` against `data`, expecting a runtime error.
+fn eval_error(engine: &Engine, rule: Value, data: Value) -> (datalogic_rs::Logic, Error) {
+    let compiled = engine.compile(&rule).unwrap();
+    let err = engine
+        .session()
+        .eval_into::<Value, _>(&compiled, &data)
+        .expect_err("rule must fail");
+    (compiled, err)
+}
+
+#[test]
+fn nan_in_nested_map_reports_operator_and_indices() {
+    let engine = Engine::new();
+    let (compiled, err) = eval_error(
+        &engine,
+        json!({"map": [{"var": "rows"}, {"map": [{"var": "cells"}, {"*": [{"var": ""}, 2]}]}]}),
+        json!({"rows": [{"cells": [1]}, {"cells": [2, "x", 3]}]}),
+    );
+
+    assert_eq!(err.thrown_value(), Some(&json!({"type": "NaN"}).into()));
+    assert_eq!(err.operator(), Some("map"));
+    // rows[1].cells[1] is the string that failed.
+    assert_eq!(
+        err.data_path(),
+        [DataPathStep::Index(1), DataPathStep::Index(1)]
+    );
+
+    let steps = err.resolve_path(&compiled);
+    let leaf = steps.last().expect("resolved path");
+    assert_eq!(leaf.operator.as_deref(), Some("*"));
+    assert_eq!(leaf.json_pointer, "/map/1/map/1");
+
+    let wire = to_json(&err);
+    assert_eq!(wire["operator"], json!("map"));
+    assert_eq!(wire["data_path"], json!([1, 1]));
+}
+
+/// `resolve_path` maps `let`'s flattened arguments back onto the
+/// bindings object and the body.
+#[cfg(feature = "ext-control")]
+#[test]
+fn resolve_path_points_into_let() {
+    let engine = Engine::new();
+    let (compiled, err) = eval_error(
+        &engine,
+        json!({"let": [{"x": 1, "y": {"*": [{"var": "s"}, 2]}}, {"+": [{"var": "x"}, 1]}]}),
+        json!({"s": "nope"}),
+    );
+    let leaf = err.resolve_path(&compiled).pop().expect("resolved path");
+    assert_eq!(leaf.operator.as_deref(), Some("*"));
+    assert_eq!(leaf.json_pointer, "/let/0/y");
+
+    let (compiled, err) = eval_error(
+        &engine,
+        json!({"let": [{"x": "nope"}, {"+": [{"var": "x"}, 1]}]}),
+        json!(null),
+    );
+    let leaf = err.resolve_path(&compiled).pop().expect("resolved path");
+    assert_eq!(leaf.operator.as_deref(), Some("+"));
+    assert_eq!(leaf.json_pointer, "/let/1");
+}
+
+#[test]
+fn resolve_path_points_into_cat_separator_form() {
+    let engine = Engine::new();
+    let (compiled, err) = eval_error(
+        &engine,
+        json!({"cat": {"sep": {"+": [{"var": "s"}, 1]}, "items": ["a", "b"]}}),
+        json!({"s": "nope"}),
+    );
+    let leaf = err.resolve_path(&compiled).pop().expect("resolved path");
+    assert_eq!(leaf.operator.as_deref(), Some("+"));
+    assert_eq!(leaf.json_pointer, "/cat/sep");
+
+    let (compiled, err) = eval_error(
+        &engine,
+        json!({"cat": {"sep": ", ", "items": ["a", {"*": [{"var": "n"}, 2]}]}}),
+        json!({"n": "nope"}),
+    );
+    let leaf = err.resolve_path(&compiled).pop().expect("resolved path");
+    assert_eq!(leaf.operator.as_deref(), Some("*"));
+    assert_eq!(leaf.json_pointer, "/cat/items/1");
+}
+
+#[test]
+fn data_path_covers_reduce_filter_and_object_iteration() {
+    let engine = Engine::new();
+    let cases = [
+        (
+            json!({"reduce": [{"var": "xs"}, {"+": [{"var": "accumulator"}, {"var": "current"}]}, 0]}),
+            json!({"xs": [1, 2, "a"]}),
+            json!([2]),
+        ),
+        (
+            json!({"filter": [{"var": "xs"}, {">": [{"-": [{"var": ""}, 1]}, 0]}]}),
+            json!({"xs": [1, "b"]}),
+            json!([1]),
+        ),
+        // Object iteration records the entry's key, not its position.
+        (
+            json!({"map": [{"var": "obj"}, {"*": [{"var": ""}, 2]}]}),
+            json!({"obj": {"a": 1, "b": "two"}}),
+            json!(["b"]),
+        ),
+        (
+            json!({"reduce": [{"var": "obj"}, {"+": [{"var": "accumulator"}, {"var": "current"}]}, 0]}),
+            json!({"obj": {"x": 1, "y": "z"}}),
+            json!(["y"]),
+        ),
+        (
+            json!({"map": [{"var": "rows"}, {"map": [{"var": "prices"}, {"*": [{"var": ""}, 2]}]}]}),
+            json!({"rows": [{"prices": {"eur": 1}}, {"prices": {"usd": 2, "gbp": "?"}}]}),
+            json!([1, "gbp"]),
+        ),
+    ];
+    for (rule, data, expected) in cases {
+        let (_compiled, err) = eval_error(&engine, rule.clone(), data);
+        assert_eq!(to_json(&err)["data_path"], expected, "{rule}");
+    }
+
+    let (_compiled, err) = eval_error(
+        &engine,
+        json!({"map": [{"var": "obj"}, {"*": [{"var": ""}, 2]}]}),
+        json!({"obj": {"a": 1, "b": "two"}}),
+    );
+    assert_eq!(err.data_path(), [DataPathStep::Key("b".to_string())]);
+}
+
+#[test]
+fn data_path_is_empty_outside_iterators() {
+    let engine = Engine::new();
+    let (_compiled, err) = nan_error(&engine);
+    assert!(err.data_path().is_empty());
+    assert!(to_json(&err).get("data_path").is_none());
+
+    // A breadcrumb set through the builder carries no data path either.
+    let err = Error::invalid_arguments("x").with_node_ids(vec![1, 2]);
+    assert_eq!(err.node_ids(), &[1, 2]);
+    assert!(err.data_path().is_empty());
+}
+
+#[cfg(feature = "error-handling")]
+#[test]
+fn data_path_of_a_caught_error_does_not_leak() {
+    // The first map's error at index 2 is caught; the one that escapes
+    // comes from the second map at index 0, outside the first.
+    let engine = Engine::new();
+    let (_compiled, err) = eval_error(
+        &engine,
+        json!({"cat": [
+            {"try": [{"map": [{"var": "xs"}, {"*": [{"var": ""}, 2]}]}, "caught"]},
+            {"map": [{"var": "ys"}, {"*": [{"var": ""}, 2]}]}
+        ]}),
+        json!({"xs": [1, 2, "x"], "ys": ["y"]}),
+    );
+    assert_eq!(err.data_path(), [DataPathStep::Index(0)]);
+}
@@ -0,0 +1,159 @@
+//! Tests for `Engine::eval_msgpack`: MessagePack data must evaluate
+//! exactly like the same value passed as JSON.
+
+#![cfg(feature = "msgpack")]
+
+use bumpalo::Bump;
+use datalogic_rs::Engine;
+use datalogic_rs::datavalue::OwnedDataValue;
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct Line {
+    sku: String,
+    qty: u32,
+    price: f64,
+}
+
+#[derive(Serialize)]
+struct Order {
+    id: u64,
+    customer: Customer,
+    lines: Vec<Line>,
+    note: Option<String>,
+}
+
+#[derive(Serialize)]
+struct Customer {
+    name: String,
+    tags: Vec<String>,
+    credit: i64,
+}
+
+fn order() -> Order {
+    Order {
+        id: 9001,
+        customer: Customer {
+            name: "Ada".into(),
+            tags: vec!["vip".into(), "beta".into()],
+            credit: -25,
+        },
+        lines: vec![
+            Line {
+                sku: "A-1".into(),
+                qty: 2,
+                price: 4.5,
+            },
+            Line {
+                sku: "B-7".into(),
+                qty: 1,
+                price: 120.0,
+            },
+        ],
+        note: None,
+    }
+}
+
+#[test]
+fn msgpack_nested_structure_matches_json() {
+    let order = order();
+    let packed = rmp_serde::to_vec_named(&order).unwrap();
+    let json = OwnedDataValue::from_json(&serde_json::to_string(&order).unwrap()).unwrap();
+
+    let engine = Engine::new();
+    let rules = [
+        // Whole document: the decoded tree is identical.
+        r#"{"var": ""}"#,
+        r#"{"var": "customer.tags.1"}"#,
+        r#"{"reduce": [{"var": "lines"}, {"+": [{"var": "accumulator"}, {"*": [{"var": "current.qty"}, {"var": "current.price"}]}]}, 0]}"#,
+        r#"{"map": [{"filter": [{"var": "lines"}, {">": [{"var": "price"}, 100]}]}, {"var": "sku"}]}"#,
+        r#"{"if": [{"<": [{"var": "customer.credit"}, 0]}, {"cat": [{"var": "customer.name"}, " owes"]}, "ok"]}"#,
+        r#"{"var": ["note", "none"]}"#,
+    ];
+    for rule in rules {
+        let compiled = engine.compile(rule).unwrap();
+        let arena = Bump::new();
+        let expected = engine
+            .evaluate(&compiled, &json, &arena)
+            .unwrap()
+            .to_owned();
+        assert_eq!(
+            engine.eval_msgpack(&compiled, &packed).unwrap(),
+            expected,
+            "{rule}"
+        );
+    }
+}
+
+/// Every scalar marker family decodes to the value its JSON form would.
+#[test]
+fn msgpack_scalars_cover_every_marker_width() {
+    let doc = r#"[0, 127, 128, 65535, 4294967295, 18446744073709551615,
+        -1, -32, -33, -129, -32769, -2147483649,
+        1.5, -0.25, true, false, null, "", "fixstr",
+        "a string that is longer than thirty-one bytes", [], {}]"#;
+    let value = OwnedDataValue::from_json(doc).unwrap();
+    let packed = rmp_serde::to_vec(&value).unwrap();
+
+    let engine = Engine::new();
+    let compiled = engine.compile(r#"{"var": ""}"#).unwrap();
+    assert_eq!(engine.eval_msgpack(&compiled, &packed).unwrap(), value);
+
+    // `float32` widens to f64; `bin` becomes an array of byte values.
+    let f32_and_bin = [0x92, 0xca, 0x3f, 0xc0, 0x00, 0x00, 0xc4, 0x02, 0x07, 0xff];
+    assert_eq!(
+        engine.eval_msgpack(&compiled, &f32_and_bin).unwrap(),
+        OwnedDataValue::from_json("[1.5, [7, 255]]").unwrap()
+    );
+}
+
+#[test]
+fn msgpack_rejects_malformed_input() {
+    let engine = Engine::new();
+    let compiled = engine.compile(r#"{"var": ""}"#).unwrap();
+    let decode = |bytes: &[u8]| engine.eval_msgpack(&compiled, bytes).unwrap_err();
+
+    for (bytes, expected) in [
+        (&[][..], "unexpected end of input"),
+        (&[0xc0, 0xc0][..], "trailing bytes"),
+        (&[0x81, 0x01, 0x02][..], "map key must be a string"),
+        (&[0xd4, 0x01, 0x00][..], "ext types"),
+        (&[0xc1][..], "reserved marker"),
+        (&[0xa2, 0xff, 0xfe][..], "not valid UTF-8"),
+        // An array header promising far more elements than remain.
+        (
+            &[0xdd, 0xff, 0xff, 0xff, 0xff, 0xc0][..],
+            "unexpected end of input",
+        ),
+    ] {
+        let err = decode(bytes);
+        assert_eq!(err.tag(), "ParseError");
+        assert!(err.to_string().contains(expected), "{bytes:?}: {err}");
+    }
+
+    // Nesting past the JSON parser's cap fails instead of recursing on.
+    let mut deep = vec![0x91; 300];
+    deep.push(0xc0);
+    assert!(decode(&deep).to_string().contains("depth limit"));
+    let mut shallow = vec![0x91; 200];
+    shallow.push(0xc0);
+    assert!(engine.eval_msgpack(&compiled, &shallow).is_ok());
+}
+
+#[test]
+fn msgpack_decode_errors_are_parse_errors() {
+    let engine = Engine::new();
+    let compiled = engine.compile(r#"{"var": "a"}"#).unwrap();
+
+    // A map header promising one entry, then nothing.
+    let err = engine.eval_msgpack(&compiled, &[0x81]).unwrap_err();
+    assert_eq!(err.tag(), "ParseError");
+    assert!(err.to_string().contains("MessagePack data"), "{err}");
+
+    // Evaluation errors keep their own kind.
+    let compiled = engine.compile(r#"{"+": [{"var": "a"}, 1]}"#).unwrap();
+    let packed =
+        rmp_serde::to_vec_named(&OwnedDataValue::from_json(r#"{"a": "x"}"#).unwrap()).unwrap();
+    let err = engine.eval_msgpack(&compiled, &packed).unwrap_err();
+    assert_ne!(err.tag(), "ParseError");
+}
@@ -412,3 +412,127 @@ fn test_reduce_multiply_overflow_promotes_to_float() {
     let n = result.as_f64().expect("numeric result");
     assert!(n > 9.9e26, "expected ~1e27, got {n}");
 }
+
+#[test]
+fn test_float_overflow_to_non_finite_does_not_panic() {
+    let engine = Engine::new();
+
+    // Regression: converting an infinite or NaN float to JSON must not
+    // unwrap `serde_json::Number::from_f64`. Each rule overflows a
+    // different arithmetic path; inf - inf lands on NaN.
+    for logic in [
+        json!({"*": [1e308, 1e308]}),
+        json!({"*": [1e308, 10, 10]}),
+        json!({"+": [1.7e308, 1.7e308]}),
+        json!({"-": [-1.7e308, 1.7e308]}),
+        json!({"/": [1e308, 1e-308]}),
+        json!({"-": [{"*": [1e308, 10]}, {"*": [1e308, 10]}]}),
+        json!({"reduce": [[1e308, 1e308], {"*": [{"var": "current"}, {"var": "accumulator"}]}, 1]}),
+        json!({"map": [[1e308], {"*": [{"var": ""}, 1e308]}]}),
+    ] {
+        // JSON has no spelling for ±Infinity / NaN: every JSON boundary
+        // writes null.
+        let value = engine
+            .eval_into::<serde_json::Value, _, _>(&logic, &json!({}))
+            .unwrap();
+        assert!(
+            value.is_null() || value == json!([null]),
+            "{logic}: {value}"
+        );
+        let text = engine.eval_str(&logic, &json!({})).unwrap();
+        assert!(text == "null" || text == "[null]", "{logic}: {text}");
+
+        // Serialising the owned result goes through the same path.
+        let owned = engine.eval(&logic, &json!({})).unwrap();
+        assert_eq!(serde_json::to_value(&owned).unwrap(), value, "{logic}");
+    }
+
+    let f = engine
+        .eval_as_f64(&json!({"*": [1e308, 1e308]}), &json!({}))
+        .unwrap();
+    assert_eq!(f, f64::INFINITY);
+    let f = engine
+        .eval_as_f64(
+            &json!({"-": [{"*": [1e308, 10]}, {"*": [1e308, 10]}]}),
+            &json!({}),
+        )
+        .unwrap();
+    assert!(f.is_nan());
+}
+
+#[test]
+fn test_integer_overflow_agrees_across_evaluation_paths() {
+    // The same overflowing operation reaches the arithmetic from several
+    // places: the operator itself (literal, folded at compile time, or
+    // over `var` reads), the `reduce` fold fast path, and the per-element
+    // `map` fast path. All must promote to the same float instead of
+    // wrapping or diverging.
+    let folded = Engine::new();
+    let unfolded = Engine::builder().with_constant_folding(false).build();
+    let data = json!({"max": i64::MAX, "min": i64::MIN, "big": 1i64 << 62});
+    let acc = |op: &str| json!({op: [{"var": "accumulator"}, {"var": "current"}]});
+
+    let cases = [
+        (
+            (i64::MAX as f64) + 1.0,
+            vec![
+                json!({"+": [i64::MAX, 1]}),
+                json!({"+": [{"var": "max"}, 1]}),
+                json!({"reduce": [[i64::MAX, 1], acc("+"), 0]}),
+                json!({"reduce": [[1], acc("+"), {"var": "max"}]}),
+            ],
+        ),
+        (
+            (i64::MIN as f64) - 1.0,
+            vec![
+                json!({"-": [i64::MIN, 1]}),
+                json!({"-": [{"var": "min"}, 1]}),
+                json!({"reduce": [[1], acc("-"), i64::MIN]}),
+                json!({"reduce": [[1], acc("-"), {"var": "min"}]}),
+            ],
+        ),
+        (
+            (1i64 << 62) as f64 * 4.0,
+            vec![
+                json!({"*": [1i64 << 62, 4]}),
+                json!({"*": [{"var": "big"}, 4]}),
+                json!({"reduce": [[1i64 << 62, 4], acc("*"), 1]}),
+                json!({"reduce": [[4], acc("*"), {"var": "big"}]}),
+            ],
+        ),
+        (
+            -(i64::MIN as f64),
+            vec![
+                json!({"-": [i64::MIN]}),
+                json!({"-": [{"var": "min"}]}),
+                json!({"-": [0, {"var": "min"}]}),
+                json!({"reduce": [[{"var": "min"}], acc("-"), 0]}),
+            ],
+        ),
+    ];
+
+    for (expected, rules) in cases {
+        for logic in rules {
+            for engine in [&folded, &unfolded] {
+                let result = engine
+                    .eval_into::<serde_json::Value, _, _>(&logic, &data)
+                    .unwrap();
+                assert_eq!(result.as_f64(), Some(expected), "{logic}");
+            }
+        }
+    }
+
+    // The map fast path, element by element.
+    for engine in [&folded, &unfolded] {
+        let result = engine
+            .eval_into::<serde_json::Value, _, _>(
+                &json!({"map": [[{"var": "max"}, {"var": "big"}], {"*": [{"var": ""}, 4]}]}),
+                &data,
+            )
+            .unwrap();
+        assert_eq!(
+            result,
+            json!([(i64::MAX as f64) * 4.0, (1i64 << 62) as f64 * 4.0])
+        );
+    }
+}
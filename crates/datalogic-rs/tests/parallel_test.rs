@@ -0,0 +1,216 @@
+//! Tests for the `parallel` feature: a `map` past the engine's parallel
+//! threshold must produce exactly what the sequential loop does — same
+//! values, same order, same error.
+
+#![cfg(feature = "parallel")]
+
+use bumpalo::Bump;
+use datalogic_rs::datavalue::OwnedDataValue;
+use datalogic_rs::operator::EvalContext;
+use datalogic_rs::{CustomOperator, DataPathStep, DataValue, Engine, EvaluationConfig, Result};
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+use std::thread::ThreadId;
+use std::time::Duration;
+
+/// Fails on any integer argument divisible by 1 000, naming it; passes
+/// everything else through.
+struct FailOnThousands;
+impl CustomOperator for FailOnThousands {
+    fn evaluate<'a>(
+        &self,
+        args: &[&'a DataValue<'a>],
+        _ctx: &mut EvalContext<'_, 'a>,
+        _arena: &'a Bump,
+    ) -> Result<&'a DataValue<'a>> {
+        if let Some(n) = args[0].as_i64().filter(|n| n % 1_000 == 0) {
+            return Err(datalogic_rs::Error::custom_message(format!(
+                "failed on {n}"
+            )));
+        }
+        Ok(args[0])
+    }
+}
+
+fn engines(config: EvaluationConfig) -> (Engine, Engine) {
+    let sequential = Engine::builder()
+        .with_config(config.clone())
+        .add_operator("fail_on_thousands", FailOnThousands)
+        .build();
+    let parallel = Engine::builder()
+        .with_config(config)
+        .add_operator("fail_on_thousands", FailOnThousands)
+        .with_parallel_threshold(64)
+        .build();
+    (sequential, parallel)
+}
+
+/// Run `f` on a four-thread pool so the array is split into several
+/// chunks even on a single-core machine.
+fn on_four_threads<T: Send>(f: impl FnOnce() -> T + Send) -> T {
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(4)
+        .build()
+        .unwrap()
+        .install(f)
+}
+
+fn records(n: usize) -> OwnedDataValue {
+    let items: Vec<String> = (0..n)
+        .map(|i| {
+            format!(
+                r#"{{"id": {i}, "name": "item-{i}", "tags": ["t{}", "u{}"]}}"#,
+                i % 7,
+                i % 3
+            )
+        })
+        .collect();
+    OwnedDataValue::from_json(&format!(
+        r#"{{"prefix": "p", "items": [{}], "ids": {:?}}}"#,
+        items.join(","),
+        (1..=n).collect::<Vec<_>>()
+    ))
+    .unwrap()
+}
+
+#[test]
+fn parallel_map_matches_sequential_output_and_order() {
+    let (sequential, parallel) = engines(EvaluationConfig::default());
+    let data = records(5_000);
+    let rules = [
+        // Fresh strings per item, built in the worker arenas.
+        r#"{"map": [{"var": "items"}, {"cat": [{"var": "name"}, "/", {"var": "id"}]}]}"#,
+        // Reads the enclosing scope from inside the body.
+        r#"{"map": [{"var": "items"}, {"cat": [{"val": [[-1], "prefix"]}, {"var": "id"}]}]}"#,
+        // Nested iterators: the inner map stays below the threshold.
+        r#"{"map": [{"var": "items"}, {"map": [{"var": "tags"}, {"cat": [{"var": ""}, "!"]}]}]}"#,
+        // Objects and arrays built per item.
+        r#"{"map": [{"var": "items"}, {"if": [{">": [{"var": "id"}, 2500]}, {"var": "tags"}, {"var": "name"}]}]}"#,
+        r#"{"map": [{"var": "ids"}, {"fail_on_thousands": [{"+": [{"var": ""}, 0.5]}]}]}"#,
+        // A parallel map feeding a sequential reduce.
+        r#"{"reduce": [{"map": [{"var": "ids"}, {"%": [{"var": ""}, 7]}]}, {"+": [{"var": "current"}, {"var": "accumulator"}]}, 0]}"#,
+    ];
+    for rule in rules {
+        let expected = sequential.eval(rule, &data).unwrap();
+        let on_pool = on_four_threads(|| parallel.eval(rule, &data).unwrap());
+        assert_eq!(on_pool, expected, "{rule}");
+        // Same result on the global pool, however many threads it has.
+        assert_eq!(parallel.eval(rule, &data).unwrap(), expected, "{rule}");
+    }
+
+    // Below the threshold nothing changes either.
+    let small = records(10);
+    let rule = r#"{"map": [{"var": "items"}, {"cat": [{"var": "name"}, "!"]}]}"#;
+    assert_eq!(
+        parallel.eval(rule, &small).unwrap(),
+        sequential.eval(rule, &small).unwrap()
+    );
+}
+
+#[cfg(feature = "ext-math")]
+#[test]
+fn parallel_map_keeps_the_seeded_random_stream_in_order() {
+    // Seeded `random` calls share one stream per evaluation, so a body
+    // that draws from it runs sequentially and matches the sequential map.
+    let (sequential, parallel) = engines(EvaluationConfig::default().with_random_seed(Some(42)));
+    let data = records(5_000);
+    let rule = r#"{"map": [{"var": "ids"}, {"random": [0, 1000000]}]}"#;
+    let expected = sequential.eval(rule, &data).unwrap();
+    assert_eq!(
+        on_four_threads(|| parallel.eval(rule, &data).unwrap()),
+        expected
+    );
+}
+
+#[test]
+fn parallel_map_reports_the_lowest_index_error() {
+    let (sequential, parallel) = engines(EvaluationConfig::default());
+    let data = records(5_000);
+    // Items 1 000, 2 000, … fail; whichever worker finishes first, the
+    // reported error is the one for 1 000.
+    let rule = r#"{"map": [{"var": "ids"}, {"fail_on_thousands": [{"var": ""}]}]}"#;
+
+    let expected = sequential.eval(rule, &data).unwrap_err();
+    assert!(
+        expected.to_string().contains("failed on 1000"),
+        "{expected}"
+    );
+    for _ in 0..20 {
+        let err = on_four_threads(|| parallel.eval(rule, &data).unwrap_err());
+        assert_eq!(err.to_string(), expected.to_string());
+    }
+
+    // The breadcrumb and data paths reach the failing node and item like
+    // the sequential ones.
+    let paths = |engine: &Engine| {
+        let compiled = engine.compile(rule).unwrap();
+        let arena = Bump::new();
+        let err = engine.evaluate(&compiled, &data, &arena).unwrap_err();
+        (err.node_ids().to_vec(), err.data_path())
+    };
+    let expected = paths(&sequential);
+    assert!(!expected.0.is_empty());
+    assert_eq!(
+        expected.1,
+        vec![DataPathStep::Index(999)],
+        "ids are 1-based: 1 000 sits at index 999"
+    );
+    assert_eq!(on_four_threads(|| paths(&parallel)), expected);
+}
+
+#[test]
+fn parallel_map_charges_the_step_budget() {
+    let rule = r#"{"map": [{"var": "ids"}, {"cat": ["n", {"var": ""}]}]}"#;
+    let data = records(2_000);
+
+    // Enough for the whole map: both succeed.
+    let (sequential, parallel) =
+        engines(EvaluationConfig::default().with_step_limit(Some(100_000)));
+    assert_eq!(
+        on_four_threads(|| parallel.eval(rule, &data).unwrap()),
+        sequential.eval(rule, &data).unwrap()
+    );
+
+    // Each quarter fits the budget, the whole map doesn't: the combined
+    // charge still trips it.
+    let (sequential, parallel) = engines(EvaluationConfig::default().with_step_limit(Some(3_000)));
+    let expected = sequential.eval(rule, &data).unwrap_err();
+    let err = on_four_threads(|| parallel.eval(rule, &data).unwrap_err());
+    assert_eq!(err.tag(), expected.tag());
+}
+
+type ThreadSet = Arc<Mutex<HashSet<ThreadId>>>;
+
+/// Records which threads evaluated it. Each call sleeps briefly so idle
+/// workers get to steal chunks even on a single core.
+struct ThreadProbe(ThreadSet);
+impl CustomOperator for ThreadProbe {
+    fn evaluate<'a>(
+        &self,
+        args: &[&'a DataValue<'a>],
+        _ctx: &mut EvalContext<'_, 'a>,
+        _arena: &'a Bump,
+    ) -> Result<&'a DataValue<'a>> {
+        self.0.lock().unwrap().insert(std::thread::current().id());
+        std::thread::sleep(Duration::from_micros(100));
+        Ok(args[0])
+    }
+}
+
+#[test]
+fn parallel_map_fans_out_only_past_the_threshold() {
+    let seen = ThreadSet::default();
+    let engine = Engine::builder()
+        .add_operator("probe", ThreadProbe(seen.clone()))
+        .with_parallel_threshold(100)
+        .build();
+    let rule = r#"{"map": [{"var": "ids"}, {"probe": [{"var": ""}]}]}"#;
+    let threads = |n: usize| {
+        seen.lock().unwrap().clear();
+        on_four_threads(|| engine.eval(rule, records(n)).unwrap());
+        seen.lock().unwrap().len()
+    };
+
+    assert_eq!(threads(100), 1);
+    assert!(threads(2_000) > 1);
+}
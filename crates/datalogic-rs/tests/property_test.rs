@@ -309,4 +309,20 @@ proptest! {
             ),
         }
     }
+
+    /// Property E: `base64_decode` inverts `base64_encode` for any string,
+    /// and decoding arbitrary text either succeeds or errors — never
+    /// panics on malformed padding, stray bytes, or non-UTF-8 payloads.
+    #[cfg(feature = "ext-string")]
+    #[test]
+    fn base64_round_trips(s in any::<String>(), junk in "[A-Za-z0-9+/=*\\s]{0,16}") {
+        let engine = Engine::new();
+        let data = json!({ "s": s.clone(), "junk": junk });
+        let rule = json!({ "base64_decode": { "base64_encode": { "var": "s" } } });
+        let decoded = engine.eval_into::<Value, _, _>(&rule, &data).expect("round trip");
+        prop_assert_eq!(decoded, json!(s));
+
+        let rule = json!({ "base64_decode": { "var": "junk" } });
+        let _ = engine.eval_into::<Value, _, _>(&rule, &data);
+    }
 }
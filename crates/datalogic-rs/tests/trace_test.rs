@@ -375,3 +375,244 @@ fn test_trace_custom_operator_error_propagation() {
         run.expression_tree
     );
 }
+
+/// `with_timings` stamps every step with a duration that covers its
+/// children: a slow custom operator dominates, and its parent takes at
+/// least as long as it does.
+#[test]
+fn test_trace_timings_attribute_slow_operator() {
+    use bumpalo::Bump;
+    use datalogic_rs::datavalue::NumberValue;
+    use datalogic_rs::operator::EvalContext;
+    use datalogic_rs::{CustomOperator, DataValue, Result as DLResult};
+    use std::time::Duration;
+
+    const SLEEP: Duration = Duration::from_millis(20);
+
+    struct SlowOp;
+    impl CustomOperator for SlowOp {
+        fn evaluate<'a>(
+            &self,
+            _args: &[&'a DataValue<'a>],
+            _ctx: &mut EvalContext<'_, 'a>,
+            arena: &'a Bump,
+        ) -> DLResult<&'a DataValue<'a>> {
+            std::thread::sleep(SLEEP);
+            Ok(arena.alloc(DataValue::Number(NumberValue::Integer(100))))
+        }
+    }
+
+    let engine = Engine::builder().add_operator("slow", SlowOp).build();
+    let rule = r#"{"+": [{"slow": []}, {"var": "a"}]}"#;
+
+    // Off by default: no step carries a duration.
+    let run = engine.trace().eval_str(rule, r#"{"a": 1}"#);
+    assert!(run.steps.iter().all(|s| s.elapsed_nanos.is_none()));
+
+    let run = engine
+        .trace()
+        .with_timings(true)
+        .eval_str(rule, r#"{"a": 1}"#);
+    assert_eq!(run.result.unwrap(), "101");
+    let elapsed = |result: serde_json::Value| {
+        let step = run
+            .steps
+            .iter()
+            .find(|s| s.result.as_ref() == Some(&result))
+            .expect("step recorded");
+        step.elapsed_nanos.expect("timed step")
+    };
+    let slow = elapsed(json!(100));
+    let var = elapsed(json!(1));
+    let plus = elapsed(json!(101));
+
+    assert!(slow >= SLEEP.as_nanos() as u64, "slow op took {slow}ns");
+    assert!(
+        var < slow,
+        "var ({var}ns) should be cheaper than slow ({slow}ns)"
+    );
+    assert!(plus >= slow, "parent ({plus}ns) includes child ({slow}ns)");
+    assert!(plus >= slow + var);
+
+    // Serialized steps expose the field.
+    let step = serde_json::to_value(&run.steps[0]).unwrap();
+    assert!(step.get("elapsed_nanos").is_some());
+}
+
+/// Every expression-tree node carries a JSON pointer that resolves, in the
+/// submitted logic, to the operator it was compiled from.
+#[test]
+fn test_expression_tree_pointers_resolve_into_logic() {
+    use datalogic_rs::ExpressionNode;
+
+    fn collect<'t>(node: &'t ExpressionNode, out: &mut Vec<&'t ExpressionNode>) {
+        out.push(node);
+        for child in &node.children {
+            collect(child, out);
+        }
+    }
+
+    let rule = json!({
+        "and": [
+            {"==": [{"var": "a"}, 1]},
+            {"if": [
+                {">": [{"/": [{"var": "b"}, 2]}, 3]},
+                {"map": [{"var": "xs"}, {"+": [{"var": ""}, 1]}]},
+                {"var": ["c", {"cat": ["d", {"var": "e"}]}]}
+            ]}
+        ]
+    });
+    let engine = Engine::new();
+    let run = engine
+        .trace()
+        .eval_str(&rule, r#"{"a": 1, "b": 8, "xs": [1]}"#);
+    assert!(run.result.is_ok());
+
+    let mut nodes = Vec::new();
+    collect(&run.expression_tree, &mut nodes);
+    let pointers: Vec<&str> = nodes.iter().map(|n| n.pointer.as_str()).collect();
+    assert_eq!(
+        pointers,
+        [
+            "",
+            "/and/0",
+            "/and/0/==/0",
+            "/and/1",
+            "/and/1/if/0",
+            "/and/1/if/0/>/0",
+            "/and/1/if/0/>/0/~1/0",
+            "/and/1/if/1",
+            "/and/1/if/1/map/0",
+            "/and/1/if/1/map/1",
+            "/and/1/if/1/map/1/+/0",
+            "/and/1/if/2",
+            "/and/1/if/2/var/1",
+            "/and/1/if/2/var/1/cat/1",
+        ]
+    );
+
+    // Each pointer lands on an object whose single key is the operator
+    // the tree node renders.
+    for node in nodes {
+        let target = rule
+            .pointer(&node.pointer)
+            .unwrap_or_else(|| panic!("pointer {:?} does not resolve", node.pointer));
+        let op = target.as_object().unwrap().keys().next().unwrap();
+        let rendered: serde_json::Value = serde_json::from_str(&node.expression).unwrap();
+        assert!(
+            rendered.get(op).is_some(),
+            "{:?} resolved to `{op}` but node renders {}",
+            node.pointer,
+            node.expression
+        );
+    }
+}
+
+/// `let` compiles its bindings object into a flat argument list, but its
+/// pointers still address the bindings by name and the body as operand 1.
+#[cfg(feature = "ext-control")]
+#[test]
+fn test_expression_tree_pointers_resolve_into_let() {
+    use datalogic_rs::ExpressionNode;
+
+    fn collect<'t>(node: &'t ExpressionNode, out: &mut Vec<&'t ExpressionNode>) {
+        out.push(node);
+        for child in &node.children {
+            collect(child, out);
+        }
+    }
+
+    let rule = json!({"let": [
+        {"total": {"+": [{"var": "a"}, 1]}, "label": "n", "a/b": {"*": [2, {"var": "a"}]}},
+        {"cat": [{"var": "label"}, {"var": "total"}, {"var": "a/b"}]}
+    ]});
+    let engine = Engine::new();
+    let run = engine.trace().eval_str(&rule, r#"{"a": 2}"#);
+    assert_eq!(run.result.unwrap(), r#""n34""#);
+
+    let mut nodes = Vec::new();
+    collect(&run.expression_tree, &mut nodes);
+    let pointers: Vec<&str> = nodes.iter().map(|n| n.pointer.as_str()).collect();
+    assert_eq!(
+        pointers,
+        [
+            "",
+            "/let/0/a~1b",
+            "/let/0/a~1b/*/1",
+            "/let/0/total",
+            "/let/0/total/+/0",
+            "/let/1",
+            "/let/1/cat/0",
+            "/let/1/cat/1",
+            "/let/1/cat/2",
+        ]
+    );
+    for node in nodes {
+        let target = rule
+            .pointer(&node.pointer)
+            .unwrap_or_else(|| panic!("pointer {:?} does not resolve", node.pointer));
+        let op = target.as_object().unwrap().keys().next().unwrap();
+        let rendered: serde_json::Value = serde_json::from_str(&node.expression).unwrap();
+        assert!(rendered.get(op).is_some(), "{:?}", node.pointer);
+    }
+}
+
+/// `cat`'s separator form compiles to `[sep, item, ...]`; its pointers
+/// still follow the `{"sep", "items"}` object as written.
+#[test]
+fn test_expression_tree_pointers_resolve_into_cat_separator_form() {
+    let rule = json!({"cat": {
+        "sep": {"var": "s"},
+        "items": ["a", {"var": "b"}, {"substr": [{"var": "c"}, 1]}]
+    }});
+    let engine = Engine::new();
+    let run = engine
+        .trace()
+        .eval_str(&rule, r#"{"s": "-", "b": "x", "c": "yz"}"#);
+    assert_eq!(run.result.unwrap(), r#""a-x-z""#);
+
+    let tree = &run.expression_tree;
+    assert_eq!(tree.pointer, "");
+    let pointers: Vec<&str> = tree.children.iter().map(|n| n.pointer.as_str()).collect();
+    assert_eq!(pointers, ["/cat/sep", "/cat/items/1", "/cat/items/2"]);
+    for node in &tree.children {
+        let target = rule
+            .pointer(&node.pointer)
+            .unwrap_or_else(|| panic!("pointer {:?} does not resolve", node.pointer));
+        let op = target.as_object().unwrap().keys().next().unwrap();
+        let rendered: serde_json::Value = serde_json::from_str(&node.expression).unwrap();
+        assert!(rendered.get(op).is_some(), "{:?}", node.pointer);
+    }
+    assert_eq!(
+        tree.children[2].children[0].pointer,
+        "/cat/items/2/substr/0"
+    );
+}
+
+/// Pointers follow the rule as submitted: operator aliases keep their
+/// spelling and a bare single operand is addressed without an index. A
+/// pre-compiled `Logic` no longer has the source, so its pointers use the
+/// canonical form.
+#[test]
+fn test_expression_tree_pointers_follow_submitted_spelling() {
+    let rule = json!({"?:": [{"!": {"var": "a"}}, {"var": [{"cat": ["b"]}]}, 0]});
+    let engine = Engine::new();
+
+    let run = engine.trace().eval_str(&rule, r#"{"b": 7}"#);
+    assert_eq!(run.result.unwrap(), "7");
+    let not = &run.expression_tree.children[0];
+    assert_eq!(not.pointer, "/?:/0");
+    assert_eq!(not.children[0].pointer, "/?:/0/!");
+    assert!(rule.pointer(&not.children[0].pointer).unwrap()["var"] == "a");
+    let var = &run.expression_tree.children[1];
+    assert_eq!(var.pointer, "/?:/1");
+    assert_eq!(var.children[0].pointer, "/?:/1/var/0");
+    assert!(rule.pointer(&var.children[0].pointer).unwrap()["cat"].is_array());
+
+    let compiled = engine.compile(&rule).unwrap();
+    let run = engine.trace().eval(&compiled, r#"{"b": 7}"#);
+    assert_eq!(
+        run.expression_tree.children[0].children[0].pointer,
+        "/if/0/!/0"
+    );
+}
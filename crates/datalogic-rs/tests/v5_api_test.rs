@@ -22,6 +22,16 @@ fn evaluate_str_one_shot_with_variable() {
     assert_eq!(result, "\"Alice\"");
 }
 
+#[test]
+fn between_is_a_baseline_comparison() {
+    // Registered with the other comparisons, so no feature flag needed.
+    let engine = Engine::new();
+    let closed = r#"{"between": [{"var": "x"}, 1, 10]}"#;
+    let half_open = r#"{"between": [{"var": "x"}, 1, 10, false]}"#;
+    assert_eq!(engine.eval_str(closed, r#"{"x": 10}"#).unwrap(), "true");
+    assert_eq!(engine.eval_str(half_open, r#"{"x": 10}"#).unwrap(), "false");
+}
+
 #[test]
 fn evaluate_arena_path() {
     let engine = Engine::new();
@@ -140,6 +150,136 @@ fn logic_to_json_handles_constant_folded_subtree() {
     assert_eq!(result.as_i64(), Some(6));
 }
 
+/// `let` compiles its bindings object into flat argument pairs; `to_json`
+/// must fold them back into the object form so the output re-compiles.
+#[cfg(feature = "ext-control")]
+#[test]
+fn logic_to_json_round_trips_let_bindings() {
+    let engine = Engine::new();
+    let compiled = engine
+        .compile(r#"{"let": [{"t": {"*": [{"var": "p"}, 2]}, "u": 1}, {"+": [{"var": "t"}, {"var": "u"}]}]}"#)
+        .unwrap();
+    let serialised = compiled.to_json();
+    assert!(
+        serialised.starts_with(r#"{"let": [{"t": "#),
+        "unexpected let form: {serialised}"
+    );
+
+    let recompiled = engine.compile(&serialised).unwrap();
+    let arena = Bump::new();
+    let data = DataValue::from_str(r#"{"p": 5}"#, &arena).unwrap();
+    assert_eq!(
+        engine.evaluate(&recompiled, data, &arena).unwrap().as_i64(),
+        Some(11)
+    );
+}
+
+/// `cat`'s separator form compiles to one separator plus the items;
+/// `to_json` must render it as the `{"sep", "items"}` object again.
+#[test]
+fn logic_to_json_round_trips_cat_separator_form() {
+    let engine = Engine::new();
+    let compiled = engine
+        .compile(r#"{"cat": {"sep": {"var": "s"}, "items": [{"var": "a"}, "b", {"var": "c"}]}}"#)
+        .unwrap();
+    let serialised = compiled.to_json();
+    assert_eq!(
+        serialised,
+        r#"{"cat": {"sep": {"var": "s"}, "items": [{"var": "a"}, "b", {"var": "c"}]}}"#
+    );
+
+    let recompiled = engine.compile(&serialised).unwrap();
+    assert_eq!(recompiled.to_json(), serialised);
+    let data = r#"{"s": "-", "a": 1, "c": true}"#;
+    assert_eq!(engine.eval_str(&serialised, data).unwrap(), r#""1-b-true""#);
+
+    // Without `sep` the default empty separator is spelled out.
+    let compiled = engine
+        .compile(r#"{"cat": {"items": ["a", {"var": "x"}]}}"#)
+        .unwrap();
+    assert_eq!(
+        compiled.to_json(),
+        r#"{"cat": {"sep": "", "items": ["a", {"var": "x"}]}}"#
+    );
+}
+
+/// A thrown object literal must keep every field through `to_json`, not
+/// just `type`.
+#[cfg(feature = "error-handling")]
+#[test]
+fn logic_to_json_round_trips_thrown_object() {
+    let engine = Engine::new();
+    let compiled = engine
+        .compile(r#"{"throw": {"type": "validation", "field": "x"}}"#)
+        .unwrap();
+    let recompiled = engine.compile(&compiled.to_json()).unwrap();
+    let arena = Bump::new();
+    let data = DataValue::from_str("null", &arena).unwrap();
+    let err = engine.evaluate(&recompiled, data, &arena).unwrap_err();
+    let thrown = err.thrown_value().unwrap();
+    assert_eq!(
+        thrown.to_json_string(),
+        r#"{"type":"validation","field":"x"}"#
+    );
+}
+
+/// `Logic::to_value` is a fixed point under recompilation for every core
+/// operator family: compiling the value back yields the same value, and
+/// both rules evaluate identically.
+#[test]
+fn logic_to_value_round_trips_core_operators() {
+    use datalogic_rs::datavalue::OwnedDataValue;
+
+    let engine = Engine::new();
+    let data = r#"{"a": 3, "b": "x", "xs": [1, 2, 3], "q\"k": true}"#;
+    let rules = [
+        r#"{"==": [{"var": "a"}, 3]}"#,
+        r#"{"var": ["missing", 7]}"#,
+        r#"{"val": "b"}"#,
+        r#"{"and": [{"var": "a"}, {"or": [false, {"var": "b"}]}]}"#,
+        r#"{"if": [{">": [{"var": "a"}, 1]}, "big", "small"]}"#,
+        r#"{"?:": [{"var": "b"}, 1, 2]}"#,
+        r#"{"!": {"!": {"var": "a"}}}"#,
+        r#"{"+": [{"var": "a"}, {"*": [2, 3]}]}"#,
+        r#"{"cat": [{"var": "b"}, "-", {"substr": ["hello", 1, 2]}]}"#,
+        r#"{"cat": {"sep": ", ", "items": [{"var": "b"}, {"var": "a"}]}}"#,
+        r#"{"in": [{"var": "b"}, ["x", "y"]]}"#,
+        r#"{"map": [{"var": "xs"}, {"*": [{"var": ""}, 2]}]}"#,
+        r#"{"filter": [{"var": "xs"}, {">": [{"var": ""}, 1]}]}"#,
+        r#"{"reduce": [{"var": "xs"}, {"+": [{"var": "accumulator"}, {"var": "current"}]}, 0]}"#,
+        r#"{"all": [{"var": "xs"}, {">": [{"var": ""}, 0]}]}"#,
+        r#"{"merge": [{"var": "xs"}, [4]]}"#,
+        r#"{"missing": ["a", "nope"]}"#,
+        r#"{"missing_some": [1, ["a", "nope"]]}"#,
+        r#"{"var": "q\"k"}"#,
+    ];
+
+    for rule in rules {
+        let compiled = engine.compile(rule).unwrap();
+        let value = compiled.to_value();
+        assert_eq!(
+            value,
+            OwnedDataValue::from_json(&compiled.to_json()).unwrap(),
+            "to_value disagrees with to_json for {rule}"
+        );
+
+        let recompiled = engine.compile(&value).unwrap();
+        assert_eq!(recompiled.to_value(), value, "not a fixed point: {rule}");
+        assert_eq!(
+            engine.eval_str(&value, data).unwrap(),
+            engine.eval_str(rule, data).unwrap(),
+            "evaluation changed for {rule}"
+        );
+    }
+
+    // Rewritten forms serialise as their rewrite.
+    let double_not = engine.compile(r#"{"!": {"!": {"var": "a"}}}"#).unwrap();
+    assert_eq!(
+        double_not.to_value(),
+        OwnedDataValue::from_json(r#"{"!!": {"var": "a"}}"#).unwrap()
+    );
+}
+
 /// `EngineBuilder::with_constant_folding(false)` keeps every operator in
 /// the compiled tree. We can't observe the tree shape directly (it's
 /// `pub(crate)`), but `Logic::to_json` reflects it — when folding is on
@@ -273,16 +413,15 @@ fn max_recursion_depth_catches_custom_operator_reentry() {
     );
 }
 
-/// Built-in operators always win against a custom registration with the
-/// same name. The compile path checks `op_name.parse::<OpCode>()` first
-/// (`compile/walker.rs:79`) and routes through the built-in dispatcher
-/// before consulting the engine's custom-operator registry — so a
-/// `CustomOperator` registered as `"+"` is silently shadowed and never
-/// reached at runtime.
+/// Built-in operators win against a custom registration with the same
+/// name unless `EngineBuilder::with_operator_overrides` is enabled. The
+/// compile path checks `op_name.parse::<OpCode>()` first and routes
+/// through the built-in dispatcher before consulting the engine's
+/// custom-operator registry — so by default a `CustomOperator`
+/// registered as `"+"` is silently shadowed and never reached at runtime.
 ///
-/// This test pins that contract. If a future change wants custom
-/// operators to override built-ins, that's a deliberate behaviour change
-/// that would flip this test.
+/// This test pins that default; `arena_operator_test` covers the
+/// opt-in override.
 #[test]
 fn builtin_shadows_custom_operator_with_same_name() {
     use datalogic_rs::operator::EvalContext;
@@ -310,3 +449,587 @@ fn builtin_shadows_custom_operator_with_same_name() {
     // Built-in `+` ran (3), not the imposter (-1).
     assert_eq!(result, "3");
 }
+
+/// `EngineBuilder::remove_operator` takes a built-in away: rules using it
+/// fail to compile, everything else (including other engines) is untouched.
+#[test]
+fn removed_builtin_fails_to_compile() {
+    use datalogic_rs::ErrorKind;
+
+    let engine = Engine::builder().remove_operator("substr").build();
+    let err = engine.compile(r#"{"substr": ["hello", 1]}"#).unwrap_err();
+    assert!(
+        matches!(&err.kind, ErrorKind::InvalidOperator(name) if name == "substr"),
+        "expected InvalidOperator(substr), got: {:?}",
+        err.kind,
+    );
+    // Nested uses are rejected too, not just the root operator.
+    assert!(
+        engine
+            .compile(r#"{"cat": ["x", {"substr": ["hello", 1]}]}"#)
+            .is_err()
+    );
+    assert_eq!(
+        engine.eval_str(r#"{"cat": ["a", "b"]}"#, "null").unwrap(),
+        r#""ab""#
+    );
+
+    let stock = Engine::new();
+    assert_eq!(
+        stock
+            .eval_str(r#"{"substr": ["hello", 1]}"#, "null")
+            .unwrap(),
+        r#""ello""#
+    );
+}
+
+/// `Engine::validate` keeps walking past the first problem and reports
+/// each one, without data and without evaluating anything.
+#[test]
+fn validate_reports_every_problem() {
+    use datalogic_rs::ErrorKind;
+
+    let engine = Engine::new();
+    let rule = r#"{"and": [
+        {"frobnicate": [{"var": "x"}]},
+        {"if": {"var": "flag"}},
+        {"map": [{"var": "xs"}, {"wibble": []}]}
+    ]}"#;
+
+    // `compile` accepts the rule (unknown operators fail only when
+    // evaluated); `validate` lists all three problems in document order.
+    assert!(engine.compile(rule).is_ok());
+    let errors = engine.validate(rule);
+    assert_eq!(errors.len(), 3, "got: {errors:?}");
+    assert!(matches!(&errors[0].kind, ErrorKind::InvalidOperator(name) if name == "frobnicate"));
+    assert!(matches!(errors[1].kind, ErrorKind::InvalidArguments(_)));
+    assert_eq!(errors[1].operator(), Some("if"));
+    assert!(matches!(&errors[2].kind, ErrorKind::InvalidOperator(name) if name == "wibble"));
+
+    assert!(
+        engine
+            .validate(r#"{"if": [{"var": "a"}, 1, 2]}"#)
+            .is_empty()
+    );
+}
+
+/// `map` / `filter` / `reduce` with the wrong number of arguments fail
+/// the compile itself instead of erroring only when evaluated.
+#[test]
+fn iterator_arity_is_checked_at_compile_time() {
+    use datalogic_rs::ErrorKind;
+
+    let engine = Engine::new();
+    for (rule, op) in [
+        (r#"{"map": [{"var": "x"}]}"#, "map"),
+        (r#"{"filter": [{"var": "x"}]}"#, "filter"),
+        (r#"{"reduce": [{"var": "x"}]}"#, "reduce"),
+        (r#"{"reduce": [[1], {"var": "current"}, 0, 1]}"#, "reduce"),
+        (r#"{"if": [false, {"map": []}, 1]}"#, "map"),
+    ] {
+        let err = engine.compile(rule).unwrap_err();
+        assert!(
+            matches!(err.kind, ErrorKind::InvalidArguments(_)),
+            "{rule}: {err:?}"
+        );
+        assert_eq!(err.operator(), Some(op), "{rule}");
+    }
+
+    // `validate` reports each one and keeps walking.
+    let errors = engine.validate(r#"[{"map": [[1]]}, {"filter": [[1], true, 2]}]"#);
+    let ops: Vec<_> = errors.iter().map(|e| e.operator()).collect();
+    assert_eq!(ops, [Some("map"), Some("filter")]);
+
+    // Empty data arrays are fine; only the argument count is checked.
+    assert_eq!(
+        engine
+            .eval_str(r#"{"map": [[], {"var": ""}]}"#, "null")
+            .unwrap(),
+        "[]"
+    );
+    assert!(
+        engine
+            .compile(r#"{"reduce": [[], {"var": "current"}]}"#)
+            .is_ok()
+    );
+}
+
+#[test]
+fn validate_knows_about_engine_operators() {
+    use datalogic_rs::operator::EvalContext;
+    use datalogic_rs::{CustomOperator, ErrorKind, Result as DLResult};
+
+    struct Nothing;
+    impl CustomOperator for Nothing {
+        fn evaluate<'a>(
+            &self,
+            _args: &[&'a DataValue<'a>],
+            _ctx: &mut EvalContext<'_, 'a>,
+            arena: &'a Bump,
+        ) -> DLResult<&'a DataValue<'a>> {
+            Ok(arena.alloc(DataValue::Null))
+        }
+    }
+
+    let engine = Engine::builder()
+        .add_operator("nothing", Nothing)
+        .remove_operator("substr")
+        .build();
+    // A registered custom operator is fine; a removed built-in is not,
+    // and the walk carries on past it into its arguments.
+    let errors = engine.validate(r#"{"substr": [{"nothing": [1]}, {"nope": []}]}"#);
+    let names: Vec<_> = errors
+        .iter()
+        .map(|e| match &e.kind {
+            ErrorKind::InvalidOperator(name) => name.to_string(),
+            other => panic!("unexpected error: {other:?}"),
+        })
+        .collect();
+    assert_eq!(names, ["substr", "nope"]);
+
+    // Unparseable rule text is reported as the only error.
+    let errors = engine.validate(r#"{"+": [1, "#);
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(errors[0].kind, ErrorKind::ParseError(_)));
+}
+
+#[test]
+fn operator_names_lists_builtins_and_custom_minus_removed() {
+    use datalogic_rs::operator::EvalContext;
+    use datalogic_rs::{CustomOperator, Result as DLResult};
+
+    struct Nothing;
+    impl CustomOperator for Nothing {
+        fn evaluate<'a>(
+            &self,
+            _args: &[&'a DataValue<'a>],
+            _ctx: &mut EvalContext<'_, 'a>,
+            arena: &'a Bump,
+        ) -> DLResult<&'a DataValue<'a>> {
+            Ok(arena.alloc(DataValue::Null))
+        }
+    }
+
+    let stock_engine = Engine::new();
+    let stock: Vec<&str> = stock_engine.operator_names().collect();
+    assert!(stock.contains(&"var"));
+    assert!(stock.contains(&"val"));
+    assert!(stock.contains(&"substr"));
+
+    let engine = Engine::builder()
+        .add_operator("nothing", Nothing)
+        .add_operator("dropped", Nothing)
+        .add_operator("+", Nothing)
+        .remove_operator("dropped")
+        .remove_operator("substr")
+        .build();
+    let names: Vec<&str> = engine.operator_names().collect();
+    assert!(names.contains(&"nothing"));
+    assert!(!names.contains(&"dropped"));
+    assert!(!names.contains(&"substr"));
+    assert_eq!(names.iter().filter(|name| **name == "+").count(), 1);
+    assert_eq!(names.len(), stock.len());
+    assert!(engine.compile(r#"{"dropped": []}"#).is_err());
+}
+
+/// Removing a built-in takes its aliases with it, whichever name was
+/// removed; a custom operator registered under an alias afterwards still
+/// compiles.
+#[test]
+fn removed_builtin_takes_its_aliases() {
+    use datalogic_rs::ErrorKind;
+    use datalogic_rs::operator::EvalContext;
+    use datalogic_rs::{CustomOperator, Result as DLResult};
+
+    struct Nothing;
+    impl CustomOperator for Nothing {
+        fn evaluate<'a>(
+            &self,
+            _args: &[&'a DataValue<'a>],
+            _ctx: &mut EvalContext<'_, 'a>,
+            arena: &'a Bump,
+        ) -> DLResult<&'a DataValue<'a>> {
+            Ok(arena.alloc(DataValue::Null))
+        }
+    }
+
+    let engine = Engine::builder().remove_operator("var").build();
+    let err = engine.compile(r#"{"val": "x"}"#).unwrap_err();
+    assert!(
+        matches!(&err.kind, ErrorKind::InvalidOperator(name) if name == "val"),
+        "expected InvalidOperator(val), got: {:?}",
+        err.kind,
+    );
+    let names: Vec<&str> = engine.operator_names().collect();
+    assert!(!names.contains(&"var"));
+    assert!(!names.contains(&"val"));
+
+    #[cfg(feature = "ext-control")]
+    {
+        let engine = Engine::builder().remove_operator("switch").build();
+        assert!(
+            engine
+                .compile(r#"{"match": [1, [[1, "a"]], "b"]}"#)
+                .is_err()
+        );
+    }
+    #[cfg(feature = "ext-array")]
+    {
+        let engine = Engine::builder().remove_operator("distinct").build();
+        assert!(engine.compile(r#"{"unique": [[1, 1]]}"#).is_err());
+    }
+
+    let engine = Engine::builder()
+        .remove_operator("val")
+        .add_operator("var", Nothing)
+        .build();
+    assert!(engine.compile(r#"{"val": "x"}"#).is_err());
+    assert_eq!(
+        engine.eval_str(r#"{"var": "x"}"#, r#"{"x": 1}"#).unwrap(),
+        "null"
+    );
+    let names: Vec<&str> = engine.operator_names().collect();
+    assert_eq!(names.iter().filter(|name| **name == "var").count(), 1);
+    assert!(!names.contains(&"val"));
+}
+
+/// `Engine::eval_batch` reuses one arena across records but must produce
+/// exactly what a fresh per-record evaluation would — including errors,
+/// which stay in their slot without affecting the records after them.
+#[test]
+fn eval_batch_matches_per_record_evaluation() {
+    use datalogic_rs::datavalue::OwnedDataValue;
+
+    let engine = Engine::new();
+    let compiled = engine
+        .compile(
+            r#"{"if": [
+                {">": [{"reduce": [{"var": "items"}, {"+": [{"var": "accumulator"}, {"var": "current"}]}, 0]}, 10]},
+                {"cat": ["big:", {"var": "name"}]},
+                {"map": [{"var": "items"}, {"*": [{"var": ""}, 2]}]}
+            ]}"#,
+        )
+        .unwrap();
+
+    let records: Vec<OwnedDataValue> = [
+        r#"{"name": "a", "items": [1, 2, 3]}"#,
+        r#"{"name": "b", "items": [5, 6, 7]}"#,
+        r#"{"name": "c", "items": [1, "x"]}"#,
+        r#"{"name": "d", "items": []}"#,
+        r#"{"name": "e", "items": [10, 1]}"#,
+    ]
+    .iter()
+    .map(|s| OwnedDataValue::from_json(s).unwrap())
+    .collect();
+
+    let batch = engine.eval_batch(&compiled, &records);
+    assert_eq!(batch.len(), records.len());
+    assert!(batch[2].is_err() && batch[3].is_ok());
+    for (record, batched) in records.iter().zip(&batch) {
+        let arena = Bump::new();
+        let single = engine
+            .evaluate(&compiled, record, &arena)
+            .map(|v| v.to_owned());
+        match (single, batched) {
+            (Ok(expected), Ok(got)) => assert_eq!(&expected, got),
+            (Err(expected), Err(got)) => assert_eq!(expected.to_string(), got.to_string()),
+            (expected, got) => panic!("mismatch for {record:?}: {expected:?} vs {got:?}"),
+        }
+    }
+
+    assert!(
+        engine
+            .eval_batch::<OwnedDataValue>(&compiled, &[])
+            .is_empty()
+    );
+}
+
+/// `Engine::eval_iter` resets its arena before every record. Records that
+/// omit a key must see the rule's default, not whatever the previous
+/// record left in the reused arena, and `take` must stop pulling input.
+#[test]
+fn eval_iter_is_lazy_and_isolates_records() {
+    use datalogic_rs::datavalue::OwnedDataValue;
+    use std::cell::Cell;
+
+    let engine = Engine::new();
+    let compiled = engine
+        .compile(r#"{"cat": [{"var": ["name", "anon"]}, ":", {"var": "tags.0"}]}"#)
+        .unwrap();
+
+    let records: Vec<OwnedDataValue> = [
+        r#"{"name": "a", "tags": ["x", "y"]}"#,
+        r#"{"tags": []}"#,
+        r#"{"name": "c", "tags": ["z"]}"#,
+        r#"{}"#,
+        r#"{"name": "e", "tags": ["w"]}"#,
+        r#"{"name": "f", "tags": ["v"]}"#,
+    ]
+    .iter()
+    .map(|s| OwnedDataValue::from_json(s).unwrap())
+    .collect();
+
+    let pulled = Cell::new(0);
+    let named: Vec<String> = engine
+        .eval_iter(
+            &compiled,
+            records.iter().inspect(|_| pulled.set(pulled.get() + 1)),
+        )
+        .map(|r| r.unwrap().as_str().unwrap().to_string())
+        .filter(|s| !s.starts_with("anon"))
+        .take(3)
+        .collect();
+    assert_eq!(named, ["a:x", "c:z", "e:w"]);
+    assert_eq!(pulled.get(), 5, "take(3) must not read the sixth record");
+
+    // Every streamed result matches a fresh per-record evaluation.
+    let streamed: Vec<_> = engine.eval_iter(&compiled, records.clone()).collect();
+    assert_eq!(streamed.len(), records.len());
+    for (record, got) in records.iter().zip(streamed) {
+        let arena = Bump::new();
+        let single = engine.evaluate(&compiled, record, &arena).unwrap();
+        assert_eq!(got.unwrap(), single.to_owned());
+    }
+    let names: Vec<String> = engine
+        .eval_iter(&compiled, &records)
+        .map(|r| r.unwrap().as_str().unwrap().to_string())
+        .collect();
+    assert_eq!(names, ["a:x", "anon:", "c:z", "anon:", "e:w", "f:v"]);
+}
+
+/// `Engine::eval_typed` serialises a user type straight into the arena;
+/// every shape must read back exactly as its `serde_json` form would.
+#[test]
+fn eval_typed_matches_serde_json_form() {
+    use datalogic_rs::datavalue::OwnedDataValue;
+    use serde::Serialize;
+    use std::collections::BTreeMap;
+
+    #[derive(Serialize)]
+    #[serde(rename_all = "lowercase")]
+    enum Tier {
+        Gold,
+        Custom { discount: f32 },
+    }
+
+    #[derive(Serialize)]
+    struct Order {
+        id: u64,
+        total: f64,
+        tags: Vec<&'static str>,
+    }
+
+    #[derive(Serialize)]
+    struct Customer {
+        name: String,
+        age: u8,
+        email: Option<String>,
+        tier: Tier,
+        orders: Vec<Order>,
+        scores: BTreeMap<u32, i64>,
+        big: u64,
+        pair: (char, bool),
+    }
+
+    let customer = Customer {
+        name: "Ada".into(),
+        age: 36,
+        email: None,
+        tier: Tier::Custom { discount: 0.5 },
+        orders: vec![
+            Order {
+                id: 1,
+                total: 40.0,
+                tags: vec!["a"],
+            },
+            Order {
+                id: 2,
+                total: 75.5,
+                tags: vec![],
+            },
+        ],
+        scores: BTreeMap::from([(1, 10), (2, -3)]),
+        big: u64::MAX,
+        pair: ('x', true),
+    };
+
+    let engine = Engine::new();
+    let rules = [
+        r#"{"cat": [{"var": "name"}, "/", {"var": "age"}]}"#,
+        r#"{"var": ["email", "none"]}"#,
+        r#"{"var": "tier.custom.discount"}"#,
+        r#"{"reduce": [{"var": "orders"}, {"+": [{"var": "accumulator"}, {"var": "current.total"}]}, 0]}"#,
+        r#"{"map": [{"var": "orders"}, {"var": "id"}]}"#,
+        r#"{"+": [{"var": "scores.1"}, {"var": "scores.2"}]}"#,
+        r#"{">": [{"var": "big"}, 1]}"#,
+        r#"{"var": "pair"}"#,
+        r#"{"var": ""}"#,
+    ];
+    let json = OwnedDataValue::from_json(&serde_json::to_string(&customer).unwrap()).unwrap();
+    for rule in rules {
+        let compiled = engine.compile(rule).unwrap();
+        let arena = Bump::new();
+        let expected = engine
+            .evaluate(&compiled, &json, &arena)
+            .unwrap()
+            .to_owned();
+        assert_eq!(
+            engine.eval_typed(&compiled, &customer).unwrap(),
+            expected,
+            "{rule}"
+        );
+    }
+
+    // Unit variants serialise to their name; unsized inputs work too.
+    let compiled = engine.compile(r#"{"var": "0"}"#).unwrap();
+    let tiers: &[Tier] = &[Tier::Gold];
+    assert_eq!(
+        engine.eval_typed(&compiled, tiers).unwrap().as_str(),
+        Some("gold")
+    );
+
+    // Non-string map keys that serde_json also rejects fail as ParseError.
+    let bad: BTreeMap<(i32, i32), i32> = BTreeMap::from([((1, 2), 3)]);
+    let err = engine.eval_typed(&compiled, &bad).unwrap_err();
+    assert_eq!(err.tag(), "ParseError");
+}
+
+#[test]
+fn eval_as_typed_extractors_return_matching_results() {
+    let engine = Engine::new();
+    let data = r#"{"age": 21, "name": "Ada", "rate": 0.5}"#;
+
+    assert!(
+        engine
+            .eval_as_bool(r#"{">=": [{"var": "age"}, 18]}"#, data)
+            .unwrap()
+    );
+    // Integers widen; floats pass through.
+    assert_eq!(engine.eval_as_f64(r#"{"var": "age"}"#, data).unwrap(), 21.0);
+    assert_eq!(
+        engine
+            .eval_as_f64(r#"{"*": [{"var": "age"}, {"var": "rate"}]}"#, data)
+            .unwrap(),
+        10.5
+    );
+    // The raw string, not its JSON form.
+    assert_eq!(
+        engine.eval_as_string(r#"{"var": "name"}"#, data).unwrap(),
+        "Ada"
+    );
+}
+
+#[test]
+fn eval_as_typed_extractors_reject_mismatched_results() {
+    let engine = Engine::new();
+    let data = r#"{"age": 21, "name": "Ada", "count": "42", "tags": []}"#;
+
+    for (err, expected) in [
+        // No truthiness coercion: a number isn't a boolean.
+        (
+            engine.eval_as_bool(r#"{"var": "age"}"#, data).unwrap_err(),
+            "expected a boolean result, got number",
+        ),
+        // No numeric coercion of strings either.
+        (
+            engine.eval_as_f64(r#"{"var": "count"}"#, data).unwrap_err(),
+            "expected a number result, got string",
+        ),
+        (
+            engine
+                .eval_as_f64(r#"{"var": "missing"}"#, data)
+                .unwrap_err(),
+            "expected a number result, got null",
+        ),
+        (
+            engine
+                .eval_as_string(r#"{"var": "tags"}"#, data)
+                .unwrap_err(),
+            "expected a string result, got array",
+        ),
+    ] {
+        assert_eq!(err.tag(), "TypeError");
+        assert!(err.to_string().contains(expected), "{err}");
+    }
+
+    // Compile and evaluation errors pass through unchanged.
+    let err = engine
+        .eval_as_bool(r#"{"no_such_op": []}"#, data)
+        .unwrap_err();
+    assert_ne!(err.tag(), "TypeError");
+}
+
+/// `Engine::scoped` reuses pooled arenas; nothing from one evaluation or
+/// scope may be visible to the next.
+#[test]
+fn scoped_sessions_reuse_pooled_arenas_without_bleed() {
+    use datalogic_rs::ArenaPool;
+    use datalogic_rs::datavalue::OwnedDataValue;
+
+    let engine = Engine::new();
+    let pool = ArenaPool::new();
+    let read_secret = engine.compile(r#"{"var": ["secret", "absent"]}"#).unwrap();
+    let build = engine
+        .compile(r#"{"map": [{"var": "xs"}, {"cat": [{"var": ""}, "!"]}]}"#)
+        .unwrap();
+
+    let (first, leaked) = engine.scoped(&pool, |session| {
+        let first = session
+            .eval(&build, r#"{"xs": ["a", "b"], "secret": "s1"}"#)
+            .unwrap();
+        // Same arena, different data: the previous record's fields are gone.
+        let leaked = session.eval(&read_secret, r#"{"xs": []}"#).unwrap();
+        (first, leaked)
+    });
+    assert_eq!(first, OwnedDataValue::from_json(r#"["a!", "b!"]"#).unwrap());
+    assert_eq!(leaked.as_str(), Some("absent"));
+    assert_eq!(pool.idle(), 1);
+    assert!(pool.allocated_bytes() > 0);
+
+    // The next scope gets the same (reset) arena. Earlier owned results
+    // are unaffected by it being overwritten.
+    for i in 0..50 {
+        let payload = format!(r#"{{"xs": ["r{i}"], "secret": "s{i}"}}"#);
+        let (built, secret) = engine.scoped(&pool, |session| {
+            let built = session.eval(&build, payload.as_str()).unwrap();
+            session.reset();
+            let secret = session.eval_str(&read_secret, payload.as_str()).unwrap();
+            (built, secret)
+        });
+        assert_eq!(built.to_json_string(), format!(r#"["r{i}!"]"#));
+        assert_eq!(secret, format!(r#""s{i}""#));
+    }
+    assert_eq!(pool.idle(), 1);
+    assert_eq!(first, OwnedDataValue::from_json(r#"["a!", "b!"]"#).unwrap());
+
+    pool.clear();
+    assert_eq!((pool.idle(), pool.allocated_bytes()), (0, 0));
+}
+
+#[test]
+fn arena_pool_is_shared_across_threads() {
+    use datalogic_rs::ArenaPool;
+
+    let engine = Engine::new();
+    let pool = ArenaPool::with_arena_capacity(1024);
+    let compiled = engine
+        .compile(r#"{"cat": [{"var": "worker"}, ":", {"var": "job"}]}"#)
+        .unwrap();
+
+    std::thread::scope(|s| {
+        for worker in 0..4 {
+            let (engine, pool, compiled) = (&engine, &pool, &compiled);
+            s.spawn(move || {
+                for job in 0..100 {
+                    let data = format!(r#"{{"worker": {worker}, "job": {job}}}"#);
+                    let result = engine
+                        .scoped(pool, |session| session.eval(compiled, data.as_str()))
+                        .unwrap();
+                    assert_eq!(result.as_str(), Some(format!("{worker}:{job}").as_str()));
+                }
+            });
+        }
+    });
+    // At most one arena per concurrent scope.
+    assert!((1..=4).contains(&pool.idle()));
+}
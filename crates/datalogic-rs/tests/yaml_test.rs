@@ -0,0 +1,111 @@
+//! Tests for `Engine::eval_yaml`: YAML rules and data must evaluate
+//! exactly like their JSON equivalents, and YAML syntax errors must be
+//! distinguishable from rule errors.
+
+#![cfg(feature = "yaml")]
+
+use datalogic_rs::Engine;
+
+/// Each case is (YAML rule, YAML data, equivalent JSON rule, JSON data).
+const CASES: &[(&str, &str, &str, &str)] = &[
+    (
+        "
+if:
+  - and:
+      - '>=': [{var: user.age}, 18]
+      - in: [{var: user.country}, [NZ, AU]]
+  - eligible
+  - ineligible
+",
+        "
+user:
+  age: 30
+  country: NZ
+",
+        r#"{"if": [{"and": [{">=": [{"var": "user.age"}, 18]}, {"in": [{"var": "user.country"}, ["NZ", "AU"]]}]}, "eligible", "ineligible"]}"#,
+        r#"{"user": {"age": 30, "country": "NZ"}}"#,
+    ),
+    (
+        "
+reduce:
+  - var: orders
+  - '+':
+      - var: accumulator
+      - '*': [{var: current.qty}, {var: current.price}]
+  - 0
+",
+        "
+orders:
+  - {qty: 2, price: 1.5}
+  - {qty: 1, price: 10}
+",
+        r#"{"reduce": [{"var": "orders"}, {"+": [{"var": "accumulator"}, {"*": [{"var": "current.qty"}, {"var": "current.price"}]}]}, 0]}"#,
+        r#"{"orders": [{"qty": 2, "price": 1.5}, {"qty": 1, "price": 10}]}"#,
+    ),
+    (
+        "
+map:
+  - filter:
+      - var: items
+      - '!!': {var: active}
+  - cat: [{var: name}, '!']
+",
+        "
+items:
+  - name: a
+    active: true
+  - name: b
+    active: ~
+  - name: c
+    active: yes-ish
+",
+        r#"{"map": [{"filter": [{"var": "items"}, {"!!": {"var": "active"}}]}, {"cat": [{"var": "name"}, "!"]}]}"#,
+        r#"{"items": [{"name": "a", "active": true}, {"name": "b", "active": null}, {"name": "c", "active": "yes-ish"}]}"#,
+    ),
+    (
+        "missing: [a, b.c, d]",
+        "{a: 1, b: {c: 2}}",
+        r#"{"missing": ["a", "b.c", "d"]}"#,
+        r#"{"a": 1, "b": {"c": 2}}"#,
+    ),
+];
+
+#[test]
+fn yaml_rules_match_json_equivalents() {
+    let engine = Engine::new();
+    for (yaml_rule, yaml_data, json_rule, json_data) in CASES {
+        let from_yaml = engine.eval_yaml(yaml_rule, yaml_data).unwrap();
+        let from_json = engine.eval(*json_rule, *json_data).unwrap();
+        assert_eq!(from_yaml, from_json, "mismatch for {json_rule}");
+    }
+}
+
+#[test]
+fn yaml_parse_errors_name_the_failing_document() {
+    let engine = Engine::new();
+
+    let err = engine.eval_yaml("'==': [1, 1", "null").unwrap_err();
+    assert_eq!(err.tag(), "ParseError");
+    assert!(err.to_string().contains("YAML logic"), "{err}");
+
+    let err = engine.eval_yaml("'==': [1, 1]", "a: [1, 2").unwrap_err();
+    assert_eq!(err.tag(), "ParseError");
+    assert!(err.to_string().contains("YAML data"), "{err}");
+}
+
+#[test]
+fn yaml_rule_errors_are_not_parse_errors() {
+    let engine = Engine::new();
+
+    // Valid YAML, unknown operator: same error as the JSON form.
+    let yaml = engine.eval_yaml("no_such_op: [1]", "null").unwrap_err();
+    let json = engine.eval(r#"{"no_such_op": [1]}"#, "null").unwrap_err();
+    assert_eq!(yaml.tag(), json.tag());
+    assert_ne!(yaml.tag(), "ParseError");
+
+    // Valid YAML, evaluation-time failure.
+    let err = engine
+        .eval_yaml("'+': [{var: x}, 1]", "x: abc")
+        .unwrap_err();
+    assert_ne!(err.tag(), "ParseError");
+}
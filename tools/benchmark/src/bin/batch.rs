@@ -0,0 +1,89 @@
+//! Batch-evaluation benchmark: one compiled rule over many records.
+//!
+//! Compares `Engine::eval_batch` (one arena, reset between records)
+//! against the per-call owned path — a fresh `Bump` per record plus a
+//! deep-clone of the result, which is what `Engine::eval` does minus the
+//! rule compile and input clone. Both produce `OwnedDataValue` results, so
+//! the difference is purely arena setup / teardown — expect a few percent,
+//! not a multiple: per-record cost is dominated by copying the input into
+//! the arena and evaluating the rule.
+//!
+//! Usage: `batch [records] [reps]` — defaults to 10 000 records and 5
+//! timed reps. Runs a small-record and a large-record shape and reports
+//! the median rep of each path and the speedup.
+
+use std::env;
+use std::hint::black_box;
+
+use bumpalo::Bump;
+use datalogic_bench::{TimedPath, assert_agree, record, run};
+use datalogic_rs::datavalue::OwnedDataValue;
+use datalogic_rs::{Engine, Logic, Result};
+
+/// Eligibility-style rule touching arithmetic, an iterator, and a
+/// string result — enough work per record that the arena actually grows.
+const RULE: &str = r#"{"if": [
+    {"and": [
+        {">=": [{"var": "age"}, 18]},
+        {">": [{"reduce": [{"var": "orders"}, {"+": [{"var": "accumulator"}, {"var": "current.total"}]}, 0]}, 100]}
+    ]},
+    {"cat": ["eligible:", {"var": "name"}]},
+    {"map": [{"var": "orders"}, {"var": "id"}]}
+]}"#;
+
+fn per_call(
+    engine: &Engine,
+    compiled: &Logic,
+    records: &[OwnedDataValue],
+) -> Vec<Result<OwnedDataValue>> {
+    records
+        .iter()
+        .map(|r| {
+            let arena = Bump::with_capacity(4096);
+            engine.evaluate(compiled, r, &arena).map(|v| v.to_owned())
+        })
+        .collect()
+}
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    let count: usize = args.get(1).and_then(|s| s.parse().ok()).unwrap_or(10_000);
+    let reps: usize = args.get(2).and_then(|s| s.parse().ok()).unwrap_or(5).max(1);
+
+    let engine = Engine::new();
+    let compiled = engine.compile(RULE).expect("rule compiles");
+    println!("{count} records, median of {reps} reps");
+    // Small records fit the per-call path's initial 4 KB chunk; large ones
+    // make it grow extra chunks on every call, while the batch arena keeps
+    // them across resets.
+    for (label, max_orders) in [("small", 8), ("large", 256)] {
+        let records: Vec<OwnedDataValue> = (0..count).map(|i| record(i, max_orders)).collect();
+        let records = records.as_slice();
+        let ok = |results: Vec<Result<OwnedDataValue>>| -> Vec<_> {
+            results.into_iter().map(Result::ok).collect()
+        };
+        assert_agree(
+            label,
+            ok(per_call(&engine, &compiled, records)),
+            ok(engine.eval_batch(&compiled, records)),
+        );
+        run(
+            &format!("{label} records"),
+            "record",
+            count,
+            reps,
+            TimedPath {
+                name: "per-call",
+                run: &mut || {
+                    black_box(per_call(&engine, &compiled, black_box(records)));
+                },
+            },
+            TimedPath {
+                name: "eval_batch",
+                run: &mut || {
+                    black_box(engine.eval_batch(&compiled, black_box(records)));
+                },
+            },
+        );
+    }
+}
@@ -0,0 +1,95 @@
+//! MessagePack-input benchmark: the same records as JSON text vs
+//! MessagePack bytes.
+//!
+//! Compares `Engine::eval_msgpack` against the JSON-string path — a fresh
+//! `Bump` per record, `&str` parsed into it by `Engine::evaluate`, and a
+//! deep-clone of the result, which is what `eval_msgpack` does with
+//! MessagePack decoding in place of JSON parsing. The rule is cheap on
+//! purpose so decode cost dominates.
+//!
+//! Usage: `msgpack [records] [reps]` — defaults to 10 000 records and 5
+//! timed reps. Runs a small-record and a large-record shape and reports
+//! the median rep of each path, the encoded sizes, and the speedup.
+
+use std::env;
+use std::hint::black_box;
+
+use bumpalo::Bump;
+use datalogic_bench::{TimedPath, assert_agree, record, run};
+use datalogic_rs::datavalue::OwnedDataValue;
+use datalogic_rs::{Engine, Logic};
+
+/// Reads a couple of fields and one array element — enough to check the
+/// decoded tree is wired up, without drowning the decode cost.
+const RULE: &str = r#"{"if": [
+    {">=": [{"var": "age"}, 18]},
+    {"cat": [{"var": "name"}, ":", {"var": "orders.0.id"}]},
+    null
+]}"#;
+
+fn via_json(engine: &Engine, compiled: &Logic, records: &[String]) -> Vec<OwnedDataValue> {
+    records
+        .iter()
+        .map(|r| {
+            let arena = Bump::with_capacity(4096);
+            engine
+                .evaluate(compiled, r.as_str(), &arena)
+                .expect("json eval")
+                .to_owned()
+        })
+        .collect()
+}
+
+fn via_msgpack(engine: &Engine, compiled: &Logic, records: &[Vec<u8>]) -> Vec<OwnedDataValue> {
+    records
+        .iter()
+        .map(|r| engine.eval_msgpack(compiled, r).expect("msgpack eval"))
+        .collect()
+}
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    let count: usize = args.get(1).and_then(|s| s.parse().ok()).unwrap_or(10_000);
+    let reps: usize = args.get(2).and_then(|s| s.parse().ok()).unwrap_or(5).max(1);
+
+    let engine = Engine::new();
+    let compiled = engine.compile(RULE).expect("rule compiles");
+    println!("{count} records, median of {reps} reps");
+    for (label, max_orders) in [("small", 4), ("large", 128)] {
+        let records: Vec<OwnedDataValue> = (0..count).map(|i| record(i, max_orders)).collect();
+        let json: Vec<String> = records.iter().map(|r| r.to_json_string()).collect();
+        let packed: Vec<Vec<u8>> = records
+            .iter()
+            .map(|r| rmp_serde::to_vec_named(r).expect("record encodes"))
+            .collect();
+        assert_agree(
+            label,
+            via_json(&engine, &compiled, &json),
+            via_msgpack(&engine, &compiled, &packed),
+        );
+        run(
+            &format!("{label} records"),
+            "record",
+            count,
+            reps,
+            TimedPath {
+                name: "json",
+                run: &mut || {
+                    black_box(via_json(&engine, &compiled, black_box(&json)));
+                },
+            },
+            TimedPath {
+                name: "eval_msgpack",
+                run: &mut || {
+                    black_box(via_msgpack(&engine, &compiled, black_box(&packed)));
+                },
+            },
+        );
+        let avg_bytes = |total: usize| total as f64 / count as f64;
+        println!(
+            "  size         json {:.1} B/record, msgpack {:.1} B/record",
+            avg_bytes(json.iter().map(String::len).sum()),
+            avg_bytes(packed.iter().map(Vec::len).sum())
+        );
+    }
+}
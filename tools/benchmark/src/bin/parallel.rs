@@ -0,0 +1,108 @@
+//! Parallel-`map` benchmark: one large array, sequential vs rayon.
+//!
+//! Evaluates the same `map` rules on an engine without a parallel
+//! threshold and on one built with
+//! `EngineBuilder::with_parallel_threshold`, over a single array of
+//! `items` records. The bodies are deliberately heavier than the fused
+//! fast paths (string building, a nested iterator, arithmetic through
+//! `if`) so per-item work can outweigh the fan-out and copy-back.
+//!
+//! Usage: `parallel [items] [reps] [threshold]` — defaults to 200 000
+//! items, 5 timed reps, and a threshold of 1 024. Reports the median rep
+//! of each path, the speedup, and rayon's thread count; the speedup is
+//! bounded by that count.
+
+use std::env;
+use std::hint::black_box;
+
+use bumpalo::Bump;
+use datalogic_bench::{TimedPath, assert_agree, run};
+use datalogic_rs::datavalue::OwnedDataValue;
+use datalogic_rs::{Engine, Logic};
+
+const RULES: &[(&str, &str)] = &[
+    (
+        "label",
+        r#"{"map": [{"var": "items"}, {"cat": [{"var": "name"}, "/", {"var": "id"}, "/", {"substr": [{"var": "sku"}, 2]}]}]}"#,
+    ),
+    (
+        "nested",
+        r#"{"map": [{"var": "items"}, {"reduce": [{"var": "scores"}, {"+": [{"var": "accumulator"}, {"*": [{"var": "current"}, 1.5]}]}, 0]}]}"#,
+    ),
+    (
+        "branchy",
+        r#"{"map": [{"var": "items"}, {"if": [{">": [{"var": "id"}, {"val": [[-1], "cutoff"]}]}, {"*": [{"var": "id"}, 2]}, {"-": [{"var": "id"}]}]}]}"#,
+    ),
+];
+
+fn data(items: usize) -> OwnedDataValue {
+    let records: Vec<String> = (0..items)
+        .map(|i| {
+            format!(
+                r#"{{"id": {i}, "name": "item{i}", "sku": "SK{:06}", "scores": [{}, {}, {}, {}]}}"#,
+                i * 31 % 1_000_000,
+                i % 10,
+                i % 7,
+                i % 5,
+                i % 3
+            )
+        })
+        .collect();
+    let json = format!(
+        r#"{{"cutoff": {}, "items": [{}]}}"#,
+        items / 2,
+        records.join(",")
+    );
+    OwnedDataValue::from_json(&json).expect("data parses")
+}
+
+fn eval(engine: &Engine, compiled: &Logic, data: &OwnedDataValue) -> OwnedDataValue {
+    let arena = Bump::new();
+    engine
+        .evaluate(compiled, data, &arena)
+        .expect("eval")
+        .to_owned()
+}
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    let items: usize = args.get(1).and_then(|s| s.parse().ok()).unwrap_or(200_000);
+    let reps: usize = args.get(2).and_then(|s| s.parse().ok()).unwrap_or(5).max(1);
+    let threshold: usize = args.get(3).and_then(|s| s.parse().ok()).unwrap_or(1_024);
+
+    let sequential = Engine::new();
+    let parallel = Engine::builder().with_parallel_threshold(threshold).build();
+    let data = data(items);
+    println!(
+        "{items} items, median of {reps} reps, threshold {threshold}, {} rayon threads",
+        rayon::current_num_threads()
+    );
+
+    for (label, rule) in RULES {
+        let seq_rule = sequential.compile(*rule).expect("rule compiles");
+        let par_rule = parallel.compile(*rule).expect("rule compiles");
+        assert_agree(
+            label,
+            eval(&sequential, &seq_rule, &data),
+            eval(&parallel, &par_rule, &data),
+        );
+        run(
+            label,
+            "item",
+            items,
+            reps,
+            TimedPath {
+                name: "sequential",
+                run: &mut || {
+                    black_box(eval(&sequential, &seq_rule, black_box(&data)));
+                },
+            },
+            TimedPath {
+                name: "parallel",
+                run: &mut || {
+                    black_box(eval(&parallel, &par_rule, black_box(&data)));
+                },
+            },
+        );
+    }
+}
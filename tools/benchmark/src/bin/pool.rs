@@ -0,0 +1,137 @@
+//! Arena-pool benchmark: request-style workloads on a thread pool.
+//!
+//! Each "request" evaluates a handful of compiled rules against one
+//! payload and keeps owned results, the shape of a rules-engine endpoint.
+//! Compares a fresh `Bump` per request (what a handler without a
+//! long-lived `Session` does) against `Engine::scoped` over a shared
+//! `ArenaPool`, which hands each request an arena whose chunks were grown
+//! by earlier requests. Worker threads pull requests from a shared
+//! counter, so a request rarely lands on the thread that served the
+//! previous one — the case a per-thread session can't cover.
+//!
+//! Usage: `pool [requests] [threads] [reps]` — defaults to 20 000
+//! requests, 4 threads, and 5 timed reps. Runs a small-payload and a
+//! large-payload shape and reports the median rep of each path and the
+//! speedup.
+
+use std::env;
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use bumpalo::Bump;
+use datalogic_bench::{TimedPath, assert_agree, record, run};
+use datalogic_rs::datavalue::OwnedDataValue;
+use datalogic_rs::{ArenaPool, Engine, Logic, Result};
+
+/// Rules a single request runs against its payload.
+const RULES: &[&str] = &[
+    r#"{">=": [{"var": "age"}, 18]}"#,
+    r#"{"reduce": [{"var": "orders"}, {"+": [{"var": "accumulator"}, {"var": "current.total"}]}, 0]}"#,
+    r#"{"map": [{"filter": [{"var": "orders"}, {">": [{"var": "total"}, 40]}]}, {"var": "id"}]}"#,
+    r#"{"cat": [{"var": "name"}, " has ", {"count": [{"var": "orders"}]}, " orders"]}"#,
+];
+
+/// One request on a fresh arena.
+fn fresh(engine: &Engine, rules: &[Logic], data: &OwnedDataValue) -> Vec<OwnedDataValue> {
+    let arena = Bump::with_capacity(4096);
+    rules
+        .iter()
+        .map(|r| engine.evaluate(r, data, &arena).expect("eval").to_owned())
+        .collect()
+}
+
+/// One request on a pooled arena.
+fn pooled(
+    engine: &Engine,
+    pool: &ArenaPool,
+    rules: &[Logic],
+    data: &OwnedDataValue,
+) -> Vec<OwnedDataValue> {
+    engine
+        .scoped(pool, |session| {
+            rules
+                .iter()
+                .map(|r| session.eval(r, data))
+                .collect::<Result<Vec<_>>>()
+        })
+        .expect("eval")
+}
+
+/// Serve every payload once across `threads` workers.
+fn serve<F>(threads: usize, payloads: &[OwnedDataValue], handle: F)
+where
+    F: Fn(&OwnedDataValue) -> Vec<OwnedDataValue> + Sync,
+{
+    let next = AtomicUsize::new(0);
+    std::thread::scope(|s| {
+        for _ in 0..threads {
+            s.spawn(|| {
+                while let Some(p) = payloads.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    black_box(handle(black_box(p)));
+                }
+            });
+        }
+    });
+}
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    let count: usize = args.get(1).and_then(|s| s.parse().ok()).unwrap_or(20_000);
+    let threads: usize = args.get(2).and_then(|s| s.parse().ok()).unwrap_or(4).max(1);
+    let reps: usize = args.get(3).and_then(|s| s.parse().ok()).unwrap_or(5).max(1);
+
+    let engine = Engine::new();
+    let rules: Vec<Logic> = RULES
+        .iter()
+        .map(|r| engine.compile(*r).expect("rule compiles"))
+        .collect();
+    println!(
+        "{count} requests x {} rules, {threads} threads, median of {reps} reps",
+        rules.len()
+    );
+    // Small payloads fit the fresh path's initial 4 KB chunk; large ones
+    // make it grow extra chunks on every request, while pooled arenas keep
+    // them.
+    for (label, max_orders) in [("small", 8), ("large", 256)] {
+        let payloads: Vec<OwnedDataValue> = (0..count).map(|i| record(i, max_orders)).collect();
+        compare(label, &engine, &rules, &payloads, threads, reps);
+    }
+}
+
+fn compare(
+    label: &str,
+    engine: &Engine,
+    rules: &[Logic],
+    payloads: &[OwnedDataValue],
+    threads: usize,
+    reps: usize,
+) {
+    let pool = ArenaPool::new();
+    for p in payloads.iter().take(256) {
+        assert_agree(
+            label,
+            fresh(engine, rules, p),
+            pooled(engine, &pool, rules, p),
+        );
+    }
+
+    run(
+        &format!("{label} payloads"),
+        "request",
+        payloads.len(),
+        reps,
+        TimedPath {
+            name: "fresh arena",
+            run: &mut || serve(threads, payloads, |p| fresh(engine, rules, p)),
+        },
+        TimedPath {
+            name: "scoped pool",
+            run: &mut || serve(threads, payloads, |p| pooled(engine, &pool, rules, p)),
+        },
+    );
+    println!(
+        "  pool         {} arenas, {} KiB idle",
+        pool.idle(),
+        pool.allocated_bytes() / 1024
+    );
+}
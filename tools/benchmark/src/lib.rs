@@ -5,12 +5,19 @@
 //! interface) reuse the suite loader, summary printer, and JSON reporter
 //! defined here. Engine-specific timing loops live in their respective
 //! binaries because the inner loop differs (arena reuse vs string round-trip).
+//!
+//! The two-path binaries (`batch`, `msgpack`, `pool`, `parallel`) share the
+//! record generator and the check-time-report loop at the bottom of this
+//! file; each supplies only the two paths it compares.
 
 pub mod macro_suites;
 
+use std::fmt::Debug;
 use std::fs;
 use std::path::{Path, PathBuf};
-use std::time::Duration;
+use std::time::{Duration, Instant};
+
+use datalogic_rs::datavalue::OwnedDataValue;
 
 use serde_json::Value;
 
@@ -693,3 +700,89 @@ pub fn write_matrix_report(
     fs::write(&path, serde_json::to_string_pretty(&report).unwrap()).expect("write report");
     path
 }
+
+// ============================================================
+// Two-path comparisons (used by `bin/batch.rs`, `bin/msgpack.rs`,
+// `bin/pool.rs`, and `bin/parallel.rs`).
+// ============================================================
+
+/// A customer record with `i % max_orders + 1` orders: strings, integers,
+/// a float, booleans, and a nested array of objects, so both the
+/// evaluation and the input decoding have something to chew on.
+pub fn record(i: usize, max_orders: usize) -> OwnedDataValue {
+    let orders: Vec<String> = (0..(i % max_orders) + 1)
+        .map(|j| {
+            format!(
+                r#"{{"id": "o{i}-{j}", "total": {}, "paid": {}}}"#,
+                (i * 7 + j * 13) % 90,
+                j % 2 == 0
+            )
+        })
+        .collect();
+    let json = format!(
+        r#"{{"name": "user{i}", "age": {}, "score": {}, "orders": [{}]}}"#,
+        14 + i % 40,
+        (i as f64) * 0.25,
+        orders.join(",")
+    );
+    OwnedDataValue::from_json(&json).expect("record parses")
+}
+
+fn median(mut samples: Vec<Duration>) -> Duration {
+    samples.sort();
+    samples[samples.len() / 2]
+}
+
+/// Median wall time of `reps` calls to `f`.
+pub fn time<F: FnMut()>(reps: usize, mut f: F) -> Duration {
+    let samples = (0..reps)
+        .map(|_| {
+            let start = Instant::now();
+            f();
+            start.elapsed()
+        })
+        .collect();
+    median(samples)
+}
+
+/// One side of a comparison: its row label and one rep of its work.
+pub struct TimedPath<'a> {
+    pub name: &'a str,
+    pub run: &'a mut dyn FnMut(),
+}
+
+/// Panic unless `baseline` and `candidate` produced the same results.
+/// Both paths must agree before their timings mean anything, so every
+/// binary calls this on the outputs of its two paths before [`run`].
+pub fn assert_agree<T: PartialEq + Debug>(label: &str, baseline: T, candidate: T) {
+    assert_eq!(baseline, candidate, "{label}: results differ");
+}
+
+/// Time `baseline` and `candidate` over `count` units of work (records,
+/// requests, items) and print each path's median rep, the per-unit cost,
+/// and the candidate's speedup.
+pub fn run(
+    label: &str,
+    unit: &str,
+    count: usize,
+    reps: usize,
+    baseline: TimedPath<'_>,
+    candidate: TimedPath<'_>,
+) {
+    let baseline_time = time(reps, baseline.run);
+    let candidate_time = time(reps, candidate.run);
+
+    let ns = |d: Duration| d.as_nanos() as f64 / count as f64;
+    println!("{label}");
+    for (name, t) in [
+        (baseline.name, baseline_time),
+        (candidate.name, candidate_time),
+    ] {
+        println!("  {name:<12} {t:>10.2?}  {:>10.1} ns/{unit}", ns(t));
+    }
+    println!(
+        "  {:<12} {:.2}x",
+        "speedup",
+        baseline_time.as_secs_f64() / candidate_time.as_secs_f64()
+    );
+}
@@ -3,6 +3,7 @@
 export interface ExpressionNode {
   id: number;
   expression: string;  // JSON string of sub-expression
+  pointer: string;     // JSON pointer into the submitted logic ("" for the root)
   children: ExpressionNode[];
 }
 